@@ -0,0 +1,56 @@
+//! Compares the time needed to deparse fixtures with the time needed to decode their cache
+//!
+//! Run with `cargo run --release --example cache --features cache`
+use std::convert::TryFrom;
+use std::time::Instant;
+
+use gdtf_parser::cache::ArchiveHash;
+use gdtf_parser::Gdtf;
+
+const RUNS: u32 = 20;
+
+fn main() {
+    const PATHS: [&str; 4] = [
+        "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+        "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+        "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+        "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+    ];
+    //Initializes statics like the compiled regexes, so they are not measured for the first fixture
+    for path in PATHS {
+        drop(Gdtf::try_from(std::path::Path::new(path)).unwrap());
+    }
+
+    println!(
+        "{:<45} {:>10} {:>12} {:>12}",
+        "Fixture", "Cache kB", "Deparse ms", "Decode ms"
+    );
+    for path in PATHS {
+        let bytes = std::fs::read(path).unwrap();
+        let hash = ArchiveHash::from_bytes(&bytes);
+        let cache = Gdtf::try_from(bytes.as_slice())
+            .unwrap()
+            .to_cache_bytes(&hash)
+            .unwrap();
+
+        let now = Instant::now();
+        for _ in 0..RUNS {
+            drop(Gdtf::try_from(bytes.as_slice()).unwrap());
+        }
+        let deparsing = now.elapsed() / RUNS;
+
+        let now = Instant::now();
+        for _ in 0..RUNS {
+            drop(Gdtf::from_cache_bytes(&cache, &hash).unwrap());
+        }
+        let decoding = now.elapsed() / RUNS;
+
+        println!(
+            "{:<45} {:>10.1} {:>12.2} {:>12.2}",
+            path.trim_start_matches("test/").trim_end_matches(".gdtf"),
+            cache.len() as f64 / 1024.0,
+            deparsing.as_secs_f64() * 1000.0,
+            decoding.as_secs_f64() * 1000.0
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! Measures the memory retained by deparsed fixtures and the median time needed to deparse them
+//!
+//! Run with `cargo run --release --example memory`
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::convert::TryFrom;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::Instant;
+
+use gdtf_parser::Gdtf;
+
+///Allocator that counts the bytes currently allocated and the peak since the last reset
+struct CountingAllocator;
+
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            let allocated = ALLOCATED.fetch_add(layout.size(), Ordering::Relaxed) + layout.size();
+            PEAK.fetch_max(allocated, Ordering::Relaxed);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        ALLOCATED.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const RUNS: usize = 50;
+
+fn main() {
+    const PATHS: [&str; 4] = [
+        "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+        "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+        "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+        "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+    ];
+    //Initializes statics like the compiled regexes, so they are not counted for the first fixture
+    for path in PATHS {
+        drop(Gdtf::try_from(std::path::Path::new(path)).unwrap());
+    }
+
+    println!(
+        "{:<45} {:>12} {:>12} {:>10}",
+        "Fixture", "Retained kB", "Peak kB", "Median ms"
+    );
+    for path in PATHS {
+        let bytes = std::fs::read(path).unwrap();
+
+        let before = ALLOCATED.load(Ordering::Relaxed);
+        PEAK.store(before, Ordering::Relaxed);
+        let gdtf = Gdtf::try_from(bytes.as_slice()).unwrap();
+        let retained = ALLOCATED.load(Ordering::Relaxed) - before;
+        let peak = PEAK.load(Ordering::Relaxed) - before;
+        drop(gdtf);
+
+        //The median is less affected by other processes than the mean
+        let mut times: Vec<f64> = (0..RUNS)
+            .map(|_| {
+                let now = Instant::now();
+                drop(Gdtf::try_from(bytes.as_slice()).unwrap());
+                now.elapsed().as_secs_f64()
+            })
+            .collect();
+        times.sort_by(f64::total_cmp);
+        let median = times[RUNS / 2];
+
+        println!(
+            "{:<45} {:>12.1} {:>12.1} {:>10.2}",
+            path.trim_start_matches("test/").trim_end_matches(".gdtf"),
+            retained as f64 / 1024.0,
+            peak as f64 / 1024.0,
+            median * 1000.0
+        );
+    }
+}
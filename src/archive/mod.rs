@@ -0,0 +1,266 @@
+//! Writes complete .gdtf archives containing the description.xml and all resource files
+//!
+//! A .gdtf file is a zip archive with the description.xml, the thumbnail in the root folder, the media files of wheel slots in the folder `wheels/` and 3D models in the folders `models/3ds`, `models/gltf` and `models/svg`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use std::io::Cursor;
+//! use std::path::Path;
+//! use gdtf_parser::Gdtf;
+//! use gdtf_parser::archive::GdtfArchiveWriter;
+//! use gdtf_parser::utils::errors::GdtfError;
+//!
+//! fn main() -> Result<(), GdtfError> {
+//!     let path = Path::new("test/SGM_Light@G-7_Spot@Rev_A.gdtf");
+//!     let gdtf = Gdtf::try_from(path)?;
+//!
+//!     let mut archive_writer = GdtfArchiveWriter::new(&gdtf);
+//!     archive_writer.keep_resources_from_file(path)?;
+//!     archive_writer.set_resource("G-7_RAL_black_small.png", vec![0x89, b'P', b'N', b'G']);
+//!
+//!     let mut archive = Cursor::new(Vec::new());
+//!     archive_writer.write(&mut archive)?;
+//!     Ok(())
+//! }
+//! ```
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::utils::errors::GdtfError;
+use crate::Gdtf;
+
+///Name of the file inside of the archive that contains the gdtf-xml-description
+const DESCRIPTION_XML: &str = "description.xml";
+
+///Writes a `Gdtf` together with it's resource files to a .gdtf archive
+#[derive(Debug, Clone)]
+pub struct GdtfArchiveWriter<'a> {
+    ///The Gdtf that will be written as description.xml
+    gdtf: &'a Gdtf,
+    ///The resource files with their path inside of the archive as key. Sorted so the archive is written in a stable order
+    resources: BTreeMap<String, Vec<u8>>,
+}
+
+impl<'a> GdtfArchiveWriter<'a> {
+    ///Creates a new writer for the Gdtf without any resource files
+    pub fn new(gdtf: &'a Gdtf) -> Self {
+        Self {
+            gdtf,
+            resources: BTreeMap::new(),
+        }
+    }
+
+    ///Keeps all resource files of an existing .gdtf file. Resources that were already added with the same path are replaced
+    pub fn keep_resources_from_file(&mut self, file_path: &Path) -> Result<(), GdtfError> {
+        self.keep_resources_from(File::open(file_path)?)
+    }
+
+    ///Keeps all resource files of an existing .gdtf archive. Resources that were already added with the same path are replaced
+    pub fn keep_resources_from<R: Read + Seek>(&mut self, reader: R) -> Result<(), GdtfError> {
+        let mut archive = ZipArchive::new(reader)?;
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            if file.is_dir() || file.name() == DESCRIPTION_XML {
+                continue;
+            }
+            let name = file.name().to_string();
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+            self.resources.insert(name, content);
+        }
+        Ok(())
+    }
+
+    ///Adds a resource file or replaces it if it's path is already present
+    ///
+    /// * `path` - The path inside of the archive, for example `wheels/gobo1.png` or `models/3ds/head.3ds`
+    /// * `content` - The content of the file
+    pub fn set_resource(&mut self, path: &str, content: Vec<u8>) {
+        self.resources.insert(path.to_string(), content);
+    }
+
+    ///Removes a resource file and returns it's content if it was present
+    pub fn remove_resource(&mut self, path: &str) -> Option<Vec<u8>> {
+        self.resources.remove(path)
+    }
+
+    ///Returns the paths of all resource files that will be written
+    pub fn resources(&self) -> impl Iterator<Item = &str> {
+        self.resources.keys().map(|k| k.as_str())
+    }
+
+    ///Returns the expected paths of all resources that are referenced in the description.xml but are not present.
+    ///
+    /// The thumbnail is expected as .png or .svg in the root folder, media files of wheel slots as .png in the folder `wheels/`.
+    /// Model files are expected as .3ds in `models/3ds`, as .glb in `models/gltf` or as .svg in `models/svg`, if missing the .3ds path is returned
+    pub fn missing_resources(&self) -> Vec<String> {
+        missing_resources(self.gdtf, |path| self.resources.contains_key(path))
+    }
+
+    ///Writes the archive. Returns `GdtfError::ResourceNotFoundError` without writing anything if a referenced resource is missing
+    pub fn write<W: Write + Seek>(&self, writer: W) -> Result<(), GdtfError> {
+        self.check_resources()?;
+        let mut zip = ZipWriter::new(writer);
+        let options = FileOptions::default();
+        zip.start_file(DESCRIPTION_XML, options)?;
+        self.gdtf.write_description_xml(&mut zip)?;
+        for (path, content) in self.resources.iter() {
+            zip.start_file(path.as_str(), options)?;
+            zip.write_all(content)?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+
+    ///Writes the archive to a .gdtf file. The file is only created if no referenced resource is missing
+    pub fn write_to_file(&self, file_path: &Path) -> Result<(), GdtfError> {
+        self.check_resources()?;
+        self.write(File::create(file_path)?)
+    }
+
+    ///Returns `GdtfError::ResourceNotFoundError` if a referenced resource is missing
+    fn check_resources(&self) -> Result<(), GdtfError> {
+        let missing = self.missing_resources();
+        if !missing.is_empty() {
+            return Err(GdtfError::ResourceNotFoundError(missing));
+        }
+        Ok(())
+    }
+}
+
+///Returns the expected paths of the thumbnail, the media files of wheel slots and the model files that are referenced in the Gdtf but are not present according to `contains`
+pub(crate) fn missing_resources(gdtf: &Gdtf, contains: impl Fn(&str) -> bool) -> Vec<String> {
+    let fixture_type = &gdtf.fixture_type;
+    let mut missing = Vec::new();
+    if let Some(thumbnail) = &fixture_type.thumbnail {
+        let png = format!("{}.png", thumbnail);
+        let svg = format!("{}.svg", thumbnail);
+        if !contains(&png) && !contains(&svg) {
+            missing.push(png);
+        }
+    }
+    if let Some(wheels) = &fixture_type.wheels {
+        for slot in wheels.values().flat_map(|wheel| wheel.slots.values()) {
+            if let Some(media_file_name) = &slot.media_file_name {
+                let path = format!("wheels/{}.png", media_file_name);
+                if !contains(&path) && !missing.contains(&path) {
+                    missing.push(path);
+                }
+            }
+        }
+    }
+    if let Some(models) = &fixture_type.models {
+        //The xml was already read once, only File values with invalid escapes can fail here
+        for file in models
+            .attribute_values(b"Model", b"File")
+            .unwrap_or_default()
+        {
+            if file.is_empty() {
+                continue;
+            }
+            let path = format!("models/3ds/{}.3ds", file);
+            let gltf = format!("models/gltf/{}.glb", file);
+            let svg = format!("models/svg/{}.svg", file);
+            if !contains(&path) && !contains(&gltf) && !contains(&svg) && !missing.contains(&path) {
+                missing.push(path);
+            }
+        }
+    }
+    missing.sort();
+    missing
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::io::{Cursor, Read};
+    use std::path::Path;
+
+    use crate::archive::GdtfArchiveWriter;
+    use crate::utils::errors::GdtfError;
+    use crate::Gdtf;
+
+    #[test]
+    fn test_write_keeps_resources() {
+        let path = Path::new("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf");
+        let gdtf = Gdtf::try_from(path).unwrap();
+        let mut archive_writer = GdtfArchiveWriter::new(&gdtf);
+        archive_writer.keep_resources_from_file(path).unwrap();
+        archive_writer.set_resource("wheels/201906022.png", b"replaced".to_vec());
+
+        let mut archive = Cursor::new(Vec::new());
+        archive_writer.write(&mut archive).unwrap();
+
+        let mut original = zip::ZipArchive::new(std::fs::File::open(path).unwrap()).unwrap();
+        let mut written = zip::ZipArchive::new(archive).unwrap();
+        assert_eq!(original.len(), written.len());
+        for i in 0..original.len() {
+            let mut original = original.by_index(i).unwrap();
+            let mut content = Vec::new();
+            written
+                .by_name(original.name())
+                .unwrap()
+                .read_to_end(&mut content)
+                .unwrap();
+            match original.name() {
+                "description.xml" => assert_eq!(
+                    Gdtf::from_description_xml(std::str::from_utf8(&content).unwrap()).unwrap(),
+                    gdtf
+                ),
+                "wheels/201906022.png" => assert_eq!(content, b"replaced"),
+                _ => {
+                    let mut expected = Vec::new();
+                    original.read_to_end(&mut expected).unwrap();
+                    assert_eq!(content, expected);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_missing_resources() {
+        let path = Path::new("test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf");
+        let gdtf = Gdtf::try_from(path).unwrap();
+        let mut archive_writer = GdtfArchiveWriter::new(&gdtf);
+        archive_writer.keep_resources_from_file(path).unwrap();
+        assert!(archive_writer.missing_resources().is_empty());
+
+        archive_writer.remove_resource("thumbnail.png").unwrap();
+        archive_writer
+            .remove_resource("wheels/15020327.png")
+            .unwrap();
+        assert_eq!(
+            archive_writer.missing_resources(),
+            vec!["thumbnail.png", "wheels/15020327.png"]
+        );
+        match archive_writer.write(Cursor::new(Vec::new())) {
+            Err(GdtfError::ResourceNotFoundError(missing)) => assert_eq!(missing.len(), 2),
+            other => panic!("Expected ResourceNotFoundError but got {:?}", other),
+        }
+
+        archive_writer.set_resource("thumbnail.svg", b"<svg/>".to_vec());
+        assert_eq!(
+            archive_writer.missing_resources(),
+            vec!["wheels/15020327.png"]
+        );
+
+        archive_writer
+            .remove_resource("models/3ds/head.3ds")
+            .unwrap();
+        archive_writer
+            .remove_resource("models/3ds/yoke.3ds")
+            .unwrap();
+        archive_writer.set_resource("models/gltf/yoke.glb", vec![]);
+        assert_eq!(
+            archive_writer.missing_resources(),
+            vec!["models/3ds/head.3ds", "wheels/15020327.png"]
+        );
+    }
+}
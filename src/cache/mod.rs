@@ -0,0 +1,235 @@
+//! Versioned binary encoding of a deparsed `Gdtf` to cache it, for example between application starts
+//!
+//! Decoding a cache is a lot faster than deparsing the description.xml again. Each cache starts with the format version and the SHA-256 hash of the .gdtf archive it was deparsed from. A cache written by an other version of the format or for an archive that changed since is rejected, so it can be replaced by deparsing the archive again. The example `cache` compares both times for the fixtures in the folder `test`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use std::fs::File;
+//! use std::path::Path;
+//! use gdtf_parser::Gdtf;
+//! use gdtf_parser::cache::{ArchiveHash, GdtfCacheError};
+//! use gdtf_parser::utils::errors::GdtfError;
+//!
+//! fn main() -> Result<(), GdtfError> {
+//!     let path = Path::new("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf");
+//!     let hash = ArchiveHash::from_reader(File::open(path)?)?;
+//!
+//!     let gdtf = Gdtf::try_from(path)?;
+//!     let cache = gdtf.to_cache_bytes(&hash)?;
+//!     assert_eq!(Gdtf::from_cache_bytes(&cache, &hash)?, gdtf);
+//!
+//!     let other_hash = ArchiveHash::from_bytes(b"An other archive");
+//!     assert!(matches!(
+//!         Gdtf::from_cache_bytes(&cache, &other_hash),
+//!         Err(GdtfError::CacheError(GdtfCacheError::ArchiveChanged))
+//!     ));
+//!     Ok(())
+//! }
+//! ```
+use std::convert::TryInto;
+use std::error::Error;
+use std::fmt;
+use std::io::Read;
+
+use bincode::Options;
+use sha2::{Digest, Sha256};
+
+use crate::utils::errors::GdtfError;
+use crate::utils::units::name;
+use crate::Gdtf;
+
+///Version of the binary format. Must be increased whenever the model or it's encoding changes
+pub const CACHE_VERSION: u16 = 1;
+
+///Bytes every cache starts with
+const MAGIC: &[u8; 4] = b"GDTC";
+
+///Length of magic, version and hash in front of the encoded Gdtf
+const HEADER_LEN: usize = MAGIC.len() + 2 + 32;
+
+///SHA-256 hash of a .gdtf archive, stored in a cache to detect if the archive changed
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct ArchiveHash(pub [u8; 32]);
+
+impl ArchiveHash {
+    ///Hashes the archive read from the reader
+    pub fn from_reader<R: Read>(mut reader: R) -> Result<Self, GdtfError> {
+        let mut hasher = Sha256::new();
+        let mut buf = [0; 8192];
+        loop {
+            match reader.read(&mut buf)? {
+                0 => return Ok(Self(hasher.finalize().into())),
+                n => hasher.update(&buf[..n]),
+            }
+        }
+    }
+
+    ///Hashes an archive in memory
+    /// ```rust
+    /// use gdtf_parser::cache::ArchiveHash;
+    /// assert_eq!(ArchiveHash::from_bytes(b"archive"), ArchiveHash::from_reader(&b"archive"[..]).unwrap());
+    /// assert_ne!(ArchiveHash::from_bytes(b"archive"), ArchiveHash::from_bytes(b"other archive"));
+    /// ```
+    pub fn from_bytes(archive: &[u8]) -> Self {
+        Self(Sha256::digest(archive).into())
+    }
+
+    ///Returns the hash of the archive a cache was written for, without decoding it
+    pub fn from_cache_bytes(cache: &[u8]) -> Result<Self, GdtfError> {
+        let header = cache
+            .get(..HEADER_LEN)
+            .filter(|header| header.starts_with(MAGIC))
+            .ok_or(GdtfCacheError::NotACache)?;
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != CACHE_VERSION {
+            return Err(GdtfCacheError::UnsupportedVersion(version).into());
+        }
+        //The slice is always 32 bytes long, the header length was checked above
+        Ok(Self(header[6..].try_into().unwrap()))
+    }
+}
+
+impl Gdtf {
+    ///Encodes the Gdtf in the binary cache format. The hash of the archive it was deparsed from is stored with it
+    pub fn to_cache_bytes(&self, archive_hash: &ArchiveHash) -> Result<Vec<u8>, GdtfError> {
+        let mut out = Vec::with_capacity(HEADER_LEN);
+        out.extend_from_slice(MAGIC);
+        out.extend_from_slice(&CACHE_VERSION.to_le_bytes());
+        out.extend_from_slice(&archive_hash.0);
+        options()
+            .serialize_into(&mut out, self)
+            .map_err(GdtfCacheError::EncodingError)?;
+        Ok(out)
+    }
+
+    ///Decodes a Gdtf from the binary cache format.
+    ///
+    ///Returns `GdtfCacheError::UnsupportedVersion` if the cache was written by an other version of the format and `GdtfCacheError::ArchiveChanged` if it was written for an archive with an other hash
+    pub fn from_cache_bytes(cache: &[u8], archive_hash: &ArchiveHash) -> Result<Self, GdtfError> {
+        if ArchiveHash::from_cache_bytes(cache)? != *archive_hash {
+            return Err(GdtfCacheError::ArchiveChanged.into());
+        }
+        let encoded = &cache[HEADER_LEN..];
+        name::interning(|| {
+            options()
+                .with_limit(encoded.len() as u64)
+                .deserialize(encoded)
+                .map_err(|e| GdtfCacheError::EncodingError(e).into())
+        })
+    }
+}
+
+///Options of bincode used for the encoded Gdtf
+fn options() -> impl Options {
+    bincode::DefaultOptions::new().reject_trailing_bytes()
+}
+
+#[derive(Debug)]
+///Error that occurs if a cache can not be decoded or is outdated
+pub enum GdtfCacheError {
+    ///The bytes are not a cache or are truncated
+    NotACache,
+    ///The cache was written by an other version of the format. Contains the version of the cache
+    UnsupportedVersion(u16),
+    ///The cache was written for an archive with an other hash
+    ArchiveChanged,
+    ///The Gdtf could not be encoded or decoded
+    EncodingError(bincode::Error),
+}
+
+impl fmt::Display for GdtfCacheError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GdtfCacheError::NotACache => write!(f, "The bytes are not a cache of a Gdtf"),
+            GdtfCacheError::UnsupportedVersion(version) => write!(
+                f,
+                "The cache has version {} but version {} is supported",
+                version, CACHE_VERSION
+            ),
+            GdtfCacheError::ArchiveChanged => {
+                write!(f, "The cache was written for an other archive")
+            }
+            GdtfCacheError::EncodingError(e) => write!(f, "The cache is invalid: {}", e),
+        }
+    }
+}
+
+impl Error for GdtfCacheError {}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::fs;
+    use std::path::Path;
+
+    use crate::cache::{ArchiveHash, GdtfCacheError, CACHE_VERSION};
+    use crate::utils::errors::GdtfError;
+    use crate::Gdtf;
+
+    #[test]
+    fn test_round_trip() {
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let archive = fs::read(path).unwrap();
+            let hash = ArchiveHash::from_bytes(&archive);
+            let gdtf = Gdtf::try_from(Path::new(path)).unwrap();
+            let cache = gdtf.to_cache_bytes(&hash).unwrap();
+            let cached = Gdtf::from_cache_bytes(&cache, &hash).unwrap();
+            assert_eq!(gdtf, cached, "Cache of {} is not lossless", path);
+            assert_eq!(ArchiveHash::from_cache_bytes(&cache).unwrap(), hash);
+        }
+    }
+
+    #[test]
+    fn test_invalid() {
+        let gdtf = Gdtf::try_from(Path::new("test/SGM_Light@G-7_Spot@Rev_A.gdtf")).unwrap();
+        let hash = ArchiveHash::from_bytes(b"archive");
+        let cache = gdtf.to_cache_bytes(&hash).unwrap();
+
+        let error = |cache: &[u8]| match Gdtf::from_cache_bytes(cache, &hash) {
+            Err(GdtfError::CacheError(e)) => e,
+            result => panic!("Expected a cache error but got {:?}", result),
+        };
+        assert!(matches!(error(&cache[..20]), GdtfCacheError::NotACache));
+        assert!(matches!(error(b"<?xml"), GdtfCacheError::NotACache));
+        assert!(matches!(
+            Gdtf::from_cache_bytes(&cache, &ArchiveHash::from_bytes(b"changed archive")),
+            Err(GdtfError::CacheError(GdtfCacheError::ArchiveChanged))
+        ));
+
+        let mut other_version = cache.clone();
+        other_version[4..6].copy_from_slice(&(CACHE_VERSION + 1).to_le_bytes());
+        assert!(matches!(
+            error(&other_version),
+            GdtfCacheError::UnsupportedVersion(version) if version == CACHE_VERSION + 1
+        ));
+
+        assert!(matches!(
+            error(&cache[..cache.len() - 1]),
+            GdtfCacheError::EncodingError(_)
+        ));
+        let mut trailing = cache.clone();
+        trailing.push(0);
+        assert!(matches!(error(&trailing), GdtfCacheError::EncodingError(_)));
+
+        //Corrupted caches must return an error or a Gdtf, but never panic
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            let mut corrupted = cache.clone();
+            for _ in 0..4 {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                let index = 38 + state as usize % (corrupted.len() - 38);
+                corrupted[index] = (state >> 32) as u8;
+            }
+            let _ = Gdtf::from_cache_bytes(&corrupted, &hash);
+        }
+    }
+}
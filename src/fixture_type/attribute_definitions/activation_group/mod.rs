@@ -13,9 +13,11 @@ use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
+use crate::utils::write::WriteGdtf;
 
 ///ActivationGroup only contains one attribute Name, so only this primary keys are stored in a vec in AttributeDefinitions
 #[derive(Debug, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActivationGroup {}
 
 impl ReadGdtf for ActivationGroup {
@@ -34,7 +36,7 @@ impl ReadGdtf for ActivationGroup {
         Ok(Some(Name::new_from_attr(attr)?))
     }
     fn read_any_attribute(_: &mut Self::DataHolder, _: Attribute<'_>) -> Result<(), Self::Error> {
-        panic!("Should not be used!");
+        Ok(())
     }
 
     fn read_any_child(
@@ -43,14 +45,18 @@ impl ReadGdtf for ActivationGroup {
         _: BytesStart<'_>,
         _: bool,
     ) -> Result<(), Self::Error> {
-        panic!("Should not be used!");
+        Ok(())
     }
 
-    fn move_data(_: Self::DataHolder) -> Result<Self, Self::Error> {
-        panic!("Should not be used!");
+    fn move_data(data_holder: Self::DataHolder) -> Result<Self, Self::Error> {
+        Ok(data_holder)
     }
 }
 
+impl WriteGdtf for ActivationGroup {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+}
+
 #[cfg(test)]
 impl TestReadGdtf for ActivationGroup {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -15,9 +15,13 @@ use crate::utils::units::color_cie::ColorCie;
 use crate::utils::units::name::Name;
 use crate::utils::units::node::Node;
 use crate::utils::units::physical_unit::PhysicalUnit;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///Describes a singular mutual exclusive control function
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Attribute {
     /// The pretty name of the attribute
     pub pretty: String,
@@ -89,7 +93,12 @@ impl ReadGdtf for Attribute {
             b"Feature" => data_holder.feature = Node::new_from_attr(attr)?,
             b"MainAttribute" => data_holder.main_attribute = Node::new_from_attr(attr)?,
             b"PhysicalUnit" => data_holder.physical_unit = Some(PhysicalUnit::new_from_attr(attr)),
-            b"Color" => data_holder.color = ColorCie::new_from_attr(attr).ok(),
+            b"Color" => {
+                data_holder.color = ColorCie::new_from_attr(attr).ok();
+                if data_holder.color.is_none() {
+                    warnings::dropped();
+                }
+            }
             _ => {}
         }
         Ok(())
@@ -105,6 +114,17 @@ impl ReadGdtf for Attribute {
     }
 }
 
+impl WriteGdtf for Attribute {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Pretty", &self.pretty);
+        write::push_attribute_option(event, b"ActivationGroup", &self.activation_group);
+        write::push_attribute(event, b"Feature", &self.feature);
+        write::push_attribute_option(event, b"MainAttribute", &self.main_attribute);
+        write::push_attribute(event, b"PhysicalUnit", &self.physical_unit);
+        write::push_attribute_option(event, b"Color", &self.color);
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Attribute {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
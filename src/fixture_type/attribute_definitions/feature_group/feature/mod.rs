@@ -13,9 +13,11 @@ use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
+use crate::utils::write::WriteGdtf;
 
 ///Feature only contains one feature Name, so only this primary keys are stored in a vec in FeatureGroup
 #[derive(Default, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Feature {}
 
 impl ReadGdtf for Feature {
@@ -35,7 +37,7 @@ impl ReadGdtf for Feature {
     }
 
     fn read_any_attribute(_: &mut Self::DataHolder, _: Attribute<'_>) -> Result<(), Self::Error> {
-        panic!("Should not be executed");
+        Ok(())
     }
 
     fn read_any_child(
@@ -44,14 +46,18 @@ impl ReadGdtf for Feature {
         _: BytesStart<'_>,
         _: bool,
     ) -> Result<(), Self::Error> {
-        panic!("Should not be executed");
+        Ok(())
     }
 
-    fn move_data(_: Self::DataHolder) -> Result<Self, Self::Error> {
-        panic!("Should not be executed");
+    fn move_data(data_holder: Self::DataHolder) -> Result<Self, Self::Error> {
+        Ok(data_holder)
     }
 }
 
+impl WriteGdtf for Feature {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Feature {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
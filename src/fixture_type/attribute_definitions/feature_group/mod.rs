@@ -3,10 +3,11 @@
 //!
 
 use std::fmt::Debug;
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::attribute_definitions::feature_group::feature::Feature;
 use crate::utils::errors::GdtfError;
@@ -15,10 +16,13 @@ use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub(crate) mod feature;
 
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Groups the logical control elements called Feature into a structured way for easier access and finding
 pub struct FeatureGroup {
     /// The pretty name of the feature group
@@ -73,6 +77,20 @@ impl ReadGdtf for FeatureGroup {
     }
 }
 
+impl WriteGdtf for FeatureGroup {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Pretty", &self.pretty);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.features.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        Feature::write_primary_keys(&self.features, writer)
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for FeatureGroup {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -1,9 +1,10 @@
 //! Defines the attribute definitions for the Fixture Type Attributes.
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::io::Write;
 
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::attribute_definitions::activation_group::ActivationGroup;
 use crate::fixture_type::attribute_definitions::attribute::Attribute;
@@ -15,12 +16,14 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::attribute_name::AttributeName;
 use crate::utils::units::name::Name;
+use crate::utils::write::WriteGdtf;
 
 pub(crate) mod activation_group;
 pub mod attribute;
 pub mod feature_group;
 
 #[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Defines the attribute definitions for the Fixture Type Attributes.
 pub struct AttributeDefinitions {
     ///Describes the logical grouping of attributes. For example, Gobo 1 and Gobo 2 are grouped in the feature Gobo of the feature group Gobo.
@@ -47,7 +50,7 @@ impl ReadGdtf for AttributeDefinitions {
     fn read_primary_key_from_attr(
         _: quick_xml::events::attributes::Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed");
+        Ok(None)
     }
 
     fn read_any_attribute(
@@ -86,6 +89,20 @@ impl ReadGdtf for AttributeDefinitions {
     }
 }
 
+impl WriteGdtf for AttributeDefinitions {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        FeatureGroup::write_hash_map(&self.feature_groups, writer)?;
+        Attribute::write_hash_map(&self.attributes, writer)?;
+        ActivationGroup::write_primary_key_vec(&self.activation_groups, writer)
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for AttributeDefinitions {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
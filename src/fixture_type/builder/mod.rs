@@ -0,0 +1,1285 @@
+//! Fluent builders to create a fixture type without a GDTF file
+//!
+//! The builders apply the defaults of the GDTF specification for all values that are not set. Names and links are passed as strings and are validated on `build()`. `FixtureTypeBuilder::build` also checks that all links between the nodes point to existing nodes and that DMXFrom of the channel sets is ascending inside of their channel function.
+//!
+//! Geometries are not deparsed and are passed as xml with `FixtureTypeBuilder::geometries`. Without them one top level geometry is created for each geometry of the DMX modes, containing the geometries of their DMX channels.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use gdtf_parser::fixture_type::builder::{
+//!     ChannelFunctionBuilder, ChannelSetBuilder, DmxChannelBuilder, DmxModeBuilder,
+//!     FixtureTypeBuilder, LogicalChannelBuilder, SlotBuilder, WheelBuilder,
+//! };
+//! use gdtf_parser::utils::units::dmx_value::DmxValue;
+//! use gdtf_parser::utils::units::guid::Guid;
+//!
+//! let fixture_type = FixtureTypeBuilder::new("Prototype", "ACME", Guid::dummy())
+//!     .attribute("Pan")
+//!     .attribute("Color1")
+//!     .wheel(
+//!         WheelBuilder::new("Colors")
+//!             .slot(SlotBuilder::new("Open"))
+//!             .slot(SlotBuilder::new("Red")),
+//!     )
+//!     .dmx_mode(
+//!         DmxModeBuilder::new("Mode 1", "Base")
+//!             .dmx_channel(
+//!                 DmxChannelBuilder::new("Yoke").offset(&[1, 2]).logical_channel(
+//!                     LogicalChannelBuilder::new("Pan").channel_function(
+//!                         ChannelFunctionBuilder::new("Pan").physical_range(-270.0, 270.0),
+//!                     ),
+//!                 ),
+//!             )
+//!             .dmx_channel(
+//!                 DmxChannelBuilder::new("Base").offset(&[3]).logical_channel(
+//!                     LogicalChannelBuilder::new("Color1").channel_function(
+//!                         ChannelFunctionBuilder::new("Color1")
+//!                             .wheel("Colors")
+//!                             .channel_set(ChannelSetBuilder::new("Open", DmxValue::new_from_str("0/1").unwrap()).wheel_slot_index(1))
+//!                             .channel_set(ChannelSetBuilder::new("Red", DmxValue::new_from_str("128/1").unwrap()).wheel_slot_index(2)),
+//!                     ),
+//!                 ),
+//!             ),
+//!     )
+//!     .build()
+//!     .unwrap();
+//!
+//! let mode = &fixture_type.dmx_modes[&gdtf_parser::utils::units::name::Name::new("Mode 1").unwrap()];
+//! assert_eq!(mode.dmx_channels[0].initial_function.as_ref().unwrap().to_string(), "Yoke_Pan.Pan.Pan");
+//! ```
+use std::collections::HashMap;
+
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::Writer;
+
+use crate::fixture_type::attribute_definitions::attribute::Attribute;
+use crate::fixture_type::attribute_definitions::feature_group::FeatureGroup;
+use crate::fixture_type::attribute_definitions::AttributeDefinitions;
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::channel_set::ChannelSet;
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::{
+    Attribute as FunctionAttribute, ChannelFunction, ModeMaster, DEFAULT_DMX_DEFAULT,
+    DEFAULT_DMX_FROM,
+};
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::{LogicalChannel, Master, Snap};
+use crate::fixture_type::dmx_mode::dmx_channel::{DmxBreak, DmxChannel, Offset};
+use crate::fixture_type::dmx_mode::DmxMode;
+use crate::fixture_type::physical_descriptions::PhysicalDescriptions;
+use crate::fixture_type::wheel::slot::animation_system::AnimationSystem;
+use crate::fixture_type::wheel::slot::facet::Facet;
+use crate::fixture_type::wheel::slot::Slot;
+use crate::fixture_type::wheel::Wheel;
+use crate::fixture_type::{CanHaveChildren, FixtureType};
+use crate::utils::errors::GdtfError;
+use crate::utils::raw_xml::RawXml;
+use crate::utils::read::ChildMap;
+use crate::utils::units::attribute_name::AttributeName;
+use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+use crate::utils::units::dmx_value::DmxValue;
+use crate::utils::units::guid::Guid;
+use crate::utils::units::name::Name;
+use crate::utils::units::node::Node;
+use crate::utils::units::resource::Resource;
+use crate::utils::write;
+use crate::validate::{self, Geometries};
+
+///Builds a FixtureType. DMX modes and wheels are built with it and all links are validated
+#[derive(Debug, Clone)]
+pub struct FixtureTypeBuilder {
+    name: String,
+    short_name: Option<String>,
+    long_name: Option<String>,
+    manufacturer: String,
+    description: String,
+    fixture_type_id: Guid,
+    thumbnail: Option<String>,
+    ref_ft: Option<Guid>,
+    can_have_children: CanHaveChildren,
+    attribute_definitions: AttributeDefinitions,
+    attributes: Vec<String>,
+    wheels: Vec<WheelBuilder>,
+    physical_descriptions: Option<PhysicalDescriptions>,
+    geometries: Option<RawXml>,
+    dmx_modes: Vec<DmxModeBuilder>,
+}
+
+impl FixtureTypeBuilder {
+    ///Creates a builder for a fixture type. ShortName and LongName default to the name
+    pub fn new(name: &str, manufacturer: &str, fixture_type_id: Guid) -> Self {
+        Self {
+            name: name.to_string(),
+            short_name: None,
+            long_name: None,
+            manufacturer: manufacturer.to_string(),
+            description: String::new(),
+            fixture_type_id,
+            thumbnail: None,
+            ref_ft: None,
+            can_have_children: CanHaveChildren::default(),
+            attribute_definitions: AttributeDefinitions::default(),
+            attributes: Vec::new(),
+            wheels: Vec::new(),
+            physical_descriptions: None,
+            geometries: None,
+            dmx_modes: Vec::new(),
+        }
+    }
+
+    ///Sets the ShortName. Defaults to the name
+    pub fn short_name(mut self, short_name: &str) -> Self {
+        self.short_name = Some(short_name.to_string());
+        self
+    }
+
+    ///Sets the LongName. Defaults to the name
+    pub fn long_name(mut self, long_name: &str) -> Self {
+        self.long_name = Some(long_name.to_string());
+        self
+    }
+
+    ///Sets the description of the fixture type. Defaults to an empty string
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = description.to_string();
+        self
+    }
+
+    ///File name of the thumbnail without extension
+    pub fn thumbnail(mut self, thumbnail: &str) -> Self {
+        self.thumbnail = Some(thumbnail.to_string());
+        self
+    }
+
+    ///Sets the GUID of the referenced fixture type
+    pub fn ref_ft(mut self, ref_ft: Guid) -> Self {
+        self.ref_ft = Some(ref_ft);
+        self
+    }
+
+    ///Sets if the fixture type can have children in a MVR. Defaults to yes
+    pub fn can_have_children(mut self, can_have_children: CanHaveChildren) -> Self {
+        self.can_have_children = can_have_children;
+        self
+    }
+
+    ///Sets the attribute definitions. Attributes added with `attribute` are added to them on `build()`
+    pub fn attribute_definitions(mut self, attribute_definitions: AttributeDefinitions) -> Self {
+        self.attribute_definitions = attribute_definitions;
+        self
+    }
+
+    ///Adds an attribute predefined in the GDTF specification together with it's feature group and feature. User defined attributes must be added with `attribute_definitions`
+    pub fn attribute(mut self, attribute: &str) -> Self {
+        self.attributes.push(attribute.to_string());
+        self
+    }
+
+    ///Sets the physical descriptions containing the emitters and filters linked by wheels and channel functions
+    pub fn physical_descriptions(mut self, physical_descriptions: PhysicalDescriptions) -> Self {
+        self.physical_descriptions = Some(physical_descriptions);
+        self
+    }
+
+    ///Sets the xml of the node Geometries. Geometries linked by DMX modes and DMX channels are checked on `build()`. Defaults to the geometries used by the DMX modes
+    pub fn geometries(mut self, geometries: RawXml) -> Self {
+        self.geometries = Some(geometries);
+        self
+    }
+
+    ///Adds a wheel. The wheels are built and checked for unique names on `build()`
+    pub fn wheel(mut self, wheel: WheelBuilder) -> Self {
+        self.wheels.push(wheel);
+        self
+    }
+
+    ///Adds a DMX mode. The DMX modes are built and checked for unique names on `build()`
+    pub fn dmx_mode(mut self, dmx_mode: DmxModeBuilder) -> Self {
+        self.dmx_modes.push(dmx_mode);
+        self
+    }
+
+    ///Builds the fixture type and checks that the attributes, wheels, emitters, filters and geometries linked in DMX modes and wheels exist
+    pub fn build(self) -> Result<FixtureType, GdtfError> {
+        let geometries = match self.geometries {
+            Some(geometries) => geometries,
+            None => default_geometries(&self.dmx_modes)?,
+        };
+        let mut attribute_definitions = self.attribute_definitions;
+        for attribute in self.attributes.iter() {
+            add_predefined_attribute(&mut attribute_definitions, attribute)?;
+        }
+        let wheels: HashMap<Name, Wheel> =
+            build_map("Wheels", self.wheels.into_iter().map(WheelBuilder::build))?;
+        let dmx_modes = build_map(
+            "DMXModes",
+            self.dmx_modes.into_iter().map(DmxModeBuilder::build),
+        )?;
+        let fixture_type = FixtureType {
+            name: Name::new(&self.name)?,
+            short_name: self.short_name.unwrap_or_else(|| self.name.clone()),
+            long_name: self.long_name.unwrap_or_else(|| self.name.clone()),
+            manufacturer: self.manufacturer,
+            description: self.description,
+            fixture_type_id: self.fixture_type_id,
+            thumbnail: self.thumbnail.map(Resource),
+            ref_ft: self.ref_ft,
+            can_have_children: self.can_have_children,
+            attribute_definitions,
+            wheels: if wheels.is_empty() {
+                None
+            } else {
+                Some(wheels)
+            },
+            physical_descriptions: self.physical_descriptions,
+            models: None,
+            geometries: Some(geometries),
+            revisions: None,
+            ft_presets: None,
+            protocols: None,
+            dmx_modes,
+        };
+        validate_links(&fixture_type)?;
+        Ok(fixture_type)
+    }
+}
+
+///Builds a DmxMode. The Name of the mode is returned together with the mode on `build()`
+#[derive(Debug, Clone)]
+pub struct DmxModeBuilder {
+    name: String,
+    geometry: String,
+    dmx_channels: Vec<DmxChannelBuilder>,
+}
+
+impl DmxModeBuilder {
+    ///Creates a builder for a DMX mode with the name of it's top level geometry
+    pub fn new(name: &str, geometry: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            geometry: geometry.to_string(),
+            dmx_channels: Vec::new(),
+        }
+    }
+
+    ///Adds a DMX channel. The order of the DMX channels is kept
+    pub fn dmx_channel(mut self, dmx_channel: DmxChannelBuilder) -> Self {
+        self.dmx_channels.push(dmx_channel);
+        self
+    }
+
+    ///Builds the DMX mode and checks that the names of the DMX channels are unique and that InitialFunction and ModeMaster link to DMX channels and channel functions of the mode
+    pub fn build(self) -> Result<(Name, DmxMode), GdtfError> {
+        let name = Name::new(&self.name)?;
+        let dmx_channels = self
+            .dmx_channels
+            .into_iter()
+            .map(DmxChannelBuilder::build)
+            .collect::<Result<Vec<DmxChannel>, GdtfError>>()?;
+        let path = format!("DMXModes.{}", name);
+        let mut names: Vec<Name> = Vec::new();
+        for dmx_channel in dmx_channels.iter() {
+            let channel_name = dmx_channel.name();
+            if names.contains(&channel_name) {
+                return Err(GdtfError::BuilderDuplicateNameError(
+                    path,
+                    channel_name.to_string(),
+                ));
+            }
+            names.push(channel_name);
+        }
+        for dmx_channel in dmx_channels.iter() {
+            let channel_path = format!("{}.{}", path, dmx_channel.name());
+            if let Some(initial_function) = &dmx_channel.initial_function {
+                if !is_channel_function(&dmx_channels, initial_function) {
+                    return Err(link_not_found(
+                        channel_path,
+                        "InitialFunction",
+                        initial_function,
+                    ));
+                }
+            }
+            for (logical_channel, function_name, channel_function) in functions(dmx_channel) {
+                let mode_master = match &channel_function.mode_master {
+                    Some(mode_master) => &mode_master.mode_master,
+                    None => continue,
+                };
+                let is_dmx_channel = mode_master.0.len() == 1 && names.contains(&mode_master.0[0]);
+                if !is_dmx_channel && !is_channel_function(&dmx_channels, mode_master) {
+                    return Err(link_not_found(
+                        format!(
+                            "{}.{}.{}",
+                            channel_path, logical_channel.attribute, function_name
+                        ),
+                        "ModeMaster",
+                        mode_master,
+                    ));
+                }
+            }
+        }
+        Ok((
+            name,
+            DmxMode {
+                geometry: Name::new(&self.geometry)?,
+                dmx_channels,
+                relations: HashMap::new(),
+                ft_macros: HashMap::new(),
+            },
+        ))
+    }
+}
+
+///Builds a DmxChannel
+#[derive(Debug, Clone)]
+pub struct DmxChannelBuilder {
+    geometry: String,
+    dmx_break: DmxBreak,
+    offset: Option<Offset>,
+    initial_function: Option<String>,
+    highlight: Option<DmxValue>,
+    logical_channels: Vec<LogicalChannelBuilder>,
+}
+
+impl DmxChannelBuilder {
+    ///Creates a builder for a DMX channel that controls the given geometry. DMXBreak defaults to 1 and the channel is virtual until an offset is set
+    pub fn new(geometry: &str) -> Self {
+        Self {
+            geometry: geometry.to_string(),
+            dmx_break: DmxBreak::Value(1),
+            offset: None,
+            initial_function: None,
+            highlight: None,
+            logical_channels: Vec::new(),
+        }
+    }
+
+    ///Sets the DMXBreak of the channel. Defaults to 1
+    pub fn dmx_break(mut self, dmx_break: DmxBreak) -> Self {
+        self.dmx_break = dmx_break;
+        self
+    }
+
+    ///Sets the relative addresses of the channel from highest to least significant byte
+    pub fn offset(mut self, offset: &[i32]) -> Self {
+        self.offset = Some(Offset(offset.to_vec()));
+        self
+    }
+
+    ///Sets the link to the channel function activated by default in the format `DMXChannel.LogicalChannel.ChannelFunction`. Defaults to the first channel function of the first logical channel
+    pub fn initial_function(mut self, initial_function: &str) -> Self {
+        self.initial_function = Some(initial_function.to_string());
+        self
+    }
+
+    ///Sets the DMX value used to highlight the fixture
+    pub fn highlight(mut self, highlight: DmxValue) -> Self {
+        self.highlight = Some(highlight);
+        self
+    }
+
+    ///Adds a logical channel. The first logical channel contains the default initial function
+    pub fn logical_channel(mut self, logical_channel: LogicalChannelBuilder) -> Self {
+        self.logical_channels.push(logical_channel);
+        self
+    }
+
+    ///Builds the DMX channel. A link set with `initial_function` is only validated when the channel is built with it's DMX mode
+    pub fn build(self) -> Result<DmxChannel, GdtfError> {
+        let default_function = self.logical_channels.first().and_then(|logical_channel| {
+            logical_channel
+                .channel_functions
+                .first()
+                .map(|channel_function| {
+                    (
+                        logical_channel.attribute.clone(),
+                        channel_function.name.clone(),
+                    )
+                })
+        });
+        let mut dmx_channel = DmxChannel {
+            dmx_break: self.dmx_break,
+            offset: self.offset,
+            initial_function: None,
+            highlight: self.highlight,
+            geometry: Name::new(&self.geometry)?,
+            logical_channels: self
+                .logical_channels
+                .into_iter()
+                .map(LogicalChannelBuilder::build)
+                .collect::<Result<Vec<LogicalChannel>, GdtfError>>()?,
+        };
+        dmx_channel.initial_function = match (self.initial_function, default_function) {
+            (Some(initial_function), _) => Node::new_from_str(&initial_function)?,
+            (None, Some((attribute, function))) => Node::new_from_str(&format!(
+                "{}.{}.{}",
+                dmx_channel.name(),
+                attribute,
+                function
+            ))?,
+            (None, None) => None,
+        };
+        Ok(dmx_channel)
+    }
+}
+
+///Builds a LogicalChannel
+#[derive(Debug, Clone)]
+pub struct LogicalChannelBuilder {
+    attribute: String,
+    snap: Snap,
+    master: Master,
+    mib_fade: f32,
+    dmx_change_time_limit: f32,
+    channel_functions: Vec<ChannelFunctionBuilder>,
+}
+
+impl LogicalChannelBuilder {
+    ///Creates a builder for a logical channel with the link to it's attribute
+    pub fn new(attribute: &str) -> Self {
+        Self {
+            attribute: attribute.to_string(),
+            snap: Snap::No,
+            master: Master::None,
+            mib_fade: 0_f32,
+            dmx_change_time_limit: 0_f32,
+            channel_functions: Vec::new(),
+        }
+    }
+
+    ///Sets if the logical channel jumps directly to the new value. Defaults to no
+    pub fn snap(mut self, snap: Snap) -> Self {
+        self.snap = snap;
+        self
+    }
+
+    ///Sets the relation of the logical channel to the master dimmer. Defaults to none
+    pub fn master(mut self, master: Master) -> Self {
+        self.master = master;
+        self
+    }
+
+    ///Sets the move in black fade time in seconds. Defaults to 0
+    pub fn mib_fade(mut self, mib_fade: f32) -> Self {
+        self.mib_fade = mib_fade;
+        self
+    }
+
+    ///Sets the minimum time between two DMX changes in seconds. Defaults to 0
+    pub fn dmx_change_time_limit(mut self, dmx_change_time_limit: f32) -> Self {
+        self.dmx_change_time_limit = dmx_change_time_limit;
+        self
+    }
+
+    ///Adds a channel function. The channel functions are built and checked for unique names on `build()`
+    pub fn channel_function(mut self, channel_function: ChannelFunctionBuilder) -> Self {
+        self.channel_functions.push(channel_function);
+        self
+    }
+
+    ///Builds the logical channel and checks that the names of the channel functions are unique
+    pub fn build(self) -> Result<LogicalChannel, GdtfError> {
+        let attribute = Node::new_from_str(&self.attribute)?.unwrap_or(Node(vec![]));
+        let channel_functions = build_map(
+            &attribute.to_string(),
+            self.channel_functions.into_iter().map(|channel_function| {
+                match channel_function.attribute {
+                    Some(_) => channel_function,
+                    None => channel_function.attribute(&self.attribute),
+                }
+                .build()
+            }),
+        )?;
+        Ok(LogicalChannel {
+            attribute,
+            snap: self.snap,
+            master: self.master,
+            mib_fade: self.mib_fade,
+            dmx_change_time_limit: self.dmx_change_time_limit,
+            channel_functions,
+        })
+    }
+}
+
+///Builds a ChannelFunction. The Name of the channel function is returned together with the channel function on `build()`
+#[derive(Debug, Clone)]
+pub struct ChannelFunctionBuilder {
+    name: String,
+    attribute: Option<String>,
+    original_attribute: String,
+    dmx_from: DmxValue,
+    default: DmxValue,
+    physical_from: f32,
+    physical_to: f32,
+    real_fade: f32,
+    real_acceleration: f32,
+    wheel: Option<String>,
+    emitter: Option<String>,
+    filter: Option<String>,
+    mode_master: Option<(String, DmxValue, DmxValue)>,
+    channel_sets: Vec<ChannelSetBuilder>,
+}
+
+impl ChannelFunctionBuilder {
+    ///Creates a builder for a channel function. DMXFrom and Default default to 0/1 and the physical range to 0..1
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            attribute: None,
+            original_attribute: String::new(),
+            dmx_from: DEFAULT_DMX_FROM,
+            default: DEFAULT_DMX_DEFAULT,
+            physical_from: 0_f32,
+            physical_to: 1_f32,
+            real_fade: 0_f32,
+            real_acceleration: 0_f32,
+            wheel: None,
+            emitter: None,
+            filter: None,
+            mode_master: None,
+            channel_sets: Vec::new(),
+        }
+    }
+
+    ///Sets the link to the attribute. `NoFeature` is allowed. Defaults to the attribute of the logical channel if built with it, else to NoFeature
+    pub fn attribute(mut self, attribute: &str) -> Self {
+        self.attribute = Some(attribute.to_string());
+        self
+    }
+
+    ///Sets the name of the attribute in the original device. Defaults to an empty string
+    pub fn original_attribute(mut self, original_attribute: &str) -> Self {
+        self.original_attribute = original_attribute.to_string();
+        self
+    }
+
+    ///Sets the DMX value where the channel function starts
+    pub fn dmx_from(mut self, dmx_from: DmxValue) -> Self {
+        self.dmx_from = dmx_from;
+        self
+    }
+
+    ///Sets the default DMX value of the channel function
+    pub fn default(mut self, default: DmxValue) -> Self {
+        self.default = default;
+        self
+    }
+
+    ///Sets the physical values at the start and the end of the channel function. Defaults to 0..1
+    pub fn physical_range(mut self, physical_from: f32, physical_to: f32) -> Self {
+        self.physical_from = physical_from;
+        self.physical_to = physical_to;
+        self
+    }
+
+    ///Sets the time in seconds to move from the start to the end of the physical range. Defaults to 0
+    pub fn real_fade(mut self, real_fade: f32) -> Self {
+        self.real_fade = real_fade;
+        self
+    }
+
+    ///Sets the time in seconds to accelerate from stop to the maximum velocity. Defaults to 0
+    pub fn real_acceleration(mut self, real_acceleration: f32) -> Self {
+        self.real_acceleration = real_acceleration;
+        self
+    }
+
+    ///Sets the link to a wheel of the fixture type
+    pub fn wheel(mut self, wheel: &str) -> Self {
+        self.wheel = Some(wheel.to_string());
+        self
+    }
+
+    ///Sets the link to an emitter of the physical descriptions
+    pub fn emitter(mut self, emitter: &str) -> Self {
+        self.emitter = Some(emitter.to_string());
+        self
+    }
+
+    ///Sets the link to a filter of the physical descriptions
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    ///Sets the link to the DMX channel or channel function of the same DMX mode that must be in the given range for this channel function to be active
+    pub fn mode_master(
+        mut self,
+        mode_master: &str,
+        mode_from: DmxValue,
+        mode_to: DmxValue,
+    ) -> Self {
+        self.mode_master = Some((mode_master.to_string(), mode_from, mode_to));
+        self
+    }
+
+    ///Adds a channel set. The order of the channel sets is kept, so each channel set ends before DMXFrom of the next one
+    pub fn channel_set(mut self, channel_set: ChannelSetBuilder) -> Self {
+        self.channel_sets.push(channel_set);
+        self
+    }
+
+    ///Builds the channel function and checks that the names of the channel sets are unique
+    pub fn build(self) -> Result<(Name, ChannelFunction), GdtfError> {
+        let name = Name::new(&self.name)?;
+        let attribute = match self.attribute {
+            Some(attribute) => FunctionAttribute::new_from_str(&attribute)?,
+            None => FunctionAttribute::NoFeature,
+        };
+        let mode_master = match self.mode_master {
+            Some((mode_master, mode_from, mode_to)) => Node::new_from_str(&mode_master)?
+                .map(|node| ModeMaster::new(node, Some(mode_from), Some(mode_to))),
+            None => None,
+        };
+        let channel_sets = build_map(
+            &name.0,
+            self.channel_sets.into_iter().map(ChannelSetBuilder::build),
+        )?;
+        Ok((
+            name,
+            ChannelFunction {
+                attribute,
+                original_attribute: self.original_attribute,
+                dmx_from: self.dmx_from,
+                default: self.default,
+                physical_from: self.physical_from,
+                physical_to: self.physical_to,
+                real_fade: self.real_fade,
+                real_acceleration: self.real_acceleration,
+                wheel: optional_node(self.wheel)?,
+                emitter: optional_node(self.emitter)?,
+                filter: optional_node(self.filter)?,
+                mode_master,
+                channel_sets,
+            },
+        ))
+    }
+}
+
+///Builds a ChannelSet. The Name of the channel set is returned together with the channel set on `build()`
+#[derive(Debug, Clone)]
+pub struct ChannelSetBuilder {
+    name: String,
+    dmx_from: DmxValue,
+    physical_from: Option<f32>,
+    physical_to: Option<f32>,
+    wheel_slot_index: Option<u8>,
+}
+
+impl ChannelSetBuilder {
+    ///Creates a builder for a channel set starting at the given DMX value
+    pub fn new(name: &str, dmx_from: DmxValue) -> Self {
+        Self {
+            name: name.to_string(),
+            dmx_from,
+            physical_from: None,
+            physical_to: None,
+            wheel_slot_index: None,
+        }
+    }
+
+    ///Sets the physical values at the start and the end of the channel set. Defaults to the range of the channel function
+    pub fn physical_range(mut self, physical_from: f32, physical_to: f32) -> Self {
+        self.physical_from = Some(physical_from);
+        self.physical_to = Some(physical_to);
+        self
+    }
+
+    ///Sets the index of the slot on the wheel of the channel function, starting with 1
+    pub fn wheel_slot_index(mut self, wheel_slot_index: u8) -> Self {
+        self.wheel_slot_index = Some(wheel_slot_index);
+        self
+    }
+
+    ///Builds the channel set
+    pub fn build(self) -> Result<(Name, ChannelSet), GdtfError> {
+        Ok((
+            Name::new(&self.name)?,
+            ChannelSet {
+                dmx_from: self.dmx_from,
+                physical_from: self.physical_from,
+                physical_to: self.physical_to,
+                wheel_slot_index: self.wheel_slot_index,
+            },
+        ))
+    }
+}
+
+///Builds a Wheel. The Name of the wheel is returned together with the wheel on `build()`
+#[derive(Debug, Clone)]
+pub struct WheelBuilder {
+    name: String,
+    slots: Vec<SlotBuilder>,
+}
+
+impl WheelBuilder {
+    ///Creates a builder for a wheel without slots
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            slots: Vec::new(),
+        }
+    }
+
+    ///Adds a slot. The order of the slots is kept, so the first slot has the index 1
+    pub fn slot(mut self, slot: SlotBuilder) -> Self {
+        self.slots.push(slot);
+        self
+    }
+
+    ///Builds the wheel and checks that the names of the slots are unique
+    pub fn build(self) -> Result<(Name, Wheel), GdtfError> {
+        let name = Name::new(&self.name)?;
+        let slots = build_map(&name.0, self.slots.into_iter().map(SlotBuilder::build))?;
+        Ok((name, Wheel { slots }))
+    }
+}
+
+///Builds a Slot. The Name of the slot is returned together with the slot on `build()`
+#[derive(Debug, Clone)]
+pub struct SlotBuilder {
+    name: String,
+    color: ColorCie,
+    filter: Option<String>,
+    media_file_name: Option<String>,
+    prism_facets: Vec<Facet>,
+    animation_wheel: Option<AnimationSystem>,
+}
+
+impl SlotBuilder {
+    ///Creates a builder for a wheel slot. The color defaults to white
+    pub fn new(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            color: COLOR_CIE_WHITE,
+            filter: None,
+            media_file_name: None,
+            prism_facets: Vec::new(),
+            animation_wheel: None,
+        }
+    }
+
+    ///Sets the color of the slot. Defaults to white
+    pub fn color(mut self, color: ColorCie) -> Self {
+        self.color = color;
+        self
+    }
+
+    ///Sets the link to a filter of the physical descriptions
+    pub fn filter(mut self, filter: &str) -> Self {
+        self.filter = Some(filter.to_string());
+        self
+    }
+
+    ///File name of the image in the folder `wheels` without extension
+    pub fn media_file_name(mut self, media_file_name: &str) -> Self {
+        self.media_file_name = Some(media_file_name.to_string());
+        self
+    }
+
+    ///Adds a facet to the prism of the slot
+    pub fn prism_facet(mut self, prism_facet: Facet) -> Self {
+        self.prism_facets.push(prism_facet);
+        self
+    }
+
+    ///Sets the animation system if the slot is an animation wheel
+    pub fn animation_wheel(mut self, animation_wheel: AnimationSystem) -> Self {
+        self.animation_wheel = Some(animation_wheel);
+        self
+    }
+
+    ///Builds the slot
+    pub fn build(self) -> Result<(Name, Slot), GdtfError> {
+        Ok((
+            Name::new(&self.name)?,
+            Slot {
+                color: self.color,
+                filter: optional_node(self.filter)?,
+                media_file_name: self.media_file_name.map(Resource),
+                prism_facets: if self.prism_facets.is_empty() {
+                    None
+                } else {
+                    Some(self.prism_facets)
+                },
+                animation_wheel: self.animation_wheel,
+            },
+        ))
+    }
+}
+
+fn optional_node(value: Option<String>) -> Result<Option<Node>, GdtfError> {
+    match value {
+        Some(value) => Ok(Node::new_from_str(&value)?),
+        None => Ok(None),
+    }
+}
+
+///Collects built nodes into a hash-map and returns an error if a name is used more than once
+fn build_map<T, M: Default + ChildMap<Name, T>>(
+    parent: &str,
+    built: impl Iterator<Item = Result<(Name, T), GdtfError>>,
+) -> Result<M, GdtfError> {
+    let mut map = M::default();
+    for entry in built {
+        let (name, value) = entry?;
+        if map.contains_child(&name) {
+            return Err(GdtfError::BuilderDuplicateNameError(
+                parent.to_string(),
+                name.to_string(),
+            ));
+        }
+        map.insert_child(name, value);
+    }
+    Ok(map)
+}
+
+///Creates one top level geometry for each geometry of the DMX modes that contains the other geometries used by their DMX channels
+fn default_geometries(dmx_modes: &[DmxModeBuilder]) -> Result<RawXml, GdtfError> {
+    let mut top_levels: Vec<(&str, Vec<&str>)> = Vec::new();
+    for dmx_mode in dmx_modes.iter() {
+        let index = match top_levels
+            .iter()
+            .position(|(name, _)| *name == dmx_mode.geometry)
+        {
+            Some(index) => index,
+            None => {
+                top_levels.push((&dmx_mode.geometry, Vec::new()));
+                top_levels.len() - 1
+            }
+        };
+        let (top_level, children) = &mut top_levels[index];
+        for dmx_channel in dmx_mode.dmx_channels.iter() {
+            if dmx_channel.geometry != *top_level
+                && !children.contains(&dmx_channel.geometry.as_str())
+            {
+                children.push(&dmx_channel.geometry);
+            }
+        }
+    }
+    let mut writer = Writer::new(Vec::new());
+    write::write_parent(
+        &mut writer,
+        b"Geometries",
+        !top_levels.is_empty(),
+        |writer| {
+            for (top_level, children) in top_levels.iter() {
+                let mut geometry = BytesStart::borrowed_name(b"Geometry");
+                write::push_attribute(&mut geometry, b"Name", top_level);
+                if children.is_empty() {
+                    writer.write_event(Event::Empty(geometry))?;
+                    continue;
+                }
+                writer.write_event(Event::Start(geometry))?;
+                for child in children.iter() {
+                    let mut geometry = BytesStart::borrowed_name(b"Geometry");
+                    write::push_attribute(&mut geometry, b"Name", child);
+                    writer.write_event(Event::Empty(geometry))?;
+                }
+                writer.write_event(Event::End(BytesEnd::borrowed(b"Geometry")))?;
+            }
+            Ok(())
+        },
+    )?;
+    RawXml::new_from_str(std::str::from_utf8(&writer.into_inner())?)
+}
+
+fn link_not_found(path: String, attribute: &str, link: &Node) -> GdtfError {
+    GdtfError::BuilderLinkNotFoundError(path, attribute.to_string(), link.to_string())
+}
+
+///Returns the logical channel, name and channel function for all channel functions of a DMX channel
+fn functions(
+    dmx_channel: &DmxChannel,
+) -> impl Iterator<Item = (&LogicalChannel, &Name, &ChannelFunction)> {
+    dmx_channel
+        .logical_channels
+        .iter()
+        .flat_map(|logical_channel| {
+            logical_channel
+                .channel_functions
+                .iter()
+                .map(move |(name, channel_function)| (logical_channel, name, channel_function))
+        })
+}
+
+///Checks if a link in the format `DMXChannel.LogicalChannel.ChannelFunction` points to a channel function of the DMX channels
+fn is_channel_function(dmx_channels: &[DmxChannel], link: &Node) -> bool {
+    let (channel, attribute, function) = match link.0.as_slice() {
+        [channel, attribute, function] => (channel, attribute, function),
+        _ => return false,
+    };
+    dmx_channels
+        .iter()
+        .filter(|dmx_channel| &dmx_channel.name() == channel)
+        .flat_map(functions)
+        .any(|(logical_channel, name, _)| {
+            logical_channel.attribute.to_string() == attribute.as_str() && name == function
+        })
+}
+
+///Adds a predefined attribute with the defaults of the GDTF specification and creates it's feature group and feature if they don't exist
+fn add_predefined_attribute(
+    attribute_definitions: &mut AttributeDefinitions,
+    attribute: &str,
+) -> Result<(), GdtfError> {
+    let attribute_name = AttributeName::new_from_str(attribute)?;
+    let (feature_group, feature) = match (
+        attribute_name.default_feature_group(),
+        attribute_name.default_feature(),
+    ) {
+        (Some(feature_group), Some(feature)) => (feature_group, feature),
+        _ => {
+            return Err(GdtfError::BuilderAttributeNotPredefinedError(
+                attribute.to_string(),
+            ))
+        }
+    };
+    let group = attribute_definitions
+        .feature_groups
+        .entry(feature_group.clone())
+        .or_insert_with(|| FeatureGroup {
+            pretty: feature_group.to_string(),
+            features: Vec::new(),
+        });
+    if let Some(feature_name) = feature.0.last() {
+        if !group.features.contains(feature_name) {
+            group.features.push(feature_name.clone());
+        }
+    }
+    attribute_definitions
+        .attributes
+        .entry(attribute_name.clone())
+        .or_insert_with(|| Attribute {
+            pretty: attribute_name
+                .default_pretty()
+                .unwrap_or_else(|| attribute.to_string()),
+            activation_group: None,
+            feature,
+            main_attribute: attribute_name
+                .default_main_attribute()
+                .map(|main_attribute| Node(vec![Name::from(main_attribute.to_string())])),
+            physical_unit: attribute_name.default_physical_unit(),
+            color: attribute_name.default_color(),
+        });
+    Ok(())
+}
+
+///Checks the links from DMX modes and wheels to attributes, wheels, emitters, filters and geometries and the DMXFrom of the channel sets
+fn validate_links(fixture_type: &FixtureType) -> Result<(), GdtfError> {
+    let attributes: Vec<String> = fixture_type
+        .attribute_definitions
+        .attributes
+        .keys()
+        .map(|attribute| attribute.to_string())
+        .collect();
+    let empty = HashMap::new();
+    let wheels = fixture_type.wheels.as_ref().unwrap_or(&empty);
+    let (emitters, filters) = match &fixture_type.physical_descriptions {
+        Some(physical_descriptions) => (
+            physical_descriptions.emitters.keys().collect(),
+            physical_descriptions.filters.keys().collect(),
+        ),
+        None => (vec![], vec![]),
+    };
+    let is_single = |link: &Node, names: &[&Name]| link.0.len() == 1 && names.contains(&&link.0[0]);
+    let geometries = match &fixture_type.geometries {
+        Some(geometries) => Geometries::from_raw_xml(geometries)?,
+        None => Geometries::default(),
+    };
+
+    for (mode_name, dmx_mode) in fixture_type.dmx_modes.iter() {
+        let mode_path = format!("DMXModes.{}", mode_name);
+        if !geometries.is_top_level(dmx_mode.geometry.as_str()) {
+            return Err(GdtfError::BuilderLinkNotFoundError(
+                mode_path,
+                "Geometry".to_string(),
+                dmx_mode.geometry.to_string(),
+            ));
+        }
+        let geometry_names = geometries.names_of(dmx_mode.geometry.as_str());
+        for dmx_channel in dmx_mode.dmx_channels.iter() {
+            let channel_path = format!("{}.{}", mode_path, dmx_channel.name());
+            if !geometry_names.contains(dmx_channel.geometry.as_str()) {
+                return Err(GdtfError::BuilderLinkNotFoundError(
+                    channel_path,
+                    "Geometry".to_string(),
+                    dmx_channel.geometry.to_string(),
+                ));
+            }
+            let mut diagnostics = Vec::new();
+            validate::validate_channel_set_dmx_from(&mode_path, dmx_channel, &mut diagnostics);
+            if let Some(diagnostic) = diagnostics.into_iter().next() {
+                return Err(GdtfError::BuilderChannelSetDmxFromError(
+                    diagnostic.path,
+                    diagnostic.message,
+                ));
+            }
+            for logical_channel in dmx_channel.logical_channels.iter() {
+                let logical_path = format!("{}.{}", channel_path, logical_channel.attribute);
+                if !attributes.contains(&logical_channel.attribute.to_string()) {
+                    return Err(link_not_found(
+                        channel_path,
+                        "Attribute",
+                        &logical_channel.attribute,
+                    ));
+                }
+                for (function_name, channel_function) in logical_channel.channel_functions.iter() {
+                    let function_path = format!("{}.{}", logical_path, function_name);
+                    if let FunctionAttribute::Feature(attribute) = &channel_function.attribute {
+                        if !attributes.contains(&attribute.to_string()) {
+                            return Err(link_not_found(function_path, "Attribute", attribute));
+                        }
+                    }
+                    if let Some(emitter) = &channel_function.emitter {
+                        if !is_single(emitter, &emitters) {
+                            return Err(link_not_found(function_path, "Emitter", emitter));
+                        }
+                    }
+                    if let Some(filter) = &channel_function.filter {
+                        if !is_single(filter, &filters) {
+                            return Err(link_not_found(function_path, "Filter", filter));
+                        }
+                    }
+                    let wheel = match &channel_function.wheel {
+                        Some(link) => match link.0.as_slice() {
+                            [name] if wheels.contains_key(name) => Some(&wheels[name]),
+                            _ => return Err(link_not_found(function_path, "Wheel", link)),
+                        },
+                        None => None,
+                    };
+                    for (set_name, channel_set) in channel_function.channel_sets.iter() {
+                        let slot_count = wheel.map_or(0, |wheel| wheel.slots.len());
+                        match channel_set.wheel_slot_index {
+                            Some(index) if index == 0 || usize::from(index) > slot_count => {
+                                return Err(GdtfError::BuilderLinkNotFoundError(
+                                    format!("{}.{}", function_path, set_name),
+                                    "WheelSlotIndex".to_string(),
+                                    index.to_string(),
+                                ))
+                            }
+                            _ => {}
+                        }
+                    }
+                }
+            }
+        }
+    }
+    for (wheel_name, wheel) in wheels.iter() {
+        for (slot_name, slot) in wheel.slots.iter() {
+            if let Some(filter) = &slot.filter {
+                if !is_single(filter, &filters) {
+                    return Err(link_not_found(
+                        format!("Wheels.{}.{}", wheel_name, slot_name),
+                        "Filter",
+                        filter,
+                    ));
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fixture_type::builder::{
+        ChannelFunctionBuilder, ChannelSetBuilder, DmxChannelBuilder, DmxModeBuilder,
+        FixtureTypeBuilder, LogicalChannelBuilder, SlotBuilder, WheelBuilder,
+    };
+    use crate::utils::errors::GdtfError;
+    use crate::utils::raw_xml::RawXml;
+    use crate::utils::units::dmx_value::DmxValue;
+    use crate::utils::units::guid::Guid;
+    use crate::utils::units::name::Name;
+    use crate::validate::validate;
+    use crate::{DataVersion, Gdtf};
+
+    fn dmx(value: &str) -> DmxValue {
+        DmxValue::new_from_str(value).unwrap()
+    }
+
+    fn prototype() -> FixtureTypeBuilder {
+        FixtureTypeBuilder::new("Prototype", "ACME", Guid::dummy())
+            .attribute("Dimmer")
+            .attribute("Gobo1")
+            .wheel(
+                WheelBuilder::new("Gobo1")
+                    .slot(SlotBuilder::new("Open"))
+                    .slot(SlotBuilder::new("Dots").media_file_name("dots")),
+            )
+            .dmx_mode(
+                DmxModeBuilder::new("Standard", "Base")
+                    .dmx_channel(
+                        DmxChannelBuilder::new("Beam")
+                            .offset(&[1, 2])
+                            .highlight(dmx("65535/2"))
+                            .logical_channel(
+                                LogicalChannelBuilder::new("Dimmer").channel_function(
+                                    ChannelFunctionBuilder::new("Dimmer").physical_range(0.0, 1.0),
+                                ),
+                            ),
+                    )
+                    .dmx_channel(
+                        DmxChannelBuilder::new("Gobo").offset(&[3]).logical_channel(
+                            LogicalChannelBuilder::new("Gobo1").channel_function(
+                                ChannelFunctionBuilder::new("Gobo1")
+                                    .wheel("Gobo1")
+                                    .mode_master("Beam_Dimmer", dmx("1/1"), dmx("255/1"))
+                                    .channel_set(
+                                        ChannelSetBuilder::new("Open", dmx("0/1"))
+                                            .wheel_slot_index(1),
+                                    )
+                                    .channel_set(
+                                        ChannelSetBuilder::new("Dots", dmx("128/1"))
+                                            .wheel_slot_index(2),
+                                    ),
+                            ),
+                        ),
+                    ),
+            )
+    }
+
+    #[test]
+    fn test_build_round_trip() {
+        let gdtf = Gdtf {
+            data_version: DataVersion::Version1_1,
+            fixture_type: prototype().build().unwrap(),
+        };
+        let xml = gdtf.to_description_xml().unwrap();
+        assert_eq!(Gdtf::from_description_xml(&xml).unwrap(), gdtf);
+        assert_eq!(
+            gdtf.fixture_type.geometries.as_ref().unwrap().as_str(),
+            r#"<Geometries><Geometry Name="Base"><Geometry Name="Beam"/><Geometry Name="Gobo"/></Geometry></Geometries>"#
+        );
+        assert_eq!(validate(&gdtf), vec![]);
+    }
+
+    #[test]
+    fn test_build_slot_order() {
+        let gdtf = Gdtf {
+            data_version: DataVersion::Version1_1,
+            fixture_type: prototype().build().unwrap(),
+        };
+        let xml = gdtf.to_description_xml().unwrap();
+        let open = xml.find("<Slot Name=\"Open\"").unwrap();
+        let dots = xml.find("<Slot Name=\"Dots\"").unwrap();
+        assert!(open < dots);
+
+        let read = Gdtf::from_description_xml(&xml).unwrap();
+        let slots = &read.fixture_type.wheels.unwrap()[&Name::new("Gobo1").unwrap()].slots;
+        assert_eq!(
+            slots.keys().collect::<Vec<&Name>>(),
+            vec![&Name::new("Open").unwrap(), &Name::new("Dots").unwrap()]
+        );
+    }
+
+    #[test]
+    fn test_build_faulty() {
+        let unknown_wheel = prototype().dmx_mode(
+            DmxModeBuilder::new("Wheel", "Base").dmx_channel(
+                DmxChannelBuilder::new("Gobo").logical_channel(
+                    LogicalChannelBuilder::new("Gobo1")
+                        .channel_function(ChannelFunctionBuilder::new("Gobo1").wheel("Gobo2")),
+                ),
+            ),
+        );
+        assert!(matches!(
+            unknown_wheel.build(),
+            Err(GdtfError::BuilderLinkNotFoundError(path, attribute, link))
+                if path == "DMXModes.Wheel.Gobo_Gobo1.Gobo1.Gobo1" && attribute == "Wheel" && link == "Gobo2"
+        ));
+
+        let unknown_attribute =
+            prototype().dmx_mode(DmxModeBuilder::new("Attribute", "Base").dmx_channel(
+                DmxChannelBuilder::new("Yoke").logical_channel(LogicalChannelBuilder::new("Pan")),
+            ));
+        assert!(matches!(
+            unknown_attribute.build(),
+            Err(GdtfError::BuilderLinkNotFoundError(_, attribute, link)) if attribute == "Attribute" && link == "Pan"
+        ));
+
+        let slot_out_of_range = FixtureTypeBuilder::new("Prototype", "ACME", Guid::dummy())
+            .attribute("Gobo1")
+            .wheel(WheelBuilder::new("Gobo1").slot(SlotBuilder::new("Open")))
+            .dmx_mode(
+                DmxModeBuilder::new("Mode", "Base").dmx_channel(
+                    DmxChannelBuilder::new("Gobo").logical_channel(
+                        LogicalChannelBuilder::new("Gobo1").channel_function(
+                            ChannelFunctionBuilder::new("Gobo1")
+                                .wheel("Gobo1")
+                                .channel_set(
+                                    ChannelSetBuilder::new("Dots", dmx("128/1"))
+                                        .wheel_slot_index(2),
+                                ),
+                        ),
+                    ),
+                ),
+            );
+        assert!(slot_out_of_range.build().is_err());
+
+        let unknown_mode_master = DmxModeBuilder::new("Mode", "Base").dmx_channel(
+            DmxChannelBuilder::new("Gobo").logical_channel(
+                LogicalChannelBuilder::new("Gobo1").channel_function(
+                    ChannelFunctionBuilder::new("Gobo1").mode_master(
+                        "Beam_Dimmer",
+                        dmx("0/1"),
+                        dmx("255/1"),
+                    ),
+                ),
+            ),
+        );
+        assert!(matches!(
+            unknown_mode_master.build(),
+            Err(GdtfError::BuilderLinkNotFoundError(_, attribute, _)) if attribute == "ModeMaster"
+        ));
+
+        let duplicate_channel = DmxModeBuilder::new("Mode", "Base")
+            .dmx_channel(
+                DmxChannelBuilder::new("Beam")
+                    .logical_channel(LogicalChannelBuilder::new("Dimmer")),
+            )
+            .dmx_channel(
+                DmxChannelBuilder::new("Beam")
+                    .logical_channel(LogicalChannelBuilder::new("Dimmer")),
+            );
+        assert!(matches!(
+            duplicate_channel.build(),
+            Err(GdtfError::BuilderDuplicateNameError(parent, name)) if parent == "DMXModes.Mode" && name == "Beam_Dimmer"
+        ));
+
+        let unknown_geometry = prototype().geometries(
+            RawXml::new_from_str(r#"<Geometries><Geometry Name="Base"><Geometry Name="Beam"/></Geometry></Geometries>"#)
+                .unwrap(),
+        );
+        assert!(matches!(
+            unknown_geometry.build(),
+            Err(GdtfError::BuilderLinkNotFoundError(path, attribute, link))
+                if path == "DMXModes.Standard.Gobo_Gobo1" && attribute == "Geometry" && link == "Gobo"
+        ));
+
+        let descending_channel_sets = prototype().dmx_mode(
+            DmxModeBuilder::new("Descending", "Base").dmx_channel(
+                DmxChannelBuilder::new("Gobo").offset(&[1]).logical_channel(
+                    LogicalChannelBuilder::new("Gobo1").channel_function(
+                        ChannelFunctionBuilder::new("Gobo1")
+                            .channel_set(ChannelSetBuilder::new("High", dmx("128/1")))
+                            .channel_set(ChannelSetBuilder::new("Low", dmx("0/1"))),
+                    ),
+                ),
+            ),
+        );
+        assert!(matches!(
+            descending_channel_sets.build(),
+            Err(GdtfError::BuilderChannelSetDmxFromError(path, _))
+                if path == "DMXModes.Descending.Gobo_Gobo1.Gobo1.Gobo1.Low"
+        ));
+
+        assert!(matches!(
+            FixtureTypeBuilder::new("Prototype", "ACME", Guid::dummy())
+                .attribute("Something else")
+                .build(),
+            Err(GdtfError::BuilderAttributeNotPredefinedError(_))
+        ));
+        assert!(FixtureTypeBuilder::new("Invalid {", "ACME", Guid::dummy())
+            .build()
+            .is_err());
+    }
+}
@@ -0,0 +1,784 @@
+//! Structural comparison of two revisions of a fixture type
+//!
+//! `diff` compares the DMX modes and wheels of two fixture types and returns a list of changes. Every change has a path in the style of a GDTF `Node`, starting with the collection `DMXModes` or `Wheels`, for example `DMXModes.Mode 1.Yoke_Pan.Pan.Pan 1`.
+//!
+//! DMX channels are matched by their name, logical channels by their attribute and all other nodes by their Name. DMX channels, channel sets and wheel slots that share their name are matched in their order. A wheel slot that changed it's position is reported as moved, because channel sets link to it by it's WheelSlotIndex.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use std::path::Path;
+//! use gdtf_parser::Gdtf;
+//! use gdtf_parser::fixture_type::diff::diff;
+//!
+//! let old = Gdtf::try_from(Path::new("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf")).unwrap().fixture_type;
+//! let mut new = old.clone();
+//! new.dmx_modes.clear();
+//!
+//! let changes = diff(&old, &new);
+//! assert!(changes.requires_repatch());
+//! println!("{}", changes);
+//! ```
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::channel_set::ChannelSet;
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::ChannelFunction;
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::LogicalChannel;
+use crate::fixture_type::dmx_mode::dmx_channel::{DmxBreak, DmxChannel, Offset};
+use crate::fixture_type::dmx_mode::DmxMode;
+use crate::fixture_type::wheel::slot::Slot;
+use crate::fixture_type::wheel::Wheel;
+use crate::fixture_type::FixtureType;
+use crate::utils::units::dmx_value::DmxValue;
+use crate::utils::units::name::Name;
+use crate::utils::units::node::Node;
+
+///Name of the collection at the start of the path of changes in DMX modes
+const DMX_MODES: &str = "DMXModes";
+///Name of the collection at the start of the path of changes in wheels
+const WHEELS: &str = "Wheels";
+
+///All changes between two revisions of a fixture type
+#[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FixtureTypeDiff {
+    ///The changes, DMX modes first, then wheels. Nodes in a hash-map are compared sorted by their Name, DMX channels, logical channels, channel sets and wheel slots in the order of the old revision followed by the added ones
+    pub changes: Vec<Change>,
+}
+
+///A single change of a node
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Change {
+    ///The path of the changed node
+    pub path: Node,
+    ///What changed
+    pub kind: ChangeKind,
+}
+
+///Kind of a change. Values are given as they are in the old and in the new revision
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChangeKind {
+    DmxModeAdded,
+    DmxModeRemoved,
+    DmxChannelAdded,
+    DmxChannelRemoved,
+    DmxChannelOffsetChanged {
+        old: Option<Offset>,
+        new: Option<Offset>,
+    },
+    DmxChannelBreakChanged {
+        old: DmxBreak,
+        new: DmxBreak,
+    },
+    LogicalChannelAdded,
+    LogicalChannelRemoved,
+    ChannelFunctionAdded,
+    ChannelFunctionRemoved,
+    ChannelFunctionDmxFromChanged {
+        old: DmxValue,
+        new: DmxValue,
+    },
+    ///PhysicalFrom and PhysicalTo of the channel function
+    ChannelFunctionPhysicalRangeChanged {
+        old: (f32, f32),
+        new: (f32, f32),
+    },
+    ChannelSetAdded,
+    ChannelSetRemoved,
+    ChannelSetDmxFromChanged {
+        old: DmxValue,
+        new: DmxValue,
+    },
+    ///PhysicalFrom and PhysicalTo of the channel set
+    ChannelSetPhysicalRangeChanged {
+        old: (Option<f32>, Option<f32>),
+        new: (Option<f32>, Option<f32>),
+    },
+    WheelAdded,
+    WheelRemoved,
+    WheelSlotAdded,
+    WheelSlotRemoved,
+    WheelSlotChanged {
+        old: Box<Slot>,
+        new: Box<Slot>,
+    },
+    ///The position of the slot in the wheel, it's WheelSlotIndex starting at 1
+    WheelSlotMoved {
+        old: usize,
+        new: usize,
+    },
+}
+
+///Compares two revisions of a fixture type
+pub fn diff(old: &FixtureType, new: &FixtureType) -> FixtureTypeDiff {
+    let mut changes = Vec::new();
+    let root = vec![Name::from(DMX_MODES.to_string())];
+    diff_map(
+        &mut changes,
+        &root,
+        &old.dmx_modes,
+        &new.dmx_modes,
+        (ChangeKind::DmxModeAdded, ChangeKind::DmxModeRemoved),
+        diff_dmx_mode,
+    );
+    let root = vec![Name::from(WHEELS.to_string())];
+    let empty = HashMap::new();
+    diff_map(
+        &mut changes,
+        &root,
+        old.wheels.as_ref().unwrap_or(&empty),
+        new.wheels.as_ref().unwrap_or(&empty),
+        (ChangeKind::WheelAdded, ChangeKind::WheelRemoved),
+        diff_wheel,
+    );
+    FixtureTypeDiff { changes }
+}
+
+impl FixtureTypeDiff {
+    ///Returns true if both revisions are equal in all compared nodes
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+
+    ///Returns the names of all DMX modes of the old revision where DMX channels were added, removed or moved. Fixtures patched in these modes must be re-patched in show files
+    pub fn modes_to_repatch(&self) -> Vec<&Name> {
+        let mut modes: Vec<&Name> = Vec::new();
+        for change in self.changes.iter() {
+            let shifts_layout = matches!(
+                change.kind,
+                ChangeKind::DmxModeRemoved
+                    | ChangeKind::DmxChannelAdded
+                    | ChangeKind::DmxChannelRemoved
+                    | ChangeKind::DmxChannelOffsetChanged { .. }
+                    | ChangeKind::DmxChannelBreakChanged { .. }
+            );
+            if let (true, Some(mode)) = (shifts_layout, change.path.0.get(1)) {
+                if !modes.contains(&mode) {
+                    modes.push(mode);
+                }
+            }
+        }
+        modes
+    }
+
+    ///Returns true if the DMX layout of at least one DMX mode of the old revision changed
+    pub fn requires_repatch(&self) -> bool {
+        !self.modes_to_repatch().is_empty()
+    }
+}
+
+///Displays the changes as report with one line per change. Added nodes are marked with `+`, removed with `-` and changed with `~`
+/// ```rust
+/// use gdtf_parser::fixture_type::diff::{Change, ChangeKind, FixtureTypeDiff};
+/// use gdtf_parser::utils::units::node::Node;
+///
+/// let changes = FixtureTypeDiff {
+///     changes: vec![Change {
+///         path: Node::new_from_str("DMXModes.Mode 1").unwrap().unwrap(),
+///         kind: ChangeKind::DmxModeRemoved,
+///     }],
+/// };
+/// assert_eq!(
+///     changes.to_string(),
+///     "- DMXModes.Mode 1: DMX mode removed\nShow files must be re-patched for DMX modes: Mode 1\n"
+/// );
+/// ```
+impl Display for FixtureTypeDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "No changes");
+        }
+        for change in self.changes.iter() {
+            writeln!(f, "{}", change)?;
+        }
+        let modes = self.modes_to_repatch();
+        if !modes.is_empty() {
+            let modes: Vec<&str> = modes.iter().map(|mode| mode.as_str()).collect();
+            writeln!(
+                f,
+                "Show files must be re-patched for DMX modes: {}",
+                modes.join(", ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use ChangeKind::*;
+        let sign = match self.kind {
+            DmxModeAdded | DmxChannelAdded | LogicalChannelAdded | ChannelFunctionAdded
+            | ChannelSetAdded | WheelAdded | WheelSlotAdded => '+',
+            DmxModeRemoved
+            | DmxChannelRemoved
+            | LogicalChannelRemoved
+            | ChannelFunctionRemoved
+            | ChannelSetRemoved
+            | WheelRemoved
+            | WheelSlotRemoved => '-',
+            _ => '~',
+        };
+        write!(f, "{} {}: ", sign, self.path)?;
+        match &self.kind {
+            DmxModeAdded => write!(f, "DMX mode added"),
+            DmxModeRemoved => write!(f, "DMX mode removed"),
+            DmxChannelAdded => write!(f, "DMX channel added"),
+            DmxChannelRemoved => write!(f, "DMX channel removed"),
+            DmxChannelOffsetChanged { old, new } => write!(
+                f,
+                "Offset {} -> {}",
+                option_to_string(old),
+                option_to_string(new)
+            ),
+            DmxChannelBreakChanged { old, new } => write!(f, "DMXBreak {} -> {}", old, new),
+            LogicalChannelAdded => write!(f, "logical channel added"),
+            LogicalChannelRemoved => write!(f, "logical channel removed"),
+            ChannelFunctionAdded => write!(f, "channel function added"),
+            ChannelFunctionRemoved => write!(f, "channel function removed"),
+            ChannelFunctionDmxFromChanged { old, new } | ChannelSetDmxFromChanged { old, new } => {
+                write!(f, "DMXFrom {} -> {}", old, new)
+            }
+            ChannelFunctionPhysicalRangeChanged { old, new } => write!(
+                f,
+                "physical range {}..{} -> {}..{}",
+                old.0, old.1, new.0, new.1
+            ),
+            ChannelSetAdded => write!(f, "channel set added"),
+            ChannelSetRemoved => write!(f, "channel set removed"),
+            ChannelSetPhysicalRangeChanged { old, new } => write!(
+                f,
+                "physical range {}..{} -> {}..{}",
+                option_to_string(&old.0),
+                option_to_string(&old.1),
+                option_to_string(&new.0),
+                option_to_string(&new.1)
+            ),
+            WheelAdded => write!(f, "wheel added"),
+            WheelRemoved => write!(f, "wheel removed"),
+            WheelSlotAdded => write!(f, "wheel slot added"),
+            WheelSlotRemoved => write!(f, "wheel slot removed"),
+            WheelSlotMoved { old, new } => write!(f, "WheelSlotIndex {} -> {}", old, new),
+            WheelSlotChanged { old, new } => {
+                let mut fields = Vec::new();
+                if old.color != new.color {
+                    fields.push("color changed".to_string());
+                }
+                if old.filter != new.filter {
+                    fields.push(format!(
+                        "Filter {} -> {}",
+                        option_to_string(&old.filter),
+                        option_to_string(&new.filter)
+                    ));
+                }
+                if old.media_file_name != new.media_file_name {
+                    fields.push(format!(
+                        "MediaFileName {} -> {}",
+                        option_to_string(&old.media_file_name),
+                        option_to_string(&new.media_file_name)
+                    ));
+                }
+                if old.prism_facets != new.prism_facets {
+                    fields.push("prism facets changed".to_string());
+                }
+                if old.animation_wheel != new.animation_wheel {
+                    fields.push("animation system changed".to_string());
+                }
+                write!(f, "{}", fields.join(", "))
+            }
+        }
+    }
+}
+
+fn option_to_string<T: Display>(value: &Option<T>) -> String {
+    match value {
+        Some(value) => value.to_string(),
+        None => "None".to_string(),
+    }
+}
+
+fn child_path(path: &[Name], name: &Name) -> Vec<Name> {
+    let mut path = path.to_vec();
+    path.push(name.clone());
+    path
+}
+
+fn push(changes: &mut Vec<Change>, path: Vec<Name>, kind: ChangeKind) {
+    changes.push(Change {
+        path: Node(path),
+        kind,
+    });
+}
+
+///Compares two hash-maps sorted by their keys. `kinds` are the kinds for an added and a removed node, `diff_entry` is called for nodes present in both
+fn diff_map<T>(
+    changes: &mut Vec<Change>,
+    path: &[Name],
+    old: &HashMap<Name, T>,
+    new: &HashMap<Name, T>,
+    kinds: (ChangeKind, ChangeKind),
+    diff_entry: fn(&mut Vec<Change>, &[Name], &T, &T),
+) {
+    let mut names: Vec<&Name> = old
+        .keys()
+        .chain(new.keys().filter(|k| !old.contains_key(*k)))
+        .collect();
+    names.sort_by(|a, b| a.0.cmp(&b.0));
+    for name in names {
+        let path = child_path(path, name);
+        match (old.get(name), new.get(name)) {
+            (Some(old), Some(new)) => diff_entry(changes, &path, old, new),
+            (Some(_), None) => push(changes, path, kinds.1.clone()),
+            (None, Some(_)) => push(changes, path, kinds.0.clone()),
+            (None, None) => {}
+        }
+    }
+}
+
+///Compares two vecs where the entries are matched with their name. Entries of the old vec are compared in their order, added entries follow in the order of the new vec. Returns the index in the new vec of every entry in the old vec, see `pair_by_name`
+fn diff_vec<T>(
+    changes: &mut Vec<Change>,
+    path: &[Name],
+    old: &[(Name, &T)],
+    new: &[(Name, &T)],
+    kinds: (ChangeKind, ChangeKind),
+    diff_entry: fn(&mut Vec<Change>, &[Name], &T, &T),
+) -> Vec<Option<usize>> {
+    let pairs = pair_by_name(old, new);
+    for ((name, old), index) in old.iter().zip(pairs.iter()) {
+        let path = child_path(path, name);
+        match index {
+            Some(index) => diff_entry(changes, &path, old, new[*index].1),
+            None => push(changes, path, kinds.1.clone()),
+        }
+    }
+    for (index, (name, _)) in new.iter().enumerate() {
+        if !pairs.contains(&Some(index)) {
+            push(changes, child_path(path, name), kinds.0.clone());
+        }
+    }
+    pairs
+}
+
+///Returns the index in the new vec of every entry in the old vec or None if it was removed. Entries that share their name are paired in their order, the second `Head` of the old vec with the second `Head` of the new vec
+fn pair_by_name<T>(old: &[(Name, &T)], new: &[(Name, &T)]) -> Vec<Option<usize>> {
+    let mut paired = vec![false; new.len()];
+    old.iter()
+        .map(|(name, _)| {
+            let index = (0..new.len()).find(|i| !paired[*i] && &new[*i].0 == name)?;
+            paired[index] = true;
+            Some(index)
+        })
+        .collect()
+}
+
+fn diff_dmx_mode(changes: &mut Vec<Change>, path: &[Name], old: &DmxMode, new: &DmxMode) {
+    let old_channels: Vec<(Name, &DmxChannel)> =
+        old.dmx_channels.iter().map(|c| (c.name(), c)).collect();
+    let new_channels: Vec<(Name, &DmxChannel)> =
+        new.dmx_channels.iter().map(|c| (c.name(), c)).collect();
+    diff_vec(
+        changes,
+        path,
+        &old_channels,
+        &new_channels,
+        (ChangeKind::DmxChannelAdded, ChangeKind::DmxChannelRemoved),
+        diff_dmx_channel,
+    );
+}
+
+fn diff_dmx_channel(changes: &mut Vec<Change>, path: &[Name], old: &DmxChannel, new: &DmxChannel) {
+    if old.offset != new.offset {
+        push(
+            changes,
+            path.to_vec(),
+            ChangeKind::DmxChannelOffsetChanged {
+                old: old.offset.clone(),
+                new: new.offset.clone(),
+            },
+        );
+    }
+    if old.dmx_break != new.dmx_break {
+        push(
+            changes,
+            path.to_vec(),
+            ChangeKind::DmxChannelBreakChanged {
+                old: old.dmx_break.clone(),
+                new: new.dmx_break.clone(),
+            },
+        );
+    }
+    let old_channels: Vec<(Name, &LogicalChannel)> = old
+        .logical_channels
+        .iter()
+        .map(|l| (Name::from(l.attribute.to_string()), l))
+        .collect();
+    let new_channels: Vec<(Name, &LogicalChannel)> = new
+        .logical_channels
+        .iter()
+        .map(|l| (Name::from(l.attribute.to_string()), l))
+        .collect();
+    diff_vec(
+        changes,
+        path,
+        &old_channels,
+        &new_channels,
+        (
+            ChangeKind::LogicalChannelAdded,
+            ChangeKind::LogicalChannelRemoved,
+        ),
+        diff_logical_channel,
+    );
+}
+
+fn diff_logical_channel(
+    changes: &mut Vec<Change>,
+    path: &[Name],
+    old: &LogicalChannel,
+    new: &LogicalChannel,
+) {
+    diff_map(
+        changes,
+        path,
+        &old.channel_functions,
+        &new.channel_functions,
+        (
+            ChangeKind::ChannelFunctionAdded,
+            ChangeKind::ChannelFunctionRemoved,
+        ),
+        diff_channel_function,
+    );
+}
+
+fn diff_channel_function(
+    changes: &mut Vec<Change>,
+    path: &[Name],
+    old: &ChannelFunction,
+    new: &ChannelFunction,
+) {
+    if old.dmx_from != new.dmx_from {
+        push(
+            changes,
+            path.to_vec(),
+            ChangeKind::ChannelFunctionDmxFromChanged {
+                old: old.dmx_from.clone(),
+                new: new.dmx_from.clone(),
+            },
+        );
+    }
+    let (old_range, new_range) = (
+        (old.physical_from, old.physical_to),
+        (new.physical_from, new.physical_to),
+    );
+    if old_range != new_range {
+        push(
+            changes,
+            path.to_vec(),
+            ChangeKind::ChannelFunctionPhysicalRangeChanged {
+                old: old_range,
+                new: new_range,
+            },
+        );
+    }
+    let old_sets: Vec<(Name, &ChannelSet)> = old
+        .channel_sets
+        .iter()
+        .map(|(k, v)| (k.clone(), v))
+        .collect();
+    let new_sets: Vec<(Name, &ChannelSet)> = new
+        .channel_sets
+        .iter()
+        .map(|(k, v)| (k.clone(), v))
+        .collect();
+    diff_vec(
+        changes,
+        path,
+        &old_sets,
+        &new_sets,
+        (ChangeKind::ChannelSetAdded, ChangeKind::ChannelSetRemoved),
+        diff_channel_set,
+    );
+}
+
+fn diff_channel_set(changes: &mut Vec<Change>, path: &[Name], old: &ChannelSet, new: &ChannelSet) {
+    if old.dmx_from != new.dmx_from {
+        push(
+            changes,
+            path.to_vec(),
+            ChangeKind::ChannelSetDmxFromChanged {
+                old: old.dmx_from.clone(),
+                new: new.dmx_from.clone(),
+            },
+        );
+    }
+    let (old_range, new_range) = (
+        (old.physical_from, old.physical_to),
+        (new.physical_from, new.physical_to),
+    );
+    if old_range != new_range {
+        push(
+            changes,
+            path.to_vec(),
+            ChangeKind::ChannelSetPhysicalRangeChanged {
+                old: old_range,
+                new: new_range,
+            },
+        );
+    }
+}
+
+fn diff_wheel(changes: &mut Vec<Change>, path: &[Name], old: &Wheel, new: &Wheel) {
+    let old_slots: Vec<(Name, &Slot)> = old.slots.iter().map(|(k, v)| (k.clone(), v)).collect();
+    let new_slots: Vec<(Name, &Slot)> = new.slots.iter().map(|(k, v)| (k.clone(), v)).collect();
+    let pairs = diff_vec(
+        changes,
+        path,
+        &old_slots,
+        &new_slots,
+        (ChangeKind::WheelSlotAdded, ChangeKind::WheelSlotRemoved),
+        diff_slot,
+    );
+    for (old_index, new_index) in pairs.into_iter().enumerate() {
+        match new_index {
+            Some(new_index) if new_index != old_index => push(
+                changes,
+                child_path(path, &old_slots[old_index].0),
+                ChangeKind::WheelSlotMoved {
+                    old: old_index + 1,
+                    new: new_index + 1,
+                },
+            ),
+            _ => {}
+        }
+    }
+}
+
+fn diff_slot(changes: &mut Vec<Change>, path: &[Name], old: &Slot, new: &Slot) {
+    if old != new {
+        push(
+            changes,
+            path.to_vec(),
+            ChangeKind::WheelSlotChanged {
+                old: Box::new(old.clone()),
+                new: Box::new(new.clone()),
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::path::Path;
+
+    use crate::fixture_type::diff::{diff, ChangeKind};
+    use crate::fixture_type::dmx_mode::dmx_channel::Offset;
+    use crate::fixture_type::FixtureType;
+    use crate::utils::units::dmx_value::DmxValue;
+    use crate::utils::units::name::Name;
+    use crate::Gdtf;
+
+    fn acme() -> FixtureType {
+        Gdtf::try_from(Path::new(
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+        ))
+        .unwrap()
+        .fixture_type
+    }
+
+    fn name(name: &str) -> Name {
+        Name::new(name).unwrap()
+    }
+
+    #[test]
+    fn test_diff_equal() {
+        let acme = acme();
+        let changes = diff(&acme, &acme.clone());
+        assert!(changes.is_empty());
+        assert!(!changes.requires_repatch());
+        assert_eq!(changes.to_string(), "No changes\n");
+    }
+
+    #[test]
+    fn test_diff() {
+        let old = acme();
+        let mut new = old.clone();
+        let mode = name("Mode 1 12 DMX");
+        let mut added_mode = new.dmx_modes[&mode].clone();
+        added_mode.dmx_channels.truncate(1);
+        new.dmx_modes.insert(name("Mode 2"), added_mode);
+
+        let dmx_mode = new.dmx_modes.get_mut(&mode).unwrap();
+        let removed = dmx_mode.dmx_channels.remove(1).name();
+        let shifted = dmx_mode.dmx_channels[1].name();
+        dmx_mode.dmx_channels[1].offset = Some(Offset(vec![20]));
+        let pan = &mut dmx_mode.dmx_channels[0].logical_channels[0];
+        let (pan_function_name, pan_function) = pan.channel_functions.iter_mut().next().unwrap();
+        pan_function.physical_to += 90.0;
+        let pan_function_name = pan_function_name.clone();
+
+        let wheel_name = new.wheels.as_ref().unwrap().keys().next().unwrap().clone();
+        let wheel = new.wheels.as_mut().unwrap().get_mut(&wheel_name).unwrap();
+        let slot_name = wheel.slots.keys().next().unwrap().clone();
+        wheel.slots.get_mut(&slot_name).unwrap().color.x += 0.01;
+
+        let changes = diff(&old, &new);
+        let found = |kind: &ChangeKind| {
+            changes
+                .changes
+                .iter()
+                .filter(|c| &c.kind == kind)
+                .map(|c| c.path.to_string())
+                .collect::<Vec<String>>()
+        };
+        assert_eq!(found(&ChangeKind::DmxModeAdded), vec!["DMXModes.Mode 2"]);
+        assert_eq!(
+            found(&ChangeKind::DmxChannelRemoved),
+            vec![format!("DMXModes.Mode 1 12 DMX.{}", removed)]
+        );
+        let offset_changes: Vec<_> = changes
+            .changes
+            .iter()
+            .filter(|c| matches!(c.kind, ChangeKind::DmxChannelOffsetChanged { .. }))
+            .collect();
+        assert_eq!(offset_changes.len(), 1);
+        assert_eq!(
+            offset_changes[0].path.to_string(),
+            format!("DMXModes.Mode 1 12 DMX.{}", shifted)
+        );
+        assert!(changes.changes.iter().any(|c| matches!(
+            &c.kind,
+            ChangeKind::ChannelFunctionPhysicalRangeChanged { old, new } if new.1 == old.1 + 90.0
+        ) && c.path.0.last() == Some(&pan_function_name)));
+        assert!(changes.changes.iter().any(|c| matches!(
+            c.kind,
+            ChangeKind::WheelSlotChanged { .. }
+        ) && c.path.to_string()
+            == format!("Wheels.{}.{}", wheel_name, slot_name)));
+        assert_eq!(changes.modes_to_repatch(), vec![&mode]);
+
+        let report = changes.to_string();
+        assert!(report.contains("+ DMXModes.Mode 2: DMX mode added\n"));
+        assert!(report.contains(&format!(
+            "- DMXModes.Mode 1 12 DMX.{}: DMX channel removed\n",
+            removed
+        )));
+        assert!(report.ends_with("Show files must be re-patched for DMX modes: Mode 1 12 DMX\n"));
+
+        let reverse = diff(&new, &old);
+        assert_eq!(reverse.modes_to_repatch(), vec![&mode, &name("Mode 2")]);
+    }
+
+    #[test]
+    fn test_diff_same_names() {
+        let old = Gdtf::try_from(Path::new("test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf"))
+            .unwrap()
+            .fixture_type;
+        let mut new = old.clone();
+        let (mode_name, dmx_mode) = new
+            .dmx_modes
+            .iter_mut()
+            .find(|(_, m)| {
+                let names: Vec<Name> = m.dmx_channels.iter().map(|c| c.name()).collect();
+                (1..names.len()).any(|i| names[..i].contains(&names[i]))
+            })
+            .unwrap();
+        let mode_name = mode_name.clone();
+        let index = (1..dmx_mode.dmx_channels.len())
+            .rev()
+            .find(|i| {
+                dmx_mode.dmx_channels[..*i]
+                    .iter()
+                    .any(|c| c.name() == dmx_mode.dmx_channels[*i].name())
+            })
+            .unwrap();
+        dmx_mode.dmx_channels[index].offset = Some(Offset(vec![500]));
+
+        let changes = diff(&old, &new);
+        assert_eq!(changes.changes.len(), 1);
+        assert!(matches!(
+            &changes.changes[0].kind,
+            ChangeKind::DmxChannelOffsetChanged { new, .. } if new == &Some(Offset(vec![500]))
+        ));
+        assert_eq!(changes.modes_to_repatch(), vec![&mode_name]);
+    }
+
+    #[test]
+    fn test_diff_slot_moved() {
+        let old = acme();
+        let mut new = old.clone();
+        let (wheel_name, wheel) = new
+            .wheels
+            .as_mut()
+            .unwrap()
+            .iter_mut()
+            .find(|(_, w)| w.slots.len() > 2)
+            .unwrap();
+        let wheel_name = wheel_name.clone();
+        wheel.slots.swap_indices(0, 1);
+        let first = old.wheels.as_ref().unwrap()[&wheel_name]
+            .slots
+            .keys()
+            .next()
+            .unwrap()
+            .clone();
+
+        let changes = diff(&old, &new);
+        assert_eq!(changes.changes.len(), 2);
+        assert_eq!(
+            changes.changes[0].to_string(),
+            format!("~ Wheels.{}.{}: WheelSlotIndex 1 -> 2", wheel_name, first)
+        );
+        assert_eq!(
+            changes.changes[1].kind,
+            ChangeKind::WheelSlotMoved { old: 2, new: 1 }
+        );
+        assert!(!changes.requires_repatch());
+    }
+
+    #[test]
+    fn test_diff_channel_sets() {
+        let old = acme();
+        let mut new = old.clone();
+        let mode = new.dmx_modes.values_mut().next().unwrap();
+        let channel_function = mode
+            .dmx_channels
+            .iter_mut()
+            .flat_map(|c| c.logical_channels.iter_mut())
+            .flat_map(|l| l.channel_functions.values_mut())
+            .find(|f| f.channel_sets.len() > 1)
+            .unwrap();
+        let set_name = channel_function
+            .channel_sets
+            .keys()
+            .max_by(|a, b| a.0.cmp(&b.0))
+            .unwrap()
+            .clone();
+        let set = channel_function.channel_sets.get_mut(&set_name).unwrap();
+        let old_dmx_from = set.dmx_from.clone();
+        set.dmx_from = DmxValue {
+            initial_value: old_dmx_from.initial_value + 1,
+            ..old_dmx_from.clone()
+        };
+        set.physical_to = Some(1234.5);
+
+        let changes = diff(&old, &new);
+        assert_eq!(changes.changes.len(), 2);
+        assert!(!changes.requires_repatch());
+        assert_eq!(changes.changes[0].path.0.last(), Some(&set_name));
+        assert!(matches!(
+            &changes.changes[0].kind,
+            ChangeKind::ChannelSetDmxFromChanged { old, .. } if old == &old_dmx_from
+        ));
+        assert!(matches!(
+            &changes.changes[1].kind,
+            ChangeKind::ChannelSetPhysicalRangeChanged { new, .. } if new.1 == Some(1234.5)
+        ));
+        assert!(changes.changes[1].to_string().ends_with("..1234.5"));
+    }
+}
@@ -13,8 +13,11 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::dmx_value::DmxValue;
 use crate::utils::units::name::Name;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 /// Defines the channel sets of the channel function
 pub struct ChannelSet {
     ///Start DMX value; The end DMX value is calculated as a DMXFrom of the next channel set – 1 or the maximum value of the current channel function
@@ -91,6 +94,15 @@ impl ReadGdtf for ChannelSet {
     }
 }
 
+impl WriteGdtf for ChannelSet {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"DMXFrom", &self.dmx_from);
+        write::push_attribute_option(event, b"PhysicalFrom", &self.physical_from);
+        write::push_attribute_option(event, b"PhysicalTo", &self.physical_to);
+        write::push_attribute_option(event, b"WheelSlotIndex", &self.wheel_slot_index);
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for ChannelSet {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -1,11 +1,14 @@
 //! Contains ChannelFunction and it's children
 
-use std::collections::HashMap;
-use std::fmt::Debug;
+use std::cmp::Ordering;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
 
+use indexmap::IndexMap;
 use quick_xml::events::attributes::Attribute as XmlAttribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::channel_set::ChannelSet;
 use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::LogicalChannel;
@@ -17,11 +20,15 @@ use crate::utils::read::TestReadGdtf;
 use crate::utils::units::dmx_value::DmxValue;
 use crate::utils::units::name::Name;
 use crate::utils::units::node::{GdtfNodeError, Node};
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod channel_set;
 
 ///The Fixture Type Attribute is assinged to a Channel Function and defines the function of its DMX Range
 #[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ChannelFunction {
     ///Link to attribute; Starting point is the attributes node_2. Default value: “NoFeature”.
     pub attribute: Attribute,
@@ -47,8 +54,8 @@ pub struct ChannelFunction {
     pub filter: Option<Node>,
     ///Link to DMX Channel or Channel Function; Starting point DMX mode
     pub mode_master: Option<ModeMaster>,
-    //A list of channel sets for the channel function
-    pub channel_sets: HashMap<Name, ChannelSet>,
+    ///The channel sets of the channel function in the order of the description.xml. The end DMX value of a channel set is calculated from DMXFrom of the next one
+    pub channel_sets: IndexMap<Name, ChannelSet>,
 }
 
 ///Helper struct to hold temporary data during deparsing
@@ -81,16 +88,18 @@ pub(crate) struct ChannelFunctionDataHolder {
     mode_from: Option<DmxValue>,
     mode_to: Option<DmxValue>,
     //A list of channel sets for the channel function
-    channel_sets: HashMap<Name, ChannelSet>,
+    channel_sets: IndexMap<Name, ChannelSet>,
 }
 
-const DEFAULT_DMX_FROM: DmxValue = DmxValue {
+///DMXFrom of a channel function if it is not set in the description.xml
+pub(crate) const DEFAULT_DMX_FROM: DmxValue = DmxValue {
     initial_value: 0,
     n: 1,
     is_byte_shifting: false,
 };
 
-const DEFAULT_DMX_DEFAULT: DmxValue = DmxValue {
+///Default of a channel function if it is not set in the description.xml
+pub(crate) const DEFAULT_DMX_DEFAULT: DmxValue = DmxValue {
     initial_value: 0,
     n: 1,
     is_byte_shifting: false,
@@ -122,12 +131,16 @@ impl ReadGdtf for ChannelFunction {
                 data_holder.original_attribute = Some(read::attr_to_string(attr))
             }
             b"DMXFrom" => {
-                data_holder.dmx_from =
-                    Some(DmxValue::new_from_attr(attr).unwrap_or(DEFAULT_DMX_FROM))
+                data_holder.dmx_from = Some(DmxValue::new_from_attr(attr).unwrap_or_else(|_| {
+                    warnings::substituted(DEFAULT_DMX_FROM);
+                    DEFAULT_DMX_FROM
+                }))
             }
             b"Default" => {
-                data_holder.default =
-                    Some(DmxValue::new_from_attr(attr).unwrap_or(DEFAULT_DMX_DEFAULT))
+                data_holder.default = Some(DmxValue::new_from_attr(attr).unwrap_or_else(|_| {
+                    warnings::substituted(DEFAULT_DMX_DEFAULT);
+                    DEFAULT_DMX_DEFAULT
+                }))
             }
             b"PhysicalFrom" => data_holder.physical_from = Some(read::attr_to_f32(attr)),
             b"PhysicalTo" => data_holder.physical_to = Some(read::attr_to_f32(attr)),
@@ -140,13 +153,19 @@ impl ReadGdtf for ChannelFunction {
             b"ModeFrom" => {
                 data_holder.mode_from = match DmxValue::new_from_attr(attr) {
                     Ok(val) => Some(val),
-                    Err(_) => None,
+                    Err(_) => {
+                        warnings::dropped();
+                        None
+                    }
                 }
             }
             b"ModeTo" => {
                 data_holder.mode_to = match DmxValue::new_from_attr(attr) {
                     Ok(val) => Some(val),
-                    Err(_) => None,
+                    Err(_) => {
+                        warnings::dropped();
+                        None
+                    }
                 }
             }
             _ => {}
@@ -161,14 +180,19 @@ impl ReadGdtf for ChannelFunction {
         has_children: bool,
     ) -> Result<(), Self::Error> {
         if event.name() == ChannelSet::NODE_NAME {
+            let position = warnings::event_start(reader.buffer_position(), &event, has_children);
             let cs = ChannelSet::read_single_from_event(reader, event, has_children)?;
-            data_holder.channel_sets.insert(
-                cs.0.ok_or_else(|| {
-                    Self::child_primary_key_not_found(
-                        ChannelSet::NODE_NAME,
-                        ChannelSet::PRIMARY_KEY_NAME,
-                    )
-                })?,
+            let key = cs.0.ok_or_else(|| {
+                Self::child_primary_key_not_found(
+                    ChannelSet::NODE_NAME,
+                    ChannelSet::PRIMARY_KEY_NAME,
+                )
+            })?;
+            read::insert_unique(
+                &mut data_holder.channel_sets,
+                ChannelSet::NODE_NAME,
+                position,
+                key,
                 cs.1,
             );
         }
@@ -204,6 +228,45 @@ impl ReadGdtf for ChannelFunction {
     }
 }
 
+impl WriteGdtf for ChannelFunction {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Attribute", &self.attribute);
+        write::push_attribute(event, b"OriginalAttribute", &self.original_attribute);
+        write::push_attribute(event, b"DMXFrom", &self.dmx_from);
+        write::push_attribute(event, b"Default", &self.default);
+        write::push_attribute(event, b"PhysicalFrom", &self.physical_from);
+        write::push_attribute(event, b"PhysicalTo", &self.physical_to);
+        write::push_attribute(event, b"RealFade", &self.real_fade);
+        write::push_attribute(event, b"RealAcceleration", &self.real_acceleration);
+        write::push_attribute_option(event, b"Wheel", &self.wheel);
+        write::push_attribute_option(event, b"Emitter", &self.emitter);
+        write::push_attribute_option(event, b"Filter", &self.filter);
+        if let Some(mode_master) = &self.mode_master {
+            write::push_attribute(event, b"ModeMaster", &mode_master.mode_master);
+            write::push_attribute(event, b"ModeFrom", &mode_master.mode_from);
+            write::push_attribute(event, b"ModeTo", &mode_master.mode_to);
+        }
+    }
+
+    ///LogicalChannel deparses every ChannelFunction as node with children, so it is never written as empty node
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    ///Channel sets are written in their order, so the DMX range of each channel set is kept
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        ChannelSet::write_entries(&ChannelSet::ordered_entries(&self.channel_sets), writer)
+    }
+}
+
+///Compares two DMXFrom values by their position in the DMX range, independent of their byte count
+pub(crate) fn cmp_dmx_from(a: &DmxValue, b: &DmxValue) -> Ordering {
+    let position = |v: &DmxValue| v.initial_value as f64 / 256_f64.powi(v.n as i32);
+    position(a)
+        .partial_cmp(&position(b))
+        .unwrap_or(Ordering::Equal)
+}
+
 #[cfg(test)]
 impl TestReadGdtf for ChannelFunction {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -231,7 +294,7 @@ impl TestReadGdtf for ChannelFunction {
                     filter: None,
                     wheel: None,
                     mode_master: None,
-                    channel_sets: HashMap::new(),
+                    channel_sets: IndexMap::new(),
                 }),
             ),
             (
@@ -257,7 +320,7 @@ impl TestReadGdtf for ChannelFunction {
                     filter: None,
                     wheel: None,
                     mode_master: None,
-                    channel_sets: HashMap::new(),
+                    channel_sets: IndexMap::new(),
                 }),
             ),
             (
@@ -283,7 +346,7 @@ impl TestReadGdtf for ChannelFunction {
                     filter: None,
                     wheel: None,
                     mode_master: None,
-                    channel_sets: ChannelSet::testdata_hash_map(),
+                    channel_sets: ChannelSet::testdata_index_map(),
                 }),
             ),
             (
@@ -309,7 +372,7 @@ impl TestReadGdtf for ChannelFunction {
                     filter: None,
                     wheel: None,
                     mode_master: None,
-                    channel_sets: ChannelSet::testdata_hash_map(),
+                    channel_sets: ChannelSet::testdata_index_map(),
                 }),
             ),
             (
@@ -347,7 +410,7 @@ impl TestReadGdtf for ChannelFunction {
                             is_byte_shifting: true,
                         },
                     }),
-                    channel_sets: ChannelSet::testdata_hash_map(),
+                    channel_sets: ChannelSet::testdata_index_map(),
                 }),
             ),
             (
@@ -373,7 +436,7 @@ impl TestReadGdtf for ChannelFunction {
                     filter: Node::new_from_str("Magenta").unwrap(),
                     wheel: None,
                     mode_master: None,
-                    channel_sets: ChannelSet::testdata_hash_map(),
+                    channel_sets: ChannelSet::testdata_index_map(),
                 }),
             ),
             (
@@ -411,7 +474,7 @@ impl TestReadGdtf for ChannelFunction {
                             is_byte_shifting: false,
                         },
                     }),
-                    channel_sets: ChannelSet::testdata_hash_map(),
+                    channel_sets: ChannelSet::testdata_index_map(),
                 }),
             ),
             (
@@ -437,7 +500,7 @@ impl TestReadGdtf for ChannelFunction {
                     filter: None,
                     wheel: None,
                     mode_master: None,
-                    channel_sets: ChannelSet::testdata_hash_map(),
+                    channel_sets: ChannelSet::testdata_index_map(),
                 }),
             ),
         ]
@@ -490,6 +553,7 @@ impl TestReadGdtf for ChannelFunction {
 //-----------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Node used in ChannelFunction.attribute. Link to attribute; Starting point is the attributes node. Default value: “NoFeature”.
 pub enum Attribute {
     ///Used when a reference to a node is present
@@ -507,8 +571,8 @@ impl Attribute {
     ///
     /// assert_eq!(Attribute::new_from_str("NoFeature").unwrap(), Attribute::NoFeature);
     /// assert_eq!(Attribute::new_from_str("").unwrap(), Attribute::NoFeature);
-    /// assert_eq!(Attribute::new_from_str("Name1").unwrap(), Attribute::Feature(Node(vec![Name("Name1".to_string())])));
-    /// assert_eq!(Attribute::new_from_str("Name1.Name2").unwrap(), Attribute::Feature(Node(vec![Name("Name1".to_string()), Name("Name2".to_string())])));
+    /// assert_eq!(Attribute::new_from_str("Name1").unwrap(), Attribute::Feature(Node(vec![Name::from("Name1")])));
+    /// assert_eq!(Attribute::new_from_str("Name1.Name2").unwrap(), Attribute::Feature(Node(vec![Name::from("Name1"), Name::from("Name2")])));
     /// assert!(Attribute::new_from_str("Name with invalid char {").is_err());
     /// assert!(Attribute::new_from_str("Name with invalid char ȸ").is_err());
     /// ```
@@ -533,8 +597,8 @@ impl Attribute {
     ///
     /// assert_eq!(Attribute::new_from_attr(XmlAttribute{ key: &[], value: Cow::Borrowed(b"NoFeature")}).unwrap(), Attribute::NoFeature);
     /// assert_eq!(Attribute::new_from_attr(XmlAttribute{ key: &[], value: Cow::Borrowed(b"")}).unwrap(), Attribute::NoFeature);
-    /// assert_eq!(Attribute::new_from_attr(XmlAttribute{ key: &[], value: Cow::Borrowed(b"Name1")}).unwrap(), Attribute::Feature(Node(vec![Name("Name1".to_string())])));
-    /// assert_eq!(Attribute::new_from_attr(XmlAttribute{ key: &[], value: Cow::Borrowed(b"Name1.Name2")}).unwrap(), Attribute::Feature(Node(vec![Name("Name1".to_string()), Name("Name2".to_string())])));
+    /// assert_eq!(Attribute::new_from_attr(XmlAttribute{ key: &[], value: Cow::Borrowed(b"Name1")}).unwrap(), Attribute::Feature(Node(vec![Name::from("Name1")])));
+    /// assert_eq!(Attribute::new_from_attr(XmlAttribute{ key: &[], value: Cow::Borrowed(b"Name1.Name2")}).unwrap(), Attribute::Feature(Node(vec![Name::from("Name1"), Name::from("Name2")])));
     /// assert!(Attribute::new_from_attr(XmlAttribute{ key: &[], value: Cow::Borrowed(b"Name with invalid char {")}).is_err());
     /// ```
     pub fn new_from_attr(attr: XmlAttribute<'_>) -> Result<Self, GdtfNodeError> {
@@ -553,6 +617,23 @@ impl Default for Attribute {
     }
 }
 
+///Displays Attribute as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::node::Node;
+/// use gdtf_parser::utils::units::name::Name;
+/// use gdtf_parser::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::Attribute;
+///
+/// assert_eq!(Attribute::NoFeature.to_string(), "NoFeature");
+/// assert_eq!(Attribute::Feature(Node(vec![Name::from("Name1"), Name::from("Name2")])).to_string(), "Name1.Name2");
+/// ```
+impl Display for Attribute {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Attribute::Feature(node) => write!(f, "{}", node),
+            Attribute::NoFeature => write!(f, "NoFeature"),
+        }
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of Attribute
@@ -566,6 +647,7 @@ impl Default for Attribute {
 //-----------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ModeMaster {
     ///Link to DMX Channel or Channel Function; Starting point DMX mode
     pub mode_master: Node,
@@ -635,14 +717,11 @@ pub mod tests {
         assert_eq!(Attribute::new_from_str("").unwrap(), Attribute::NoFeature);
         assert_eq!(
             Attribute::new_from_str("Name1").unwrap(),
-            Attribute::Feature(Node(vec![Name("Name1".to_string())]))
+            Attribute::Feature(Node(vec![Name::from("Name1")]))
         );
         assert_eq!(
             Attribute::new_from_str("Name1.Name2").unwrap(),
-            Attribute::Feature(Node(vec![
-                Name("Name1".to_string()),
-                Name("Name2".to_string())
-            ]))
+            Attribute::Feature(Node(vec![Name::from("Name1"), Name::from("Name2")]))
         );
         assert!(Attribute::new_from_str("Name with invalid char {").is_err());
         assert!(Attribute::new_from_str("Name with invalid char ȸ").is_err());
@@ -660,14 +739,11 @@ pub mod tests {
         );
         assert_eq!(
             Attribute::new_from_attr(testdata::to_attr_owned(b"Name1")).unwrap(),
-            Attribute::Feature(Node(vec![Name("Name1".to_string())]))
+            Attribute::Feature(Node(vec![Name::from("Name1")]))
         );
         assert_eq!(
             Attribute::new_from_attr(testdata::to_attr_owned(b"Name1.Name2")).unwrap(),
-            Attribute::Feature(Node(vec![
-                Name("Name1".to_string()),
-                Name("Name2".to_string())
-            ]))
+            Attribute::Feature(Node(vec![Name::from("Name1"), Name::from("Name2")]))
         );
         assert!(
             Attribute::new_from_attr(testdata::to_attr_owned(b"Name with invalid char {")).is_err()
@@ -686,14 +762,11 @@ pub mod tests {
         );
         assert_eq!(
             Attribute::new_from_attr(testdata::to_attr_borrowed(b"Name1")).unwrap(),
-            Attribute::Feature(Node(vec![Name("Name1".to_string())]))
+            Attribute::Feature(Node(vec![Name::from("Name1")]))
         );
         assert_eq!(
             Attribute::new_from_attr(testdata::to_attr_borrowed(b"Name1.Name2")).unwrap(),
-            Attribute::Feature(Node(vec![
-                Name("Name1".to_string()),
-                Name("Name2".to_string())
-            ]))
+            Attribute::Feature(Node(vec![Name::from("Name1"), Name::from("Name2")]))
         );
         assert!(
             Attribute::new_from_attr(testdata::to_attr_borrowed(b"Name with invalid char {"))
@@ -711,7 +784,7 @@ pub mod tests {
         assert_eq!(
             ModeMaster::new(Node::new_from_str("Name")?.unwrap(), None, None),
             ModeMaster {
-                mode_master: Node(vec![Name("Name".to_string())]),
+                mode_master: Node(vec![Name::from("Name")]),
                 mode_from: DmxValue {
                     initial_value: 0,
                     n: 1,
@@ -736,7 +809,7 @@ pub mod tests {
                 None
             ),
             ModeMaster {
-                mode_master: Node(vec![Name("Name".to_string())]),
+                mode_master: Node(vec![Name::from("Name")]),
                 mode_from: DmxValue {
                     initial_value: 13,
                     n: 2,
@@ -761,7 +834,7 @@ pub mod tests {
                 })
             ),
             ModeMaster {
-                mode_master: Node(vec![Name("Name".to_string())]),
+                mode_master: Node(vec![Name::from("Name")]),
                 mode_to: DmxValue {
                     initial_value: 13,
                     n: 2,
@@ -790,7 +863,7 @@ pub mod tests {
                 })
             ),
             ModeMaster {
-                mode_master: Node(vec![Name("Name".to_string())]),
+                mode_master: Node(vec![Name::from("Name")]),
                 mode_to: DmxValue {
                     initial_value: 13,
                     n: 2,
@@ -1,10 +1,12 @@
 //! Contains LogicalChannel and it's children
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::ChannelFunction;
 use crate::fixture_type::dmx_mode::dmx_channel::DmxChannel;
@@ -15,11 +17,15 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
 use crate::utils::units::node::Node;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod channel_function;
 
 ///The Fixture Type Attribute is assinged to a LogicalChannel and defines the function of the LogicalChannel. All logical channels that are children of the same DMX channel are mutually exclusive. In a DMX mode, only one logical channel with the same attribute can reference the same geometry at a time.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LogicalChannel {
     ///Link to the attribute; The starting point is the Attribute Collect
     pub attribute: Node,
@@ -65,7 +71,7 @@ impl ReadGdtf for LogicalChannel {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
     }
 
     fn read_any_attribute(
@@ -89,17 +95,22 @@ impl ReadGdtf for LogicalChannel {
         data_holder: &mut Self::DataHolder,
         reader: &mut Reader<&[u8]>,
         event: BytesStart<'_>,
-        _: bool,
+        has_children: bool,
     ) -> Result<(), Self::Error> {
         if event.name() == ChannelFunction::NODE_NAME {
-            let cf = ChannelFunction::read_single_from_event(reader, event, true)?;
-            data_holder.channel_functions.insert(
-                cf.0.ok_or_else(|| {
-                    Self::child_primary_key_not_found(
-                        ChannelFunction::NODE_NAME,
-                        ChannelFunction::PRIMARY_KEY_NAME,
-                    )
-                })?,
+            let position = warnings::event_start(reader.buffer_position(), &event, has_children);
+            let cf = ChannelFunction::read_single_from_event(reader, event, has_children)?;
+            let key = cf.0.ok_or_else(|| {
+                Self::child_primary_key_not_found(
+                    ChannelFunction::NODE_NAME,
+                    ChannelFunction::PRIMARY_KEY_NAME,
+                )
+            })?;
+            read::insert_unique(
+                &mut data_holder.channel_functions,
+                ChannelFunction::NODE_NAME,
+                position,
+                key,
                 cf.1,
             );
         }
@@ -120,6 +131,28 @@ impl ReadGdtf for LogicalChannel {
     }
 }
 
+impl WriteGdtf for LogicalChannel {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Attribute", &self.attribute);
+        write::push_attribute(event, b"Snap", &self.snap);
+        write::push_attribute(event, b"Master", &self.master);
+        write::push_attribute(event, b"MibFade", &self.mib_fade);
+        write::push_attribute(event, b"DMXChangeTimeLimit", &self.dmx_change_time_limit);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.channel_functions.is_empty()
+    }
+
+    ///Channel functions are written in the order of their DMXFrom
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        let mut channel_functions = ChannelFunction::sorted_entries(&self.channel_functions);
+        channel_functions
+            .sort_by(|a, b| channel_function::cmp_dmx_from(&a.1.dmx_from, &b.1.dmx_from));
+        ChannelFunction::write_entries(&channel_functions, writer)
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for LogicalChannel {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -221,6 +254,7 @@ impl TestReadGdtf for LogicalChannel {
 ///Snap representation for Snap for LogicalChannel used in GDTF
 /// If snap is enabled, the logical channel will not fade between values. Instead, it will jump directly to the new value
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Snap {
     No,
     Yes,
@@ -258,7 +292,10 @@ impl Snap {
             "Yes" => Yes,
             "On" => On,
             "Off" => Off,
-            _ => Default::default(),
+            _ => {
+                warnings::substituted("No");
+                Default::default()
+            }
         }
     }
     ///Creates a new snap from an xml attribute deparsed by quick-xml
@@ -280,6 +317,26 @@ impl Snap {
     }
 }
 
+///Displays Snap as it is used in gdtf-xml
+/// ## Examples
+/// ```rust
+/// use gdtf_parser::fixture_type::dmx_mode::dmx_channel::logical_channel::Snap;
+///
+/// assert_eq!(Snap::No.to_string(), "No");
+/// assert_eq!(Snap::Yes.to_string(), "Yes");
+/// assert_eq!(Snap::On.to_string(), "On");
+/// assert_eq!(Snap::Off.to_string(), "Off");
+/// ```
+impl Display for Snap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Snap::No => write!(f, "No"),
+            Snap::Yes => write!(f, "Yes"),
+            Snap::On => write!(f, "On"),
+            Snap::Off => write!(f, "Off"),
+        }
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of Snap
@@ -295,6 +352,7 @@ impl Snap {
 ///Master representation for logicalChannel in GDTF
 ///Defines if all the subordinate channel functions react to a Group Control defined by the control system
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Master {
     None,
     Grand,
@@ -327,7 +385,11 @@ impl Master {
         match s {
             "Grand" => Grand,
             "Group" => Group,
-            _ => None,
+            "None" => None,
+            _ => {
+                warnings::substituted("None");
+                None
+            }
         }
     }
 
@@ -347,6 +409,24 @@ impl Master {
     }
 }
 
+///Displays Master as it is used in gdtf-xml
+/// ## Examples
+/// ```rust
+/// use gdtf_parser::fixture_type::dmx_mode::dmx_channel::logical_channel::Master;
+///
+/// assert_eq!(Master::None.to_string(), "None");
+/// assert_eq!(Master::Grand.to_string(), "Grand");
+/// assert_eq!(Master::Group.to_string(), "Group");
+/// ```
+impl Display for Master {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Master::None => write!(f, "None"),
+            Master::Grand => write!(f, "Grand"),
+            Master::Group => write!(f, "Group"),
+        }
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of Master
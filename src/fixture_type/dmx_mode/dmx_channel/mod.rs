@@ -1,10 +1,12 @@
 //!Holds the DMXChannel and it's children
-use std::fmt::Debug;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
 use std::str::FromStr;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::LogicalChannel;
 use crate::utils::errors::GdtfError;
@@ -15,11 +17,15 @@ use crate::utils::read::TestReadGdtf;
 use crate::utils::units::dmx_value::DmxValue;
 use crate::utils::units::name::Name;
 use crate::utils::units::node::Node;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod logical_channel;
 
 ///This section defines the DMX channe
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DmxChannel {
     ///Number of the DMXBreak; Default value: 1; Special value: “Overwrite” – means that this number will be overwritten by Geometry Reference; Size: 4 bytes
     pub dmx_break: DmxBreak,
@@ -52,6 +58,39 @@ pub(crate) struct DmxChannelDataHolder {
     pub logical_channels: Vec<LogicalChannel>,
 }
 
+impl DmxChannel {
+    ///Returns the name of the DMX channel. It is not stored in the description.xml but generated as `<Geometry>_<Attribute of the first LogicalChannel>`
+    /// ```rust
+    /// use gdtf_parser::fixture_type::dmx_mode::dmx_channel::{DmxBreak, DmxChannel};
+    /// use gdtf_parser::fixture_type::dmx_mode::dmx_channel::logical_channel::{LogicalChannel, Master, Snap};
+    /// use gdtf_parser::utils::units::name::Name;
+    /// use gdtf_parser::utils::units::node::Node;
+    ///
+    /// let dmx_channel = DmxChannel {
+    ///     dmx_break: DmxBreak::Value(1),
+    ///     offset: None,
+    ///     initial_function: None,
+    ///     highlight: None,
+    ///     geometry: Name::new("Yoke").unwrap(),
+    ///     logical_channels: vec![LogicalChannel {
+    ///         attribute: Node::new_from_str("Pan").unwrap().unwrap(),
+    ///         snap: Snap::No,
+    ///         master: Master::None,
+    ///         mib_fade: 0.0,
+    ///         dmx_change_time_limit: 0.0,
+    ///         channel_functions: Default::default(),
+    ///     }],
+    /// };
+    /// assert_eq!(dmx_channel.name(), Name::new("Yoke_Pan").unwrap());
+    /// ```
+    pub fn name(&self) -> Name {
+        match self.logical_channels.first() {
+            Some(logical_channel) => Name::from(format!("{}_{}", self.geometry, logical_channel.attribute)),
+            None => self.geometry.clone(),
+        }
+    }
+}
+
 impl ReadGdtf for DmxChannel {
     type PrimaryKey = ();
     type Error = GdtfError;
@@ -66,7 +105,7 @@ impl ReadGdtf for DmxChannel {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed");
+        Ok(None)
     }
 
     fn read_any_attribute(
@@ -78,9 +117,14 @@ impl ReadGdtf for DmxChannel {
             b"Offset" => data_holder.offset = Offset::new_from_attr(attr),
             b"InitialFunction" => data_holder.initial_function = Node::new_from_attr(attr)?,
             b"Highlight" => {
-                data_holder.highlight = match DmxValue::new_from_attr(attr) {
-                    Ok(attr) => Some(attr),
-                    Err(_) => None,
+                data_holder.highlight = match DmxValue::new_from_str(read::attr_to_str(&attr)) {
+                    Ok(value) => Some(value),
+                    Err(_) => {
+                        if read::attr_to_str(&attr) != "None" {
+                            warnings::dropped();
+                        }
+                        None
+                    }
                 }
             }
             b"Geometry" => data_holder.geometry = Some(Name::new_from_attr(attr)?),
@@ -117,6 +161,27 @@ impl ReadGdtf for DmxChannel {
     }
 }
 
+impl WriteGdtf for DmxChannel {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"DMXBreak", &self.dmx_break);
+        write::push_attribute_option(event, b"Offset", &self.offset);
+        write::push_attribute_option(event, b"InitialFunction", &self.initial_function);
+        write::push_attribute_option(event, b"Highlight", &self.highlight);
+        write::push_attribute(event, b"Geometry", &self.geometry);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.logical_channels.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        for logical_channel in self.logical_channels.iter() {
+            logical_channel.write_single(writer, None)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for DmxChannel {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -281,6 +346,7 @@ impl TestReadGdtf for DmxChannel {
 ///The unit Offset used for DMXChannel used in GDTF
 ///Relative addresses of the current DMX channel from highest to least significant
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Offset(pub Vec<i32>);
 
 impl Offset {
@@ -353,6 +419,20 @@ impl Offset {
     }
 }
 
+///Displays Offset as comma separated list used in gdtf-xml-description
+/// ## Examples
+/// ```rust
+/// use gdtf_parser::fixture_type::dmx_mode::dmx_channel::Offset;
+///
+/// assert_eq!(Offset(vec![1]).to_string(), "1");
+/// assert_eq!(Offset(vec![0, 1, 2, -3]).to_string(), "0,1,2,-3");
+/// ```
+impl Display for Offset {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let offsets: Vec<String> = self.0.iter().map(|o| o.to_string()).collect();
+        write!(f, "{}", offsets.join(","))
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of Offset
@@ -367,6 +447,7 @@ impl Offset {
 
 ///DMXBreak used for DMXChannel in GDTF
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DmxBreak {
     ///Number of the DMXBreak; Default value: 1
     Value(u32),
@@ -388,7 +469,10 @@ impl DmxBreak {
         if s == "Overwrite" {
             Overwrite
         } else {
-            Value(u32::from_str(s).unwrap_or(1))
+            Value(u32::from_str(s).unwrap_or_else(|_| {
+                warnings::substituted(1);
+                1
+            }))
         }
     }
     ///Parses a quick-xml-attribute from gdtf-xml-description to a DmxBreak
@@ -417,6 +501,21 @@ impl Default for DmxBreak {
     }
 }
 
+///Displays DmxBreak as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::fixture_type::dmx_mode::dmx_channel::DmxBreak;
+///
+/// assert_eq!(DmxBreak::Value(32).to_string(), "32");
+/// assert_eq!(DmxBreak::Overwrite.to_string(), "Overwrite");
+/// ```
+impl Display for DmxBreak {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DmxBreak::Value(value) => write!(f, "{}", value),
+            DmxBreak::Overwrite => write!(f, "Overwrite"),
+        }
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of DmxBreak
@@ -13,9 +13,12 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::dmx_value::DmxValue;
 use crate::utils::units::node::Node;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///Defines a dmx value for a step in a macro
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MacroDmxValue {
     ///Value of the DMX channel
     pub value: DmxValue,
@@ -77,7 +80,14 @@ impl ReadGdtf for MacroDmxValue {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed");
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for MacroDmxValue {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Value", &self.value);
+        write::push_attribute(event, b"DMXChannel", &self.dmx_channel);
     }
 }
 
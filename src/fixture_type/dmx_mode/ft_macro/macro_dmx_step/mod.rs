@@ -1,9 +1,10 @@
 //!Defines a DMX sequence for a macro
 use std::fmt::Debug;
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::dmx_mode::ft_macro::macro_dmx_step::macro_dmx_value::MacroDmxValue;
 use crate::utils::errors::GdtfError;
@@ -11,11 +12,14 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod macro_dmx_value;
 
 ///Defines a DMX sequence for a macro
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MacroDmxStep {
     ///Duration of a step; Default value: 1; Unit: seconds.
     pub duration: f32,
@@ -76,7 +80,24 @@ impl ReadGdtf for MacroDmxStep {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed");
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for MacroDmxStep {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Duration", &self.duration);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.dmx_values.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        for dmx_value in self.dmx_values.iter() {
+            dmx_value.write_single(writer, None)?;
+        }
+        Ok(())
     }
 }
 
@@ -1,9 +1,10 @@
 //!Describes a macro defined by the manufacturer
 use std::fmt::Debug;
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::dmx_mode::ft_macro::macro_dmx_step::MacroDmxStep;
 use crate::utils::errors::GdtfError;
@@ -11,11 +12,13 @@ use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
+use crate::utils::write::WriteGdtf;
 
 pub mod macro_dmx_step;
 
 ///Describes a macro defined by the manufacturer
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FtMacro {
     ///All steps to execute the Macro
     pub macro_dmx_steps: Vec<MacroDmxStep>,
@@ -59,6 +62,18 @@ impl ReadGdtf for FtMacro {
     }
 }
 
+impl WriteGdtf for FtMacro {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+
+    fn has_children(&self) -> bool {
+        !self.macro_dmx_steps.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        MacroDmxStep::write_vec(&self.macro_dmx_steps, writer)
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for FtMacro {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
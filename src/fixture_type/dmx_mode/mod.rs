@@ -1,19 +1,41 @@
 //!This section is describes all DMX modes of the device
 use std::collections::HashMap;
 use std::fmt::Debug;
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
+#[cfg(feature = "parallel")]
+use std::sync::Arc;
+
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+#[cfg(feature = "parallel")]
+use quick_xml::events::Event;
+use quick_xml::{Reader, Writer};
 
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::{
+    cmp_dmx_from, Attribute as FunctionAttribute, ChannelFunction,
+};
 use crate::fixture_type::dmx_mode::dmx_channel::DmxChannel;
 use crate::fixture_type::dmx_mode::ft_macro::FtMacro;
 use crate::fixture_type::dmx_mode::relation::Relation;
 use crate::utils::errors::GdtfError;
+#[cfg(feature = "parallel")]
+use crate::utils::pool;
+#[cfg(feature = "parallel")]
+use crate::utils::read;
+#[cfg(feature = "parallel")]
+use crate::utils::read::GdtfReadError;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+#[cfg(feature = "parallel")]
+use crate::utils::units::name;
 use crate::utils::units::name::Name;
+use crate::utils::units::node::Node;
+#[cfg(feature = "parallel")]
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod dmx_channel;
 
@@ -22,6 +44,7 @@ pub mod relation;
 
 ///Each DMX mode describes logical control a part of the device in a specific mode
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DmxMode {
     ///Name of the first geometry in the device; Only top level geometries are allowed to be linked.
     pub geometry: Name,
@@ -113,6 +136,163 @@ impl ReadGdtf for DmxMode {
     }
 }
 
+impl DmxMode {
+    ///Returns the channel functions with an attribute starting with the prefix, in the order of the DMX channels and of their DMXFrom. Each one comes with the name of it's DMX channel, it's path `DMXChannel.LogicalChannel.ChannelFunction` and it's attribute
+    pub(crate) fn channel_functions_with_attribute(
+        &self,
+        prefix: &str,
+    ) -> Vec<(Name, String, &Node, &ChannelFunction)> {
+        let mut out = vec![];
+        for dmx_channel in self.dmx_channels.iter() {
+            let channel_name = dmx_channel.name();
+            for logical_channel in dmx_channel.logical_channels.iter() {
+                let mut channel_functions: Vec<_> =
+                    logical_channel.channel_functions.iter().collect();
+                channel_functions.sort_by(|(_, a), (_, b)| cmp_dmx_from(&a.dmx_from, &b.dmx_from));
+                for (function_name, channel_function) in channel_functions {
+                    if let FunctionAttribute::Feature(attribute) = &channel_function.attribute {
+                        if attribute.to_string().starts_with(prefix) {
+                            let path = format!(
+                                "{}.{}.{}",
+                                channel_name, logical_channel.attribute, function_name
+                            );
+                            out.push((channel_name.clone(), path, attribute, channel_function));
+                        }
+                    }
+                }
+            }
+        }
+        out
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl DmxMode {
+    ///Deparses the DMXModes node like `read_hash_map_from_event`, but reads the DMXMode subtrees on the threads of the crate's pool.
+    ///
+    ///The subtrees are found by scanning the element boundaries first and are deparsed with the Names and warnings of the running deparse, so the result, errors and warnings are the same as without the feature. Only if the scan finds malformed xml or there is only one mode, the node is read sequentially
+    pub(crate) fn read_hash_map_parallel(
+        reader: &mut Reader<&[u8]>,
+        event: BytesStart<'_>,
+        has_children: bool,
+    ) -> Result<HashMap<Name, Self>, GdtfError> {
+        if !has_children || event.name() != Self::PARENT_NODE_NAME {
+            return Self::read_hash_map_from_event(reader, event, has_children);
+        }
+        let sequential = reader.clone();
+        let offset = reader.buffer_position();
+        let input = reader.clone().into_underlying_reader();
+        let subtrees = match Self::find_subtrees(reader) {
+            Some(subtrees) if subtrees.len() > 1 => subtrees,
+            _ => {
+                *reader = sequential;
+                return Self::read_hash_map_from_event(reader, event, has_children);
+            }
+        };
+        //Subtrees are only found at the start of a node, so the section is valid utf-8 if the input is
+        let first = subtrees[0].0;
+        let last = subtrees[subtrees.len() - 1].1;
+        let section: Arc<str> = match std::str::from_utf8(&input[first - offset..last - offset]) {
+            Ok(section) => Arc::from(section),
+            Err(_) => {
+                *reader = sequential;
+                return Self::read_hash_map_from_event(reader, event, has_children);
+            }
+        };
+
+        let position = warnings::event_start(offset, &event, has_children);
+        let _node = warnings::enter_node(Self::PARENT_NODE_NAME, position);
+        let interner = name::interner();
+        let collecting = warnings::read_nodes().is_some();
+        let starts: Vec<usize> = subtrees.iter().map(|(start, _)| *start).collect();
+        let modes = pool::map(subtrees, move |(start, end)| {
+            name::interning_with(interner.clone(), || {
+                warnings::fork(collecting, || {
+                    Self::read_subtree(&section[start - first..end - first])
+                })
+            })
+        })
+        .map_err(GdtfError::ParallelDeparseError)?;
+
+        let mut out = HashMap::new();
+        for (start, (mode, forked)) in starts.into_iter().zip(modes) {
+            if let Some(forked) = forked {
+                warnings::join(forked, start);
+            }
+            let (key, mode) =
+                mode.map_err(|e| Self::locate_in_parent(e.offset_location(start), position))?;
+            let key = key.ok_or_else(|| {
+                Self::locate_in_parent(
+                    Self::child_primary_key_not_found(Self::NODE_NAME, Self::PRIMARY_KEY_NAME)
+                        .into(),
+                    position,
+                )
+            })?;
+            read::insert_unique(&mut out, Self::NODE_NAME, start, key, mode);
+        }
+        Ok(out)
+    }
+
+    ///Moves the reader behind the end of DMXModes and returns the start and end position of every DMXMode in it. Returns None if the xml is malformed
+    fn find_subtrees(reader: &mut Reader<&[u8]>) -> Option<Vec<(usize, usize)>> {
+        let mut buf: Vec<u8> = Vec::new();
+        let mut subtrees = vec![];
+        loop {
+            match reader.read_event(&mut buf).ok()? {
+                Event::Start(e) if e.name() == Self::NODE_NAME => {
+                    let start = warnings::event_start(reader.buffer_position(), &e, true);
+                    reader.read_to_end(Self::NODE_NAME, &mut Vec::new()).ok()?;
+                    subtrees.push((start, reader.buffer_position()));
+                }
+                Event::Empty(e) if e.name() == Self::NODE_NAME => {
+                    let start = warnings::event_start(reader.buffer_position(), &e, false);
+                    subtrees.push((start, reader.buffer_position()));
+                }
+                Event::End(e) if e.name() == Self::PARENT_NODE_NAME => return Some(subtrees),
+                Event::Eof => return None,
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+
+    ///Deparses a single DMXMode from the xml of its subtree
+    fn read_subtree(xml: &str) -> Result<(Option<Name>, Self), GdtfError> {
+        let mut reader = Reader::from_str(xml);
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            match reader
+                .read_event(&mut buf)
+                .map_err(GdtfReadError::QuickXmlError)?
+            {
+                Event::Start(e) => return Self::read_single_from_event(&mut reader, e, true),
+                Event::Empty(e) => return Self::read_single_from_event(&mut reader, e, false),
+                Event::Eof => {
+                    return Err(GdtfReadError::new_xml_unexpected_eof(Self::NODE_NAME).into())
+                }
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+}
+
+impl WriteGdtf for DmxMode {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Geometry", &self.geometry);
+    }
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        DmxChannel::write_vec(&self.dmx_channels, writer)?;
+        Relation::write_hash_map(&self.relations, writer)?;
+        FtMacro::write_hash_map(&self.ft_macros, writer)
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for DmxMode {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -267,11 +447,93 @@ impl TestReadGdtf for DmxMode {
 
 #[cfg(test)]
 mod tests {
+    #[cfg(feature = "parallel")]
+    use std::collections::HashMap;
+
     use crate::fixture_type::dmx_mode::DmxMode;
     use crate::utils::read::TestReadGdtf;
+    #[cfg(feature = "parallel")]
+    use crate::utils::units::name::Name;
 
     #[test]
     fn test_deparse() {
         DmxMode::execute_tests();
     }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_read_hash_map_parallel() {
+        use quick_xml::events::Event;
+        use quick_xml::Reader;
+
+        use crate::utils::read::ReadGdtf;
+
+        use crate::utils::warnings::{self, ParseMode, ParseWarning};
+
+        type Read = (
+            Result<(HashMap<Name, DmxMode>, Vec<ParseWarning>), String>,
+            usize,
+        );
+
+        fn read(xml: &str, parallel: bool, mode: Option<ParseMode>) -> Read {
+            let mut reader = Reader::from_str(xml);
+            let mut buf: Vec<u8> = Vec::new();
+            loop {
+                if let Event::Start(e) = reader.read_event(&mut buf).unwrap() {
+                    let read = || {
+                        if parallel {
+                            DmxMode::read_hash_map_parallel(&mut reader, e, true)
+                        } else {
+                            DmxMode::read_hash_map_from_event(&mut reader, e, true)
+                        }
+                    };
+                    let result = match mode {
+                        Some(mode) => warnings::collect(mode, xml, read),
+                        None => read().map(|modes| (modes, vec![])),
+                    };
+                    let result = result.map_err(|e| format!("{:?}", e.locate_in(xml)));
+                    return (result, reader.buffer_position());
+                }
+            }
+        }
+
+        let modes = DmxMode::testdata_xml();
+        let faulty = r#"<DMXMode Name="Mode9"><DMXChannels/></DMXMode>"#;
+        let renamed =
+            r#"<DMXMode Name="Mode1" Geometry="Geometry1"><DMXChannels/><Unknown/></DMXMode>"#;
+        for xml in [
+            format!("<DMXModes>{}</DMXModes><Next/>", modes),
+            format!("<DMXModes>\n  {}\n  {}\n</DMXModes>", modes, modes),
+            format!("<DMXModes>{}{}</DMXModes>", modes, faulty),
+            format!(
+                "<DMXModes>\n{}\n  {}\n{}\n</DMXModes>",
+                modes, renamed, faulty
+            ),
+            format!("<DMXModes>{}<DMXMode Name=\"Mode9\"></DMXModes>", modes),
+            format!("<DMXModes>\n{}\n  {}\n</DMXModes>", modes, renamed),
+            "<DMXModes></DMXModes>".to_string(),
+        ] {
+            for mode in [None, Some(ParseMode::Lenient), Some(ParseMode::Strict)] {
+                let (sequential, parallel) = (read(&xml, false, mode), read(&xml, true, mode));
+                assert_eq!(sequential.0, parallel.0);
+                //Deparsing stops at an error, so the position only matters if it succeeded
+                if sequential.0.is_ok() {
+                    assert_eq!(sequential.1, parallel.1);
+                }
+            }
+        }
+        let mode1 = r#"<DMXMode Name="Mode1" Geometry="Geometry1"><DMXChannels/></DMXMode>"#;
+        let xml = format!("<DMXModes>\n  {}\n  {}\n</DMXModes>", mode1, renamed);
+        let (read_modes, warnings) = read(&xml, true, Some(ParseMode::Lenient)).0.unwrap();
+        assert!(read_modes.contains_key(&Name::from("Mode1 {2}")));
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings.iter().all(|w| w.line == 3 && w.column > 1));
+        assert_eq!(warnings[0].path, "DMXModes/DMXMode[Mode1]/Unknown");
+        let error = read(
+            &format!("<DMXModes>{}\n  {}</DMXModes>", modes, faulty),
+            true,
+            None,
+        );
+        assert!(error.0.unwrap_err().contains("line: 2, column: 3"));
+    }
 }
@@ -1,4 +1,5 @@
-use std::fmt::Debug;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
@@ -11,9 +12,13 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
 use crate::utils::units::node::Node;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///Relation between the master DMX channel and the following logical channel
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Relation {
     ///Link to the master DMX channel; Starting point: DMX mode
     pub master: Node,
@@ -68,12 +73,14 @@ impl ReadGdtf for Relation {
 
     fn move_data(data_holder: Self::DataHolder) -> Result<Self, Self::Error> {
         Ok(Self {
-            master: data_holder
-                .master
-                .unwrap_or_else(|| Node::new_from_str("?").unwrap().unwrap()),
-            follower: data_holder
-                .follower
-                .unwrap_or_else(|| Node::new_from_str("?").unwrap().unwrap()),
+            master: data_holder.master.unwrap_or_else(|| {
+                warnings::missing("Master", "?");
+                Node(vec![Name::from("?")])
+            }),
+            follower: data_holder.follower.unwrap_or_else(|| {
+                warnings::missing("Follower", "?");
+                Node(vec![Name::from("?")])
+            }),
             relation_type: data_holder.relation_type,
         })
     }
@@ -85,6 +92,14 @@ impl ReadGdtf for Relation {
     }
 }
 
+impl WriteGdtf for Relation {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Master", &self.master);
+        write::push_attribute(event, b"Follower", &self.follower);
+        write::push_attribute(event, b"Type", &self.relation_type);
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Relation {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -154,6 +169,7 @@ impl TestReadGdtf for Relation {
 //-----------------------------------------------------------------------------------------------------------------
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Type of the relation; Values: “Multiply”, “Override”
 pub enum RelationType {
     Multiply,
@@ -171,7 +187,11 @@ impl RelationType {
     pub fn new_from_str(value: &str) -> Self {
         match value {
             "Override" => RelationType::Override,
-            _ => RelationType::Multiply,
+            "Multiply" => RelationType::Multiply,
+            _ => {
+                warnings::substituted("Multiply");
+                RelationType::Multiply
+            }
         }
     }
     /// Parses a quick-xml-attribute provided by gdtf-xml-description to RelationType
@@ -198,6 +218,20 @@ impl Default for RelationType {
     }
 }
 
+/// Displays RelationType as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::fixture_type::dmx_mode::relation::RelationType;
+/// assert_eq!(RelationType::Multiply.to_string(), "Multiply");
+/// assert_eq!(RelationType::Override.to_string(), "Override");
+/// ```
+impl Display for RelationType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            RelationType::Multiply => write!(f, "Multiply"),
+            RelationType::Override => write!(f, "Override"),
+        }
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of RelationType
@@ -1,9 +1,11 @@
 //! Holds the GDTF FixtureType and it's children
 use std::collections::HashMap;
-use std::fmt::Debug;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
 
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 #[cfg(test)]
 use crate::fixture_type::attribute_definitions::activation_group::ActivationGroup;
@@ -16,6 +18,7 @@ use crate::fixture_type::dmx_mode::DmxMode;
 use crate::fixture_type::physical_descriptions::PhysicalDescriptions;
 use crate::fixture_type::wheel::Wheel;
 use crate::utils::errors::GdtfError;
+use crate::utils::raw_xml::RawXml;
 use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
@@ -23,15 +26,22 @@ use crate::utils::read::TestReadGdtf;
 use crate::utils::units::guid::Guid;
 use crate::utils::units::name::Name;
 use crate::utils::units::resource::Resource;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 use crate::Gdtf;
 
 pub mod attribute_definitions;
+pub mod builder;
+pub mod diff;
 pub mod dmx_mode;
 pub mod physical_descriptions;
+pub mod ref_ft;
 pub mod wheel;
 
 ///The FixtureType node_2 is the starting point of the description of the fixture type
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct FixtureType {
     ///Name of the fixture type.
     pub name: Name,
@@ -57,18 +67,18 @@ pub struct FixtureType {
     pub wheels: Option<HashMap<Name, Wheel>>,
     //Contains additional physical descriptions.
     pub physical_descriptions: Option<PhysicalDescriptions>,
-    //Contains models of physically separated parts of the device.
-    // pub models: Option<Models>,
-    //Describes physically separated parts of the device.
-    //pub geometries: Geometries,
+    ///Contains models of physically separated parts of the device. Not deparsed but kept as xml
+    pub models: Option<RawXml>,
+    ///Describes physically separated parts of the device. Not deparsed but kept as xml
+    pub geometries: Option<RawXml>,
     ///Contains descriptions of the DMX modes.
     pub dmx_modes: HashMap<Name, DmxMode>,
-    //Describe the history of the fixture type.
-    //pub revisions: Option<Revisions>,
-    //Is used to transfer user - defined and fixture type specific presets to other show files.
-    //pub ft_presets: Option<FTPresets>,
-    //Specifies supported protocols.
-    //pub protocols: Option<Protocols>,
+    ///Describe the history of the fixture type. Not deparsed but kept as xml
+    pub revisions: Option<RawXml>,
+    ///Is used to transfer user - defined and fixture type specific presets to other show files. Not deparsed but kept as xml
+    pub ft_presets: Option<RawXml>,
+    ///Specifies supported protocols. Not deparsed but kept as xml
+    pub protocols: Option<RawXml>,
 }
 
 #[derive(Default)]
@@ -86,6 +96,11 @@ pub(crate) struct FixtureTypeDataHolder {
     pub dmx_modes: Option<HashMap<Name, DmxMode>>,
     pub wheels: Option<HashMap<Name, Wheel>>,
     pub physical_descriptions: Option<PhysicalDescriptions>,
+    pub models: Option<RawXml>,
+    pub geometries: Option<RawXml>,
+    pub revisions: Option<RawXml>,
+    pub ft_presets: Option<RawXml>,
+    pub protocols: Option<RawXml>,
 }
 
 impl ReadGdtf for FixtureType {
@@ -109,7 +124,12 @@ impl ReadGdtf for FixtureType {
             b"Description" => data_holder.description = Some(read::attr_to_string(attr)),
             b"FixtureTypeID" => data_holder.fixture_type_id = Some(Guid::new_from_attr(attr)?),
             b"Thumbnail" => data_holder.thumbnail = Some(Resource::new_from_attr(attr)),
-            b"RefFT" => data_holder.ref_ft = Guid::new_from_attr(attr).ok(),
+            b"RefFT" => {
+                data_holder.ref_ft = Guid::new_from_attr(attr.clone()).ok();
+                if data_holder.ref_ft.is_none() && !read::attr_to_str(&attr).is_empty() {
+                    warnings::dropped();
+                }
+            }
             b"CanHaveChildren" => {
                 data_holder.can_have_children = Some(CanHaveChildren::new_from_attr(attr))
             }
@@ -125,6 +145,7 @@ impl ReadGdtf for FixtureType {
         has_children: bool,
     ) -> Result<(), Self::Error> {
         match event.name() {
+            #[cfg(not(feature = "parallel"))]
             DmxMode::PARENT_NODE_NAME => {
                 data_holder.dmx_modes = Some(DmxMode::read_hash_map_from_event(
                     reader,
@@ -132,6 +153,14 @@ impl ReadGdtf for FixtureType {
                     has_children,
                 )?)
             }
+            #[cfg(feature = "parallel")]
+            DmxMode::PARENT_NODE_NAME => {
+                data_holder.dmx_modes = Some(DmxMode::read_hash_map_parallel(
+                    reader,
+                    event,
+                    has_children,
+                )?)
+            }
             AttributeDefinitions::NODE_NAME => {
                 data_holder.attribute_definitions = Some(
                     AttributeDefinitions::read_single_from_event(reader, event, has_children)?.1,
@@ -149,6 +178,21 @@ impl ReadGdtf for FixtureType {
                     PhysicalDescriptions::read_single_from_event(reader, event, has_children)?.1,
                 )
             }
+            b"Models" => {
+                data_holder.models = Some(RawXml::read_from_event(reader, event, has_children)?)
+            }
+            b"Geometries" => {
+                data_holder.geometries = Some(RawXml::read_from_event(reader, event, has_children)?)
+            }
+            b"Revisions" => {
+                data_holder.revisions = Some(RawXml::read_from_event(reader, event, has_children)?)
+            }
+            b"FTPresets" => {
+                data_holder.ft_presets = Some(RawXml::read_from_event(reader, event, has_children)?)
+            }
+            b"Protocols" => {
+                data_holder.protocols = Some(RawXml::read_from_event(reader, event, has_children)?)
+            }
             _ => {}
         }
         Ok(())
@@ -185,13 +229,57 @@ impl ReadGdtf for FixtureType {
                 .ok_or_else(|| Self::attribute_not_found(b"DmxModes"))?,
             wheels: data_holder.wheels,
             physical_descriptions: data_holder.physical_descriptions,
+            models: data_holder.models,
+            geometries: data_holder.geometries,
+            revisions: data_holder.revisions,
+            ft_presets: data_holder.ft_presets,
+            protocols: data_holder.protocols,
         })
     }
 
     fn read_primary_key_from_attr(
         _: quick_xml::events::attributes::Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed!");
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for FixtureType {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Name", &self.name);
+        write::push_attribute(event, b"ShortName", &self.short_name);
+        write::push_attribute(event, b"LongName", &self.long_name);
+        write::push_attribute(event, b"Manufacturer", &self.manufacturer);
+        write::push_attribute(event, b"Description", &self.description);
+        write::push_attribute(event, b"FixtureTypeID", &self.fixture_type_id);
+        write::push_attribute_option(event, b"Thumbnail", &self.thumbnail);
+        write::push_attribute_option(event, b"RefFT", &self.ref_ft);
+        write::push_attribute(event, b"CanHaveChildren", &self.can_have_children);
+    }
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        self.attribute_definitions.write_single(writer, None)?;
+        if let Some(wheels) = &self.wheels {
+            Wheel::write_hash_map(wheels, writer)?;
+        }
+        if let Some(physical_descriptions) = &self.physical_descriptions {
+            physical_descriptions.write_single(writer, None)?;
+        }
+        for raw_xml in [&self.models, &self.geometries].into_iter().flatten() {
+            raw_xml.write(writer)?;
+        }
+        DmxMode::write_hash_map(&self.dmx_modes, writer)?;
+        for raw_xml in [&self.revisions, &self.ft_presets, &self.protocols]
+            .into_iter()
+            .flatten()
+        {
+            raw_xml.write(writer)?;
+        }
+        Ok(())
     }
 }
 
@@ -222,6 +310,11 @@ impl TestReadGdtf for FixtureType {
                     dmx_modes: DmxMode::testdata_hash_map(),
                     wheels: None,
                     physical_descriptions: Some(PhysicalDescriptions::testdata_vec()[0].clone()),
+                    models: None,
+                    geometries: None,
+                    revisions: None,
+                    ft_presets: None,
+                    protocols: None,
                 }),
             ),
             (
@@ -245,6 +338,11 @@ impl TestReadGdtf for FixtureType {
                     dmx_modes: DmxMode::testdata_hash_map(),
                     wheels: None,
                     physical_descriptions: None,
+                    models: None,
+                    geometries: None,
+                    revisions: None,
+                    ft_presets: None,
+                    protocols: None,
                 }),
             ),
             (
@@ -268,6 +366,11 @@ impl TestReadGdtf for FixtureType {
                     dmx_modes: DmxMode::testdata_hash_map(),
                     wheels: Some(Wheel::testdata_hash_map()),
                     physical_descriptions: None,
+                    models: None,
+                    geometries: None,
+                    revisions: None,
+                    ft_presets: None,
+                    protocols: None,
                 }),
             ),
         ]
@@ -313,6 +416,7 @@ impl TestReadGdtf for FixtureType {
 
 ///Describes if it is possible to mount other devices to this device. Value: “Yes”, “No”. Default value: “Yes”
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CanHaveChildren {
     Yes,
     No,
@@ -329,7 +433,11 @@ impl CanHaveChildren {
     pub fn new_from_str(value: &str) -> Self {
         match value {
             "No" => Self::No,
-            _ => Self::Yes,
+            "Yes" => Self::Yes,
+            _ => {
+                warnings::substituted("Yes");
+                Self::Yes
+            }
         }
     }
     ///Parses a quick-xml-attribute provided by gdtf-xml-description to CanHaveChildren
@@ -356,6 +464,20 @@ impl Default for CanHaveChildren {
     }
 }
 
+///```rust
+/// use gdtf_parser::fixture_type::CanHaveChildren;
+/// assert_eq!(CanHaveChildren::Yes.to_string(), "Yes");
+/// assert_eq!(CanHaveChildren::No.to_string(), "No");
+/// ```
+impl Display for CanHaveChildren {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            CanHaveChildren::Yes => write!(f, "Yes"),
+            CanHaveChildren::No => write!(f, "No"),
+        }
+    }
+}
+
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // Start of CanHaveChildren
@@ -1,17 +1,25 @@
 //!Defines the color space that is used for color mixing with indirect RGB, Hue/Sat, xyY or CMY control input
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
 use quick_xml::Reader;
 
+use crate::utils::color::{ColorMatrix, ColorRgb, ColorXyz, Gamut};
 use crate::utils::errors::GdtfError;
 use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::color_cie::ColorCie;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///Defines the color space that is used for color mixing with indirect RGB, Hue/Sat, xyY or CMY control input
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ColorSpace {
     ///CIE xyY of the Red Primary
     pub red: ColorCie,
@@ -78,20 +86,73 @@ impl ReadGdtf for ColorSpace {
     }
 
     fn move_data(data_holder: Self::DataHolder) -> Result<Self, Self::Error> {
-        Ok(match data_holder.mode {
-            ColorSpaceMode::Custom => Self {
-                red: data_holder
-                    .red
-                    .ok_or_else(|| Self::attribute_not_found(b"Red"))?,
-                green: data_holder
-                    .green
-                    .ok_or_else(|| Self::attribute_not_found(b"Green"))?,
-                blue: data_holder
-                    .blue
-                    .ok_or_else(|| Self::attribute_not_found(b"Blue"))?,
-                white_point: data_holder
-                    .white_point
-                    .ok_or_else(|| Self::attribute_not_found(b"WhitePoint"))?,
+        if let Some(color_space) = Self::predefined(&data_holder.mode) {
+            return Ok(color_space);
+        }
+        Ok(Self {
+            red: data_holder
+                .red
+                .ok_or_else(|| Self::attribute_not_found(b"Red"))?,
+            green: data_holder
+                .green
+                .ok_or_else(|| Self::attribute_not_found(b"Green"))?,
+            blue: data_holder
+                .blue
+                .ok_or_else(|| Self::attribute_not_found(b"Blue"))?,
+            white_point: data_holder
+                .white_point
+                .ok_or_else(|| Self::attribute_not_found(b"WhitePoint"))?,
+        })
+    }
+
+    fn read_primary_key_from_attr(
+        _: Attribute<'_>,
+    ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
+        Ok(None)
+    }
+}
+
+///The Mode is written as one of the predefined color spaces if all colors match, otherwise as Custom with all colors
+impl WriteGdtf for ColorSpace {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        let mode = self.mode();
+        write::push_attribute(event, b"Mode", &mode);
+        if mode == ColorSpaceMode::Custom {
+            write::push_attribute(event, b"Red", &self.red);
+            write::push_attribute(event, b"Green", &self.green);
+            write::push_attribute(event, b"Blue", &self.blue);
+            write::push_attribute(event, b"WhitePoint", &self.white_point);
+        }
+    }
+}
+
+///A colour converted into the RGB space of a ColorSpace
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorSpaceRgb {
+    ///Linear RGB components from 0 to 1, for example to drive the ColorRGB_* attributes
+    pub rgb: ColorRgb,
+    ///True if the requested colour is outside of the gamut or too bright and had to be clamped
+    pub out_of_gamut: bool,
+}
+
+///Tolerance for rounding errors of RGB components before a colour is reported as out of gamut
+const GAMUT_TOLERANCE: f32 = 1e-4;
+
+impl ColorSpace {
+    ///Returns the color space with the primaries of a predefined ColorSpaceMode or None for ColorSpaceMode::Custom
+    /// ```rust
+    /// use gdtf_parser::fixture_type::physical_descriptions::color_space::{ColorSpace, ColorSpaceMode};
+    /// assert_eq!(ColorSpace::predefined(&ColorSpaceMode::SRgb).unwrap().red.x, 0.64);
+    /// assert!(ColorSpace::predefined(&ColorSpaceMode::Custom).is_none());
+    /// ```
+    pub fn predefined(mode: &ColorSpaceMode) -> Option<Self> {
+        Some(match mode {
+            ColorSpaceMode::Custom => return None,
+            ColorSpaceMode::SRgb => Self {
+                red: SRGB_RED,
+                green: SRGB_GREEN,
+                blue: SRGB_BLUE,
+                white_point: SRGB_WHITE_POINT,
             },
             ColorSpaceMode::ProPhoto => Self {
                 red: PRO_PHOTO_RED,
@@ -99,12 +160,6 @@ impl ReadGdtf for ColorSpace {
                 blue: PRO_PHOTO_BLUE,
                 white_point: PRO_PHOTO_WHITE_POINT,
             },
-            ColorSpaceMode::SRgb => Self {
-                red: SRGB_RED,
-                green: SRGB_GREEN,
-                blue: SRGB_BLUE,
-                white_point: SRGB_WHITE_POINT,
-            },
             ColorSpaceMode::Ansi => Self {
                 red: ANSI_RED,
                 green: ANSI_GREEN,
@@ -114,10 +169,103 @@ impl ReadGdtf for ColorSpace {
         })
     }
 
-    fn read_primary_key_from_attr(
-        _: Attribute<'_>,
-    ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+    ///Returns the matrix that converts linear RGB of the color space to XYZ with a white Y of 1. Returns None if the primaries are on a line
+    pub fn rgb_to_xyz(&self) -> Option<ColorMatrix> {
+        ColorMatrix::rgb_to_xyz(&self.red, &self.green, &self.blue, &self.white_point)
+    }
+
+    ///Returns the matrix that converts XYZ with a white Y of 1 to linear RGB of the color space. Returns None if the primaries are on a line
+    pub fn xyz_to_rgb(&self) -> Option<ColorMatrix> {
+        self.rgb_to_xyz()?.inverse()
+    }
+
+    ///Returns the triangle of the primaries
+    pub fn gamut(&self) -> Gamut {
+        Gamut {
+            red: self.red.clone(),
+            green: self.green.clone(),
+            blue: self.blue.clone(),
+            white: self.white_point.clone(),
+        }
+    }
+
+    ///Converts a colour with a white Y of 100 into linear RGB of the color space.
+    ///
+    ///Colours outside of the primaries are moved towards the white point and colours that are too bright are scaled down, both are reported with `out_of_gamut`. Returns None if the primaries are on a line
+    /// ```rust
+    /// use gdtf_parser::fixture_type::physical_descriptions::color_space::{ColorSpace, ColorSpaceMode};
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// let srgb = ColorSpace::predefined(&ColorSpaceMode::SRgb).unwrap();
+    ///
+    /// let red = srgb.to_rgb(&ColorCie{ x: 0.64, y: 0.33, Y: 21.26 }).unwrap();
+    /// assert!((red.rgb.r - 1.0).abs() < 0.001 && red.rgb.g < 0.001 && red.rgb.b < 0.001);
+    /// assert!(!red.out_of_gamut);
+    ///
+    /// let laser_green = srgb.to_rgb(&ColorCie{ x: 0.17, y: 0.8, Y: 50.0 }).unwrap();
+    /// assert!(laser_green.out_of_gamut);
+    /// ```
+    pub fn to_rgb(&self, color: &ColorCie) -> Option<ColorSpaceRgb> {
+        let matrix = self.xyz_to_rgb()?;
+        let convert = |color: &ColorCie| {
+            let xyz = color.to_xyz();
+            let [r, g, b] = matrix.apply([xyz.X / 100.0, xyz.Y / 100.0, xyz.Z / 100.0]);
+            ColorRgb { r, g, b }
+        };
+        let mut rgb = convert(color);
+        let mut out_of_gamut = false;
+        if rgb.r.min(rgb.g).min(rgb.b) < -GAMUT_TOLERANCE {
+            rgb = convert(&color.clamp_to_gamut(&self.gamut()));
+            out_of_gamut = true;
+        }
+        let max = rgb.r.max(rgb.g).max(rgb.b);
+        if max > 1.0 + GAMUT_TOLERANCE {
+            rgb = ColorRgb {
+                r: rgb.r / max,
+                g: rgb.g / max,
+                b: rgb.b / max,
+            };
+            out_of_gamut = true;
+        }
+        Some(ColorSpaceRgb {
+            rgb: ColorRgb {
+                r: rgb.r.clamp(0.0, 1.0),
+                g: rgb.g.clamp(0.0, 1.0),
+                b: rgb.b.clamp(0.0, 1.0),
+            },
+            out_of_gamut,
+        })
+    }
+
+    ///Converts linear RGB of the color space to a colour with a white Y of 100. Returns None if the primaries are on a line
+    #[allow(non_snake_case)]
+    pub fn from_rgb(&self, rgb: &ColorRgb) -> Option<ColorCie> {
+        let [X, Y, Z] = self.rgb_to_xyz()?.apply([rgb.r, rgb.g, rgb.b]);
+        Some(ColorCie::from_xyz(&ColorXyz {
+            X: X * 100.0,
+            Y: Y * 100.0,
+            Z: Z * 100.0,
+        }))
+    }
+
+    ///Returns the predefined ColorSpaceMode that matches all colors of the color space or ColorSpaceMode::Custom if there is none
+    fn mode(&self) -> ColorSpaceMode {
+        let colors = (&self.red, &self.green, &self.blue, &self.white_point);
+        if colors == (&SRGB_RED, &SRGB_GREEN, &SRGB_BLUE, &SRGB_WHITE_POINT) {
+            ColorSpaceMode::SRgb
+        } else if colors
+            == (
+                &PRO_PHOTO_RED,
+                &PRO_PHOTO_GREEN,
+                &PRO_PHOTO_BLUE,
+                &PRO_PHOTO_WHITE_POINT,
+            )
+        {
+            ColorSpaceMode::ProPhoto
+        } else if colors == (&ANSI_RED, &ANSI_GREEN, &ANSI_BLUE, &ANSI_WHITE_POINT) {
+            ColorSpaceMode::Ansi
+        } else {
+            ColorSpaceMode::Custom
+        }
     }
 }
 
@@ -342,6 +490,7 @@ const ANSI_WHITE_POINT: ColorCie = ColorCie {
 //-----------------------------------------------------------------------------------------------------------------
 ///Definition of the Color Space that used for the indirect color mixing. The defined values are "Custom", "sRGB", "ProPhoto" and "ANSI". Default Value: "sRGB"
 #[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ColorSpaceMode {
     Custom,
     ///Adobe sRGB, HDTV IEC 61966-2-1:1999
@@ -367,7 +516,11 @@ impl ColorSpaceMode {
             "Custom" => Self::Custom,
             "ProPhoto" => Self::ProPhoto,
             "ANSI" => Self::Ansi,
-            _ => Self::SRgb,
+            "sRGB" => Self::SRgb,
+            _ => {
+                warnings::substituted("sRGB");
+                Self::SRgb
+            }
         }
     }
 
@@ -397,6 +550,24 @@ impl Default for ColorSpaceMode {
     }
 }
 
+/// Displays ColorSpaceMode as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::fixture_type::physical_descriptions::color_space::ColorSpaceMode;
+/// assert_eq!(ColorSpaceMode::Custom.to_string(), "Custom");
+/// assert_eq!(ColorSpaceMode::SRgb.to_string(), "sRGB");
+/// assert_eq!(ColorSpaceMode::ProPhoto.to_string(), "ProPhoto");
+/// assert_eq!(ColorSpaceMode::Ansi.to_string(), "ANSI");
+/// ```
+impl Display for ColorSpaceMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorSpaceMode::Custom => write!(f, "Custom"),
+            ColorSpaceMode::SRgb => write!(f, "sRGB"),
+            ColorSpaceMode::ProPhoto => write!(f, "ProPhoto"),
+            ColorSpaceMode::Ansi => write!(f, "ANSI"),
+        }
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of ColorSpaceMode
@@ -404,11 +575,156 @@ impl Default for ColorSpaceMode {
 //-----------------------------------------------------------------------------------------------------------------
 #[cfg(test)]
 mod tests {
-    use crate::fixture_type::physical_descriptions::color_space::ColorSpace;
+    use crate::fixture_type::physical_descriptions::color_space::{ColorSpace, ColorSpaceMode};
+    use crate::utils::color::{ColorMatrix, ColorRgb};
     use crate::utils::read::TestReadGdtf;
+    use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+
+    fn assert_matrix(expected: [[f32; 3]; 3], actual: ColorMatrix) {
+        for (expected, actual) in expected.iter().flatten().zip(actual.0.iter().flatten()) {
+            assert!(
+                (expected - actual).abs() < 0.0002,
+                "Expected {:?} but got {:?}",
+                expected,
+                actual
+            );
+        }
+    }
 
     #[test]
     fn test_deparse() {
         ColorSpace::execute_tests()
     }
+
+    #[test]
+    fn test_matrices() {
+        let srgb = ColorSpace::predefined(&ColorSpaceMode::SRgb).unwrap();
+        assert_matrix(
+            [
+                [0.4124, 0.3576, 0.1805],
+                [0.2126, 0.7152, 0.0722],
+                [0.0193, 0.1192, 0.9505],
+            ],
+            srgb.rgb_to_xyz().unwrap(),
+        );
+        assert_matrix(
+            [
+                [3.2410, -1.5374, -0.4986],
+                [-0.9692, 1.8760, 0.0416],
+                [0.0556, -0.2040, 1.0570],
+            ],
+            srgb.xyz_to_rgb().unwrap(),
+        );
+
+        let pro_photo = ColorSpace::predefined(&ColorSpaceMode::ProPhoto).unwrap();
+        assert_matrix(
+            [
+                [0.7977, 0.1352, 0.0313],
+                [0.2880, 0.7119, 0.0001],
+                [0.0, 0.0, 0.8251],
+            ],
+            pro_photo.rgb_to_xyz().unwrap(),
+        );
+
+        for mode in [
+            ColorSpaceMode::SRgb,
+            ColorSpaceMode::ProPhoto,
+            ColorSpaceMode::Ansi,
+        ] {
+            let color_space = ColorSpace::predefined(&mode).unwrap();
+            let identity = color_space
+                .rgb_to_xyz()
+                .unwrap()
+                .mul(&color_space.xyz_to_rgb().unwrap());
+            assert_matrix(
+                [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+                identity,
+            );
+            //The primaries and the white point are converted to their RGB components
+            let white = color_space
+                .from_rgb(&ColorRgb {
+                    r: 1.0,
+                    g: 1.0,
+                    b: 1.0,
+                })
+                .unwrap();
+            assert!((white.x - color_space.white_point.x).abs() < 0.0001);
+            assert!((white.y - color_space.white_point.y).abs() < 0.0001);
+            assert!((white.Y - 100.0).abs() < 0.01);
+            let red = color_space.to_rgb(&ColorCie {
+                Y: color_space
+                    .from_rgb(&ColorRgb {
+                        r: 1.0,
+                        g: 0.0,
+                        b: 0.0,
+                    })
+                    .unwrap()
+                    .Y,
+                ..color_space.red.clone()
+            });
+            let red = red.unwrap();
+            assert!(!red.out_of_gamut, "{:?}", mode);
+            assert!((red.rgb.r - 1.0).abs() < 0.001 && red.rgb.g < 0.001 && red.rgb.b < 0.001);
+        }
+
+        let collinear = ColorSpace {
+            red: ColorCie {
+                x: 0.1,
+                y: 0.1,
+                Y: 1.0,
+            },
+            green: ColorCie {
+                x: 0.2,
+                y: 0.2,
+                Y: 1.0,
+            },
+            blue: ColorCie {
+                x: 0.3,
+                y: 0.3,
+                Y: 1.0,
+            },
+            white_point: COLOR_CIE_WHITE,
+        };
+        assert!(collinear.rgb_to_xyz().is_none());
+        assert!(collinear.to_rgb(&COLOR_CIE_WHITE).is_none());
+    }
+
+    #[test]
+    fn test_to_rgb() {
+        let srgb = ColorSpace::predefined(&ColorSpaceMode::SRgb).unwrap();
+        let pro_photo = ColorSpace::predefined(&ColorSpaceMode::ProPhoto).unwrap();
+
+        let grey = ColorCie {
+            Y: 20.0,
+            ..COLOR_CIE_WHITE
+        };
+        let rgb = srgb.to_rgb(&grey).unwrap();
+        assert!(!rgb.out_of_gamut);
+        assert!((rgb.rgb.r - 0.2).abs() < 0.001 && (rgb.rgb.b - 0.2).abs() < 0.001);
+
+        let too_bright = srgb
+            .to_rgb(&ColorCie {
+                Y: 200.0,
+                ..COLOR_CIE_WHITE
+            })
+            .unwrap();
+        assert!(too_bright.out_of_gamut);
+        assert!((too_bright.rgb.g - 1.0).abs() < 0.001);
+
+        //Saturated green is outside of sRGB, but inside of ProPhoto
+        let green = ColorCie {
+            x: 0.2,
+            y: 0.7,
+            Y: 30.0,
+        };
+        let clamped = srgb.to_rgb(&green).unwrap();
+        assert!(clamped.out_of_gamut);
+        assert!(clamped.rgb.r >= 0.0 && clamped.rgb.b >= 0.0);
+        assert!(clamped.rgb.r.min(clamped.rgb.b) < 0.001);
+        let native = pro_photo.to_rgb(&green).unwrap();
+        assert!(!native.out_of_gamut);
+        let back = pro_photo.from_rgb(&native.rgb).unwrap();
+        assert!((back.x - green.x).abs() < 0.0001 && (back.y - green.y).abs() < 0.0001);
+        assert!((back.Y - green.Y).abs() < 0.01);
+    }
 }
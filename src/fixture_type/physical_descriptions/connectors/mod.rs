@@ -15,9 +15,13 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::connector_type::ConnectorType;
 use crate::utils::units::name::Name;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///defines the connector
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Connector {
     ///The type of the connector. Find a list of predefined types in Annex D.
     pub connector_type: ConnectorType,
@@ -31,6 +35,7 @@ pub struct Connector {
 
 ///Connectors where the addition of the Gender value equals 0, can be connected; Default value: 0; Male Connectors are −1, Female are +1, Universal are 0.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectionGender {
     Male = -1,
     Neutral = 0,
@@ -80,15 +85,23 @@ impl ReadGdtf for Connector {
         match attr.key {
             b"Type" => data_holder.connector_type = ConnectorType::new_from_attr(attr)?,
             b"DMXBreak" => {
-                data_holder.dmx_break =
-                    Some(u32::from_str(read::attr_try_to_str(&attr).unwrap_or("")).unwrap_or(0_u32))
+                data_holder.dmx_break = Some(
+                    u32::from_str(read::attr_try_to_str(&attr).unwrap_or("")).unwrap_or_else(|_| {
+                        warnings::substituted(0);
+                        0_u32
+                    }),
+                )
             }
             b"Gender" => {
                 data_holder.gender =
-                    match i8::from_str(read::attr_try_to_str(&attr).unwrap_or("")).unwrap_or(0_i8) {
-                        -1 => Female,
-                        1 => Male,
-                        _ => Neutral,
+                    match i8::from_str(read::attr_try_to_str(&attr).unwrap_or("")) {
+                        Ok(-1) => Female,
+                        Ok(1) => Male,
+                        Ok(0) => Neutral,
+                        _ => {
+                            warnings::substituted(0);
+                            Neutral
+                        }
                     }
             }
             b"Length" => data_holder.length = read::attr_to_f32(attr),
@@ -117,6 +130,20 @@ impl ReadGdtf for Connector {
     }
 }
 
+impl WriteGdtf for Connector {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Type", &self.connector_type);
+        write::push_attribute_option(event, b"DMXBreak", &self.dmx_break);
+        let gender = match self.gender {
+            Female => -1,
+            Male => 1,
+            Neutral => 0,
+        };
+        write::push_attribute(event, b"Gender", &gender);
+        write::push_attribute(event, b"Length", &self.length);
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Connector {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
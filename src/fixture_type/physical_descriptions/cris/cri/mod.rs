@@ -13,9 +13,12 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///Defines the CRI for one of the 99 color samples
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cri {
     ///Color sample. The defined values are “CES01”, “CES02”, … “CES99”. Default Value “CES01"
     pub ces: u8,
@@ -82,7 +85,14 @@ impl ReadGdtf for Cri {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for Cri {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"CES", &format!("CES{:02}", self.ces));
+        write::push_attribute(event, b"ColorRenderingIndex", &self.color_rendering_index);
     }
 }
 
@@ -1,7 +1,9 @@
 //!Contains Color Rendering Indexes (CRI) for a single color temperature
+use std::io::Write;
+
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::physical_descriptions::cris::cri::Cri;
 use crate::utils::errors::GdtfError;
@@ -9,11 +11,14 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod cri;
 
 ///Contains Color Rendering Indexes (CRI) for a single color temperature
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CriGroup {
     ///Color temperature; Default value: 6 000; Unit: Kelvin
     pub color_temperature: f32,
@@ -74,7 +79,24 @@ impl ReadGdtf for CriGroup {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for CriGroup {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"ColorTemperature", &self.color_temperature);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.cris.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        for cri in self.cris.iter() {
+            cri.write_single(writer, None)?;
+        }
+        Ok(())
     }
 }
 
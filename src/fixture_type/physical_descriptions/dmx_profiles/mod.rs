@@ -7,8 +7,10 @@ use crate::utils::errors::GdtfError;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::write::WriteGdtf;
 
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 ///Defines DMX profile descriptions.
 pub struct DmxProfile {}
 
@@ -41,10 +43,14 @@ impl ReadGdtf for DmxProfile {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
     }
 }
 
+impl WriteGdtf for DmxProfile {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+}
+
 #[cfg(test)]
 impl TestReadGdtf for DmxProfile {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
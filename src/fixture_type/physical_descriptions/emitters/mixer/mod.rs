@@ -0,0 +1,612 @@
+//! Additive colour mixing of the emitters controlled by the ColorAdd channel functions of a DMX mode
+//!
+//! The mixer predicts the colour and luminous intensity of a mix from the intensities of the emitters and solves the intensities for a target colour. Intensities are fractions from 0 to 1 of the DMX range of a channel function.
+//!
+//! Every emitter contributes it's colour at full intensity, scaled by it's relative output. If the emitter has measurements with a luminous intensity, the output follows them, otherwise it is proportional to the intensity. Emitters without a colour get the colour of the spectrum of their measurements or of their dominant wavelength.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use gdtf_parser::fixture_type::builder::{
+//!     ChannelFunctionBuilder, DmxChannelBuilder, DmxModeBuilder, LogicalChannelBuilder,
+//! };
+//! use gdtf_parser::fixture_type::physical_descriptions::emitters::mixer::AdditiveMixer;
+//! use gdtf_parser::fixture_type::physical_descriptions::emitters::Emitter;
+//! use gdtf_parser::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+//! use gdtf_parser::utils::units::name::Name;
+//!
+//! let emitter = |x, y, luminance| Emitter {
+//!     color: Some(ColorCie { x, y, Y: luminance }),
+//!     ..Default::default()
+//! };
+//! let emitters = HashMap::from([
+//!     (Name::new("Red").unwrap(), emitter(0.7, 0.29, 20.0)),
+//!     (Name::new("Green").unwrap(), emitter(0.17, 0.7, 65.0)),
+//!     (Name::new("Blue").unwrap(), emitter(0.135, 0.05, 8.0)),
+//! ]);
+//! let channel = |attribute: &str, emitter: &str| {
+//!     DmxChannelBuilder::new("Beam").logical_channel(
+//!         LogicalChannelBuilder::new(attribute)
+//!             .channel_function(ChannelFunctionBuilder::new(attribute).emitter(emitter)),
+//!     )
+//! };
+//! let (_, dmx_mode) = DmxModeBuilder::new("Mode 1", "Base")
+//!     .dmx_channel(channel("ColorAdd_R", "Red"))
+//!     .dmx_channel(channel("ColorAdd_G", "Green"))
+//!     .dmx_channel(channel("ColorAdd_B", "Blue"))
+//!     .build()
+//!     .unwrap();
+//!
+//! let mixer = AdditiveMixer::from_dmx_mode(&dmx_mode, &emitters).unwrap();
+//! let intensities = mixer.solve_brightest(&COLOR_CIE_WHITE).unwrap();
+//! let mix = mixer.mix(&intensities).color;
+//! assert!((mix.x - COLOR_CIE_WHITE.x).abs() < 0.0001 && (mix.y - COLOR_CIE_WHITE.y).abs() < 0.0001);
+//! ```
+use std::collections::HashMap;
+
+use crate::fixture_type::dmx_mode::DmxMode;
+use crate::fixture_type::physical_descriptions::emitters::Emitter;
+use crate::fixture_type::physical_descriptions::measurement;
+use crate::fixture_type::physical_descriptions::measurement::Measurement;
+use crate::utils::color::ColorXyz;
+use crate::utils::errors::GdtfError;
+use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+use crate::utils::units::name::Name;
+use crate::utils::units::node::Node;
+
+///Maximum number of emitters the solver accepts. The solver checks every combination of emitters at zero or full output, so the time doubles with every emitter
+pub const MAX_SOLVED_EMITTERS: usize = 12;
+
+///Predicts additive mixes of the emitters of a DMX mode
+#[derive(Debug, PartialEq, Clone)]
+pub struct AdditiveMixer {
+    ///Emitters in the order of the DMX channels. Intensities passed to the mixer are in the same order
+    pub emitters: Vec<MixerEmitter>,
+}
+
+///An emitter controlled by a ColorAdd channel function
+#[derive(Debug, PartialEq, Clone)]
+pub struct MixerEmitter {
+    ///Name of the DMX channel of the channel function
+    pub dmx_channel: Name,
+    ///Attribute of the channel function, for example ColorAdd_R
+    pub attribute: Node,
+    ///Name of the emitter in the physical descriptions
+    pub emitter: Name,
+    ///Colour at full intensity. Y is the output relative to the other emitters and 0 for emitters without any colour information
+    pub color: ColorCie,
+    ///Measurements of the luminous intensity, with a dark measurement at 0 % if the emitter has none
+    measurements: Vec<Measurement>,
+}
+
+///Predicted result of a mix
+#[derive(Debug, PartialEq, Clone)]
+pub struct Mix {
+    ///Colour of the mix. Y is on the scale of the colours of the emitters
+    pub color: ColorCie,
+    ///Luminous intensity in cd. None if an emitter in the mix has no measured luminous intensity
+    pub luminous_intensity: Option<f32>,
+}
+
+impl AdditiveMixer {
+    ///Creates a mixer for all channel functions of the mode with a ColorAdd attribute and a link to an emitter
+    ///
+    ///Returns `GdtfError::PhysicalDescriptionsLinkNotFoundError` if a link doesn't point to one of the emitters
+    pub fn from_dmx_mode(
+        dmx_mode: &DmxMode,
+        emitters: &HashMap<Name, Emitter>,
+    ) -> Result<Self, GdtfError> {
+        let mut mixer_emitters = vec![];
+        for (dmx_channel, path, attribute, channel_function) in
+            dmx_mode.channel_functions_with_attribute("ColorAdd_")
+        {
+            let link = match &channel_function.emitter {
+                Some(link) => link,
+                None => continue,
+            };
+            let (name, emitter) = match link.0.as_slice() {
+                [name] => emitters.get_key_value(name),
+                _ => None,
+            }
+            .ok_or_else(|| {
+                GdtfError::PhysicalDescriptionsLinkNotFoundError(
+                    path,
+                    "Emitter".to_string(),
+                    link.to_string(),
+                )
+            })?;
+            mixer_emitters.push(MixerEmitter::new(
+                dmx_channel,
+                attribute.clone(),
+                name.clone(),
+                emitter,
+            ));
+        }
+        Ok(Self {
+            emitters: mixer_emitters,
+        })
+    }
+
+    ///Predicts the mix of the emitters at the intensities. Missing intensities are 0 and intensities are clamped to 0..1
+    pub fn mix(&self, intensities: &[f32]) -> Mix {
+        let mut xyz = ColorXyz {
+            X: 0.0,
+            Y: 0.0,
+            Z: 0.0,
+        };
+        let mut luminous_intensity = Some(0.0);
+        for (emitter, intensity) in self.emitters.iter().zip(intensities) {
+            let intensity = intensity.clamp(0.0, 1.0);
+            let output = emitter.output(intensity);
+            let color = emitter.color.to_xyz();
+            xyz.X += color.X * output;
+            xyz.Y += color.Y * output;
+            xyz.Z += color.Z * output;
+            if intensity > 0.0 {
+                luminous_intensity = luminous_intensity
+                    .zip(emitter.luminous_intensity(intensity))
+                    .map(|(sum, emitter)| sum + emitter);
+            }
+        }
+        Mix {
+            color: ColorCie::from_xyz(&xyz),
+            luminous_intensity,
+        }
+    }
+
+    ///Returns the intensities of the emitters that mix the target colour including it's Y. Of all combinations the brightest one is dimmed to the target, so white emitters are preferred.
+    ///
+    ///Returns None if the chromaticity is out of the gamut of the emitters or the target is brighter than the emitters can mix it
+    pub fn solve(&self, target: &ColorCie) -> Option<Vec<f32>> {
+        let (outputs, luminance) = self.brightest_outputs(target)?;
+        let scale = f64::from(target.Y.max(0.0)) / luminance;
+        if scale > 1.0 + 1e-4 {
+            return None;
+        }
+        Some(self.intensities(outputs.iter().map(|output| output * scale.min(1.0))))
+    }
+
+    ///Returns the intensities of the emitters that mix the chromaticity of the target as bright as possible. The Y of the target is ignored
+    ///
+    ///Returns None if the chromaticity is out of the gamut of the emitters
+    pub fn solve_brightest(&self, target: &ColorCie) -> Option<Vec<f32>> {
+        let (outputs, _) = self.brightest_outputs(target)?;
+        Some(self.intensities(outputs.into_iter()))
+    }
+
+    ///Converts the relative outputs of the emitters to intensities
+    fn intensities(&self, outputs: impl Iterator<Item = f64>) -> Vec<f32> {
+        self.emitters
+            .iter()
+            .zip(outputs)
+            .map(|(emitter, output)| emitter.intensity_for_output(output as f32))
+            .collect()
+    }
+
+    ///Maximizes the Y of the mix under the constraint that it has the chromaticity of the target. Returns the relative outputs of the emitters and the Y of the mix.
+    ///
+    ///This is a linear program with two equality constraints and bounds of 0 and 1, so one of it's solutions has at most two outputs between the bounds. All of these candidates are checked
+    fn brightest_outputs(&self, target: &ColorCie) -> Option<(Vec<f64>, f64)> {
+        let n = self.emitters.len();
+        if n == 0 || n > MAX_SOLVED_EMITTERS || target.y <= 0.0 {
+            return None;
+        }
+        let (x, y) = (f64::from(target.x), f64::from(target.y));
+        let z = 1.0 - x - y;
+        let xyz: Vec<[f64; 3]> = self
+            .emitters
+            .iter()
+            .map(|emitter| {
+                let xyz = emitter.color.to_xyz();
+                [xyz.X, xyz.Y, xyz.Z].map(f64::from)
+            })
+            .collect();
+        //A mix has the target chromaticity if X - x/y * Y and Z - z/y * Y are 0
+        let a: Vec<f64> = xyz.iter().map(|c| c[0] - x / y * c[1]).collect();
+        let b: Vec<f64> = xyz.iter().map(|c| c[2] - z / y * c[1]).collect();
+        let tolerance = 1e-5 * xyz.iter().flatten().map(|v| v.abs()).sum::<f64>();
+
+        let mut best: Option<(Vec<f64>, f64)> = None;
+        let mut consider = |mut outputs: Vec<f64>| {
+            if outputs.iter().any(|&o| !(-1e-6..=1.0 + 1e-6).contains(&o)) {
+                return;
+            }
+            outputs.iter_mut().for_each(|o| *o = o.clamp(0.0, 1.0));
+            let dot = |row: &[f64]| row.iter().zip(&outputs).map(|(r, o)| r * o).sum::<f64>();
+            if dot(&a).abs() > tolerance || dot(&b).abs() > tolerance {
+                return;
+            }
+            let luminance: f64 = xyz.iter().zip(&outputs).map(|(c, o)| c[1] * o).sum();
+            if luminance > best.as_ref().map_or(0.0, |(_, best)| *best) {
+                best = Some((outputs, luminance));
+            }
+        };
+
+        for mask in 0_u32..1 << n {
+            let fixed = |k: usize| if mask & 1 << k != 0 { 1.0 } else { 0.0 };
+            consider((0..n).map(fixed).collect());
+            for i in (0..n).filter(|&i| mask & 1 << i == 0) {
+                let rest_a: f64 = (0..n).filter(|&k| k != i).map(|k| a[k] * fixed(k)).sum();
+                let rest_b: f64 = (0..n).filter(|&k| k != i).map(|k| b[k] * fixed(k)).sum();
+                //One output between the bounds, solved with the better conditioned constraint
+                let single = if a[i].abs() > b[i].abs() {
+                    -rest_a / a[i]
+                } else {
+                    -rest_b / b[i]
+                };
+                if single.is_finite() {
+                    consider(
+                        (0..n)
+                            .map(|k| if k == i { single } else { fixed(k) })
+                            .collect(),
+                    );
+                }
+                for j in (i + 1..n).filter(|&j| mask & 1 << j == 0) {
+                    let determinant = a[i] * b[j] - a[j] * b[i];
+                    if determinant.abs() < 1e-12 {
+                        continue;
+                    }
+                    let rest_a = rest_a - a[j] * fixed(j);
+                    let rest_b = rest_b - b[j] * fixed(j);
+                    let output_i = (-rest_a * b[j] + a[j] * rest_b) / determinant;
+                    let output_j = (-a[i] * rest_b + b[i] * rest_a) / determinant;
+                    consider(
+                        (0..n)
+                            .map(|k| match k {
+                                k if k == i => output_i,
+                                k if k == j => output_j,
+                                k => fixed(k),
+                            })
+                            .collect(),
+                    );
+                }
+            }
+        }
+        best
+    }
+}
+
+impl MixerEmitter {
+    ///Creates a mixer emitter. The colour is taken from the emitter, the spectrum of it's brightest measurement or it's dominant wavelength
+    fn new(dmx_channel: Name, attribute: Node, name: Name, emitter: &Emitter) -> Self {
+        let spectrum = || {
+            let measurement = emitter
+                .measurements
+                .iter()
+                .filter(|m| m.measurement_points.len() > 1)
+                .max_by(|a, b| a.physical.total_cmp(&b.physical))?;
+            let mut spectrum: Vec<(f32, f32)> = measurement
+                .measurement_points
+                .iter()
+                .map(|point| (point.wave_length, point.energy))
+                .collect();
+            spectrum.sort_by(|a, b| a.0.total_cmp(&b.0));
+            ColorCie::from_spectrum(&spectrum)
+        };
+        let color = emitter
+            .color
+            .clone()
+            .or_else(spectrum)
+            .or_else(|| {
+                emitter
+                    .dominant_wave_length
+                    .and_then(ColorCie::from_wave_length)
+            })
+            .unwrap_or(ColorCie {
+                Y: 0.0,
+                ..COLOR_CIE_WHITE
+            });
+
+        let mut measurements: Vec<Measurement> = emitter
+            .measurements
+            .iter()
+            .filter(|m| m.luminous_intensity > 0.0)
+            .cloned()
+            .collect();
+        if !measurements.is_empty() && measurements.iter().all(|m| m.physical > 0.0) {
+            measurements.push(Measurement::default());
+        }
+        Self {
+            dmx_channel,
+            attribute,
+            emitter: name,
+            color,
+            measurements,
+        }
+    }
+
+    ///Luminous intensity in cd at the intensity. None if the emitter has no measured luminous intensity
+    pub fn luminous_intensity(&self, intensity: f32) -> Option<f32> {
+        measurement::interpolate(&self.measurements, intensity * 100.0, |m| {
+            m.luminous_intensity
+        })
+    }
+
+    ///Output of the emitter at the intensity relative to it's output at full intensity
+    pub fn output(&self, intensity: f32) -> f32 {
+        let intensity = intensity.clamp(0.0, 1.0);
+        match (
+            self.luminous_intensity(intensity),
+            self.luminous_intensity(1.0),
+        ) {
+            (Some(value), Some(full)) if full > 0.0 => value / full,
+            _ => intensity,
+        }
+    }
+
+    ///Lowest intensity at which the emitter reaches the relative output
+    pub fn intensity_for_output(&self, output: f32) -> f32 {
+        let output = output.clamp(0.0, 1.0);
+        if self.measurements.is_empty() || output == 0.0 {
+            return output;
+        }
+        let (mut low, mut high) = (0.0_f32, 1.0_f32);
+        for _ in 0..32 {
+            let middle = (low + high) / 2.0;
+            if self.output(middle) >= output {
+                high = middle;
+            } else {
+                low = middle;
+            }
+        }
+        high
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use crate::fixture_type::builder::{
+        ChannelFunctionBuilder, DmxChannelBuilder, DmxModeBuilder, LogicalChannelBuilder,
+    };
+    use crate::fixture_type::physical_descriptions::emitters::mixer::AdditiveMixer;
+    use crate::fixture_type::physical_descriptions::emitters::Emitter;
+    use crate::fixture_type::physical_descriptions::measurement::measurement_point::MeasurementPoint;
+    use crate::fixture_type::physical_descriptions::measurement::{InterpolationTo, Measurement};
+    use crate::utils::errors::GdtfError;
+    use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+    use crate::utils::units::name::Name;
+
+    fn emitter(x: f32, y: f32, luminance: f32) -> Emitter {
+        Emitter {
+            color: Some(ColorCie { x, y, Y: luminance }),
+            ..Default::default()
+        }
+    }
+
+    fn measurement(physical: f32, luminous_intensity: f32) -> Measurement {
+        Measurement {
+            physical,
+            luminous_intensity,
+            ..Default::default()
+        }
+    }
+
+    fn rgbw() -> HashMap<Name, Emitter> {
+        HashMap::from([
+            (Name::from("Red"), emitter(0.7, 0.29, 20.0)),
+            (
+                Name::from("Green"),
+                Emitter {
+                    measurements: vec![measurement(50.0, 200.0), measurement(100.0, 1000.0)],
+                    ..emitter(0.17, 0.7, 65.0)
+                },
+            ),
+            (Name::from("Blue"), emitter(0.135, 0.05, 8.0)),
+            (Name::from("White"), emitter(0.3127, 0.329, 100.0)),
+        ])
+    }
+
+    fn rgbw_mixer(emitters: &HashMap<Name, Emitter>) -> Result<AdditiveMixer, GdtfError> {
+        let channel = |attribute: &str, emitter: &str| {
+            DmxChannelBuilder::new("Beam").logical_channel(
+                LogicalChannelBuilder::new(attribute)
+                    .channel_function(ChannelFunctionBuilder::new(attribute).emitter(emitter)),
+            )
+        };
+        let (_, dmx_mode) = DmxModeBuilder::new("Mode", "Base")
+            .dmx_channel(
+                DmxChannelBuilder::new("Beam").logical_channel(
+                    LogicalChannelBuilder::new("Dimmer")
+                        .channel_function(ChannelFunctionBuilder::new("Dimmer")),
+                ),
+            )
+            .dmx_channel(channel("ColorAdd_R", "Red"))
+            .dmx_channel(channel("ColorAdd_G", "Green"))
+            .dmx_channel(channel("ColorAdd_B", "Blue"))
+            .dmx_channel(channel("ColorAdd_W", "White"))
+            .build()?;
+        AdditiveMixer::from_dmx_mode(&dmx_mode, emitters)
+    }
+
+    fn assert_close(expected: f32, actual: f32, tolerance: f32) {
+        assert!(
+            (expected - actual).abs() <= tolerance,
+            "Expected {} but got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_from_dmx_mode() {
+        let mixer = rgbw_mixer(&rgbw()).unwrap();
+        let attributes: Vec<String> = mixer
+            .emitters
+            .iter()
+            .map(|emitter| format!("{} {}", emitter.attribute, emitter.emitter))
+            .collect();
+        assert_eq!(
+            attributes,
+            [
+                "ColorAdd_R Red",
+                "ColorAdd_G Green",
+                "ColorAdd_B Blue",
+                "ColorAdd_W White"
+            ]
+        );
+        assert_eq!(mixer.emitters[0].dmx_channel, Name::from("Beam_ColorAdd_R"));
+
+        let mut emitters = rgbw();
+        emitters.remove(&Name::from("Blue"));
+        assert!(matches!(
+            rgbw_mixer(&emitters),
+            Err(GdtfError::PhysicalDescriptionsLinkNotFoundError(path, attribute, link))
+                if path == "Beam_ColorAdd_B.ColorAdd_B.ColorAdd_B" && attribute == "Emitter" && link == "Blue"
+        ));
+    }
+
+    #[test]
+    fn test_emitter_colors() {
+        let emitters = HashMap::from([
+            (Name::from("Red"), emitter(0.7, 0.29, 20.0)),
+            (
+                Name::from("Green"),
+                Emitter {
+                    dominant_wave_length: Some(525.0),
+                    ..Default::default()
+                },
+            ),
+            (
+                Name::from("Blue"),
+                Emitter {
+                    dominant_wave_length: Some(525.0),
+                    measurements: vec![Measurement {
+                        physical: 100.0,
+                        measurement_points: vec![
+                            MeasurementPoint {
+                                wave_length: 455.0,
+                                energy: 1.0,
+                            },
+                            MeasurementPoint {
+                                wave_length: 445.0,
+                                energy: 1.0,
+                            },
+                        ],
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                },
+            ),
+            (Name::from("White"), Emitter::default()),
+        ]);
+        let mixer = rgbw_mixer(&emitters).unwrap();
+        let green = ColorCie::from_wave_length(525.0).unwrap();
+        assert_eq!(mixer.emitters[1].color, green);
+        let blue = ColorCie::from_wave_length(450.0).unwrap();
+        assert_close(blue.x, mixer.emitters[2].color.x, 0.002);
+        assert_close(blue.y, mixer.emitters[2].color.y, 0.002);
+        assert_eq!(mixer.emitters[3].color.Y, 0.0);
+    }
+
+    #[test]
+    fn test_mix() {
+        let mixer = rgbw_mixer(&rgbw()).unwrap();
+        let white = mixer.mix(&[0.0, 0.0, 0.0, 0.5]);
+        assert_close(COLOR_CIE_WHITE.x, white.color.x, 0.0001);
+        assert_close(COLOR_CIE_WHITE.y, white.color.y, 0.0001);
+        assert_close(50.0, white.color.Y, 0.001);
+        assert_eq!(white.luminous_intensity, None);
+
+        //Green follows it's measurements: 20 % output at 50 % intensity
+        let green = mixer.mix(&[0.0, 0.5]);
+        assert_close(13.0, green.color.Y, 0.001);
+        assert_close(0.17, green.color.x, 0.0001);
+        assert_eq!(green.luminous_intensity, Some(200.0));
+        assert_eq!(mixer.mix(&[0.0, 0.25]).luminous_intensity, Some(100.0));
+
+        let yellow = mixer.mix(&[1.0, 1.0, 0.0, 0.0]);
+        assert_close(85.0, yellow.color.Y, 0.001);
+        assert!(yellow.color.x > 0.17 && yellow.color.x < 0.7);
+
+        let black = mixer.mix(&[]);
+        assert_eq!(black.color.Y, 0.0);
+        assert_eq!(black.luminous_intensity, Some(0.0));
+    }
+
+    #[test]
+    fn test_solve() {
+        let mixer = rgbw_mixer(&rgbw()).unwrap();
+        for target in [
+            COLOR_CIE_WHITE,
+            ColorCie::from_cct(3200.0, 0.0, 50.0),
+            ColorCie {
+                x: 0.25,
+                y: 0.45,
+                Y: 40.0,
+            },
+            ColorCie {
+                x: 0.6,
+                y: 0.3,
+                Y: 10.0,
+            },
+        ] {
+            let intensities = mixer.solve(&target).unwrap();
+            assert!(intensities.iter().all(|i| (0.0..=1.0).contains(i)));
+            let mix = mixer.mix(&intensities).color;
+            assert_close(target.x, mix.x, 0.0005);
+            assert_close(target.y, mix.y, 0.0005);
+            assert_close(target.Y, mix.Y, 0.01);
+
+            let brightest = mixer.mix(&mixer.solve_brightest(&target).unwrap()).color;
+            assert_close(target.x, brightest.x, 0.0005);
+            assert_close(target.y, brightest.y, 0.0005);
+            assert!(brightest.Y >= mix.Y);
+        }
+
+        //White uses the white emitter at full and adds the brightest white of red, green and blue
+        let intensities = mixer.solve_brightest(&COLOR_CIE_WHITE).unwrap();
+        assert_eq!(intensities[3], 1.0);
+        assert!(intensities[..3].iter().all(|&i| i > 0.0));
+        assert!(intensities[..3].contains(&1.0));
+
+        //Out of gamut and too bright
+        assert!(mixer
+            .solve(&ColorCie {
+                x: 0.08,
+                y: 0.8,
+                Y: 10.0
+            })
+            .is_none());
+        assert!(mixer
+            .solve(&ColorCie {
+                Y: 1000.0,
+                ..COLOR_CIE_WHITE
+            })
+            .is_none());
+        assert_eq!(
+            mixer.solve(&ColorCie {
+                Y: 0.0,
+                ..COLOR_CIE_WHITE
+            }),
+            Some(vec![0.0; 4])
+        );
+    }
+
+    #[test]
+    fn test_intensity_for_output() {
+        let mixer = rgbw_mixer(&rgbw()).unwrap();
+        let green = &mixer.emitters[1];
+        assert_close(0.5, green.intensity_for_output(0.2), 0.0001);
+        assert_close(0.75, green.intensity_for_output(0.6), 0.0001);
+        assert_eq!(mixer.emitters[0].intensity_for_output(0.3), 0.3);
+
+        let step = Emitter {
+            measurements: vec![
+                measurement(50.0, 500.0),
+                Measurement {
+                    interpolation_to: InterpolationTo::Step,
+                    ..measurement(100.0, 1000.0)
+                },
+            ],
+            ..emitter(0.7, 0.29, 20.0)
+        };
+        let mut emitters = rgbw();
+        emitters.insert(Name::from("Red"), step);
+        let red = &rgbw_mixer(&emitters).unwrap().emitters[0];
+        assert_close(0.5, red.output(0.75), 0.0001);
+        assert_close(1.0, red.intensity_for_output(0.7), 0.0001);
+    }
+}
@@ -1,7 +1,9 @@
 //!Defines the description of the emitter
+use std::io::Write;
+
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::physical_descriptions::measurement::Measurement;
 use crate::utils::errors::GdtfError;
@@ -11,9 +13,14 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::color_cie::ColorCie;
 use crate::utils::units::name::Name;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
+
+pub mod mixer;
 
 ///Defines the description of the emitter
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Emitter {
     ///Approximate absolute color point if applicable. Omit for non-visible emitters (e.g., UV). For Y give relative value compared to overall output defined in property Luminous Flux of related Beam Geometry (transmissive case).
     pub color: Option<ColorCie>,
@@ -77,12 +84,31 @@ impl ReadGdtf for Emitter {
     }
 }
 
+impl WriteGdtf for Emitter {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute_option(event, b"Color", &self.color);
+        write::push_attribute_option(event, b"DominantWaveLength", &self.dominant_wave_length);
+        write::push_attribute_option(event, b"DiodePart", &self.diode_part);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.measurements.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        for measurement in self.measurements.iter() {
+            measurement.write_single(writer, None)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Emitter {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
         vec![
             (
-                Some(Name("MyName".to_string())),
+                Some(Name::from("MyName")),
                 Some(Emitter {
                     color: None,
                     dominant_wave_length: None,
@@ -91,7 +117,7 @@ impl TestReadGdtf for Emitter {
                 }),
             ),
             (
-                Some(Name("Light Source 6800 High Power".to_string())),
+                Some(Name::from("Light Source 6800 High Power")),
                 Some(Emitter {
                     color: Some(ColorCie {
                         x: 0.312700,
@@ -104,7 +130,7 @@ impl TestReadGdtf for Emitter {
                 }),
             ),
             (
-                Some(Name("LED Engine".to_string())),
+                Some(Name::from("LED Engine")),
                 Some(Emitter {
                     color: Some(ColorCie {
                         x: 0.312700,
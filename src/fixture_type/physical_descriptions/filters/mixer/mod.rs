@@ -0,0 +1,392 @@
+//! Subtractive colour mixing of filters in a beam, like CMY flags and colour wheel slots
+//!
+//! The colour of a filter is the colour of the beam when it is the only filter fully inserted. The mixer derives the transmission of every filter from it's colour relative to the white of the beam and multiplies the transmissions of all filters in the beam. Transmissions are approximated per sharpened cone response of the Bradford transform.
+//!
+//! A partially inserted filter covers a part of the beam. The covered part is proportional to the insertion, unless the filter has measurements of it's transmission, then it follows them. Insertions are fractions from 0 to 1 of the DMX range of a channel function.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use std::path::Path;
+//! use gdtf_parser::Gdtf;
+//! use gdtf_parser::fixture_type::physical_descriptions::filters::mixer::{MixerFilter, SubtractiveMixer};
+//! use gdtf_parser::utils::units::color_cie::COLOR_CIE_WHITE;
+//! use gdtf_parser::utils::units::name::Name;
+//!
+//! let gdtf = Gdtf::try_from(Path::new("test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf")).unwrap();
+//! let fixture_type = &gdtf.fixture_type;
+//! let filters = &fixture_type.physical_descriptions.as_ref().unwrap().filters;
+//! let dmx_mode = &fixture_type.dmx_modes[&Name::from("Mode 1 - Standard 16 bit")];
+//!
+//! //Cyan, magenta and yellow flags of the mode, with the white of the beam
+//! let mut mixer = SubtractiveMixer::from_dmx_mode(dmx_mode, filters, COLOR_CIE_WHITE).unwrap();
+//! //The slot of the colour wheel is inserted fully together with the flags
+//! let name = Name::from("Deep Red");
+//! let slot = &fixture_type.wheels.as_ref().unwrap()[&Name::from("Color1")].slots[&name];
+//! mixer.filters.push(MixerFilter::from_slot(&name, slot, filters).unwrap());
+//!
+//! let deep_red = mixer.mix(&[0.0, 0.0, 0.0, 1.0]);
+//! assert!((deep_red.transmission - 0.152).abs() < 0.001);
+//! let darker_red = mixer.mix(&[0.0, 0.5, 0.0, 1.0]);
+//! assert!(darker_red.color.x > 0.5);
+//! ```
+use std::collections::HashMap;
+
+use crate::fixture_type::dmx_mode::DmxMode;
+use crate::fixture_type::physical_descriptions::filters::Filter;
+use crate::fixture_type::physical_descriptions::measurement;
+use crate::fixture_type::physical_descriptions::measurement::Measurement;
+use crate::fixture_type::wheel::slot::Slot;
+use crate::utils::color::ColorXyz;
+use crate::utils::errors::GdtfError;
+use crate::utils::units::color_cie::ColorCie;
+use crate::utils::units::name::Name;
+use crate::utils::units::node::Node;
+
+///Predicts the colour of a beam through filters
+#[derive(Debug, PartialEq, Clone)]
+pub struct SubtractiveMixer {
+    ///Colour of the beam without filters. The transmission of a mix is relative to it's Y
+    pub white: ColorCie,
+    ///Filters in the beam. Insertions passed to the mixer are in the same order
+    pub filters: Vec<MixerFilter>,
+}
+
+///A filter that can be inserted into the beam
+#[derive(Debug, PartialEq, Clone)]
+pub struct MixerFilter {
+    ///Name of the filter in the physical descriptions or of the wheel slot
+    pub name: Name,
+    ///Attribute of the channel function, for example ColorSub_C. None for wheel slots
+    pub attribute: Option<Node>,
+    ///Colour of the beam when only this filter is fully inserted. Y is relative to an output of 100 without filters
+    pub color: ColorCie,
+    ///Measurements of the transmission, with an open measurement at 0 % if the filter has none
+    measurements: Vec<Measurement>,
+}
+
+///Predicted result of a mix
+#[derive(Debug, PartialEq, Clone)]
+pub struct FilterMix {
+    ///Colour of the beam through the filters
+    pub color: ColorCie,
+    ///Part of the luminance of the white that passes the filters
+    pub transmission: f32,
+}
+
+impl SubtractiveMixer {
+    ///Creates a mixer for all channel functions of the mode with a ColorSub attribute and a link to a filter
+    ///
+    ///Returns `GdtfError::PhysicalDescriptionsLinkNotFoundError` if a link doesn't point to one of the filters
+    pub fn from_dmx_mode(
+        dmx_mode: &DmxMode,
+        filters: &HashMap<Name, Filter>,
+        white: ColorCie,
+    ) -> Result<Self, GdtfError> {
+        let mut mixer_filters = vec![];
+        for (_, path, attribute, channel_function) in
+            dmx_mode.channel_functions_with_attribute("ColorSub_")
+        {
+            let link = match &channel_function.filter {
+                Some(link) => link,
+                None => continue,
+            };
+            let (name, filter) = find_filter(link, filters).ok_or_else(|| {
+                GdtfError::PhysicalDescriptionsLinkNotFoundError(
+                    path,
+                    "Filter".to_string(),
+                    link.to_string(),
+                )
+            })?;
+            mixer_filters.push(MixerFilter {
+                attribute: Some(attribute.clone()),
+                ..MixerFilter::from_filter(name.clone(), filter)
+            });
+        }
+        Ok(Self {
+            white,
+            filters: mixer_filters,
+        })
+    }
+
+    ///Predicts the beam through the filters at the insertions. Missing insertions are 0 and insertions are clamped to 0..1
+    pub fn mix(&self, insertions: &[f32]) -> FilterMix {
+        let reference = ColorCie {
+            Y: 100.0,
+            ..self.white.clone()
+        }
+        .to_xyz()
+        .to_cone_response();
+        let mut cone = self.white.to_xyz().to_cone_response();
+        for (filter, insertion) in self.filters.iter().zip(insertions) {
+            let coverage = filter.coverage(*insertion);
+            let transmission = filter.transmission(&reference);
+            for (response, transmission) in cone.iter_mut().zip(transmission) {
+                *response *= 1.0 - coverage * (1.0 - transmission);
+            }
+        }
+        let color = ColorCie::from_xyz(&ColorXyz::from_cone_response(cone));
+        FilterMix {
+            transmission: if self.white.Y > 0.0 {
+                color.Y / self.white.Y
+            } else {
+                0.0
+            },
+            color,
+        }
+    }
+}
+
+impl MixerFilter {
+    ///Creates a mixer filter from a filter of the physical descriptions
+    pub fn from_filter(name: Name, filter: &Filter) -> Self {
+        let mut measurements = filter.measurements.clone();
+        if !measurements.is_empty() && measurements.iter().all(|m| m.physical > 0.0) {
+            measurements.push(Measurement {
+                transmission: 1.0,
+                ..Default::default()
+            });
+        }
+        Self {
+            name,
+            attribute: None,
+            color: filter.color.clone(),
+            measurements,
+        }
+    }
+
+    ///Creates a mixer filter from a wheel slot. The linked filter is used if the slot has one, else the colour of the slot
+    ///
+    ///Returns `GdtfError::PhysicalDescriptionsLinkNotFoundError` if the link doesn't point to one of the filters
+    pub fn from_slot(
+        name: &Name,
+        slot: &Slot,
+        filters: &HashMap<Name, Filter>,
+    ) -> Result<Self, GdtfError> {
+        match &slot.filter {
+            Some(link) => match find_filter(link, filters) {
+                Some((_, filter)) => Ok(Self::from_filter(name.clone(), filter)),
+                None => Err(GdtfError::PhysicalDescriptionsLinkNotFoundError(
+                    name.to_string(),
+                    "Filter".to_string(),
+                    link.to_string(),
+                )),
+            },
+            None => Ok(Self {
+                name: name.clone(),
+                attribute: None,
+                color: slot.color.clone(),
+                measurements: vec![],
+            }),
+        }
+    }
+
+    ///Part of the beam covered by the filter at the insertion
+    pub fn coverage(&self, insertion: f32) -> f32 {
+        let insertion = insertion.clamp(0.0, 1.0);
+        let transmission =
+            |physical| measurement::interpolate(&self.measurements, physical, |m| m.transmission);
+        match (transmission(insertion * 100.0), transmission(100.0)) {
+            (Some(transmission), Some(full)) if full < 1.0 - 1e-4 => {
+                ((1.0 - transmission) / (1.0 - full)).clamp(0.0, 1.0)
+            }
+            _ => insertion,
+        }
+    }
+
+    ///Transmission of every cone response of the fully inserted filter, relative to the cone responses of the white with a Y of 100
+    fn transmission(&self, reference: &[f32; 3]) -> [f32; 3] {
+        let filter = self.color.to_xyz().to_cone_response();
+        [0, 1, 2].map(|i| {
+            if reference[i] > 0.0 {
+                (filter[i] / reference[i]).max(0.0)
+            } else {
+                1.0
+            }
+        })
+    }
+}
+
+///Resolves a link to a filter
+fn find_filter<'a>(
+    link: &Node,
+    filters: &'a HashMap<Name, Filter>,
+) -> Option<(&'a Name, &'a Filter)> {
+    match link.0.as_slice() {
+        [name] => filters.get_key_value(name),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::path::Path;
+
+    use crate::fixture_type::physical_descriptions::filters::mixer::{
+        MixerFilter, SubtractiveMixer,
+    };
+    use crate::fixture_type::physical_descriptions::filters::Filter;
+    use crate::fixture_type::physical_descriptions::measurement::Measurement;
+    use crate::fixture_type::wheel::slot::Slot;
+    use crate::utils::errors::GdtfError;
+    use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+    use crate::utils::units::name::Name;
+    use crate::utils::units::node::Node;
+    use crate::Gdtf;
+
+    fn assert_close(expected: f32, actual: f32, tolerance: f32) {
+        assert!(
+            (expected - actual).abs() <= tolerance,
+            "Expected {} but got {}",
+            expected,
+            actual
+        );
+    }
+
+    fn grey(transmission: f32, measurements: Vec<Measurement>) -> Filter {
+        Filter {
+            color: ColorCie {
+                Y: transmission * 100.0,
+                ..COLOR_CIE_WHITE
+            },
+            measurements,
+        }
+    }
+
+    fn measurement(physical: f32, transmission: f32) -> Measurement {
+        Measurement {
+            physical,
+            transmission,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_mix() {
+        let mixer = SubtractiveMixer {
+            white: ColorCie {
+                Y: 50.0,
+                ..COLOR_CIE_WHITE
+            },
+            filters: vec![
+                MixerFilter::from_filter(Name::from("Grey"), &grey(0.5, vec![])),
+                MixerFilter::from_filter(
+                    Name::from("Measured"),
+                    &grey(0.5, vec![measurement(50.0, 0.9), measurement(100.0, 0.5)]),
+                ),
+            ],
+        };
+        let open = mixer.mix(&[]);
+        assert_close(1.0, open.transmission, 0.0001);
+        assert_close(50.0, open.color.Y, 0.001);
+
+        assert_close(0.75, mixer.mix(&[0.5]).transmission, 0.0001);
+        assert_close(0.5, mixer.mix(&[1.0]).transmission, 0.0001);
+        //The measured filter follows it's measurements
+        assert_close(0.9, mixer.mix(&[0.0, 0.5]).transmission, 0.0001);
+        assert_close(0.95, mixer.mix(&[0.0, 0.25]).transmission, 0.0001);
+        let both = mixer.mix(&[1.0, 1.0]);
+        assert_close(0.25, both.transmission, 0.0001);
+        assert_close(12.5, both.color.Y, 0.001);
+        assert_close(COLOR_CIE_WHITE.x, both.color.x, 0.0001);
+        assert_close(COLOR_CIE_WHITE.y, both.color.y, 0.0001);
+    }
+
+    #[test]
+    fn test_from_dmx_mode() {
+        let gdtf =
+            Gdtf::try_from(Path::new("test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf")).unwrap();
+        let fixture_type = &gdtf.fixture_type;
+        let filters = &fixture_type.physical_descriptions.as_ref().unwrap().filters;
+        for (mode_name, dmx_mode) in fixture_type.dmx_modes.iter() {
+            let mixer =
+                SubtractiveMixer::from_dmx_mode(dmx_mode, filters, COLOR_CIE_WHITE).unwrap();
+            let names: Vec<&str> = mixer.filters.iter().map(|f| f.name.as_str()).collect();
+            assert_eq!(names, ["Cyan", "Magenta", "Yellow"], "{}", mode_name);
+            assert_eq!(
+                mixer.filters[0].attribute.as_ref().unwrap().to_string(),
+                "ColorSub_C"
+            );
+
+            let open = mixer.mix(&[0.0, 0.0, 0.0]);
+            assert_close(COLOR_CIE_WHITE.x, open.color.x, 0.0001);
+            assert_close(COLOR_CIE_WHITE.y, open.color.y, 0.0001);
+            assert_close(1.0, open.transmission, 0.0001);
+
+            //A single filter gives it's own colour
+            for (i, filter) in mixer.filters.iter().enumerate() {
+                let mut insertions = [0.0; 3];
+                insertions[i] = 1.0;
+                let mix = mixer.mix(&insertions);
+                assert_close(filter.color.x, mix.color.x, 0.0001);
+                assert_close(filter.color.y, mix.color.y, 0.0001);
+                assert_close(filter.color.Y, mix.color.Y, 0.01);
+            }
+
+            //Half of the cyan flag gives a colour between white and cyan
+            let half = mixer.mix(&[0.5, 0.0, 0.0]).color;
+            assert!(half.x < COLOR_CIE_WHITE.x && half.x > mixer.filters[0].color.x);
+            //Cyan and yellow give green
+            let green = mixer.mix(&[1.0, 0.0, 1.0]).color;
+            assert!(green.y > 0.45 && green.x < 0.33, "{:?}", green);
+        }
+
+        let mut dmx_mode = fixture_type.dmx_modes.values().next().unwrap().clone();
+        let mut filters = filters.clone();
+        filters.remove(&Name::from("Magenta"));
+        assert!(matches!(
+            SubtractiveMixer::from_dmx_mode(&dmx_mode, &filters, COLOR_CIE_WHITE),
+            Err(GdtfError::PhysicalDescriptionsLinkNotFoundError(_, attribute, link))
+                if attribute == "Filter" && link == "Magenta"
+        ));
+        dmx_mode.dmx_channels.clear();
+        assert!(
+            SubtractiveMixer::from_dmx_mode(&dmx_mode, &filters, COLOR_CIE_WHITE)
+                .unwrap()
+                .filters
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_from_slot() {
+        let filters = HashMap::from([(Name::from("Grey"), grey(0.5, vec![]))]);
+        let slot = |filter: Option<&str>| Slot {
+            color: ColorCie {
+                x: 0.5837,
+                y: 0.3247,
+                Y: 15.2,
+            },
+            filter: filter.map(|filter| Node::new_from_str(filter).unwrap().unwrap()),
+            media_file_name: None,
+            prism_facets: None,
+            animation_wheel: None,
+        };
+        let name = Name::from("Red");
+        let red = MixerFilter::from_slot(&name, &slot(None), &filters).unwrap();
+        assert_eq!(red.color, slot(None).color);
+        let linked = MixerFilter::from_slot(&name, &slot(Some("Grey")), &filters).unwrap();
+        assert_eq!(linked.color.Y, 50.0);
+        assert_eq!(linked.name, name);
+        assert!(matches!(
+            MixerFilter::from_slot(&name, &slot(Some("Blue")), &filters),
+            Err(GdtfError::PhysicalDescriptionsLinkNotFoundError(path, _, link))
+                if path == "Red" && link == "Blue"
+        ));
+
+        //The red slot stacked with a grey filter halves the transmission of it
+        let mixer = SubtractiveMixer {
+            white: COLOR_CIE_WHITE,
+            filters: vec![red, linked],
+        };
+        let alone = mixer.mix(&[1.0]);
+        assert_close(0.152, alone.transmission, 0.0001);
+        let stacked = mixer.mix(&[1.0, 1.0]);
+        assert_close(0.076, stacked.transmission, 0.0001);
+        assert_close(alone.color.x, stacked.color.x, 0.0001);
+        assert_close(alone.color.y, stacked.color.y, 0.0001);
+    }
+}
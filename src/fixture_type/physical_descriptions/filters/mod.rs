@@ -1,7 +1,9 @@
 //!Defines the description of the filter
+use std::io::Write;
+
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::physical_descriptions::measurement::Measurement;
 use crate::utils::errors::GdtfError;
@@ -10,9 +12,14 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::color_cie::ColorCie;
 use crate::utils::units::name::Name;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
+
+pub mod mixer;
 
 ///Defines the description of the filter
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Filter {
     ///Approximate absolute color point when this filter is the only item fully inserted into the beam and the fixture is at maximum intensity. For Y give relative value compared to overall output defined in property Luminous Flux of related Beam Geometry (transmissive case).
     pub color: ColorCie,
@@ -78,12 +85,29 @@ impl ReadGdtf for Filter {
     }
 }
 
+impl WriteGdtf for Filter {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Color", &self.color);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.measurements.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        for measurement in self.measurements.iter() {
+            measurement.write_single(writer, None)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Filter {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
         vec![
             (
-                Some(Name("magenta_flag".to_string())),
+                Some(Name::from("magenta_flag")),
                 Some(Filter {
                     color: ColorCie {
                         x: 0.384400,
@@ -94,7 +118,7 @@ impl TestReadGdtf for Filter {
                 }),
             ),
             (
-                Some(Name("yellow_flag".to_string())),
+                Some(Name::from("yellow_flag")),
                 Some(Filter {
                     color: ColorCie {
                         x: 0.431200,
@@ -105,7 +129,7 @@ impl TestReadGdtf for Filter {
                 }),
             ),
             (
-                Some(Name("cto_flag".to_string())),
+                Some(Name::from("cto_flag")),
                 Some(Filter {
                     color: ColorCie {
                         x: 0.470600,
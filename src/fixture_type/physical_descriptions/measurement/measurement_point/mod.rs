@@ -9,9 +9,12 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///The measurement point defines the energy of a specific wavelength of a spectrum
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MeasurementPoint {
     ///Center wavelength of measurement (nm).
     pub wave_length: f32,
@@ -56,7 +59,14 @@ impl ReadGdtf for MeasurementPoint {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for MeasurementPoint {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"WaveLength", &self.wave_length);
+        write::push_attribute(event, b"Energy", &self.energy);
     }
 }
 
@@ -1,10 +1,12 @@
 //!The measurement defines the relation between the requested output by a control channel and the physically achieved intensity
 
-use std::fmt::Debug;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::physical_descriptions::measurement::measurement_point::MeasurementPoint;
 
@@ -13,11 +15,15 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::warnings;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod measurement_point;
 
 ///The measurement defines the relation between the requested output by a control channel and the physically achieved intensity
 #[derive(Debug, PartialEq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Measurement {
     ///For additive color mixing: uniquely given emitter intensity DMX percentage. Value range between > 0 and ≤ 100.
     ///For subtractive color mixing: uniquely given flag insertion DMX percentage. Value range between 0 and 100.
@@ -78,7 +84,27 @@ impl ReadGdtf for Measurement {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for Measurement {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Physical", &self.physical);
+        write::push_attribute(event, b"LuminousIntensity", &self.luminous_intensity);
+        write::push_attribute(event, b"Transmission", &self.transmission);
+        write::push_attribute(event, b"InterpolationTo", &self.interpolation_to);
+    }
+
+    fn has_children(&self) -> bool {
+        !self.measurement_points.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        for measurement_point in self.measurement_points.iter() {
+            measurement_point.write_single(writer, None)?;
+        }
+        Ok(())
     }
 }
 
@@ -143,6 +169,31 @@ impl TestReadGdtf for Measurement {
     }
 }
 
+///Returns the value of the measurements at a DMX percentage, interpolated with the scheme of the next measurement. The measurements don't need to be sorted. Before the first and after the last measurement the value of it is returned. Returns None if there are no measurements
+pub(crate) fn interpolate(
+    measurements: &[Measurement],
+    physical: f32,
+    value: impl Fn(&Measurement) -> f32,
+) -> Option<f32> {
+    let mut sorted: Vec<&Measurement> = measurements.iter().collect();
+    sorted.sort_by(|a, b| a.physical.total_cmp(&b.physical));
+    let first = sorted.first()?;
+    if physical <= first.physical {
+        return Some(value(first));
+    }
+    for pair in sorted.windows(2) {
+        let (from, to) = (pair[0], pair[1]);
+        if physical <= to.physical {
+            return Some(to.interpolation_to.interpolate(
+                (from.physical, value(from)),
+                (to.physical, value(to)),
+                physical,
+            ));
+        }
+    }
+    sorted.last().map(|last| value(last))
+}
+
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // Start of InterpolationTo
@@ -151,6 +202,7 @@ impl TestReadGdtf for Measurement {
 
 ///Interpolation scheme from the previous value.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum InterpolationTo {
     Linear,
     Step,
@@ -170,7 +222,11 @@ impl InterpolationTo {
         match value {
             "Step" => Self::Step,
             "Log" => Self::Log,
-            _ => Self::Linear,
+            "Linear" => Self::Linear,
+            _ => {
+                warnings::substituted("Linear");
+                Self::Linear
+            }
         }
     }
 
@@ -187,6 +243,28 @@ impl InterpolationTo {
     pub fn new_from_attr(attr: Attribute<'_>) -> Self {
         Self::new_from_str(read::attr_to_str(&attr))
     }
+
+    ///Interpolates the value at a DMX percentage between the previous point `from` and the point `to` with this scheme. The points are pairs of DMX percentage and value. Log falls back to Linear if one of the values is not positive
+    /// ```rust
+    /// use gdtf_parser::fixture_type::physical_descriptions::measurement::InterpolationTo;
+    /// assert_eq!(InterpolationTo::Linear.interpolate((0.0, 0.0), (50.0, 100.0), 25.0), 50.0);
+    /// assert_eq!(InterpolationTo::Step.interpolate((0.0, 0.0), (50.0, 100.0), 25.0), 0.0);
+    /// assert_eq!(InterpolationTo::Step.interpolate((0.0, 0.0), (50.0, 100.0), 50.0), 100.0);
+    /// assert!((InterpolationTo::Log.interpolate((0.0, 1.0), (50.0, 100.0), 25.0) - 10.0).abs() < 0.001);
+    /// ```
+    pub fn interpolate(&self, from: (f32, f32), to: (f32, f32), physical: f32) -> f32 {
+        let p = if to.0 > from.0 {
+            ((physical - from.0) / (to.0 - from.0)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+        match self {
+            _ if p >= 1.0 => to.1,
+            InterpolationTo::Step => from.1,
+            InterpolationTo::Log if from.1 > 0.0 && to.1 > 0.0 => from.1 * (to.1 / from.1).powf(p),
+            _ => from.1 + (to.1 - from.1) * p,
+        }
+    }
 }
 
 ///```rust
@@ -199,6 +277,22 @@ impl Default for InterpolationTo {
     }
 }
 
+/// Displays InterpolationTo as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::fixture_type::physical_descriptions::measurement::InterpolationTo;
+/// assert_eq!(InterpolationTo::Linear.to_string(), "Linear");
+/// assert_eq!(InterpolationTo::Step.to_string(), "Step");
+/// assert_eq!(InterpolationTo::Log.to_string(), "Log");
+/// ```
+impl Display for InterpolationTo {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            InterpolationTo::Linear => write!(f, "Linear"),
+            InterpolationTo::Step => write!(f, "Step"),
+            InterpolationTo::Log => write!(f, "Log"),
+        }
+    }
+}
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of InterpolationTo
@@ -206,7 +300,9 @@ impl Default for InterpolationTo {
 //-----------------------------------------------------------------------------------------------------------------
 #[cfg(test)]
 mod tests {
-    use crate::fixture_type::physical_descriptions::measurement::{InterpolationTo, Measurement};
+    use crate::fixture_type::physical_descriptions::measurement::{
+        interpolate, InterpolationTo, Measurement,
+    };
     use crate::utils::read::TestReadGdtf;
     use crate::utils::testdata;
 
@@ -215,6 +311,33 @@ mod tests {
         Measurement::execute_tests()
     }
 
+    #[test]
+    fn test_interpolate() {
+        let measurement = |physical: f32, transmission: f32, interpolation_to| Measurement {
+            physical,
+            luminous_intensity: 0.0,
+            transmission,
+            interpolation_to,
+            measurement_points: vec![],
+        };
+        let measurements = vec![
+            measurement(100.0, 0.2, InterpolationTo::Step),
+            measurement(0.0, 1.0, InterpolationTo::Linear),
+            measurement(50.0, 0.6, InterpolationTo::Linear),
+        ];
+        let transmission = |physical| interpolate(&measurements, physical, |m| m.transmission);
+        assert_eq!(transmission(0.0), Some(1.0));
+        assert_eq!(transmission(25.0), Some(0.8));
+        assert_eq!(transmission(50.0), Some(0.6));
+        assert_eq!(transmission(99.0), Some(0.6));
+        assert_eq!(transmission(100.0), Some(0.2));
+        assert_eq!(
+            interpolate(&measurements[..1], 10.0, |m| m.transmission),
+            Some(0.2)
+        );
+        assert_eq!(interpolate(&[], 10.0, |m| m.transmission), None);
+    }
+
     #[test]
     fn test_interpolation_to_new_from_str() {
         assert_eq!(
@@ -1,9 +1,10 @@
 //!Describes the physical constitution of the device
 use std::collections::HashMap;
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::physical_descriptions::color_space::ColorSpace;
 use crate::fixture_type::physical_descriptions::connectors::Connector;
@@ -17,6 +18,7 @@ use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
+use crate::utils::write::WriteGdtf;
 
 pub mod color_space;
 pub mod connectors;
@@ -29,6 +31,7 @@ pub mod properties;
 
 ///Describes the physical constitution of the device
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PhysicalDescriptions {
     ///Describes device emitters
     pub emitters: HashMap<Name, Emitter>,
@@ -104,7 +107,30 @@ impl ReadGdtf for PhysicalDescriptions {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for PhysicalDescriptions {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        Emitter::write_hash_map(&self.emitters, writer)?;
+        Filter::write_hash_map(&self.filters, writer)?;
+        if let Some(color_space) = &self.color_space {
+            color_space.write_single(writer, None)?;
+        }
+        DmxProfile::write_vec(&self.dmx_profiles, writer)?;
+        CriGroup::write_vec(&self.cris, writer)?;
+        Connector::write_hash_map(&self.connectors, writer)?;
+        if let Some(properties) = &self.properties {
+            properties.write_single(writer, None)?;
+        }
+        Ok(())
     }
 }
 
@@ -8,9 +8,12 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///defines the height of the legs
 #[derive(Debug, Default, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LegHeight {
     ///Defines height of the legs – distance between the floor and the bottom base plate. Unit: meter. Default value: 0
     pub value: f32,
@@ -51,7 +54,13 @@ impl ReadGdtf for LegHeight {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for LegHeight {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Value", &self.value);
     }
 }
 
@@ -1,7 +1,9 @@
 //!Defines the general properties of the device type
+use std::io::Write;
+
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::physical_descriptions::properties::leg_height::LegHeight;
 use crate::fixture_type::physical_descriptions::properties::operating_temperature::OperatingTemperature;
@@ -13,6 +15,7 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 #[cfg(test)]
 use crate::utils::units::node::Node;
+use crate::utils::write::WriteGdtf;
 
 pub mod leg_height;
 pub mod operating_temperature;
@@ -21,6 +24,7 @@ pub mod weight;
 
 ///Defines the general properties of the device type
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Properties {
     ///Temperature range in which the device can be operated.
     pub operationg_temperature: Option<OperatingTemperature>,
@@ -80,7 +84,34 @@ impl ReadGdtf for Properties {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for Properties {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+
+    fn has_children(&self) -> bool {
+        self.operationg_temperature.is_some()
+            || self.weight.is_some()
+            || !self.power_consumtion.is_empty()
+            || self.leg_height.is_some()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        if let Some(operating_temperature) = &self.operationg_temperature {
+            operating_temperature.write_single(writer, None)?;
+        }
+        if let Some(weight) = &self.weight {
+            weight.write_single(writer, None)?;
+        }
+        for power_consumtion in self.power_consumtion.iter() {
+            power_consumtion.write_single(writer, None)?;
+        }
+        if let Some(leg_height) = &self.leg_height {
+            leg_height.write_single(writer, None)?;
+        }
+        Ok(())
     }
 }
 
@@ -8,9 +8,12 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///defines the ambient operating temperature range
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OperatingTemperature {
     ///Lowest temperature the device can be operated. Unit: °C. Default value: 0
     pub low: f32,
@@ -71,7 +74,14 @@ impl ReadGdtf for OperatingTemperature {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for OperatingTemperature {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Low", &self.low);
+        write::push_attribute(event, b"High", &self.high);
     }
 }
 
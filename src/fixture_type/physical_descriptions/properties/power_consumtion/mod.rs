@@ -9,10 +9,14 @@ use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
+use crate::utils::units::name::Name;
 use crate::utils::units::node::Node;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///defines the maximum power consumption per connector
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PowerConsumtion {
     ///Defines the power consumption of the connector at full load. Unit: VA. Default value: 0
     pub value: f32,
@@ -75,7 +79,7 @@ impl ReadGdtf for PowerConsumtion {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
     }
 }
 
@@ -101,7 +105,7 @@ impl Default for PowerConsumtion {
         Self {
             value: 0.0,
             power_factor: 1.0,
-            connector: Node::new_from_str("??").unwrap().unwrap(),
+            connector: Node(vec![Name::from("??")]),
             voltage_low: 90.0,
             voltage_high: 240.0,
             frequency_low: 50.0,
@@ -110,6 +114,18 @@ impl Default for PowerConsumtion {
     }
 }
 
+impl WriteGdtf for PowerConsumtion {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Value", &self.value);
+        write::push_attribute(event, b"PowerFactor", &self.power_factor);
+        write::push_attribute(event, b"Connector", &self.connector);
+        write::push_attribute(event, b"VoltageLow", &self.voltage_low);
+        write::push_attribute(event, b"VoltageHigh", &self.voltage_high);
+        write::push_attribute(event, b"FrequencyLow", &self.frequency_low);
+        write::push_attribute(event, b"FrequencyHigh", &self.frequency_high);
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for PowerConsumtion {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
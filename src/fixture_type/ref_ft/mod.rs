@@ -0,0 +1,291 @@
+//! Resolves the fixture types referenced with `FixtureType::ref_ft`
+//!
+//! A fixture type can reference another fixture type with the attribute RefFT, for example a variant of the same hardware with a different firmware. `RefFtResolver` follows these references in a `FixtureTypeSource` and reports missing fixture types and cycles.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::path::Path;
+//! use gdtf_parser::fixture_type::ref_ft::RefFtResolver;
+//! use gdtf_parser::library::FixtureLibrary;
+//!
+//! let library = FixtureLibrary::scan(Path::new("test")).unwrap();
+//! let resolver = RefFtResolver::new(&library);
+//! for entry in library.entries() {
+//!     let fixture_type = &entry.gdtf().unwrap().fixture_type;
+//!     match resolver.chain(fixture_type) {
+//!         Ok(chain) => println!("{} references {} fixture types", fixture_type.name, chain.len()),
+//!         Err(e) => println!("{}", e),
+//!     }
+//! }
+//! ```
+use std::collections::HashMap;
+
+use crate::fixture_type::FixtureType;
+use crate::utils::errors::GdtfError;
+use crate::utils::units::guid::Guid;
+use crate::Gdtf;
+
+///Source to look up fixture types by their FixtureTypeID
+pub trait FixtureTypeSource {
+    ///Returns the fixture type with the given FixtureTypeID or None if the source does not contain it
+    fn fixture_type(&self, fixture_type_id: &Guid) -> Result<Option<&FixtureType>, GdtfError>;
+}
+
+impl FixtureTypeSource for HashMap<Guid, FixtureType> {
+    fn fixture_type(&self, fixture_type_id: &Guid) -> Result<Option<&FixtureType>, GdtfError> {
+        Ok(self.get(fixture_type_id))
+    }
+}
+
+impl FixtureTypeSource for [FixtureType] {
+    fn fixture_type(&self, fixture_type_id: &Guid) -> Result<Option<&FixtureType>, GdtfError> {
+        Ok(self
+            .iter()
+            .find(|fixture_type| &fixture_type.fixture_type_id == fixture_type_id))
+    }
+}
+
+impl FixtureTypeSource for [Gdtf] {
+    fn fixture_type(&self, fixture_type_id: &Guid) -> Result<Option<&FixtureType>, GdtfError> {
+        Ok(self
+            .iter()
+            .map(|gdtf| &gdtf.fixture_type)
+            .find(|fixture_type| &fixture_type.fixture_type_id == fixture_type_id))
+    }
+}
+
+///Follows the RefFT of fixture types in a `FixtureTypeSource`
+pub struct RefFtResolver<'a, S: FixtureTypeSource + ?Sized> {
+    source: &'a S,
+}
+
+impl<'a, S: FixtureTypeSource + ?Sized> RefFtResolver<'a, S> {
+    ///Creates a resolver that looks up referenced fixture types in the source
+    pub fn new(source: &'a S) -> Self {
+        Self { source }
+    }
+
+    ///Returns the fixture type that is directly referenced with RefFT, None if RefFT is not set. Returns `GdtfError::RefFtNotFoundError` if the source does not contain it
+    pub fn referenced(
+        &self,
+        fixture_type: &FixtureType,
+    ) -> Result<Option<&'a FixtureType>, GdtfError> {
+        let ref_ft = match &fixture_type.ref_ft {
+            None => return Ok(None),
+            Some(ref_ft) => ref_ft,
+        };
+        match self.source.fixture_type(ref_ft)? {
+            Some(referenced) => Ok(Some(referenced)),
+            None => Err(GdtfError::RefFtNotFoundError(
+                fixture_type.fixture_type_id.clone(),
+                ref_ft.clone(),
+            )),
+        }
+    }
+
+    ///Returns all referenced fixture types, starting with the directly referenced one. The chain ends at the first fixture type without RefFT.
+    ///
+    /// Returns `GdtfError::RefFtNotFoundError` if a fixture type of the chain is missing and `GdtfError::RefFtCycleError` if a fixture type is referenced twice
+    pub fn chain(&self, fixture_type: &FixtureType) -> Result<Vec<&'a FixtureType>, GdtfError> {
+        let mut visited = vec![fixture_type.fixture_type_id.clone()];
+        let mut chain: Vec<&'a FixtureType> = Vec::new();
+        let mut referenced = self.referenced(fixture_type)?;
+        while let Some(current) = referenced {
+            let is_cycle = visited.contains(&current.fixture_type_id);
+            visited.push(current.fixture_type_id.clone());
+            if is_cycle {
+                return Err(GdtfError::RefFtCycleError(visited));
+            }
+            chain.push(current);
+            referenced = self.referenced(current)?;
+        }
+        Ok(chain)
+    }
+
+    ///Returns a copy of the fixture type where all data it does not define itself is inherited from the referenced fixture types.
+    ///
+    /// Name, FixtureTypeID, RefFT and CanHaveChildren always belong to the fixture type itself. Texts that are empty, optional children that are `None` and DMX modes, wheels and attribute definitions that are empty are taken from the nearest fixture type in the chain that defines them.
+    /// DMX modes link to geometries and geometries to models, so inherited DMX modes bring the geometries and models of their fixture type with them
+    pub fn merged(&self, fixture_type: &FixtureType) -> Result<FixtureType, GdtfError> {
+        let mut merged = fixture_type.clone();
+        for referenced in self.chain(fixture_type)? {
+            inherit(&mut merged, referenced);
+        }
+        Ok(merged)
+    }
+}
+
+///Takes all data that is absent in the fixture type from the referenced fixture type
+fn inherit(fixture_type: &mut FixtureType, referenced: &FixtureType) {
+    inherit_string(&mut fixture_type.short_name, &referenced.short_name);
+    inherit_string(&mut fixture_type.long_name, &referenced.long_name);
+    inherit_string(&mut fixture_type.manufacturer, &referenced.manufacturer);
+    inherit_string(&mut fixture_type.description, &referenced.description);
+    inherit_option(&mut fixture_type.thumbnail, &referenced.thumbnail);
+    let attribute_definitions = &fixture_type.attribute_definitions;
+    if attribute_definitions.attributes.is_empty()
+        && attribute_definitions.feature_groups.is_empty()
+        && attribute_definitions.activation_groups.is_empty()
+    {
+        fixture_type.attribute_definitions = referenced.attribute_definitions.clone();
+    }
+    if fixture_type.wheels.as_ref().is_none_or(|w| w.is_empty()) && referenced.wheels.is_some() {
+        fixture_type.wheels = referenced.wheels.clone();
+    }
+    inherit_option(
+        &mut fixture_type.physical_descriptions,
+        &referenced.physical_descriptions,
+    );
+    if fixture_type.dmx_modes.is_empty() && !referenced.dmx_modes.is_empty() {
+        fixture_type.dmx_modes = referenced.dmx_modes.clone();
+        fixture_type.geometries = referenced.geometries.clone();
+        fixture_type.models = referenced.models.clone();
+    }
+    inherit_option(&mut fixture_type.models, &referenced.models);
+    inherit_option(&mut fixture_type.geometries, &referenced.geometries);
+    inherit_option(&mut fixture_type.revisions, &referenced.revisions);
+    inherit_option(&mut fixture_type.ft_presets, &referenced.ft_presets);
+    inherit_option(&mut fixture_type.protocols, &referenced.protocols);
+}
+
+fn inherit_option<T: Clone>(value: &mut Option<T>, referenced: &Option<T>) {
+    if value.is_none() {
+        *value = referenced.clone();
+    }
+}
+
+fn inherit_string(value: &mut String, referenced: &str) {
+    if value.is_empty() {
+        *value = referenced.to_string();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::convert::TryFrom;
+    use std::path::Path;
+
+    use crate::fixture_type::ref_ft::RefFtResolver;
+    use crate::fixture_type::FixtureType;
+    use crate::utils::errors::GdtfError;
+    use crate::utils::raw_xml::RawXml;
+    use crate::utils::units::guid::Guid;
+    use crate::utils::units::name::Name;
+    use crate::Gdtf;
+
+    fn guid(last: u8) -> Guid {
+        let mut guid = [0; 16];
+        guid[15] = last;
+        Guid(guid)
+    }
+
+    ///Creates a variant of the ACME fixture type that only contains the data that differs
+    fn variant(base: &FixtureType, id: u8, ref_ft: Option<u8>) -> FixtureType {
+        FixtureType {
+            name: Name::new(&format!("Variant {}", id)).unwrap(),
+            short_name: "".to_string(),
+            long_name: format!("Variant {}", id),
+            manufacturer: "".to_string(),
+            description: "".to_string(),
+            fixture_type_id: guid(id),
+            thumbnail: None,
+            ref_ft: ref_ft.map(guid),
+            can_have_children: base.can_have_children.clone(),
+            attribute_definitions: Default::default(),
+            wheels: None,
+            physical_descriptions: None,
+            models: None,
+            geometries: None,
+            revisions: None,
+            ft_presets: None,
+            protocols: None,
+            dmx_modes: HashMap::new(),
+        }
+    }
+
+    fn source() -> (FixtureType, HashMap<Guid, FixtureType>) {
+        let mut base = Gdtf::try_from(Path::new(
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+        ))
+        .unwrap()
+        .fixture_type;
+        base.fixture_type_id = guid(1);
+        base.ref_ft = None;
+        let mut source = HashMap::new();
+        source.insert(guid(1), base.clone());
+        source.insert(guid(2), variant(&base, 2, Some(1)));
+        source.insert(guid(3), variant(&base, 3, Some(2)));
+        source.insert(guid(4), variant(&base, 4, Some(9)));
+        source.insert(guid(5), variant(&base, 5, Some(6)));
+        source.insert(guid(6), variant(&base, 6, Some(5)));
+        (base, source)
+    }
+
+    #[test]
+    fn test_chain() {
+        let (_, source) = source();
+        let resolver = RefFtResolver::new(&source);
+        assert!(resolver.chain(&source[&guid(1)]).unwrap().is_empty());
+        let chain: Vec<&Guid> = resolver
+            .chain(&source[&guid(3)])
+            .unwrap()
+            .into_iter()
+            .map(|fixture_type| &fixture_type.fixture_type_id)
+            .collect();
+        assert_eq!(chain, vec![&guid(2), &guid(1)]);
+        assert_eq!(
+            resolver
+                .referenced(&source[&guid(3)])
+                .unwrap()
+                .unwrap()
+                .fixture_type_id,
+            guid(2)
+        );
+
+        match resolver.chain(&source[&guid(4)]) {
+            Err(GdtfError::RefFtNotFoundError(from, to)) => {
+                assert_eq!((from, to), (guid(4), guid(9)))
+            }
+            other => panic!("Expected RefFtNotFoundError but got {:?}", other),
+        }
+        match resolver.chain(&source[&guid(5)]) {
+            Err(GdtfError::RefFtCycleError(chain)) => {
+                assert_eq!(chain, vec![guid(5), guid(6), guid(5)])
+            }
+            other => panic!("Expected RefFtCycleError but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_merged() {
+        let (base, source) = source();
+        let resolver = RefFtResolver::new(&source);
+        let merged = resolver.merged(&source[&guid(3)]).unwrap();
+        assert_eq!(merged.name, Name::new("Variant 3").unwrap());
+        assert_eq!(merged.long_name, "Variant 3");
+        assert_eq!(merged.fixture_type_id, guid(3));
+        assert_eq!(merged.ref_ft, Some(guid(2)));
+        assert_eq!(merged.short_name, base.short_name);
+        assert_eq!(merged.manufacturer, base.manufacturer);
+        assert_eq!(merged.thumbnail, base.thumbnail);
+        assert_eq!(merged.attribute_definitions, base.attribute_definitions);
+        assert_eq!(merged.wheels, base.wheels);
+        assert_eq!(merged.physical_descriptions, base.physical_descriptions);
+        assert_eq!(merged.dmx_modes, base.dmx_modes);
+        assert!(base.geometries.is_some());
+        assert_eq!(merged.geometries, base.geometries);
+        assert_eq!(merged.models, base.models);
+        assert_eq!(merged.revisions, base.revisions);
+        assert_eq!(merged.ft_presets, base.ft_presets);
+        assert_eq!(merged.protocols, base.protocols);
+        assert!(resolver.merged(&source[&guid(5)]).is_err());
+
+        //The geometries of a variant without DMX modes would not match the inherited DMX modes
+        let mut own_geometries = source[&guid(2)].clone();
+        own_geometries.geometries = Some(RawXml::default());
+        let merged = resolver.merged(&own_geometries).unwrap();
+        assert_eq!(merged.geometries, base.geometries);
+    }
+}
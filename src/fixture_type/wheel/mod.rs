@@ -1,25 +1,30 @@
 //! Each wheel describes a single physical or virtual wheel of the fixture type.
-use std::collections::HashMap;
 use std::fmt::Debug;
+use std::io::Write;
 
+use indexmap::IndexMap;
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::wheel::slot::Slot;
 use crate::utils::errors::GdtfError;
+use crate::utils::read;
 use crate::utils::read::ReadGdtf;
 #[cfg(test)]
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::name::Name;
+use crate::utils::warnings;
+use crate::utils::write::WriteGdtf;
 
 pub mod slot;
 
 ///Each wheel describes a single physical or virtual wheel of the fixture type.
 #[derive(Debug, PartialEq, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Wheel {
-    /// All slots for the wheel
-    pub slots: HashMap<Name, Slot>,
+    /// All slots for the wheel in the order of the description.xml. The position of a slot, starting at 1, is it's WheelSlotIndex
+    pub slots: IndexMap<Name, Slot>,
 }
 
 impl ReadGdtf for Wheel {
@@ -42,10 +47,19 @@ impl ReadGdtf for Wheel {
         has_children: bool,
     ) -> Result<(), Self::Error> {
         if event.name() == Slot::NODE_NAME {
+            let position = warnings::event_start(reader.buffer_position(), &event, has_children);
             let slot = Slot::read_single_from_event(reader, event, has_children)?;
-            data_holder
-                .slots
-                .insert(slot.0.unwrap_or_else(|| Name("?".to_string())), slot.1);
+            let key = slot.0.unwrap_or_else(|| {
+                warnings::missing_in_child(Slot::NODE_NAME, position, "Name", "?");
+                Name::from("?")
+            });
+            read::insert_unique(
+                &mut data_holder.slots,
+                Slot::NODE_NAME,
+                position,
+                key,
+                slot.1,
+            );
         }
         Ok(())
     }
@@ -61,6 +75,19 @@ impl ReadGdtf for Wheel {
     }
 }
 
+///The slots are written in their order, so the WheelSlotIndex of each slot is kept
+impl WriteGdtf for Wheel {
+    fn write_any_attributes(&self, _: &mut BytesStart<'_>) {}
+
+    fn has_children(&self) -> bool {
+        !self.slots.is_empty()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        Slot::write_entries(&Slot::ordered_entries(&self.slots), writer)
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Wheel {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -68,19 +95,19 @@ impl TestReadGdtf for Wheel {
             (
                 Some(Name::new("PrismWheel 1").unwrap()),
                 Some(Self {
-                    slots: HashMap::new(),
+                    slots: IndexMap::new(),
                 }),
             ),
             (
                 Some(Name::new("PrismWheel 2").unwrap()),
                 Some(Self {
-                    slots: HashMap::new(),
+                    slots: IndexMap::new(),
                 }),
             ),
             (
                 Some(Name::new("PrismWheel 3").unwrap()),
                 Some(Self {
-                    slots: Slot::testdata_hash_map(),
+                    slots: Slot::testdata_index_map(),
                 }),
             ),
         ]
@@ -12,9 +12,12 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::pixel::Pixel;
 use crate::utils::units::pixel_array::PixelArray;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 ///Defines the animation system disk and it describes the animation system behavior
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct AnimationSystem {
     ///First Point of the Spline describing the path of animation system in the beam in relation to the middle of the Media File
     pub p1: PixelArray,
@@ -91,7 +94,16 @@ impl ReadGdtf for AnimationSystem {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for AnimationSystem {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"P1", &self.p1);
+        write::push_attribute(event, b"P2", &self.p2);
+        write::push_attribute(event, b"P3", &self.p3);
+        write::push_attribute(event, b"Radius", &self.radius);
     }
 }
 
@@ -13,9 +13,12 @@ use crate::utils::read::ReadGdtf;
 use crate::utils::read::TestReadGdtf;
 use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
 use crate::utils::units::rotation::Rotation;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 /// Contains information about PrismFacet for a wheel slot
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Facet {
     ///Color of prism facet, Default value: {0.3127, 0.3290, 100.0} (white)
     pub color: ColorCie,
@@ -77,7 +80,14 @@ impl ReadGdtf for Facet {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed")
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for Facet {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Color", &self.color);
+        write::push_attribute(event, b"Rotation", &self.rotation);
     }
 }
 
@@ -1,9 +1,10 @@
 //! Represents a slot on a wheel
 use std::fmt::Debug;
+use std::io::Write;
 
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::wheel::slot::animation_system::AnimationSystem;
 use crate::fixture_type::wheel::slot::facet::Facet;
@@ -16,12 +17,15 @@ use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
 use crate::utils::units::name::Name;
 use crate::utils::units::node::Node;
 use crate::utils::units::resource::Resource;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
 pub mod animation_system;
 pub mod facet;
 
 /// Represents a slot on a wheel
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Slot {
     /// Color of the wheel slot, Default value: {0.3127, 0.3290, 100.0} (white) For Y give relative value compared to overall output defined in property Luminous Flux of related Beam Geometry (transmissive case)
     pub color: ColorCie,
@@ -133,6 +137,30 @@ impl ReadGdtf for Slot {
     }
 }
 
+impl WriteGdtf for Slot {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"Color", &self.color);
+        write::push_attribute_option(event, b"Filter", &self.filter);
+        write::push_attribute_option(event, b"MediaFileName", &self.media_file_name);
+    }
+
+    fn has_children(&self) -> bool {
+        self.prism_facets.is_some() || self.animation_wheel.is_some()
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        if let Some(prism_facets) = &self.prism_facets {
+            for facet in prism_facets.iter() {
+                facet.write_single(writer, None)?;
+            }
+        }
+        if let Some(animation_wheel) = &self.animation_wheel {
+            animation_wheel.write_single(writer, None)?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 impl TestReadGdtf for Slot {
     fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
@@ -0,0 +1,374 @@
+//! Fast scan of the most important information of a .gdtf file without deparsing the whole description.xml
+//!
+//! `GdtfHeader` only reads the DataVersion, the attributes of the FixtureType and the names and footprints of the DMX modes. All other nodes are skipped without being deparsed, so it can be used to list large fixture libraries.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use std::path::Path;
+//! use gdtf_parser::header::GdtfHeader;
+//!
+//! let header = GdtfHeader::try_from(Path::new("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf")).unwrap();
+//! assert_eq!(header.manufacturer, "ACME");
+//! for dmx_mode in header.dmx_modes.iter() {
+//!     println!("{}: {} DMX channels", dmx_mode.name, dmx_mode.footprint);
+//! }
+//! ```
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{Cursor, Read, Seek};
+use std::path::Path;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::fixture_type::dmx_mode::dmx_channel::Offset;
+use crate::utils::errors::GdtfError;
+use crate::utils::read;
+use crate::utils::read::GdtfReadError;
+use crate::utils::units::guid::Guid;
+use crate::utils::units::name::Name;
+use crate::utils::units::resource::Resource;
+use crate::DataVersion;
+
+///The header of a .gdtf file, containing the DataVersion, the attributes of the FixtureType and a summary of the DMX modes
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GdtfHeader {
+    ///The DataVersion attribute defines the minimal version of compatibility
+    pub data_version: DataVersion,
+    ///Name of the fixture type.
+    pub name: Name,
+    ///Shortened name of the fixture type.
+    pub short_name: String,
+    ///Detailed name of the fixture type.
+    pub long_name: String,
+    ///Manufacturer of the fixture type.
+    pub manufacturer: String,
+    ///Unique number of the fixture type.
+    pub fixture_type_id: Guid,
+    ///GUID of the referenced fixture type
+    pub ref_ft: Option<Guid>,
+    ///File name without extension containing description of the thumbnail.
+    pub thumbnail: Option<Resource>,
+    ///Summary of the DMX modes in the order they are defined in the description.xml
+    pub dmx_modes: Vec<DmxModeHeader>,
+}
+
+///Summary of a DMX mode
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DmxModeHeader {
+    ///The unique name of the DMX mode
+    pub name: Name,
+    ///Count of DMXChannel nodes in the DMX mode, including virtual channels
+    pub dmx_channel_count: usize,
+    ///Count of DMX addresses the DMX mode occupies, which is the highest offset of all DMX channels
+    pub footprint: u32,
+}
+
+///Helper struct for temporary data during the scan
+#[derive(Default)]
+struct GdtfHeaderDataHolder {
+    data_version: Option<DataVersion>,
+    name: Option<Name>,
+    short_name: Option<String>,
+    long_name: Option<String>,
+    manufacturer: Option<String>,
+    fixture_type_id: Option<Guid>,
+    ref_ft: Option<Guid>,
+    thumbnail: Option<Resource>,
+    dmx_modes: Vec<DmxModeHeader>,
+}
+
+impl GdtfHeader {
+    ///Scans the content of a description.xml that was already extracted from the .gdtf archive. Scanning stops after the node DMXModes
+    /// ```rust
+    /// use gdtf_parser::header::GdtfHeader;
+    ///
+    /// let header = GdtfHeader::from_description_xml(r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <GDTF DataVersion="1.1">
+    ///   <FixtureType Name="LED PAR" ShortName="PAR" LongName="LED PAR" Manufacturer="Generic" Description="" FixtureTypeID="308EA87D-7164-42DE-8106-A6D273F57A51" Thumbnail="" RefFT="">
+    ///     <AttributeDefinitions/>
+    ///     <DMXModes>
+    ///       <DMXMode Name="RGB 16bit" Geometry="Body">
+    ///         <DMXChannels>
+    ///           <DMXChannel DMXBreak="1" Offset="1,2" Geometry="Body"/>
+    ///           <DMXChannel DMXBreak="1" Offset="3,4" Geometry="Body"/>
+    ///           <DMXChannel DMXBreak="1" Offset="5,6" Geometry="Body"/>
+    ///           <DMXChannel DMXBreak="1" Offset="None" Geometry="Body"/>
+    ///         </DMXChannels>
+    ///       </DMXMode>
+    ///     </DMXModes>
+    ///   </FixtureType>
+    /// </GDTF>"#).unwrap();
+    /// assert_eq!(header.short_name, "PAR");
+    /// assert_eq!(header.dmx_modes[0].name.as_str(), "RGB 16bit");
+    /// assert_eq!(header.dmx_modes[0].dmx_channel_count, 4);
+    /// assert_eq!(header.dmx_modes[0].footprint, 6);
+    /// ```
+    pub fn from_description_xml(description_xml: &str) -> Result<Self, GdtfError> {
+        let mut reader = Reader::from_str(description_xml);
+        let mut buf: Vec<u8> = Vec::new();
+        let mut skip_buf: Vec<u8> = Vec::new();
+        let mut data_holder = GdtfHeaderDataHolder::default();
+        let mut dmx_mode: Option<DmxModeHeader> = None;
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) => match e.name() {
+                    b"GDTF" => Self::read_gdtf_attributes(&mut data_holder, &e)?,
+                    b"FixtureType" => Self::read_fixture_type_attributes(&mut data_holder, &e)?,
+                    b"DMXModes" | b"DMXChannels" => {}
+                    b"DMXMode" => dmx_mode = Some(Self::read_dmx_mode_attributes(&e)?),
+                    name => {
+                        if name == b"DMXChannel" {
+                            Self::add_dmx_channel(&mut dmx_mode, &e)?;
+                        }
+                        let name = name.to_vec();
+                        reader.read_to_end(name, &mut skip_buf)?;
+                        skip_buf.clear();
+                    }
+                },
+                Event::Empty(e) => match e.name() {
+                    b"GDTF" => Self::read_gdtf_attributes(&mut data_holder, &e)?,
+                    b"FixtureType" => Self::read_fixture_type_attributes(&mut data_holder, &e)?,
+                    b"DMXMode" => data_holder
+                        .dmx_modes
+                        .push(Self::read_dmx_mode_attributes(&e)?),
+                    b"DMXChannel" => Self::add_dmx_channel(&mut dmx_mode, &e)?,
+                    _ => {}
+                },
+                Event::End(e) => match e.name() {
+                    b"DMXMode" => {
+                        if let Some(dmx_mode) = dmx_mode.take() {
+                            data_holder.dmx_modes.push(dmx_mode);
+                        }
+                    }
+                    b"DMXModes" | b"FixtureType" | b"GDTF" => break,
+                    _ => {}
+                },
+                //The loop ends with DMXModes, so the xml is truncated
+                Event::Eof => return Err(GdtfReadError::new_xml_unexpected_eof(b"GDTF").into()),
+                _ => {}
+            }
+            buf.clear();
+        }
+        Self::move_data(data_holder)
+    }
+
+    ///Scans a .gdtf archive from any source that can be read and seeked
+    pub fn from_reader<R: Read + Seek>(reader: R) -> Result<Self, GdtfError> {
+        Self::from_description_xml(&read::description_xml_from_archive(reader)?)
+    }
+
+    fn read_gdtf_attributes(
+        data_holder: &mut GdtfHeaderDataHolder,
+        event: &BytesStart<'_>,
+    ) -> Result<(), GdtfError> {
+        for attr in event.attributes() {
+            let attr = attr?;
+            if attr.key == b"DataVersion" {
+                data_holder.data_version = Some(DataVersion::new_from_attr(attr));
+            }
+        }
+        Ok(())
+    }
+
+    fn read_fixture_type_attributes(
+        data_holder: &mut GdtfHeaderDataHolder,
+        event: &BytesStart<'_>,
+    ) -> Result<(), GdtfError> {
+        for attr in event.attributes() {
+            let attr = attr?;
+            match attr.key {
+                b"Name" => data_holder.name = Some(Name::new_from_attr(attr)?),
+                b"ShortName" => data_holder.short_name = Some(read::attr_to_string(attr)),
+                b"LongName" => data_holder.long_name = Some(read::attr_to_string(attr)),
+                b"Manufacturer" => data_holder.manufacturer = Some(read::attr_to_string(attr)),
+                b"FixtureTypeID" => data_holder.fixture_type_id = Some(Guid::new_from_attr(attr)?),
+                b"Thumbnail" => data_holder.thumbnail = Some(Resource::new_from_attr(attr)),
+                b"RefFT" => data_holder.ref_ft = Guid::new_from_attr(attr).ok(),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn read_dmx_mode_attributes(event: &BytesStart<'_>) -> Result<DmxModeHeader, GdtfError> {
+        for attr in event.attributes() {
+            let attr = attr?;
+            if attr.key == b"Name" {
+                return Ok(DmxModeHeader {
+                    name: Name::new_from_attr(attr)?,
+                    dmx_channel_count: 0,
+                    footprint: 0,
+                });
+            }
+        }
+        Err(GdtfReadError::new_xml_attribute_not_found(b"DMXMode", b"Name").into())
+    }
+
+    fn add_dmx_channel(
+        dmx_mode: &mut Option<DmxModeHeader>,
+        event: &BytesStart<'_>,
+    ) -> Result<(), GdtfError> {
+        let dmx_mode = match dmx_mode {
+            Some(dmx_mode) => dmx_mode,
+            None => return Ok(()),
+        };
+        dmx_mode.dmx_channel_count += 1;
+        for attr in event.attributes() {
+            let attr = attr?;
+            if attr.key == b"Offset" {
+                if let Some(offset) = Offset::new_from_attr(attr) {
+                    let highest = offset.0.iter().copied().max().unwrap_or(0).max(0) as u32;
+                    dmx_mode.footprint = dmx_mode.footprint.max(highest);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn move_data(data_holder: GdtfHeaderDataHolder) -> Result<Self, GdtfError> {
+        let fixture_type_attribute_not_found = |attribute_name: &[u8]| {
+            GdtfReadError::new_xml_attribute_not_found(b"FixtureType", attribute_name)
+        };
+        Ok(Self {
+            data_version: data_holder.data_version.ok_or_else(|| {
+                GdtfReadError::new_xml_attribute_not_found(b"GDTF", b"DataVersion")
+            })?,
+            name: data_holder
+                .name
+                .ok_or_else(|| fixture_type_attribute_not_found(b"Name"))?,
+            short_name: data_holder.short_name.unwrap_or_default(),
+            long_name: data_holder.long_name.unwrap_or_default(),
+            manufacturer: data_holder
+                .manufacturer
+                .ok_or_else(|| fixture_type_attribute_not_found(b"Manufacturer"))?,
+            fixture_type_id: data_holder
+                .fixture_type_id
+                .ok_or_else(|| fixture_type_attribute_not_found(b"FixtureTypeID"))?,
+            ref_ft: data_holder.ref_ft,
+            thumbnail: data_holder.thumbnail,
+            dmx_modes: data_holder.dmx_modes,
+        })
+    }
+}
+
+impl TryFrom<&Path> for GdtfHeader {
+    type Error = GdtfError;
+
+    fn try_from(file_path: &Path) -> Result<Self, Self::Error> {
+        Self::from_reader(File::open(file_path)?)
+    }
+}
+
+impl TryFrom<&[u8]> for GdtfHeader {
+    type Error = GdtfError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_reader(Cursor::new(bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::fs::File;
+    use std::path::Path;
+
+    use xmltree::Element;
+
+    use crate::fixture_type::dmx_mode::dmx_channel::Offset;
+    use crate::header::GdtfHeader;
+    use crate::utils::read;
+    use crate::Gdtf;
+
+    #[test]
+    fn test_header_matches_gdtf() {
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let header = GdtfHeader::try_from(Path::new(path)).unwrap();
+            let gdtf = Gdtf::try_from(Path::new(path)).unwrap();
+            let fixture_type = &gdtf.fixture_type;
+            assert_eq!(header.data_version, gdtf.data_version);
+            assert_eq!(header.name, fixture_type.name);
+            assert_eq!(header.short_name, fixture_type.short_name);
+            assert_eq!(header.long_name, fixture_type.long_name);
+            assert_eq!(header.manufacturer, fixture_type.manufacturer);
+            assert_eq!(header.fixture_type_id, fixture_type.fixture_type_id);
+            assert_eq!(header.ref_ft, fixture_type.ref_ft);
+            assert_eq!(header.thumbnail, fixture_type.thumbnail);
+            assert_eq!(header.dmx_modes.len(), fixture_type.dmx_modes.len());
+
+            //Channel counts are compared with the raw xml so the check does not depend on the full deparse
+            let description_xml =
+                read::description_xml_from_archive(File::open(path).unwrap()).unwrap();
+            let root = Element::parse(description_xml.as_bytes()).unwrap();
+            let dmx_modes = &root
+                .get_child("FixtureType")
+                .unwrap()
+                .get_child("DMXModes")
+                .unwrap();
+            let dmx_modes: Vec<&Element> = dmx_modes
+                .children
+                .iter()
+                .filter_map(|c| c.as_element())
+                .collect();
+            assert_eq!(header.dmx_modes.len(), dmx_modes.len());
+            for (dmx_mode_header, dmx_mode) in header.dmx_modes.iter().zip(dmx_modes) {
+                assert_eq!(dmx_mode_header.name.as_str(), dmx_mode.attributes["Name"]);
+                let dmx_channels: Vec<&Element> = dmx_mode
+                    .get_child("DMXChannels")
+                    .unwrap()
+                    .children
+                    .iter()
+                    .filter_map(|c| c.as_element())
+                    .collect();
+                assert_eq!(dmx_mode_header.dmx_channel_count, dmx_channels.len());
+                let footprint = dmx_channels
+                    .iter()
+                    .filter_map(|dmx_channel| {
+                        Offset::new_from_str(&dmx_channel.attributes["Offset"])
+                    })
+                    .flat_map(|offset| offset.0)
+                    .max()
+                    .unwrap_or(0);
+                assert_eq!(dmx_mode_header.footprint as i32, footprint);
+            }
+        }
+    }
+
+    #[test]
+    fn test_header_dmx_modes() {
+        let header = GdtfHeader::try_from(Path::new(
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+        ))
+        .unwrap();
+        let dmx_modes: Vec<(&str, usize, u32)> = header
+            .dmx_modes
+            .iter()
+            .map(|dmx_mode| {
+                (
+                    dmx_mode.name.as_str(),
+                    dmx_mode.dmx_channel_count,
+                    dmx_mode.footprint,
+                )
+            })
+            .collect();
+        assert_eq!(dmx_modes, vec![("Mode 1 12 DMX", 10, 12)]);
+    }
+
+    #[test]
+    fn test_header_missing_attribute() {
+        assert!(GdtfHeader::from_description_xml(
+            r#"<GDTF DataVersion="1.1"><FixtureType ShortName="PAR"/></GDTF>"#
+        )
+        .is_err());
+    }
+}
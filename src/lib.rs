@@ -62,27 +62,69 @@
 //! }
 //! ```
 //!
+//! ### Example from memory
+//!
+//! Archives that are already loaded, for example from an upload, can be parsed without writing them to a file. `Gdtf` implements `TryFrom<&[u8]>` and `TryFrom<Vec<u8>>`, `Gdtf::from_reader` accepts any `Read + Seek` and `Gdtf::from_description_xml` accepts the content of an already extracted description.xml.
+//!
+//! ```rust
+//! use std::convert::TryFrom;
+//! use gdtf_parser::Gdtf;
+//!
+//! let bytes: Vec<u8> = std::fs::read("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").unwrap();
+//! let gdtf = Gdtf::try_from(bytes.as_slice()).unwrap();
+//! ```
+//!
+//! ### Strict and lenient parsing
+//!
+//! Values that can't be parsed are silently replaced by a default. `Gdtf::from_reader_with_mode` and `Gdtf::from_description_xml_with_mode` report them instead: `ParseMode::Lenient` returns every substitution and skipped node as `ParseWarning` with it's line and column in the description.xml, `ParseMode::Strict` fails with `GdtfError::StrictParseError`. Nodes that share their name with a sibling, like two `ChannelSet`s named `Open`, are kept under a renamed key like `Open {2}` and reported the same way. They are written back with their original name.
+//!
+//! ```rust
+//! use std::fs::File;
+//! use gdtf_parser::Gdtf;
+//! use gdtf_parser::utils::warnings::ParseMode;
+//!
+//! let file = File::open("test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf").unwrap();
+//! let (gdtf, warnings) = Gdtf::from_reader_with_mode(file, ParseMode::Lenient).unwrap();
+//! for warning in warnings {
+//!     println!("{}", warning);
+//! }
+//! ```
+//!
 //!
 use std::convert::TryFrom;
-use std::fmt::Debug;
+use std::fmt;
+use std::fmt::{Debug, Display, Formatter};
 use std::fs::File;
-use std::io::Read;
+use std::io::{Cursor, Read, Seek, Write};
 use std::path::Path;
 
-use quick_xml::events::{BytesStart, Event};
+use quick_xml::events::{BytesDecl, BytesStart, Event};
 use quick_xml::events::attributes::Attribute;
-use quick_xml::Reader;
+use quick_xml::{Reader, Writer};
 
 use crate::fixture_type::FixtureType;
 use crate::utils::errors::GdtfError;
 use crate::utils::read;
 use crate::utils::read::{GdtfReadError, ReadGdtf};
+use crate::utils::units::name;
+use crate::utils::warnings;
+use crate::utils::warnings::{ParseMode, ParseWarning};
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
 
+pub mod archive;
+#[cfg(feature = "cache")]
+pub mod cache;
 pub mod fixture_type;
+pub mod header;
+pub mod library;
+pub mod mvr;
 pub mod utils;
+pub mod validate;
 
 ///Describes the hierarchical and logical structure and controls of any type of controllable device (e.g. luminaires, fog machines, etc.) in the lighting and entertainment industry.
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gdtf {
     ///The DataVersion attribute defines the minimal version of compatibility
     pub data_version: DataVersion,
@@ -144,32 +186,62 @@ impl ReadGdtf for Gdtf {
     fn read_primary_key_from_attr(
         _: Attribute<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
-        panic!("Should not be executed");
+        Ok(None)
     }
 }
 
-impl TryFrom<&Path> for Gdtf {
-    type Error = GdtfError;
+impl WriteGdtf for Gdtf {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"DataVersion", &self.data_version);
+    }
 
-    fn try_from(file_path: &Path) -> Result<Self, Self::Error> {
-        let mut archive = zip::ZipArchive::new(File::open(file_path)?)?;
-        let mut description_xml = String::new();
-        archive
-            .by_name("description.xml")?
-            .read_to_string(&mut description_xml)?;
+    fn has_children(&self) -> bool {
+        true
+    }
 
-        let mut reader = Reader::from_str(&description_xml);
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        self.fixture_type.write_single(writer, None)
+    }
+}
+
+impl Gdtf {
+    ///Deparses the content of a description.xml that was already extracted from the .gdtf archive
+    /// ```rust
+    /// use gdtf_parser::{DataVersion, Gdtf};
+    ///
+    /// let gdtf = Gdtf::from_description_xml(r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <GDTF DataVersion="1.1">
+    ///   <FixtureType Name="LED PAR" ShortName="PAR" LongName="LED PAR" Manufacturer="Generic" Description="" FixtureTypeID="308EA87D-7164-42DE-8106-A6D273F57A51" Thumbnail="" RefFT="">
+    ///     <AttributeDefinitions/>
+    ///     <PhysicalDescriptions/>
+    ///     <DMXModes/>
+    ///   </FixtureType>
+    /// </GDTF>"#).unwrap();
+    /// assert_eq!(gdtf.data_version, DataVersion::Version1_1);
+    /// assert_eq!(gdtf.fixture_type.short_name, "PAR");
+    /// ```
+    pub fn from_description_xml(description_xml: &str) -> Result<Self, GdtfError> {
+        name::interning(|| Self::read_description_xml(description_xml))
+    }
+
+    ///Deparses the description.xml, equal names share their allocation
+    fn read_description_xml(description_xml: &str) -> Result<Self, GdtfError> {
+        let mut reader = Reader::from_str(description_xml);
         let mut buf: Vec<u8> = Vec::new();
         loop {
             match reader.read_event(&mut buf)? {
                 Event::Start(e) => {
                     if e.name() == b"GDTF" {
-                        return Ok(Gdtf::read_single_from_event(&mut reader, e, true)?.1);
+                        return Ok(Gdtf::read_single_from_event(&mut reader, e, true)
+                            .map_err(|e| e.locate_in(description_xml))?
+                            .1);
                     }
                 }
                 Event::Empty(e) => {
                     if e.name() == b"GDTF" {
-                        return Ok(Gdtf::read_single_from_event(&mut reader, e, false)?.1);
+                        return Ok(Gdtf::read_single_from_event(&mut reader, e, false)
+                            .map_err(|e| e.locate_in(description_xml))?
+                            .1);
                     }
                 }
                 Event::Eof => {
@@ -186,6 +258,174 @@ impl TryFrom<&Path> for Gdtf {
         buf.clear();
         Err(GdtfReadError::new_xml_node_not_found(b"TopLevel", Self::NODE_NAME).into())
     }
+
+    ///Deparses a .gdtf archive from any source that can be read and seeked, for example a file or a `Cursor` over bytes received from the network
+    /// ```rust
+    /// use std::fs::File;
+    /// use gdtf_parser::Gdtf;
+    ///
+    /// let file = File::open("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").unwrap();
+    /// let gdtf = Gdtf::from_reader(file).unwrap();
+    /// assert_eq!(gdtf.fixture_type.short_name, "ACME AE 610 BEAM");
+    /// ```
+    pub fn from_reader<R: Read + Seek>(reader: R) -> Result<Self, GdtfError> {
+        Self::from_description_xml(&read::description_xml_from_archive(reader)?)
+    }
+
+    ///Deparses a .gdtf archive from a tokio `AsyncRead` like `from_reader`. The archive is read asynchronously, the deparsing runs on a blocking task so the runtime stays responsive. Must be called within a tokio runtime
+    /// ```rust
+    /// use gdtf_parser::Gdtf;
+    ///
+    /// #[tokio::main(flavor = "current_thread")]
+    /// async fn main() {
+    ///     let file = tokio::fs::File::open("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").await.unwrap();
+    ///     let gdtf = Gdtf::from_async_reader(file).await.unwrap();
+    ///     assert_eq!(gdtf.fixture_type.short_name, "ACME AE 610 BEAM");
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn from_async_reader<R: tokio::io::AsyncRead + Unpin>(
+        mut reader: R,
+    ) -> Result<Self, GdtfError> {
+        use tokio::io::AsyncReadExt;
+
+        let mut archive = Vec::new();
+        reader.read_to_end(&mut archive).await?;
+        tokio::task::spawn_blocking(move || Self::from_reader(Cursor::new(archive)))
+            .await
+            .map_err(|e| match e.try_into_panic() {
+                Ok(panic) => std::panic::resume_unwind(panic),
+                Err(e) => GdtfError::FileReadError(e.into()),
+            })?
+    }
+
+    ///Deparses the content of a description.xml like `from_description_xml`, which silently substitutes values that can't be deparsed. In `ParseMode::Lenient` all substitutions and skipped nodes are returned as warnings, in `ParseMode::Strict` they cause a `GdtfError::StrictParseError`
+    /// ```rust
+    /// use gdtf_parser::Gdtf;
+    /// use gdtf_parser::utils::errors::GdtfError;
+    /// use gdtf_parser::utils::warnings::ParseMode;
+    ///
+    /// let description_xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <GDTF DataVersion="1.1">
+    ///   <FixtureType Name="LED PAR" ShortName="PAR" LongName="LED PAR" Manufacturer="Generic" Description="" FixtureTypeID="308EA87D-7164-42DE-8106-A6D273F57A51" Thumbnail="" RefFT="">
+    ///     <AttributeDefinitions/>
+    ///     <PhysicalDescriptions>
+    ///       <ColorSpace Mode="Adobe"/>
+    ///     </PhysicalDescriptions>
+    ///     <DMXModes/>
+    ///   </FixtureType>
+    /// </GDTF>"#;
+    /// let (_, warnings) = Gdtf::from_description_xml_with_mode(description_xml, ParseMode::Lenient).unwrap();
+    /// assert_eq!(warnings[0].to_string(), "6:7 GDTF/FixtureType[LED PAR]/PhysicalDescriptions/ColorSpace: Invalid value 'Adobe' in Mode was replaced by 'sRGB'");
+    ///
+    /// assert!(matches!(Gdtf::from_description_xml_with_mode(description_xml, ParseMode::Strict), Err(GdtfError::StrictParseError(_))));
+    /// ```
+    pub fn from_description_xml_with_mode(
+        description_xml: &str,
+        mode: ParseMode,
+    ) -> Result<(Self, Vec<ParseWarning>), GdtfError> {
+        warnings::collect(mode, description_xml, || {
+            Self::from_description_xml(description_xml)
+        })
+    }
+
+    ///Deparses a .gdtf archive like `from_reader` with the given `ParseMode`. See `from_description_xml_with_mode` for details
+    /// ```rust
+    /// use std::fs::File;
+    /// use gdtf_parser::Gdtf;
+    /// use gdtf_parser::utils::warnings::ParseMode;
+    ///
+    /// let file = File::open("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").unwrap();
+    /// let (gdtf, warnings) = Gdtf::from_reader_with_mode(file, ParseMode::Lenient).unwrap();
+    /// assert_eq!(gdtf.fixture_type.short_name, "ACME AE 610 BEAM");
+    /// assert!(warnings.is_empty());
+    /// ```
+    pub fn from_reader_with_mode<R: Read + Seek>(
+        reader: R,
+        mode: ParseMode,
+    ) -> Result<(Self, Vec<ParseWarning>), GdtfError> {
+        Self::from_description_xml_with_mode(&read::description_xml_from_archive(reader)?, mode)
+    }
+
+    ///Serialises the Gdtf to the content of a description.xml and writes it to the given writer.
+    ///
+    /// All values are written in their gdtf-xml-description syntax, so deparsing the output again results in an equal Gdtf.
+    /// Nodes that are not deparsed (Models, Geometries, Revisions, FTPresets and Protocols) are written as they were read. Wheel slots and channel sets are written in their order, nodes that are stored in a hash-map are written sorted by their Name and channel functions sorted by DMXFrom.
+    /// The order of hash-map children is not kept, so the output can list siblings like DMX modes or wheels in an other order than the original description.xml.
+    ///
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use std::path::Path;
+    /// use gdtf_parser::Gdtf;
+    ///
+    /// let gdtf = Gdtf::try_from(Path::new("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf")).unwrap();
+    /// let mut description_xml: Vec<u8> = Vec::new();
+    /// gdtf.write_description_xml(&mut description_xml).unwrap();
+    /// assert!(description_xml.starts_with(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    /// ```
+    pub fn write_description_xml<W: Write>(&self, writer: W) -> Result<(), GdtfError> {
+        let mut writer = Writer::new_with_indent(writer, b' ', 2);
+        writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)))?;
+        self.write_single(&mut writer, None)
+    }
+
+    ///Serialises the Gdtf to the content of a description.xml. See `write_description_xml` for details
+    /// ```rust
+    /// use std::convert::TryFrom;
+    /// use std::path::Path;
+    /// use gdtf_parser::Gdtf;
+    ///
+    /// let gdtf = Gdtf::try_from(Path::new("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf")).unwrap();
+    /// let description_xml = gdtf.to_description_xml().unwrap();
+    /// assert!(description_xml.contains("<GDTF DataVersion=\"1.0\">"));
+    /// ```
+    pub fn to_description_xml(&self) -> Result<String, GdtfError> {
+        let mut out: Vec<u8> = Vec::new();
+        self.write_description_xml(&mut out)?;
+        Ok(String::from_utf8(out).map_err(|e| e.utf8_error())?)
+    }
+}
+
+impl TryFrom<&Path> for Gdtf {
+    type Error = GdtfError;
+
+    fn try_from(file_path: &Path) -> Result<Self, Self::Error> {
+        Self::from_reader(File::open(file_path)?)
+    }
+}
+
+///Deparses a .gdtf archive that is already loaded into memory
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gdtf_parser::Gdtf;
+///
+/// let bytes = std::fs::read("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").unwrap();
+/// let gdtf = Gdtf::try_from(bytes.as_slice()).unwrap();
+/// assert_eq!(gdtf.fixture_type.short_name, "ACME AE 610 BEAM");
+/// ```
+impl TryFrom<&[u8]> for Gdtf {
+    type Error = GdtfError;
+
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::from_reader(Cursor::new(bytes))
+    }
+}
+
+///Deparses a .gdtf archive that is already loaded into memory
+/// ```rust
+/// use std::convert::TryFrom;
+/// use gdtf_parser::Gdtf;
+///
+/// let bytes = std::fs::read("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").unwrap();
+/// let gdtf = Gdtf::try_from(bytes).unwrap();
+/// assert_eq!(gdtf.fixture_type.short_name, "ACME AE 610 BEAM");
+/// ```
+impl TryFrom<Vec<u8>> for Gdtf {
+    type Error = GdtfError;
+
+    fn try_from(bytes: Vec<u8>) -> Result<Self, Self::Error> {
+        Self::from_reader(Cursor::new(bytes))
+    }
 }
 
 //-----------------------------------------------------------------------------------------------------------------
@@ -256,6 +496,38 @@ impl DataVersion {
     }
 }
 
+///Displays DataVersion in the format “Major.Minor” used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::DataVersion;
+///
+/// assert_eq!(DataVersion::Version1_0.to_string(), "1.0");
+/// assert_eq!(DataVersion::Version1_1.to_string(), "1.1");
+/// assert_eq!(DataVersion::Unknown("1.2".to_string()).to_string(), "1.2");
+/// ```
+impl Display for DataVersion {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            DataVersion::Version1_0 => write!(f, "1.0"),
+            DataVersion::Version1_1 => write!(f, "1.1"),
+            DataVersion::Unknown(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DataVersion {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DataVersion {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::new_from_str(&String::deserialize(deserializer)?))
+    }
+}
+
 //-----------------------------------------------------------------------------------------------------------------
 //-----------------------------------------------------------------------------------------------------------------
 // End of DataVersion
@@ -266,11 +538,17 @@ impl DataVersion {
 mod tests {
     use std::{thread, time};
     use std::convert::TryFrom;
+    use std::io::Cursor;
     use std::path::Path;
     use std::time::Duration;
 
+    use xmltree::Element;
+
     use crate::{DataVersion, Gdtf};
+    use crate::header::GdtfHeader;
+    use crate::utils::read;
     use crate::utils::testdata;
+    use crate::utils::warnings::ParseMode;
 
     #[test]
     fn test_acme() {
@@ -292,6 +570,307 @@ mod tests {
         //   crate::utils::testdata::sgm_light_at_g_7_spot_at_rev_a::expect().test_with_result(Path::new("test/SGM_Light@G-7_Spot@Rev_A.gdtf").try_into());
     }
 
+    #[test]
+    fn test_write_description_xml() {
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let gdtf = Gdtf::try_from(Path::new(path)).unwrap();
+            let description_xml = gdtf.to_description_xml().unwrap();
+            let written = Gdtf::from_description_xml(&description_xml).unwrap();
+            assert_eq!(gdtf, written, "Writing {} is not lossless", path);
+            assert_eq!(description_xml, written.to_description_xml().unwrap());
+
+            let original = fixture_type_element(
+                &read::description_xml_from_archive(std::fs::File::open(path).unwrap()).unwrap(),
+            );
+            let written = fixture_type_element(&description_xml);
+            assert_eq!(
+                wheel_slots(&original),
+                wheel_slots(&written),
+                "Slots of {} are not written in their order",
+                path
+            );
+            assert!(written.get_child("Geometries").is_some());
+            for node in [
+                "Models",
+                "Geometries",
+                "Revisions",
+                "FTPresets",
+                "Protocols",
+            ] {
+                assert_eq!(
+                    original.get_child(node),
+                    written.get_child(node),
+                    "{} of {} is not written as it was read",
+                    node,
+                    path
+                );
+            }
+        }
+    }
+
+    fn fixture_type_element(description_xml: &str) -> Element {
+        Element::parse(description_xml.as_bytes())
+            .unwrap()
+            .take_child("FixtureType")
+            .unwrap()
+    }
+
+    ///Returns the names of the slots of each wheel in the order of the xml. The wheels are sorted by their name
+    fn wheel_slots(fixture_type: &Element) -> Vec<(String, Vec<String>)> {
+        let name = |element: &Element| element.attributes.get("Name").cloned().unwrap_or_default();
+        let mut wheels: Vec<(String, Vec<String>)> = fixture_type
+            .get_child("Wheels")
+            .map(|wheels| {
+                wheels
+                    .children
+                    .iter()
+                    .filter_map(|wheel| wheel.as_element())
+                    .map(|wheel| {
+                        let slots = wheel
+                            .children
+                            .iter()
+                            .filter_map(|slot| slot.as_element())
+                            .map(name)
+                            .collect();
+                        (name(wheel), slots)
+                    })
+                    .collect()
+            })
+            .unwrap_or_default();
+        wheels.sort();
+        wheels
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_parallel() {
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let description_xml =
+                read::description_xml_from_archive(std::fs::File::open(path).unwrap()).unwrap();
+            //Collecting warnings doesn't change the deparsed modes
+            let (sequential, _) =
+                Gdtf::from_description_xml_with_mode(&description_xml, ParseMode::Lenient).unwrap();
+            let parallel = Gdtf::from_description_xml(&description_xml).unwrap();
+            assert_eq!(sequential, parallel, "Parallel deparsing of {} differs", path);
+        }
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_from_async_reader() {
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let file = tokio::fs::File::open(path).await.unwrap();
+            assert_eq!(
+                Gdtf::from_async_reader(file).await.unwrap(),
+                Gdtf::try_from(Path::new(path)).unwrap()
+            );
+        }
+        assert!(matches!(
+            Gdtf::from_async_reader(&b"Not a zip archive"[..]).await,
+            Err(crate::utils::errors::GdtfError::ZipError(_))
+        ));
+    }
+
+    #[test]
+    fn test_from_memory() {
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let expected = Gdtf::try_from(Path::new(path)).unwrap();
+            let bytes = std::fs::read(path).unwrap();
+            assert_eq!(Gdtf::try_from(bytes.as_slice()).unwrap(), expected);
+            assert_eq!(Gdtf::from_reader(Cursor::new(&bytes)).unwrap(), expected);
+            assert_eq!(Gdtf::try_from(bytes).unwrap(), expected);
+        }
+        assert!(Gdtf::try_from(b"Not a zip archive".as_ref()).is_err());
+        assert!(Gdtf::from_description_xml("<NoGdtf/>").is_err());
+    }
+
+    ///Deterministic xorshift generator, so failing mutations can be reproduced
+    struct Mutator(u64);
+
+    impl Mutator {
+        fn next(&mut self, max: usize) -> usize {
+            self.0 ^= self.0 << 13;
+            self.0 ^= self.0 >> 7;
+            self.0 ^= self.0 << 17;
+            (self.0 % max.max(1) as u64) as usize
+        }
+
+        fn mutate(&mut self, bytes: &[u8]) -> Vec<u8> {
+            const TOKENS: [&[u8]; 8] = [
+                b"<", b"/>", b"\"", b"=", b"99999999999999999999", b"-1", b"Gobo300", b"\xFF\xFE",
+            ];
+            const VALUES: [&[u8]; 12] = [
+                b"", b"-1", b"99999999999999999999", b"4294967295/4", b"255/255s", b"Gobo300",
+                b"..", b"{1,2,3}{4,5}", b"NaN", b"inf", b"A.B.", b"0,0,-1",
+            ];
+            let mut bytes = bytes.to_vec();
+            for _ in 0..=self.next(4) {
+                let position = self.next(bytes.len());
+                match self.next(6) {
+                    0 => bytes[position] = self.next(256) as u8,
+                    1 => {
+                        let end = (position + self.next(64)).min(bytes.len());
+                        bytes.drain(position..end);
+                    }
+                    2 => bytes.truncate(position),
+                    3 => {
+                        let token = TOKENS[self.next(TOKENS.len())];
+                        bytes.splice(position..position, token.iter().copied());
+                    }
+                    4 => {
+                        let end = (position + self.next(256)).min(bytes.len());
+                        let copy = bytes[position..end].to_vec();
+                        let target = self.next(bytes.len());
+                        bytes.splice(target..target, copy);
+                    }
+                    _ => {
+                        //Replaces an attribute value to keep the xml well-formed
+                        if let Some(start) = bytes[position..].iter().position(|b| *b == b'"') {
+                            let start = position + start + 1;
+                            if let Some(end) = bytes[start..].iter().position(|b| *b == b'"') {
+                                let token = VALUES[self.next(VALUES.len())];
+                                bytes.splice(start..start + end, token.iter().copied());
+                            }
+                        }
+                    }
+                }
+                if bytes.is_empty() {
+                    break;
+                }
+            }
+            bytes
+        }
+    }
+
+    #[test]
+    fn test_malformed_description_xml() {
+        let mut mutator = Mutator(0x9E37_79B9_7F4A_7C15);
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM/description.xml",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15/description.xml",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021/description.xml",
+            "test/SGM_Light@G-7_Spot@Rev_A/description.xml",
+        ] {
+            let original = std::fs::read(path).unwrap();
+            for _ in 0..30 {
+                let mutated = mutator.mutate(&original);
+                let description_xml = String::from_utf8_lossy(&mutated);
+                //Only the absence of panics is checked, mutations may still be valid
+                let _ = Gdtf::from_description_xml(&description_xml);
+                let _ = Gdtf::from_description_xml_with_mode(&description_xml, ParseMode::Strict);
+                let _ = Gdtf::from_description_xml_with_mode(&description_xml, ParseMode::Lenient);
+                let _ = GdtfHeader::from_description_xml(&description_xml);
+            }
+        }
+    }
+
+    #[test]
+    fn test_truncated_description_xml() {
+        let description_xml = std::fs::read_to_string(
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM/description.xml",
+        )
+        .unwrap();
+        //Ends inside of the geometries, that are kept as raw xml, and inside of an attribute value
+        let geometries = description_xml.find("<Geometry ").unwrap();
+        let attribute = description_xml.find("DMXFrom=\"").unwrap() + "DMXFrom=\"1".len();
+        for end in [geometries, attribute] {
+            let truncated = &description_xml[..end];
+            assert!(Gdtf::from_description_xml(truncated).is_err());
+            assert!(Gdtf::from_description_xml_with_mode(truncated, ParseMode::Lenient).is_err());
+            assert!(GdtfHeader::from_description_xml(truncated).is_err());
+        }
+        let end_of_gdtf = description_xml.find("</GDTF>").unwrap();
+        for end in (0..end_of_gdtf).step_by(97) {
+            assert!(
+                Gdtf::from_description_xml(&description_xml[..end]).is_err(),
+                "description.xml truncated after {} bytes was deparsed",
+                end
+            );
+        }
+    }
+
+    #[test]
+    fn test_malformed_archive() {
+        let mut mutator = Mutator(0xD1B5_4A32_D192_ED03);
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let original = std::fs::read(path).unwrap();
+            for _ in 0..30 {
+                let mutated = mutator.mutate(&original);
+                let _ = Gdtf::try_from(mutated.as_slice());
+                let _ = GdtfHeader::try_from(mutated.as_slice());
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        use crate::utils::units::attribute_name::AttributeName;
+        use crate::utils::units::name::Name;
+
+        for path in [
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            "test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf",
+            "test/Robe_Lighting@Robin_Viva_CMY@13042021.gdtf",
+            "test/SGM_Light@G-7_Spot@Rev_A.gdtf",
+        ] {
+            let gdtf = Gdtf::try_from(Path::new(path)).unwrap();
+            let json = serde_json::to_string(&gdtf).unwrap();
+            let deserialized: Gdtf = serde_json::from_str(&json).unwrap();
+            assert_eq!(gdtf, deserialized, "Serialising {} is not lossless", path);
+        }
+
+        let gdtf = Gdtf::try_from(Path::new(
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+        ))
+        .unwrap();
+        let json = serde_json::to_value(&gdtf).unwrap();
+        assert_eq!(json["data_version"], "1.0");
+        assert_eq!(
+            json["fixture_type"]["fixture_type_id"],
+            "E62F2ECF-2A08-491D-BEEC-F5C491B89784"
+        );
+        let dmx_channel = &json["fixture_type"]["dmx_modes"]["Mode 1 12 DMX"]["dmx_channels"][0];
+        assert_eq!(
+            dmx_channel["logical_channels"][0]["channel_functions"]["Pan 1"]["default"],
+            "0/1"
+        );
+
+        //Keys of renamed duplicates are not valid Names but must be deserialised again
+        assert_eq!(
+            serde_json::from_str::<Name>(r#""{2}""#).unwrap(),
+            Name::from("{2}")
+        );
+        assert_eq!(
+            serde_json::from_str::<AttributeName>(r#""Dimmer {2}""#).unwrap(),
+            AttributeName::UserDefined(Name::from("Dimmer {2}"))
+        );
+        assert!(serde_json::from_str::<Name>(r#""Open {x}""#).is_err());
+    }
+
     #[test]
     fn test_time() {
         thread::sleep(Duration::from_millis(2000));
@@ -0,0 +1,493 @@
+//! Index over a directory of .gdtf files
+//!
+//! `FixtureLibrary` scans a directory recursively with `GdtfHeader`, so only the header of each file is read. The full `Gdtf` of an entry is loaded on demand the first time it is requested.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::path::Path;
+//! use gdtf_parser::library::FixtureLibrary;
+//!
+//! let library = FixtureLibrary::scan(Path::new("test")).unwrap();
+//! for entry in library.by_manufacturer("ACME") {
+//!     let gdtf = entry.gdtf().unwrap();
+//!     println!("{} has {} DMX modes", entry.header.name, gdtf.fixture_type.dmx_modes.len());
+//! }
+//! ```
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::convert::TryFrom;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::SystemTime;
+
+use crate::fixture_type::ref_ft::FixtureTypeSource;
+use crate::fixture_type::FixtureType;
+use crate::header::GdtfHeader;
+use crate::utils::errors::GdtfError;
+use crate::utils::pool;
+use crate::utils::units::guid::Guid;
+use crate::Gdtf;
+
+///Parts of a file name that follows the naming convention `Manufacturer@Fixture@Revision.gdtf` used by the GDTF Share
+#[derive(Debug, PartialEq, Clone)]
+pub struct GdtfFileName {
+    ///Manufacturer part of the file name, spaces are usually replaced with `_`
+    pub manufacturer: String,
+    ///Fixture part of the file name, spaces are usually replaced with `_`
+    pub fixture: String,
+    ///Revision part of the file name
+    pub revision: String,
+}
+
+impl GdtfFileName {
+    ///Parses a file name in the format `Manufacturer@Fixture@Revision.gdtf`. Returns None if the file name does not follow the naming convention
+    /// ```rust
+    /// use gdtf_parser::library::GdtfFileName;
+    ///
+    /// let file_name = GdtfFileName::new_from_str("JB-Lighting@P12_Spot_HP@V_1.15.gdtf").unwrap();
+    /// assert_eq!(file_name.manufacturer, "JB-Lighting");
+    /// assert_eq!(file_name.fixture, "P12_Spot_HP");
+    /// assert_eq!(file_name.revision, "V_1.15");
+    /// assert!(GdtfFileName::new_from_str("Fixture.gdtf").is_none());
+    /// assert!(GdtfFileName::new_from_str("A@B@C@D.gdtf").is_none());
+    /// ```
+    pub fn new_from_str(file_name: &str) -> Option<Self> {
+        let stem = match file_name.rfind('.') {
+            Some(i) if file_name[i + 1..].eq_ignore_ascii_case("gdtf") => &file_name[..i],
+            _ => file_name,
+        };
+        let mut parts = stem.split('@');
+        let file_name = Self {
+            manufacturer: parts.next()?.to_string(),
+            fixture: parts.next()?.to_string(),
+            revision: parts.next()?.to_string(),
+        };
+        if parts.next().is_some() {
+            return None;
+        }
+        Some(file_name)
+    }
+
+    ///Compares two revisions. Groups of digits are compared by their numeric value, so `V_1.15` is newer than `V_1.9`
+    /// ```rust
+    /// use std::cmp::Ordering;
+    /// use gdtf_parser::library::GdtfFileName;
+    ///
+    /// assert_eq!(GdtfFileName::cmp_revision("V_1.15", "V_1.9"), Ordering::Greater);
+    /// assert_eq!(GdtfFileName::cmp_revision("Rev_A", "Rev_B"), Ordering::Less);
+    /// assert_eq!(GdtfFileName::cmp_revision("2", "2"), Ordering::Equal);
+    /// ```
+    pub fn cmp_revision(a: &str, b: &str) -> Ordering {
+        let mut a = a.chars().peekable();
+        let mut b = b.chars().peekable();
+        loop {
+            match (a.peek(), b.peek()) {
+                (None, None) => return Ordering::Equal,
+                (None, Some(_)) => return Ordering::Less,
+                (Some(_), None) => return Ordering::Greater,
+                (Some(ca), Some(cb)) if ca.is_ascii_digit() && cb.is_ascii_digit() => {
+                    let na = take_number(&mut a);
+                    let nb = take_number(&mut b);
+                    let ordering = na
+                        .trim_start_matches('0')
+                        .len()
+                        .cmp(&nb.trim_start_matches('0').len())
+                        .then_with(|| na.trim_start_matches('0').cmp(nb.trim_start_matches('0')));
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+                }
+                (Some(ca), Some(cb)) => {
+                    let ordering = ca.cmp(cb);
+                    if ordering != Ordering::Equal {
+                        return ordering;
+                    }
+                    a.next();
+                    b.next();
+                }
+            }
+        }
+    }
+}
+
+///Takes all following digits of the iterator
+fn take_number(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    let mut number = String::new();
+    while let Some(c) = chars.peek() {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        number.push(*c);
+        chars.next();
+    }
+    number
+}
+
+///A .gdtf file in a `FixtureLibrary`
+#[derive(Debug)]
+pub struct LibraryEntry {
+    ///Path of the .gdtf file
+    pub path: PathBuf,
+    ///Parts of the file name if it follows the naming convention `Manufacturer@Fixture@Revision.gdtf`
+    pub file_name: Option<GdtfFileName>,
+    ///Header of the .gdtf file, read during the scan
+    pub header: GdtfHeader,
+    ///Time of the last modification of the file, used to decide which entry is newer if the revisions are equal
+    pub modified: Option<SystemTime>,
+    ///The full Gdtf, only loaded when it is requested
+    gdtf: OnceLock<Gdtf>,
+    ///Held while the full Gdtf is deparsed, so concurrent calls deparse the file only once
+    loading: Mutex<()>,
+}
+
+impl LibraryEntry {
+    ///Reads the header of a .gdtf file
+    pub fn new(path: &Path) -> Result<Self, GdtfError> {
+        Ok(Self {
+            path: path.to_path_buf(),
+            file_name: path
+                .file_name()
+                .and_then(|file_name| file_name.to_str())
+                .and_then(GdtfFileName::new_from_str),
+            header: GdtfHeader::try_from(path)?,
+            modified: fs::metadata(path).and_then(|m| m.modified()).ok(),
+            gdtf: OnceLock::new(),
+            loading: Mutex::new(()),
+        })
+    }
+
+    ///Returns the full Gdtf of the entry. It is deparsed on the first call and kept for later calls. Concurrent first calls wait for the one that deparses the file. If deparsing fails, the next call tries again
+    pub fn gdtf(&self) -> Result<&Gdtf, GdtfError> {
+        if let Some(gdtf) = self.gdtf.get() {
+            return Ok(gdtf);
+        }
+        let _loading = self.loading.lock().unwrap_or_else(PoisonError::into_inner);
+        if let Some(gdtf) = self.gdtf.get() {
+            return Ok(gdtf);
+        }
+        let gdtf = Gdtf::try_from(self.path.as_path())?;
+        Ok(self.gdtf.get_or_init(|| gdtf))
+    }
+
+    ///Returns true if the full Gdtf was already loaded
+    pub fn is_loaded(&self) -> bool {
+        self.gdtf.get().is_some()
+    }
+
+    ///Revision of the entry, taken from the file name
+    pub fn revision(&self) -> Option<&str> {
+        self.file_name.as_ref().map(|f| f.revision.as_str())
+    }
+
+    ///Compares which entry is newer. The revision of the file name is compared first, if it is equal or missing the modification time of the file decides
+    pub fn cmp_newer(&self, other: &Self) -> Ordering {
+        let revision = match (self.revision(), other.revision()) {
+            (Some(a), Some(b)) => GdtfFileName::cmp_revision(a, b),
+            _ => Ordering::Equal,
+        };
+        revision.then_with(|| self.modified.cmp(&other.modified))
+    }
+}
+
+///Index over all .gdtf files in a directory and it's subdirectories
+#[derive(Debug, Default)]
+pub struct FixtureLibrary {
+    ///All files that could be read, sorted by path
+    entries: Vec<LibraryEntry>,
+    ///Files that end with .gdtf but could not be read, with the reason
+    errors: Vec<(PathBuf, GdtfError)>,
+    ///Indices of the entries by their FixtureTypeID
+    by_fixture_type_id: HashMap<Guid, Vec<usize>>,
+    ///Indices of the entries by the manufacturer of the FixtureType and of the file name, see `normalized`
+    by_manufacturer: HashMap<String, Vec<usize>>,
+    ///Indices of the entries by the Name of the FixtureType and the fixture of the file name, see `normalized`
+    by_name: HashMap<String, Vec<usize>>,
+    ///Indices of the entries by the revision of the file name, see `normalized`
+    by_revision: HashMap<String, Vec<usize>>,
+}
+
+impl FixtureLibrary {
+    ///Scans a directory recursively and reads the headers of all .gdtf files one after the other
+    pub fn scan(dir: &Path) -> Result<Self, GdtfError> {
+        let paths = Self::find_gdtf_files(dir)?;
+        let results = paths.into_iter().map(Self::read_entry).collect();
+        Ok(Self::new(results))
+    }
+
+    ///Scans a directory recursively and reads the headers of all .gdtf files on all available cores
+    pub fn scan_parallel(dir: &Path) -> Result<Self, GdtfError> {
+        let paths = Self::find_gdtf_files(dir)?;
+        let results = pool::map(paths, Self::read_entry).map_err(GdtfError::LibraryScanError)?;
+        Ok(Self::new(results))
+    }
+
+    ///Creates the library from the results of the scan
+    fn new(results: Vec<Result<LibraryEntry, (PathBuf, GdtfError)>>) -> Self {
+        let mut library = Self::default();
+        for result in results {
+            match result {
+                Ok(entry) => library.entries.push(entry),
+                Err(error) => library.errors.push(error),
+            }
+        }
+        library.entries.sort_by(|a, b| a.path.cmp(&b.path));
+        library.errors.sort_by(|a, b| a.0.cmp(&b.0));
+        for (i, entry) in library.entries.iter().enumerate() {
+            library
+                .by_fixture_type_id
+                .entry(entry.header.fixture_type_id.clone())
+                .or_default()
+                .push(i);
+            let file_name = entry.file_name.as_ref();
+            index(
+                &mut library.by_manufacturer,
+                i,
+                [
+                    Some(entry.header.manufacturer.as_str()),
+                    file_name.map(|f| f.manufacturer.as_str()),
+                ],
+            );
+            index(
+                &mut library.by_name,
+                i,
+                [
+                    Some(entry.header.name.as_str()),
+                    file_name.map(|f| f.fixture.as_str()),
+                ],
+            );
+            index(&mut library.by_revision, i, [entry.revision()]);
+        }
+        library
+    }
+
+    ///Returns the entries of an index for the given key
+    fn lookup(&self, index: &HashMap<String, Vec<usize>>, key: &str) -> Vec<&LibraryEntry> {
+        index
+            .get(&normalized(key))
+            .map(|indices| indices.iter().map(|i| &self.entries[*i]).collect())
+            .unwrap_or_default()
+    }
+
+    fn read_entry(path: PathBuf) -> Result<LibraryEntry, (PathBuf, GdtfError)> {
+        LibraryEntry::new(&path).map_err(|e| (path, e))
+    }
+
+    ///Returns the paths of all files with the extension .gdtf in the directory and it's subdirectories. Symlinks are followed, but every directory is only scanned once
+    fn find_gdtf_files(dir: &Path) -> Result<Vec<PathBuf>, GdtfError> {
+        let mut paths = Vec::new();
+        let mut visited = HashSet::new();
+        let mut dirs = vec![dir.to_path_buf()];
+        while let Some(dir) = dirs.pop() {
+            //A symlink can lead to a directory that was already scanned, even to a parent of itself
+            if !visited.insert(fs::canonicalize(&dir)?) {
+                continue;
+            }
+            for entry in fs::read_dir(dir)? {
+                let path = entry?.path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else if path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("gdtf"))
+                {
+                    paths.push(path);
+                }
+            }
+        }
+        Ok(paths)
+    }
+
+    ///Returns all files that could be read, sorted by path
+    pub fn entries(&self) -> &[LibraryEntry] {
+        &self.entries
+    }
+
+    ///Returns all files that end with .gdtf but could not be read, with the reason
+    pub fn errors(&self) -> &[(PathBuf, GdtfError)] {
+        &self.errors
+    }
+
+    ///Returns all entries with the given FixtureTypeID. More than one entry is returned if the library contains multiple revisions of the fixture type
+    pub fn by_fixture_type_id(&self, fixture_type_id: &Guid) -> Vec<&LibraryEntry> {
+        self.by_fixture_type_id
+            .get(fixture_type_id)
+            .map(|indices| indices.iter().map(|i| &self.entries[*i]).collect())
+            .unwrap_or_default()
+    }
+
+    ///Returns the newest entry with the given FixtureTypeID, see `LibraryEntry::cmp_newer`
+    pub fn newest(&self, fixture_type_id: &Guid) -> Option<&LibraryEntry> {
+        self.by_fixture_type_id(fixture_type_id)
+            .into_iter()
+            .max_by(|a, b| a.cmp_newer(b))
+    }
+
+    ///Returns all FixtureTypeIDs that are used by more than one file, together with the files
+    pub fn duplicates(&self) -> Vec<(&Guid, Vec<&LibraryEntry>)> {
+        let mut duplicates: Vec<(&Guid, Vec<&LibraryEntry>)> = self
+            .by_fixture_type_id
+            .iter()
+            .filter(|(_, indices)| indices.len() > 1)
+            .map(|(guid, indices)| (guid, indices.iter().map(|i| &self.entries[*i]).collect()))
+            .collect();
+        duplicates.sort_by(|a, b| a.1[0].path.cmp(&b.1[0].path));
+        duplicates
+    }
+
+    ///Returns the newest entry of every FixtureTypeID, sorted by path
+    pub fn newest_revisions(&self) -> Vec<&LibraryEntry> {
+        let mut newest: Vec<&LibraryEntry> = self
+            .by_fixture_type_id
+            .keys()
+            .filter_map(|guid| self.newest(guid))
+            .collect();
+        newest.sort_by(|a, b| a.path.cmp(&b.path));
+        newest
+    }
+
+    ///Returns all entries of a manufacturer. The Manufacturer of the FixtureType and the manufacturer in the file name are compared case-insensitive, `_` in the file name is treated as space
+    pub fn by_manufacturer(&self, manufacturer: &str) -> Vec<&LibraryEntry> {
+        self.lookup(&self.by_manufacturer, manufacturer)
+    }
+
+    ///Returns all entries with the given fixture name. The Name of the FixtureType and the fixture in the file name are compared case-insensitive, `_` in the file name is treated as space
+    pub fn by_name(&self, name: &str) -> Vec<&LibraryEntry> {
+        self.lookup(&self.by_name, name)
+    }
+
+    ///Returns all entries with the given revision in the file name. The revision is compared case-insensitive, `_` is treated as space
+    pub fn by_revision(&self, revision: &str) -> Vec<&LibraryEntry> {
+        self.lookup(&self.by_revision, revision)
+    }
+}
+
+///Looks up the newest revision of a fixture type and loads it if it was not loaded before
+impl FixtureTypeSource for FixtureLibrary {
+    fn fixture_type(&self, fixture_type_id: &Guid) -> Result<Option<&FixtureType>, GdtfError> {
+        match self.newest(fixture_type_id) {
+            None => Ok(None),
+            Some(entry) => Ok(Some(&entry.gdtf()?.fixture_type)),
+        }
+    }
+}
+
+///Returns the key of a name in an index, so names are compared case-insensitive and `_` is treated as space
+fn normalized(name: &str) -> String {
+    name.replace('_', " ").to_lowercase()
+}
+
+///Adds the entry at position i to the index under all given names. An entry whose names are equal is only added once
+fn index<const N: usize>(
+    index: &mut HashMap<String, Vec<usize>>,
+    i: usize,
+    names: [Option<&str>; N],
+) {
+    for name in names.into_iter().flatten() {
+        let indices = index.entry(normalized(name)).or_default();
+        if indices.last() != Some(&i) {
+            indices.push(i);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    use crate::library::FixtureLibrary;
+    use crate::utils::units::guid::Guid;
+    use crate::Gdtf;
+
+    ///Creates an empty temporary directory for the test
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("gdtf_parser_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_scan() {
+        let library = FixtureLibrary::scan(Path::new("test")).unwrap();
+        let parallel = FixtureLibrary::scan_parallel(Path::new("test")).unwrap();
+        assert_eq!(library.entries.len(), 4);
+        assert!(library.errors.is_empty());
+        assert!(library.duplicates().is_empty());
+        for (a, b) in library.entries.iter().zip(parallel.entries.iter()) {
+            assert_eq!(a.path, b.path);
+            assert_eq!(a.header, b.header);
+        }
+
+        let robe = library.by_manufacturer("Robe Lighting");
+        assert_eq!(robe.len(), 1);
+        assert_eq!(library.by_manufacturer("robe_lighting").len(), 1);
+        assert!(library.by_manufacturer("Robe").is_empty());
+        assert_eq!(robe[0].revision(), Some("13042021"));
+        assert_eq!(library.by_name("G-7 Spot").len(), 1);
+        assert_eq!(library.by_revision("v_1.15").len(), 1);
+
+        let guid = Guid::new_from_str("E62F2ECF-2A08-491D-BEEC-F5C491B89784").unwrap();
+        let acme = library.newest(&guid).unwrap();
+        assert!(!acme.is_loaded());
+        let loaded: Vec<&Gdtf> = std::thread::scope(|scope| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| scope.spawn(|| acme.gdtf().unwrap()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+        assert!(loaded.iter().all(|gdtf| std::ptr::eq(*gdtf, loaded[0])));
+        assert_eq!(acme.gdtf().unwrap().fixture_type.fixture_type_id, guid);
+        assert!(acme.is_loaded());
+    }
+
+    #[test]
+    fn test_duplicates_and_newest() {
+        let dir = temp_dir("library");
+        let acme = "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf";
+        fs::copy(acme, dir.join("ACME@AE-610_BEAM@V_1.9.gdtf")).unwrap();
+        fs::create_dir(dir.join("sub")).unwrap();
+        fs::copy(acme, dir.join("sub").join("ACME@AE-610_BEAM@V_1.15.gdtf")).unwrap();
+        fs::write(dir.join("Broken@File@1.gdtf"), b"Not a zip archive").unwrap();
+        fs::write(dir.join("readme.txt"), b"Not a gdtf").unwrap();
+
+        let library = FixtureLibrary::scan(&dir).unwrap();
+        assert_eq!(library.entries.len(), 2);
+        assert_eq!(library.errors.len(), 1);
+        assert!(library.errors[0].0.ends_with("Broken@File@1.gdtf"));
+        let duplicates = library.duplicates();
+        assert_eq!(duplicates.len(), 1);
+        assert_eq!(duplicates[0].1.len(), 2);
+        let newest = library.newest(duplicates[0].0).unwrap();
+        assert_eq!(newest.revision(), Some("V_1.15"));
+        assert_eq!(library.newest_revisions().len(), 1);
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_symlink_cycle() {
+        let dir = temp_dir("symlink");
+        fs::create_dir(dir.join("sub")).unwrap();
+        fs::copy(
+            "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf",
+            dir.join("sub").join("ACME@AE-610_BEAM@V_1.9.gdtf"),
+        )
+        .unwrap();
+        std::os::unix::fs::symlink(&dir, dir.join("sub").join("parent")).unwrap();
+        std::os::unix::fs::symlink(dir.join("sub"), dir.join("linked")).unwrap();
+
+        let library = FixtureLibrary::scan(&dir).unwrap();
+        assert_eq!(library.entries.len(), 1);
+        assert!(library.errors.is_empty());
+        assert_eq!(
+            FixtureLibrary::scan_parallel(&dir).unwrap().entries.len(),
+            1
+        );
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
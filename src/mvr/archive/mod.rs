@@ -0,0 +1,322 @@
+//! Writes complete .mvr archives containing the GeneralSceneDescription.xml and the .gdtf files of all fixtures
+//!
+//! The .gdtf files are embedded byte-for-byte as they were added, so the fixture types in the archive are exactly the files the scene was planned with.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use std::io::Cursor;
+//! use std::path::Path;
+//! use gdtf_parser::mvr::Mvr;
+//! use gdtf_parser::mvr::archive::MvrArchiveWriter;
+//! use gdtf_parser::mvr::child_list::ChildObject;
+//! use gdtf_parser::mvr::child_list::fixture::{Address, Fixture};
+//! use gdtf_parser::mvr::layer::Layer;
+//! use gdtf_parser::utils::errors::GdtfError;
+//! use gdtf_parser::utils::units::guid::Guid;
+//! use gdtf_parser::utils::units::matrix::Matrix;
+//!
+//! fn main() -> Result<(), GdtfError> {
+//!     let fixture = Fixture {
+//!         name: "Beam 1".to_string(),
+//!         uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0002")?,
+//!         fixture_id: "101".to_string(),
+//!         unit_number: None,
+//!         gdtf_spec: "ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf".to_string(),
+//!         gdtf_mode: "Mode 1 12 DMX".to_string(),
+//!         addresses: vec![Address { dmx_break: 0, universe: 1, address: 1 }],
+//!         matrix: Some(Matrix([
+//!             [1.0, 0.0, 0.0, 1000.0],
+//!             [0.0, 1.0, 0.0, 0.0],
+//!             [0.0, 0.0, 1.0, 5000.0],
+//!             [0.0, 0.0, 0.0, 1.0],
+//!         ])),
+//!         children: vec![],
+//!     };
+//!     let mvr = Mvr {
+//!         ver_major: 1,
+//!         ver_minor: 5,
+//!         layers: vec![Layer {
+//!             name: "Stage".to_string(),
+//!             uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0001")?,
+//!             matrix: None,
+//!             children: vec![ChildObject::Fixture(fixture)],
+//!         }],
+//!         gdtfs: HashMap::new(),
+//!     };
+//!
+//!     let mut archive_writer = MvrArchiveWriter::new(&mvr);
+//!     archive_writer.add_gdtf_file(Path::new("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf"))?;
+//!
+//!     let mut archive = Cursor::new(Vec::new());
+//!     archive_writer.write(&mut archive)?;
+//!     assert_eq!(Mvr::from_reader(archive)?.fixtures()[0].name, "Beam 1");
+//!     Ok(())
+//! }
+//! ```
+use std::collections::BTreeMap;
+use std::fs::File;
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+use zip::write::FileOptions;
+use zip::{ZipArchive, ZipWriter};
+
+use crate::mvr::{Mvr, GENERAL_SCENE_DESCRIPTION_XML};
+use crate::utils::errors::GdtfError;
+
+///Writes an `Mvr` together with the .gdtf files of it's fixtures to an .mvr archive
+#[derive(Debug, Clone)]
+pub struct MvrArchiveWriter<'a> {
+    ///The scene that will be written as GeneralSceneDescription.xml
+    mvr: &'a Mvr,
+    ///The content of the .gdtf files with their file name inside of the archive as key
+    gdtf_files: BTreeMap<String, Vec<u8>>,
+}
+
+impl<'a> MvrArchiveWriter<'a> {
+    ///Creates a new writer for the scene without any .gdtf files
+    pub fn new(mvr: &'a Mvr) -> Self {
+        Self {
+            mvr,
+            gdtf_files: BTreeMap::new(),
+        }
+    }
+
+    ///Adds a .gdtf file with it's file name as name inside of the archive. A file with the same name is replaced. Returns `GdtfError::MvrGdtfFileNameError` if the path has no UTF-8 file name
+    pub fn add_gdtf_file(&mut self, file_path: &Path) -> Result<(), GdtfError> {
+        let name = file_path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .ok_or_else(|| GdtfError::MvrGdtfFileNameError(file_path.display().to_string()))?
+            .to_string();
+        let mut content = Vec::new();
+        File::open(file_path)?.read_to_end(&mut content)?;
+        self.gdtf_files.insert(name, content);
+        Ok(())
+    }
+
+    ///Keeps all .gdtf files of an existing .mvr archive. Files that were already added with the same name are replaced
+    pub fn keep_gdtf_files_from<R: Read + Seek>(&mut self, reader: R) -> Result<(), GdtfError> {
+        let mut archive = ZipArchive::new(reader)?;
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            if file.is_dir() || !file.name().ends_with(".gdtf") {
+                continue;
+            }
+            let name = file.name().to_string();
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+            self.gdtf_files.insert(name, content);
+        }
+        Ok(())
+    }
+
+    ///Adds the content of a .gdtf file or replaces it if the name is already present
+    ///
+    /// * `name` - The name inside of the archive as it is referenced in GDTFSpec, for example `ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf`
+    /// * `content` - The unchanged content of the .gdtf file
+    pub fn set_gdtf_file(&mut self, name: &str, content: Vec<u8>) {
+        self.gdtf_files.insert(name.to_string(), content);
+    }
+
+    ///Removes a .gdtf file and returns it's content if it was present
+    pub fn remove_gdtf_file(&mut self, name: &str) -> Option<Vec<u8>> {
+        self.gdtf_files.remove(name)
+    }
+
+    ///Returns the names of all .gdtf files that will be written
+    pub fn gdtf_files(&self) -> impl Iterator<Item = &str> {
+        self.gdtf_files.keys().map(|k| k.as_str())
+    }
+
+    ///Returns the GDTFSpecs of all fixtures that reference a .gdtf file that is not present. GDTFSpec may be given with or without the extension .gdtf
+    pub fn missing_gdtf_files(&self) -> Vec<String> {
+        let mut missing = Vec::new();
+        for fixture in self.mvr.fixtures() {
+            let spec = &fixture.gdtf_spec;
+            if !self.gdtf_files.contains_key(spec)
+                && !self.gdtf_files.contains_key(&format!("{}.gdtf", spec))
+                && !missing.contains(spec)
+            {
+                missing.push(spec.clone());
+            }
+        }
+        missing.sort();
+        missing
+    }
+
+    ///Writes the archive. Returns `GdtfError::ResourceNotFoundError` without writing anything if a referenced .gdtf file is missing
+    pub fn write<W: Write + Seek>(&self, writer: W) -> Result<(), GdtfError> {
+        let missing = self.missing_gdtf_files();
+        if !missing.is_empty() {
+            return Err(GdtfError::ResourceNotFoundError(missing));
+        }
+        let mut zip = ZipWriter::new(writer);
+        let options = FileOptions::default();
+        zip.start_file(GENERAL_SCENE_DESCRIPTION_XML, options)?;
+        self.mvr.write_general_scene_description(&mut zip)?;
+        for (name, content) in self.gdtf_files.iter() {
+            zip.start_file(name.as_str(), options)?;
+            zip.write_all(content)?;
+        }
+        zip.finish()?;
+        Ok(())
+    }
+
+    ///Writes the archive to an .mvr file. The file is only created if no referenced .gdtf file is missing
+    pub fn write_to_file(&self, file_path: &Path) -> Result<(), GdtfError> {
+        let missing = self.missing_gdtf_files();
+        if !missing.is_empty() {
+            return Err(GdtfError::ResourceNotFoundError(missing));
+        }
+        self.write(File::create(file_path)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use std::io::{Cursor, Read};
+    use std::path::Path;
+
+    use crate::mvr::archive::MvrArchiveWriter;
+    use crate::mvr::child_list::fixture::{Address, Fixture};
+    use crate::mvr::child_list::group_object::GroupObject;
+    use crate::mvr::child_list::{ChildObject, OtherObject};
+    use crate::mvr::layer::Layer;
+    use crate::mvr::Mvr;
+    use crate::utils::errors::GdtfError;
+    use crate::utils::units::guid::Guid;
+    use crate::utils::units::matrix::Matrix;
+
+    const ACME: &str = "ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf";
+
+    fn guid(last: u8) -> Guid {
+        let mut guid = [0; 16];
+        guid[15] = last;
+        Guid(guid)
+    }
+
+    fn fixture(id: u8, gdtf_spec: &str) -> Fixture {
+        Fixture {
+            name: format!("Beam <{}> & Co &amp; \"Ltd\"", id),
+            uuid: guid(id),
+            fixture_id: id.to_string(),
+            unit_number: Some(id as u32),
+            gdtf_spec: gdtf_spec.to_string(),
+            gdtf_mode: "Mode 1 12 DMX".to_string(),
+            addresses: vec![
+                Address {
+                    dmx_break: 0,
+                    universe: 1,
+                    address: id as u16,
+                },
+                Address {
+                    dmx_break: 1,
+                    universe: 4,
+                    address: 512,
+                },
+            ],
+            matrix: Some(Matrix([
+                [0.0, -1.0, 0.0, 1000.5],
+                [1.0, 0.0, 0.0, -250.0],
+                [0.0, 0.0, 1.0, 6000.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ])),
+            children: vec![],
+        }
+    }
+
+    fn rig() -> Mvr {
+        Mvr {
+            ver_major: 1,
+            ver_minor: 5,
+            layers: vec![
+                Layer {
+                    name: "Stage".to_string(),
+                    uuid: guid(100),
+                    matrix: None,
+                    children: vec![
+                        ChildObject::Fixture(fixture(1, ACME)),
+                        ChildObject::GroupObject(GroupObject {
+                            name: "Truss group &#38; &lt;A&gt;".to_string(),
+                            uuid: guid(101),
+                            matrix: None,
+                            children: vec![ChildObject::Other(OtherObject {
+                                node_name: "Truss".to_string(),
+                                name: "Truss 1 &amp;".to_string(),
+                                uuid: guid(102),
+                                matrix: Some(Matrix([
+                                    [1.0, 0.0, 0.0, 0.0],
+                                    [0.0, 1.0, 0.0, 0.0],
+                                    [0.0, 0.0, 1.0, 7000.0],
+                                    [0.0, 0.0, 0.0, 1.0],
+                                ])),
+                                children: vec![ChildObject::Fixture(fixture(
+                                    2,
+                                    ACME.trim_end_matches(".gdtf"),
+                                ))],
+                            })],
+                        }),
+                    ],
+                },
+                Layer {
+                    name: "Empty &quot;".to_string(),
+                    uuid: guid(103),
+                    matrix: None,
+                    children: vec![],
+                },
+            ],
+            gdtfs: HashMap::new(),
+        }
+    }
+
+    #[test]
+    fn test_write_round_trip() {
+        let rig = rig();
+        let mut archive_writer = MvrArchiveWriter::new(&rig);
+        let gdtf_file = std::fs::read(format!("test/{}", ACME)).unwrap();
+        archive_writer.set_gdtf_file(ACME, gdtf_file.clone());
+        assert!(archive_writer.missing_gdtf_files().is_empty());
+
+        let mut archive = Cursor::new(Vec::new());
+        archive_writer.write(&mut archive).unwrap();
+
+        let read = Mvr::from_reader(archive.clone()).unwrap();
+        assert_eq!(read.layers, rig.layers);
+        assert_eq!(read.gdtfs.len(), 1);
+
+        let mut embedded = Vec::new();
+        zip::ZipArchive::new(archive)
+            .unwrap()
+            .by_name(ACME)
+            .unwrap()
+            .read_to_end(&mut embedded)
+            .unwrap();
+        assert_eq!(embedded, gdtf_file);
+    }
+
+    #[test]
+    fn test_missing_gdtf_files() {
+        let rig = rig();
+        let mut archive_writer = MvrArchiveWriter::new(&rig);
+        assert_eq!(
+            archive_writer.missing_gdtf_files(),
+            vec![ACME.trim_end_matches(".gdtf"), ACME]
+        );
+        match archive_writer.write(Cursor::new(Vec::new())) {
+            Err(GdtfError::ResourceNotFoundError(missing)) => assert_eq!(missing.len(), 2),
+            other => panic!("Expected ResourceNotFoundError but got {:?}", other),
+        }
+        assert!(matches!(
+            archive_writer.add_gdtf_file(Path::new("test/..")),
+            Err(GdtfError::MvrGdtfFileNameError(path)) if path == "test/.."
+        ));
+        assert_eq!(archive_writer.gdtf_files().count(), 0);
+        archive_writer.set_gdtf_file(ACME, vec![]);
+        assert!(archive_writer.missing_gdtf_files().is_empty());
+        assert_eq!(archive_writer.remove_gdtf_file(ACME), Some(vec![]));
+    }
+}
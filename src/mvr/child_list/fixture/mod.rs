@@ -0,0 +1,354 @@
+//! A fixture patched in the scene with it's fixture type, DMX mode and DMX addresses
+use std::convert::TryFrom;
+use std::fmt::Debug;
+use std::io::Write;
+
+use dmx_struct::DMXAddress;
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::mvr;
+use crate::mvr::child_list;
+use crate::mvr::child_list::ChildObject;
+use crate::utils::errors::GdtfError;
+use crate::utils::read;
+use crate::utils::read::ReadGdtf;
+#[cfg(test)]
+use crate::utils::read::TestReadGdtf;
+use crate::utils::units::guid::Guid;
+use crate::utils::units::matrix::Matrix;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
+
+///A fixture in the scene
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fixture {
+    ///The name of the fixture
+    pub name: String,
+    ///The unique identifier of the fixture
+    pub uuid: Guid,
+    ///The fixture id, used to select the fixture on a console
+    pub fixture_id: String,
+    ///The unit number of the fixture inside of the fixture id
+    pub unit_number: Option<u32>,
+    ///The file name of the embedded .gdtf file that contains the fixture type
+    pub gdtf_spec: String,
+    ///The name of the DMX mode of the fixture type the fixture is patched in
+    pub gdtf_mode: String,
+    ///The DMX addresses of the fixture, one per DMX break
+    pub addresses: Vec<Address>,
+    ///The location of the fixture inside of it's parent
+    pub matrix: Option<Matrix>,
+    ///Objects that are contained in the fixture
+    pub children: Vec<ChildObject>,
+}
+
+///The DMX address of one DMX break of a fixture
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Address {
+    ///The DMX break of the fixture type the address is used for
+    pub dmx_break: u32,
+    ///The universe starting from 1
+    pub universe: u16,
+    ///The address inside of the universe (1-512)
+    pub address: u16,
+}
+
+///Helper struct for temporary data during deparse
+#[derive(Default)]
+pub(crate) struct FixtureDataHolder {
+    pub name: Option<String>,
+    pub uuid: Option<Guid>,
+    pub fixture_id: Option<String>,
+    pub unit_number: Option<u32>,
+    pub gdtf_spec: Option<String>,
+    pub gdtf_mode: Option<String>,
+    pub addresses: Vec<Address>,
+    pub matrix: Option<Matrix>,
+    pub children: Vec<ChildObject>,
+}
+
+impl ReadGdtf for Fixture {
+    type PrimaryKey = ();
+    type Error = GdtfError;
+    type DataHolder = FixtureDataHolder;
+
+    const NODE_NAME: &'static [u8] = b"Fixture";
+    const PARENT_NODE_NAME: &'static [u8] = child_list::NODE_NAME;
+    const PRIMARY_KEY_NAME: &'static [u8] = b"";
+    const ONLY_PRIMARY_KEY: bool = false;
+
+    fn read_any_attribute(
+        data_holder: &mut Self::DataHolder,
+        attr: Attribute<'_>,
+    ) -> Result<(), Self::Error> {
+        match attr.key {
+            b"name" => data_holder.name = Some(mvr::attr_to_string(&attr)?),
+            b"uuid" => data_holder.uuid = Some(mvr::attr_to_uuid(&attr)?),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn read_any_child(
+        data_holder: &mut Self::DataHolder,
+        reader: &mut Reader<&[u8]>,
+        event: BytesStart<'_>,
+        has_children: bool,
+    ) -> Result<(), Self::Error> {
+        match event.name() {
+            b"Matrix" => data_holder.matrix = Some(mvr::read_matrix(reader, &event, has_children)?),
+            b"FixtureID" => {
+                data_holder.fixture_id = Some(mvr::read_text(reader, &event, has_children)?)
+            }
+            b"UnitNumber" => {
+                data_holder.unit_number =
+                    Some(mvr::read_text(reader, &event, has_children)?.parse()?)
+            }
+            b"GDTFSpec" => {
+                data_holder.gdtf_spec = Some(mvr::read_text(reader, &event, has_children)?)
+            }
+            b"GDTFMode" => {
+                data_holder.gdtf_mode = Some(mvr::read_text(reader, &event, has_children)?)
+            }
+            b"Addresses" => data_holder.addresses = Address::read_addresses(reader, has_children)?,
+            child_list::NODE_NAME => {
+                data_holder.children = child_list::read_child_list(reader, has_children)?
+            }
+            _ => mvr::skip(reader, &event, has_children)?,
+        }
+        Ok(())
+    }
+
+    fn move_data(data_holder: Self::DataHolder) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: data_holder.name.unwrap_or_default(),
+            uuid: data_holder
+                .uuid
+                .ok_or_else(|| Self::attribute_not_found(b"uuid"))?,
+            fixture_id: data_holder.fixture_id.unwrap_or_default(),
+            unit_number: data_holder.unit_number,
+            gdtf_spec: data_holder
+                .gdtf_spec
+                .ok_or_else(|| Self::child_not_found(b"GDTFSpec"))?,
+            gdtf_mode: data_holder
+                .gdtf_mode
+                .ok_or_else(|| Self::child_not_found(b"GDTFMode"))?,
+            addresses: data_holder.addresses,
+            matrix: data_holder.matrix,
+            children: data_holder.children,
+        })
+    }
+
+    fn read_primary_key_from_attr(
+        _: Attribute<'_>,
+    ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
+        Ok(None)
+    }
+}
+
+///The children are written in the order of the MVR schema. Addresses are written as absolute DMX addresses
+impl WriteGdtf for Fixture {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_plain_attribute(event, b"name", &self.name);
+        write::push_attribute(event, b"uuid", &self.uuid);
+    }
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        mvr::write_matrix(writer, &self.matrix)?;
+        mvr::write_text(writer, b"GDTFSpec", &self.gdtf_spec)?;
+        mvr::write_text(writer, b"GDTFMode", &self.gdtf_mode)?;
+        mvr::write_text(writer, b"FixtureID", &self.fixture_id)?;
+        if let Some(unit_number) = self.unit_number {
+            mvr::write_text(writer, b"UnitNumber", &unit_number.to_string())?;
+        }
+        write::write_parent(writer, b"Addresses", !self.addresses.is_empty(), |writer| {
+            for address in self.addresses.iter() {
+                address.write(writer)?;
+            }
+            Ok(())
+        })?;
+        child_list::write_child_list(&self.children, writer)
+    }
+}
+
+impl Address {
+    ///Returns the absolute DMX address including the universe (1-32767488)
+    /// ```rust
+    /// use gdtf_parser::mvr::child_list::fixture::Address;
+    ///
+    /// assert_eq!(Address { dmx_break: 0, universe: 2, address: 17 }.absolute(), 529);
+    /// ```
+    pub fn absolute(&self) -> u32 {
+        (self.universe as u32 - 1) * 512 + self.address as u32
+    }
+
+    ///Writes the address as `<Address break="0">1</Address>`
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        let mut event = BytesStart::borrowed_name(b"Address");
+        write::push_attribute(&mut event, b"break", &self.dmx_break);
+        writer.write_event(Event::Start(event))?;
+        writer.write_event(Event::Text(BytesText::from_plain_str(
+            &self.absolute().to_string(),
+        )))?;
+        writer.write_event(Event::End(BytesEnd::borrowed(b"Address")))?;
+        Ok(())
+    }
+
+    ///Reads all `<Address>` nodes of `<Addresses>`. The value can be absolute or in the format universe.address
+    fn read_addresses(
+        reader: &mut Reader<&[u8]>,
+        has_children: bool,
+    ) -> Result<Vec<Self>, GdtfError> {
+        let mut addresses = Vec::new();
+        if !has_children {
+            return Ok(addresses);
+        }
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) if e.name() == b"Address" => {
+                    addresses.push(Self::read_address(reader, &e)?)
+                }
+                Event::Start(e) => mvr::skip(reader, &e, true)?,
+                Event::End(e) if e.name() == b"Addresses" => {
+                    break;
+                }
+                Event::Eof => {
+                    break;
+                }
+                _ => {}
+            }
+        }
+        Ok(addresses)
+    }
+
+    ///Reads one `<Address>` node with it's attribute break
+    fn read_address(reader: &mut Reader<&[u8]>, event: &BytesStart<'_>) -> Result<Self, GdtfError> {
+        let mut dmx_break = 0;
+        for attr in event.attributes() {
+            let attr = attr?;
+            if attr.key == b"break" {
+                dmx_break = read::attr_try_to_str(&attr)?.parse()?;
+            }
+        }
+        let value = mvr::read_text(reader, event, true)?;
+        let address = DMXAddress::try_from(value.as_str())
+            .map_err(|_| GdtfError::DmxAddressNotValidError(value.clone()))?;
+        Ok(Self {
+            dmx_break,
+            universe: address.universe,
+            address: address.address,
+        })
+    }
+}
+
+#[cfg(test)]
+impl TestReadGdtf for Fixture {
+    fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
+        vec![
+            (
+                None,
+                Some(Self {
+                    name: "Beam 1".to_string(),
+                    uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0021").unwrap(),
+                    fixture_id: "101".to_string(),
+                    unit_number: Some(0),
+                    gdtf_spec: "ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf".to_string(),
+                    gdtf_mode: "Mode 1 12 DMX".to_string(),
+                    addresses: vec![
+                        Address {
+                            dmx_break: 0,
+                            universe: 1,
+                            address: 1,
+                        },
+                        Address {
+                            dmx_break: 2,
+                            universe: 3,
+                            address: 512,
+                        },
+                    ],
+                    matrix: Some(Matrix([
+                        [1.0, 0.0, 0.0, 1000.5],
+                        [0.0, 1.0, 0.0, -200.0],
+                        [0.0, 0.0, 1.0, 5000.0],
+                        [0.0, 0.0, 0.0, 1.0],
+                    ])),
+                    children: vec![],
+                }),
+            ),
+            (
+                None,
+                Some(Self {
+                    name: "".to_string(),
+                    uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0022").unwrap(),
+                    fixture_id: "".to_string(),
+                    unit_number: None,
+                    gdtf_spec: "Generic@LED_PAR".to_string(),
+                    gdtf_mode: "RGB".to_string(),
+                    addresses: vec![],
+                    matrix: None,
+                    children: vec![ChildObject::Fixture(Self {
+                        name: "Cell".to_string(),
+                        uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0023").unwrap(),
+                        fixture_id: "".to_string(),
+                        unit_number: None,
+                        gdtf_spec: "Generic@LED_PAR".to_string(),
+                        gdtf_mode: "RGB".to_string(),
+                        addresses: vec![],
+                        matrix: None,
+                        children: vec![],
+                    })],
+                }),
+            ),
+        ]
+    }
+
+    fn testdatas_xml() -> Vec<String> {
+        vec![
+            r#"<Fixture name="Beam 1" uuid="9a9a3c1c-6f31-4f5e-8a4b-5d2a2a1c0021">
+                <Matrix>{1,0,0}{0,1,0}{0,0,1}{1000.5,-200,5000}</Matrix>
+                <GDTFSpec>ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf</GDTFSpec>
+                <GDTFMode>Mode 1 12 DMX</GDTFMode>
+                <Focus>9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C00FF</Focus>
+                <FixtureID>101</FixtureID>
+                <UnitNumber>0</UnitNumber>
+                <Addresses>
+                    <Address break="0">1.1</Address>
+                    <Address break="2">1536</Address>
+                </Addresses>
+                <Protocols><Protocol geometry="NetworkInOut_1" name="NDI"/></Protocols>
+            </Fixture>"#
+                .to_string(),
+            r#"<Fixture uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0022"><GDTFSpec>Generic@LED_PAR</GDTFSpec><GDTFMode>RGB</GDTFMode><Addresses/><ChildList><Fixture name="Cell" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0023"><GDTFSpec>Generic@LED_PAR</GDTFSpec><GDTFMode>RGB</GDTFMode></Fixture></ChildList></Fixture>"#.to_string(),
+        ]
+    }
+
+    fn testdatas_xml_faulty() -> Vec<String> {
+        vec![
+            r#"<Fixture name="Beam 1"><GDTFSpec>Generic@LED_PAR</GDTFSpec><GDTFMode>RGB</GDTFMode></Fixture>"#.to_string(),
+            r#"<Fixture name="Beam 1" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0021"><GDTFMode>RGB</GDTFMode></Fixture>"#.to_string(),
+            r#"<Fixture name="Beam 1" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0021"><GDTFSpec>Generic@LED_PAR</GDTFSpec></Fixture>"#.to_string(),
+            r#"<Fixture name="Beam 1" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0021"><GDTFSpec>Generic@LED_PAR</GDTFSpec><GDTFMode>RGB</GDTFMode><Addresses><Address break="0">0.513</Address></Addresses></Fixture>"#.to_string(),
+            r#"<Fixture name="Beam 1" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0021"><GDTFSpec>Generic@LED_PAR</GDTFSpec><GDTFMode>RGB</GDTFMode><UnitNumber>first</UnitNumber></Fixture>"#.to_string(),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mvr::child_list::fixture::Fixture;
+    use crate::utils::read::TestReadGdtf;
+
+    #[test]
+    fn test_deparse() {
+        Fixture::execute_tests();
+    }
+}
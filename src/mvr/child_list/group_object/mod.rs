@@ -0,0 +1,172 @@
+//! A group of fixtures and other scene objects
+use std::fmt::Debug;
+use std::io::Write;
+
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::BytesStart;
+use quick_xml::{Reader, Writer};
+
+use crate::mvr;
+use crate::mvr::child_list;
+use crate::mvr::child_list::ChildObject;
+use crate::utils::errors::GdtfError;
+use crate::utils::read::ReadGdtf;
+#[cfg(test)]
+use crate::utils::read::TestReadGdtf;
+use crate::utils::units::guid::Guid;
+use crate::utils::units::matrix::Matrix;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
+
+///Groups fixtures and other scene objects so they can be placed together
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupObject {
+    ///The name of the group
+    pub name: String,
+    ///The unique identifier of the group
+    pub uuid: Guid,
+    ///The location of the group inside of it's parent
+    pub matrix: Option<Matrix>,
+    ///The objects of the group
+    pub children: Vec<ChildObject>,
+}
+
+///Helper struct for temporary data during deparse
+#[derive(Default)]
+pub(crate) struct GroupObjectDataHolder {
+    pub name: Option<String>,
+    pub uuid: Option<Guid>,
+    pub matrix: Option<Matrix>,
+    pub children: Vec<ChildObject>,
+}
+
+impl ReadGdtf for GroupObject {
+    type PrimaryKey = ();
+    type Error = GdtfError;
+    type DataHolder = GroupObjectDataHolder;
+
+    const NODE_NAME: &'static [u8] = b"GroupObject";
+    const PARENT_NODE_NAME: &'static [u8] = child_list::NODE_NAME;
+    const PRIMARY_KEY_NAME: &'static [u8] = b"";
+    const ONLY_PRIMARY_KEY: bool = false;
+
+    fn read_any_attribute(
+        data_holder: &mut Self::DataHolder,
+        attr: Attribute<'_>,
+    ) -> Result<(), Self::Error> {
+        match attr.key {
+            b"name" => data_holder.name = Some(mvr::attr_to_string(&attr)?),
+            b"uuid" => data_holder.uuid = Some(mvr::attr_to_uuid(&attr)?),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn read_any_child(
+        data_holder: &mut Self::DataHolder,
+        reader: &mut Reader<&[u8]>,
+        event: BytesStart<'_>,
+        has_children: bool,
+    ) -> Result<(), Self::Error> {
+        match event.name() {
+            b"Matrix" => data_holder.matrix = Some(mvr::read_matrix(reader, &event, has_children)?),
+            child_list::NODE_NAME => {
+                data_holder.children = child_list::read_child_list(reader, has_children)?
+            }
+            _ => mvr::skip(reader, &event, has_children)?,
+        }
+        Ok(())
+    }
+
+    fn move_data(data_holder: Self::DataHolder) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: data_holder.name.unwrap_or_default(),
+            uuid: data_holder
+                .uuid
+                .ok_or_else(|| Self::attribute_not_found(b"uuid"))?,
+            matrix: data_holder.matrix,
+            children: data_holder.children,
+        })
+    }
+
+    fn read_primary_key_from_attr(
+        _: Attribute<'_>,
+    ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for GroupObject {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_plain_attribute(event, b"name", &self.name);
+        write::push_attribute(event, b"uuid", &self.uuid);
+    }
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        mvr::write_matrix(writer, &self.matrix)?;
+        child_list::write_child_list(&self.children, writer)
+    }
+}
+
+#[cfg(test)]
+impl TestReadGdtf for GroupObject {
+    fn testdatas() -> Vec<(Option<Self::PrimaryKey>, Option<Self>)> {
+        vec![
+            (
+                None,
+                Some(Self {
+                    name: "Group 1".to_string(),
+                    uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0011").unwrap(),
+                    matrix: None,
+                    children: vec![],
+                }),
+            ),
+            (
+                None,
+                Some(Self {
+                    name: "Group 2".to_string(),
+                    uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0012").unwrap(),
+                    matrix: Some(Matrix([
+                        [0.0, -1.0, 0.0, 100.0],
+                        [1.0, 0.0, 0.0, 200.0],
+                        [0.0, 0.0, 1.0, 300.0],
+                        [0.0, 0.0, 0.0, 1.0],
+                    ])),
+                    children: vec![ChildObject::GroupObject(Self {
+                        name: "Group 3".to_string(),
+                        uuid: Guid::new_from_str("9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0013").unwrap(),
+                        matrix: None,
+                        children: vec![],
+                    })],
+                }),
+            ),
+        ]
+    }
+
+    fn testdatas_xml() -> Vec<String> {
+        vec![
+            r#"<GroupObject name="Group 1" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0011"/>"#.to_string(),
+            r#"<GroupObject name="Group 2" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0012"><Matrix>{0,1,0}{-1,0,0}{0,0,1}{100,200,300}</Matrix><Classing>00000000-0000-0000-0000-0000000000AA</Classing><ChildList><GroupObject name="Group 3" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0013"><ChildList/></GroupObject></ChildList></GroupObject>"#.to_string(),
+        ]
+    }
+
+    fn testdatas_xml_faulty() -> Vec<String> {
+        vec![r#"<GroupObject name="Group 1"/>"#.to_string()]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mvr::child_list::group_object::GroupObject;
+    use crate::utils::read::TestReadGdtf;
+
+    #[test]
+    fn test_deparse() {
+        GroupObject::execute_tests();
+    }
+}
@@ -0,0 +1,228 @@
+//! The objects a layer, group or other scene object contains in it's ChildList
+use std::io::Write;
+
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::mvr;
+use crate::mvr::child_list::fixture::Fixture;
+use crate::mvr::child_list::group_object::GroupObject;
+use crate::utils::errors::GdtfError;
+use crate::utils::read;
+use crate::utils::read::ReadGdtf;
+use crate::utils::units::guid::Guid;
+use crate::utils::units::matrix::Matrix;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
+
+pub mod fixture;
+pub mod group_object;
+
+///Name of the node that contains the children of a layer or scene object
+pub(crate) const NODE_NAME: &[u8] = b"ChildList";
+
+///Names of the scene objects that are read as `OtherObject`
+const OTHER_NODE_NAMES: [&[u8]; 6] = [
+    b"SceneObject",
+    b"FocusPoint",
+    b"Truss",
+    b"Support",
+    b"VideoScreen",
+    b"Projector",
+];
+
+///An object inside of a ChildList
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ChildObject {
+    ///A fixture patched in the scene
+    Fixture(Fixture),
+    ///A group of objects
+    GroupObject(GroupObject),
+    ///Scene objects that are not fixtures, for example trusses or video screens
+    Other(OtherObject),
+}
+
+///A scene object that is not a fixture or group. Only it's position and children are read
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OtherObject {
+    ///The name of the xml-node, for example `Truss` or `VideoScreen`
+    pub node_name: String,
+    ///The name of the object
+    pub name: String,
+    ///The unique identifier of the object
+    pub uuid: Guid,
+    ///The location of the object inside of it's parent
+    pub matrix: Option<Matrix>,
+    ///Objects that are contained in the object
+    pub children: Vec<ChildObject>,
+}
+
+impl ChildObject {
+    ///Returns the name of the object
+    pub fn name(&self) -> &str {
+        match self {
+            ChildObject::Fixture(fixture) => &fixture.name,
+            ChildObject::GroupObject(group) => &group.name,
+            ChildObject::Other(other) => &other.name,
+        }
+    }
+
+    ///Returns the unique identifier of the object
+    pub fn uuid(&self) -> &Guid {
+        match self {
+            ChildObject::Fixture(fixture) => &fixture.uuid,
+            ChildObject::GroupObject(group) => &group.uuid,
+            ChildObject::Other(other) => &other.uuid,
+        }
+    }
+
+    ///Returns the objects contained in the object
+    pub fn children(&self) -> &[ChildObject] {
+        match self {
+            ChildObject::Fixture(fixture) => &fixture.children,
+            ChildObject::GroupObject(group) => &group.children,
+            ChildObject::Other(other) => &other.children,
+        }
+    }
+
+    ///Adds all fixtures of the objects and their children depth-first
+    pub(crate) fn collect_fixtures<'a>(children: &'a [ChildObject], out: &mut Vec<&'a Fixture>) {
+        for child in children.iter() {
+            if let ChildObject::Fixture(fixture) = child {
+                out.push(fixture);
+            }
+            Self::collect_fixtures(child.children(), out);
+        }
+    }
+}
+
+///Reads all children of a ChildList and consumes it's end. Unknown objects are skipped
+pub(crate) fn read_child_list(
+    reader: &mut Reader<&[u8]>,
+    has_children: bool,
+) -> Result<Vec<ChildObject>, GdtfError> {
+    let mut children = Vec::new();
+    if !has_children {
+        return Ok(children);
+    }
+    let mut buf: Vec<u8> = Vec::new();
+    loop {
+        match reader.read_event(&mut buf)? {
+            Event::Start(e) => children.extend(read_child(reader, e, true)?),
+            Event::Empty(e) => children.extend(read_child(reader, e, false)?),
+            Event::End(e) if e.name() == NODE_NAME => {
+                break;
+            }
+            Event::Eof => {
+                break;
+            }
+            _ => {}
+        }
+    }
+    Ok(children)
+}
+
+///Writes the objects inside of a `<ChildList>`
+pub(crate) fn write_child_list<W: Write>(
+    children: &[ChildObject],
+    writer: &mut Writer<W>,
+) -> Result<(), GdtfError> {
+    write::write_parent(writer, NODE_NAME, !children.is_empty(), |writer| {
+        for child in children.iter() {
+            match child {
+                ChildObject::Fixture(fixture) => fixture.write_single(writer, None)?,
+                ChildObject::GroupObject(group) => group.write_single(writer, None)?,
+                ChildObject::Other(other) => other.write(writer)?,
+            }
+        }
+        Ok(())
+    })
+}
+
+fn read_child(
+    reader: &mut Reader<&[u8]>,
+    event: BytesStart<'_>,
+    has_children: bool,
+) -> Result<Option<ChildObject>, GdtfError> {
+    Ok(match event.name() {
+        Fixture::NODE_NAME => Some(ChildObject::Fixture(
+            Fixture::read_single_from_event(reader, event, has_children)?.1,
+        )),
+        GroupObject::NODE_NAME => Some(ChildObject::GroupObject(
+            GroupObject::read_single_from_event(reader, event, has_children)?.1,
+        )),
+        name if OTHER_NODE_NAMES.contains(&name) => Some(ChildObject::Other(
+            OtherObject::read_from_event(reader, event, has_children)?,
+        )),
+        _ => {
+            mvr::skip(reader, &event, has_children)?;
+            None
+        }
+    })
+}
+
+impl OtherObject {
+    ///Reads the object manually, because the node name is not known at compile time
+    fn read_from_event(
+        reader: &mut Reader<&[u8]>,
+        event: BytesStart<'_>,
+        has_children: bool,
+    ) -> Result<Self, GdtfError> {
+        let node_name = std::str::from_utf8(event.name())?.to_string();
+        let mut name = String::new();
+        let mut uuid = None;
+        for attr in event.attributes() {
+            let attr = attr?;
+            match attr.key {
+                b"name" => name = mvr::attr_to_string(&attr)?,
+                b"uuid" => uuid = Some(mvr::attr_to_uuid(&attr)?),
+                _ => {}
+            }
+        }
+        let mut matrix = None;
+        let mut children = Vec::new();
+        if has_children {
+            let mut buf: Vec<u8> = Vec::new();
+            loop {
+                match reader.read_event(&mut buf)? {
+                    Event::Start(e) => match e.name() {
+                        b"Matrix" => matrix = Some(mvr::read_matrix(reader, &e, true)?),
+                        NODE_NAME => children = read_child_list(reader, true)?,
+                        _ => mvr::skip(reader, &e, true)?,
+                    },
+                    Event::End(e) if e.name() == node_name.as_bytes() => {
+                        break;
+                    }
+                    Event::Eof => {
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let uuid = uuid.ok_or_else(|| {
+            read::GdtfReadError::new_xml_attribute_not_found(node_name.as_bytes(), b"uuid")
+        })?;
+        Ok(Self {
+            node_name,
+            name,
+            uuid,
+            matrix,
+            children,
+        })
+    }
+
+    ///Writes the object with it's position and children. Other data of the object, for example it's geometries, is not part of `OtherObject` and is not written
+    fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        let mut event = BytesStart::borrowed_name(self.node_name.as_bytes());
+        write::push_plain_attribute(&mut event, b"name", &self.name);
+        write::push_attribute(&mut event, b"uuid", &self.uuid);
+        writer.write_event(Event::Start(event))?;
+        mvr::write_matrix(writer, &self.matrix)?;
+        write_child_list(&self.children, writer)?;
+        writer.write_event(Event::End(BytesEnd::borrowed(self.node_name.as_bytes())))?;
+        Ok(())
+    }
+}
@@ -0,0 +1,515 @@
+//! Reads and writes MVR (My Virtual Rig) archives
+//!
+//! An .mvr file is a zip archive with the scene in `GeneralSceneDescription.xml` and the fixture types of all fixtures as embedded .gdtf files. The scene is organised in layers that contain fixtures, groups and other scene objects with their position as Matrix.
+//!
+//! Every embedded .gdtf file is deparsed with `Gdtf`. Scenes are written with `archive::MvrArchiveWriter`. When reading an archive, it is checked that every fixture references an embedded GDTFSpec and a GDTFMode that exists in the DMX modes of it's fixture type.
+//!
+//! ## Example
+//!
+//! ```rust,no_run
+//! use std::convert::TryFrom;
+//! use std::path::Path;
+//! use gdtf_parser::mvr::Mvr;
+//!
+//! let mvr = Mvr::try_from(Path::new("scene.mvr")).unwrap();
+//! for fixture in mvr.fixtures() {
+//!     let gdtf = mvr.gdtf(fixture).unwrap();
+//!     println!("{} is a {} in mode {}", fixture.name, gdtf.fixture_type.name, fixture.gdtf_mode);
+//! }
+//! ```
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::fixture_type::dmx_mode::DmxMode;
+use crate::mvr::child_list::fixture::Fixture;
+use crate::mvr::child_list::ChildObject;
+use crate::mvr::layer::Layer;
+use crate::utils::errors::GdtfError;
+use crate::utils::read::{GdtfReadError, ReadGdtf};
+use crate::utils::units::guid::Guid;
+use crate::utils::units::matrix::Matrix;
+use crate::utils::units::name;
+use crate::utils::units::name::Name;
+use crate::utils::write;
+use crate::utils::write::WriteGdtf;
+use crate::Gdtf;
+
+pub mod archive;
+pub mod child_list;
+pub mod layer;
+
+///Name of the file inside of the archive that contains the scene
+pub(crate) const GENERAL_SCENE_DESCRIPTION_XML: &str = "GeneralSceneDescription.xml";
+
+///A scene read from an .mvr archive together with the fixture types of it's fixtures
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mvr {
+    ///Major version of the MVR format
+    pub ver_major: u32,
+    ///Minor version of the MVR format
+    pub ver_minor: u32,
+    ///The layers of the scene in the order they are defined
+    pub layers: Vec<Layer>,
+    ///The embedded .gdtf files with their file name inside of the archive as key
+    pub gdtfs: HashMap<String, Gdtf>,
+}
+
+///Helper struct for temporary data during deparse
+#[derive(Default)]
+pub(crate) struct MvrDataHolder {
+    pub ver_major: Option<u32>,
+    pub ver_minor: Option<u32>,
+    pub layers: Vec<Layer>,
+}
+
+impl ReadGdtf for Mvr {
+    type PrimaryKey = ();
+    type Error = GdtfError;
+    type DataHolder = MvrDataHolder;
+
+    const NODE_NAME: &'static [u8] = b"GeneralSceneDescription";
+    const PARENT_NODE_NAME: &'static [u8] = b"Top";
+    const PRIMARY_KEY_NAME: &'static [u8] = &[];
+    const ONLY_PRIMARY_KEY: bool = false;
+
+    fn read_any_attribute(
+        data_holder: &mut Self::DataHolder,
+        attr: Attribute<'_>,
+    ) -> Result<(), Self::Error> {
+        match attr.key {
+            b"verMajor" => data_holder.ver_major = Some(attr_to_u32(&attr)?),
+            b"verMinor" => data_holder.ver_minor = Some(attr_to_u32(&attr)?),
+            _ => {}
+        }
+        Ok(())
+    }
+
+    fn read_any_child(
+        data_holder: &mut Self::DataHolder,
+        reader: &mut Reader<&[u8]>,
+        event: BytesStart<'_>,
+        has_children: bool,
+    ) -> Result<(), Self::Error> {
+        if event.name() != b"Scene" {
+            return skip(reader, &event, has_children);
+        }
+        if !has_children {
+            return Ok(());
+        }
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) if e.name() == Layer::PARENT_NODE_NAME => {
+                    data_holder.layers = Layer::read_vec_from_event(reader, e, true)?;
+                }
+                Event::Start(e) => skip(reader, &e, true)?,
+                Event::End(e) if e.name() == b"Scene" => {
+                    break;
+                }
+                Event::Eof => {
+                    break;
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn move_data(data_holder: Self::DataHolder) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ver_major: data_holder
+                .ver_major
+                .ok_or_else(|| Self::attribute_not_found(b"verMajor"))?,
+            ver_minor: data_holder
+                .ver_minor
+                .ok_or_else(|| Self::attribute_not_found(b"verMinor"))?,
+            layers: data_holder.layers,
+            gdtfs: HashMap::new(),
+        })
+    }
+
+    fn read_primary_key_from_attr(
+        _: Attribute<'_>,
+    ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
+        Ok(None)
+    }
+}
+
+impl WriteGdtf for Mvr {
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>) {
+        write::push_attribute(event, b"verMajor", &self.ver_major);
+        write::push_attribute(event, b"verMinor", &self.ver_minor);
+    }
+
+    fn has_children(&self) -> bool {
+        true
+    }
+
+    fn write_any_children<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        write::write_parent(writer, b"Scene", true, |writer| {
+            Layer::write_vec(&self.layers, writer)
+        })
+    }
+}
+
+impl Mvr {
+    ///Deparses an .mvr archive from any source that can be read and seeked. Returns an error if a fixture references a GDTFSpec or GDTFMode that is not present in the archive
+    pub fn from_reader<R: Read + Seek>(reader: R) -> Result<Self, GdtfError> {
+        name::interning(|| Self::read_archive(reader))
+    }
+
+    ///Deparses the archive, names are shared between the scene and all fixture types in it
+    fn read_archive<R: Read + Seek>(reader: R) -> Result<Self, GdtfError> {
+        let mut archive = zip::ZipArchive::new(reader)?;
+        let mut general_scene_description = String::new();
+        archive
+            .by_name(GENERAL_SCENE_DESCRIPTION_XML)?
+            .read_to_string(&mut general_scene_description)?;
+        let mut mvr = Self::from_general_scene_description(&general_scene_description)?;
+        for i in 0..archive.len() {
+            let mut file = archive.by_index(i)?;
+            if file.is_dir() || !file.name().ends_with(".gdtf") {
+                continue;
+            }
+            let name = file.name().to_string();
+            let mut content = Vec::new();
+            file.read_to_end(&mut content)?;
+            mvr.gdtfs.insert(name, Gdtf::try_from(content)?);
+        }
+        mvr.validate()?;
+        Ok(mvr)
+    }
+
+    ///Deparses the content of a GeneralSceneDescription.xml without any embedded fixture types
+    fn from_general_scene_description(xml: &str) -> Result<Self, GdtfError> {
+        let mut reader = Reader::from_str(xml);
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) if e.name() == Self::NODE_NAME => {
+                    return Ok(Self::read_single_from_event(&mut reader, e, true)
+                        .map_err(|e| e.locate_in(xml))?
+                        .1);
+                }
+                Event::Empty(e) if e.name() == Self::NODE_NAME => {
+                    return Ok(Self::read_single_from_event(&mut reader, e, false)
+                        .map_err(|e| e.locate_in(xml))?
+                        .1);
+                }
+                Event::Eof => {
+                    break;
+                }
+                _ => {}
+            };
+        }
+        Err(GdtfReadError::new_xml_node_not_found(b"TopLevel", Self::NODE_NAME).into())
+    }
+
+    ///Serialises the scene to the content of a GeneralSceneDescription.xml and writes it to the given writer. The embedded fixture types are not part of it, use `MvrArchiveWriter` to write a complete .mvr archive
+    /// ```rust
+    /// use gdtf_parser::mvr::Mvr;
+    ///
+    /// let mvr = Mvr { ver_major: 1, ver_minor: 5, layers: vec![], gdtfs: Default::default() };
+    /// let mut general_scene_description: Vec<u8> = Vec::new();
+    /// mvr.write_general_scene_description(&mut general_scene_description).unwrap();
+    /// assert!(general_scene_description.starts_with(b"<?xml version=\"1.0\" encoding=\"UTF-8\"?>"));
+    /// ```
+    pub fn write_general_scene_description<W: Write>(&self, writer: W) -> Result<(), GdtfError> {
+        let mut writer = Writer::new_with_indent(writer, b' ', 2);
+        writer.write_event(Event::Decl(BytesDecl::new(b"1.0", Some(b"UTF-8"), None)))?;
+        self.write_single(&mut writer, None)
+    }
+
+    ///Serialises the scene to the content of a GeneralSceneDescription.xml. See `write_general_scene_description` for details
+    pub fn to_general_scene_description(&self) -> Result<String, GdtfError> {
+        let mut out: Vec<u8> = Vec::new();
+        self.write_general_scene_description(&mut out)?;
+        Ok(String::from_utf8(out).map_err(|e| e.utf8_error())?)
+    }
+
+    ///Checks that every fixture references an embedded .gdtf file and a DMX mode of it's fixture type
+    fn validate(&self) -> Result<(), GdtfError> {
+        for fixture in self.fixtures() {
+            if self.gdtf(fixture).is_none() {
+                return Err(GdtfError::MvrGdtfSpecNotFoundError(
+                    fixture.name.clone(),
+                    fixture.gdtf_spec.clone(),
+                ));
+            }
+            if self.dmx_mode(fixture).is_none() {
+                return Err(GdtfError::MvrGdtfModeNotFoundError(
+                    fixture.name.clone(),
+                    fixture.gdtf_mode.clone(),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    ///Returns all fixtures of the scene, including fixtures inside of groups and other scene objects, in the order they are defined
+    pub fn fixtures(&self) -> Vec<&Fixture> {
+        let mut fixtures = Vec::new();
+        for layer in self.layers.iter() {
+            ChildObject::collect_fixtures(&layer.children, &mut fixtures);
+        }
+        fixtures
+    }
+
+    ///Returns the embedded fixture type of the fixture. GDTFSpec may be given with or without the extension .gdtf
+    pub fn gdtf(&self, fixture: &Fixture) -> Option<&Gdtf> {
+        self.gdtfs
+            .get(&fixture.gdtf_spec)
+            .or_else(|| self.gdtfs.get(&format!("{}.gdtf", fixture.gdtf_spec)))
+    }
+
+    ///Returns the DMX mode the fixture is patched in
+    pub fn dmx_mode(&self, fixture: &Fixture) -> Option<&DmxMode> {
+        let gdtf = self.gdtf(fixture)?;
+        let name = Name::new(&fixture.gdtf_mode).ok()?;
+        gdtf.fixture_type.dmx_modes.get(&name)
+    }
+}
+
+impl TryFrom<&Path> for Mvr {
+    type Error = GdtfError;
+
+    fn try_from(file_path: &Path) -> Result<Self, Self::Error> {
+        Self::from_reader(File::open(file_path)?)
+    }
+}
+
+///Reads the text content of a node like `<GDTFMode>Mode 1</GDTFMode>` and consumes it's end
+pub(crate) fn read_text(
+    reader: &mut Reader<&[u8]>,
+    event: &BytesStart<'_>,
+    has_children: bool,
+) -> Result<String, GdtfError> {
+    if !has_children {
+        return Ok("".to_string());
+    }
+    let mut buf: Vec<u8> = Vec::new();
+    Ok(reader.read_text(event.name(), &mut buf)?.trim().to_string())
+}
+
+///Reads the text content of a `<Matrix>` node
+pub(crate) fn read_matrix(
+    reader: &mut Reader<&[u8]>,
+    event: &BytesStart<'_>,
+    has_children: bool,
+) -> Result<Matrix, GdtfError> {
+    Ok(Matrix::new_from_mvr_str(&read_text(
+        reader,
+        event,
+        has_children,
+    )?)?)
+}
+
+///Writes a node with text content like `<GDTFMode>Mode 1</GDTFMode>`
+pub(crate) fn write_text<W: Write>(
+    writer: &mut Writer<W>,
+    name: &[u8],
+    value: &str,
+) -> Result<(), GdtfError> {
+    writer.write_event(Event::Start(BytesStart::borrowed_name(name)))?;
+    writer.write_event(Event::Text(BytesText::from_plain_str(value)))?;
+    writer.write_event(Event::End(BytesEnd::borrowed(name)))?;
+    Ok(())
+}
+
+///Writes a `<Matrix>` node if the matrix is Some
+pub(crate) fn write_matrix<W: Write>(
+    writer: &mut Writer<W>,
+    matrix: &Option<Matrix>,
+) -> Result<(), GdtfError> {
+    match matrix {
+        Some(matrix) => write_text(writer, b"Matrix", &matrix.to_mvr_string()),
+        None => Ok(()),
+    }
+}
+
+///Skips a node with all it's children
+pub(crate) fn skip(
+    reader: &mut Reader<&[u8]>,
+    event: &BytesStart<'_>,
+    has_children: bool,
+) -> Result<(), GdtfError> {
+    if has_children {
+        let mut buf: Vec<u8> = Vec::new();
+        reader.read_to_end(event.name(), &mut buf)?;
+    }
+    Ok(())
+}
+
+///Reads an attribute as String. Unlike in GDTF, attributes of MVR are unescaped, because they are usually written by other applications
+pub(crate) fn attr_to_string(attr: &Attribute<'_>) -> Result<String, GdtfError> {
+    Ok(std::str::from_utf8(&attr.unescaped_value()?)?.to_string())
+}
+
+///Parses the attribute uuid. MVR exporters write uuids in upper and lower case
+pub(crate) fn attr_to_uuid(attr: &Attribute<'_>) -> Result<Guid, GdtfError> {
+    let value = std::str::from_utf8(&attr.value)?;
+    Ok(Guid::new_from_str(&value.to_uppercase())?)
+}
+
+fn attr_to_u32(attr: &Attribute<'_>) -> Result<u32, GdtfError> {
+    Ok(std::str::from_utf8(&attr.value)?.parse()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{Cursor, Write};
+
+    use zip::write::FileOptions;
+    use zip::ZipWriter;
+
+    use crate::mvr::child_list::ChildObject;
+    use crate::mvr::Mvr;
+    use crate::utils::errors::GdtfError;
+    use crate::utils::units::matrix::Matrix;
+
+    const ACME: &str = "ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf";
+
+    fn scene(gdtf_mode: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8" standalone="no" ?>
+<GeneralSceneDescription verMajor="1" verMinor="5">
+  <UserData><Data provider="Test" ver="1.0"/></UserData>
+  <Scene>
+    <AUXData><Class name="Beams" uuid="00000000-0000-0000-0000-0000000000AA"/></AUXData>
+    <Layers>
+      <Layer name="Stage" uuid="9a9a3c1c-6f31-4f5e-8a4b-5d2a2a1c0001">
+        <ChildList>
+          <Fixture name="Beam 1" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0002">
+            <Matrix>{{1,0,0}}{{0,1,0}}{{0,0,1}}{{1000,2000,3000}}</Matrix>
+            <GDTFSpec>{acme}</GDTFSpec>
+            <GDTFMode>{mode}</GDTFMode>
+            <FixtureID>101</FixtureID>
+            <UnitNumber>1</UnitNumber>
+            <Addresses>
+              <Address break="0">1.1</Address>
+              <Address break="1">513</Address>
+            </Addresses>
+          </Fixture>
+          <GroupObject name="Truss group" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0003">
+            <ChildList>
+              <Truss name="Truss" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0004">
+                <Geometries><Geometry3D fileName="truss"/></Geometries>
+                <ChildList>
+                  <Fixture name="Beam 2" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0005">
+                    <GDTFSpec>{acme_without_extension}</GDTFSpec>
+                    <GDTFMode>{mode}</GDTFMode>
+                    <FixtureID>102</FixtureID>
+                    <Addresses><Address break="0">2.17</Address></Addresses>
+                  </Fixture>
+                </ChildList>
+              </Truss>
+            </ChildList>
+          </GroupObject>
+        </ChildList>
+      </Layer>
+      <Layer name="Empty" uuid="9A9A3C1C-6F31-4F5E-8A4B-5D2A2A1C0006"/>
+    </Layers>
+  </Scene>
+</GeneralSceneDescription>"#,
+            acme = ACME,
+            acme_without_extension = ACME.trim_end_matches(".gdtf"),
+            mode = gdtf_mode
+        )
+    }
+
+    fn archive(general_scene_description: &str, with_gdtf: bool) -> Cursor<Vec<u8>> {
+        let mut zip = ZipWriter::new(Cursor::new(Vec::new()));
+        zip.start_file("GeneralSceneDescription.xml", FileOptions::default())
+            .unwrap();
+        zip.write_all(general_scene_description.as_bytes()).unwrap();
+        if with_gdtf {
+            zip.start_file(ACME, FileOptions::default()).unwrap();
+            zip.write_all(&std::fs::read(format!("test/{}", ACME)).unwrap())
+                .unwrap();
+        }
+        let mut archive = zip.finish().unwrap();
+        archive.set_position(0);
+        archive
+    }
+
+    #[test]
+    fn test_from_reader() {
+        let mvr = Mvr::from_reader(archive(&scene("Mode 1 12 DMX"), true)).unwrap();
+        assert_eq!((mvr.ver_major, mvr.ver_minor), (1, 5));
+        assert_eq!(mvr.layers.len(), 2);
+        assert_eq!(mvr.layers[0].name, "Stage");
+        assert!(mvr.layers[1].children.is_empty());
+        assert_eq!(mvr.gdtfs.keys().collect::<Vec<_>>(), vec![ACME]);
+
+        let fixtures = mvr.fixtures();
+        assert_eq!(
+            fixtures.iter().map(|f| f.name.as_str()).collect::<Vec<_>>(),
+            vec!["Beam 1", "Beam 2"]
+        );
+        let beam = fixtures[0];
+        assert_eq!(beam.fixture_id, "101");
+        assert_eq!(beam.unit_number, Some(1));
+        assert_eq!(
+            beam.matrix,
+            Some(Matrix([
+                [1.0, 0.0, 0.0, 1000.0],
+                [0.0, 1.0, 0.0, 2000.0],
+                [0.0, 0.0, 1.0, 3000.0],
+                [0.0, 0.0, 0.0, 1.0]
+            ]))
+        );
+        assert_eq!(
+            beam.addresses
+                .iter()
+                .map(|a| (a.dmx_break, a.universe, a.address))
+                .collect::<Vec<_>>(),
+            vec![(0, 1, 1), (1, 2, 1)]
+        );
+        assert_eq!(fixtures[1].addresses[0].absolute(), 529);
+        assert_eq!(
+            mvr.gdtf(fixtures[1]).unwrap().fixture_type.short_name,
+            "ACME AE 610 BEAM"
+        );
+        assert!(mvr.dmx_mode(beam).is_some());
+
+        match &mvr.layers[0].children[1] {
+            ChildObject::GroupObject(group) => {
+                assert_eq!(group.name, "Truss group");
+                match &group.children[0] {
+                    ChildObject::Other(truss) => assert_eq!(truss.node_name, "Truss"),
+                    other => panic!("Expected Truss but got {:?}", other),
+                }
+            }
+            other => panic!("Expected GroupObject but got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_from_reader_faulty() {
+        match Mvr::from_reader(archive(&scene("Mode 1 12 DMX"), false)) {
+            Err(GdtfError::MvrGdtfSpecNotFoundError(fixture, spec)) => {
+                assert_eq!((fixture.as_str(), spec.as_str()), ("Beam 1", ACME))
+            }
+            other => panic!("Expected MvrGdtfSpecNotFoundError but got {:?}", other),
+        }
+        match Mvr::from_reader(archive(&scene("Mode 2"), true)) {
+            Err(GdtfError::MvrGdtfModeNotFoundError(fixture, mode)) => {
+                assert_eq!((fixture.as_str(), mode.as_str()), ("Beam 1", "Mode 2"))
+            }
+            other => panic!("Expected MvrGdtfModeNotFoundError but got {:?}", other),
+        }
+        assert!(Mvr::from_reader(archive(
+            &scene("Mode 1 12 DMX").replace(" verMinor=\"5\"", ""),
+            true
+        ))
+        .is_err());
+    }
+}
@@ -0,0 +1,1062 @@
+//! Colour conversions for the unit ColorCIE used in GDTF
+//!
+//! Tristimulus values use the same scale as the Y of `ColorCie`, so the reference white has a Y of 100. Linear and gamma-encoded sRGB components are in the range 0 to 1.
+#![allow(non_snake_case)]
+
+use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+
+///CIE 1931 XYZ tristimulus values
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorXyz {
+    pub X: f32,
+    pub Y: f32,
+    pub Z: f32,
+}
+
+///Components of a colour in an RGB colour space
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorRgb {
+    pub r: f32,
+    pub g: f32,
+    pub b: f32,
+}
+
+///CIELAB 1976 colour relative to a reference white
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorLab {
+    pub L: f32,
+    pub a: f32,
+    pub b: f32,
+}
+
+///Correlated colour temperature and distance to the Planckian locus
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Cct {
+    ///Correlated colour temperature in Kelvin
+    pub kelvin: f32,
+    ///Distance to the Planckian locus in the CIE 1960 uv diagram. Positive above the locus (greenish), negative below (magenta)
+    pub duv: f32,
+}
+
+///Triangle of the primaries of an RGB colour space in the xy diagram, with the white point the colours are clamped towards
+#[derive(Debug, PartialEq, Clone)]
+pub struct Gamut {
+    pub red: ColorCie,
+    pub green: ColorCie,
+    pub blue: ColorCie,
+    pub white: ColorCie,
+}
+
+///3×3 matrix that converts colour vectors, for example linear RGB to XYZ
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ColorMatrix(pub [[f32; 3]; 3]);
+
+///Linear sRGB to XYZ with a white Y of 1, IEC 61966-2-1
+const SRGB_TO_XYZ: ColorMatrix = ColorMatrix([
+    [0.412_456_4, 0.357_576_1, 0.180_437_5],
+    [0.212_672_9, 0.715_152_2, 0.072_175],
+    [0.019_333_9, 0.119_192, 0.950_304_1],
+]);
+
+///XYZ with a white Y of 1 to linear sRGB, IEC 61966-2-1
+const XYZ_TO_SRGB: ColorMatrix = ColorMatrix([
+    [3.240_454_2, -1.537_138_5, -0.498_531_4],
+    [-0.969_266, 1.876_010_8, 0.041_556],
+    [0.055_643_4, -0.204_025_9, 1.057_225_2],
+]);
+
+///XYZ to cone responses of the Bradford chromatic adaptation
+const BRADFORD: ColorMatrix = ColorMatrix([
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+]);
+
+///Inverse of `BRADFORD`
+const BRADFORD_INVERSE: ColorMatrix = ColorMatrix([
+    [0.986_992_9, -0.147_054_3, 0.159_962_7],
+    [0.432_305_3, 0.518_360_3, 0.049_291_2],
+    [-0.008_528_7, 0.040_042_8, 0.968_486_7],
+]);
+
+///Robertson's isotemperature lines: reciprocal temperature in mired, u, v and slope t in the CIE 1960 uv diagram
+const ROBERTSON: [(f32, f32, f32, f32); 31] = [
+    (0.0, 0.18006, 0.26352, -0.24341),
+    (10.0, 0.18066, 0.26589, -0.25479),
+    (20.0, 0.18133, 0.26846, -0.26876),
+    (30.0, 0.18208, 0.27119, -0.28539),
+    (40.0, 0.18293, 0.27407, -0.30470),
+    (50.0, 0.18388, 0.27709, -0.32675),
+    (60.0, 0.18494, 0.28021, -0.35156),
+    (70.0, 0.18611, 0.28342, -0.37915),
+    (80.0, 0.18740, 0.28668, -0.40955),
+    (90.0, 0.18880, 0.28997, -0.44278),
+    (100.0, 0.19032, 0.29326, -0.47888),
+    (125.0, 0.19462, 0.30141, -0.58204),
+    (150.0, 0.19962, 0.30921, -0.70471),
+    (175.0, 0.20525, 0.31647, -0.84901),
+    (200.0, 0.21142, 0.32312, -1.0182),
+    (225.0, 0.21807, 0.32909, -1.2168),
+    (250.0, 0.22511, 0.33439, -1.4512),
+    (275.0, 0.23247, 0.33904, -1.7298),
+    (300.0, 0.24010, 0.34308, -2.0637),
+    (325.0, 0.24792, 0.34655, -2.4681),
+    (350.0, 0.25591, 0.34951, -2.9641),
+    (375.0, 0.26400, 0.35200, -3.5814),
+    (400.0, 0.27218, 0.35407, -4.3633),
+    (425.0, 0.28039, 0.35577, -5.3762),
+    (450.0, 0.28863, 0.35714, -6.7262),
+    (475.0, 0.29685, 0.35823, -8.5955),
+    (500.0, 0.30505, 0.35907, -11.324),
+    (525.0, 0.31320, 0.35968, -15.628),
+    (550.0, 0.32129, 0.36011, -23.325),
+    (575.0, 0.32931, 0.36038, -40.770),
+    (600.0, 0.33724, 0.36051, -116.45),
+];
+
+///CIE 1931 2° colour matching functions x̄, ȳ and z̄ from 380 nm to 780 nm in steps of 10 nm
+const CIE_1931: [[f32; 3]; 41] = [
+    [0.001_368, 0.000_039, 0.006_45],
+    [0.004_243, 0.000_12, 0.020_05],
+    [0.014_31, 0.000_396, 0.067_85],
+    [0.043_51, 0.001_21, 0.207_4],
+    [0.134_38, 0.004, 0.645_6],
+    [0.283_9, 0.011_6, 1.385_6],
+    [0.348_28, 0.023, 1.747_06],
+    [0.336_2, 0.038, 1.772_11],
+    [0.290_8, 0.06, 1.669_2],
+    [0.195_36, 0.090_98, 1.287_64],
+    [0.095_64, 0.139_02, 0.812_95],
+    [0.032_01, 0.208_02, 0.465_18],
+    [0.004_9, 0.323, 0.272],
+    [0.009_3, 0.503, 0.158_2],
+    [0.063_27, 0.71, 0.078_25],
+    [0.165_5, 0.862, 0.042_16],
+    [0.290_4, 0.954, 0.020_3],
+    [0.433_45, 0.994_95, 0.008_75],
+    [0.594_5, 0.995, 0.003_9],
+    [0.762_1, 0.952, 0.002_1],
+    [0.916_3, 0.87, 0.001_65],
+    [1.026_3, 0.757, 0.001_1],
+    [1.062_2, 0.631, 0.000_8],
+    [1.002_6, 0.503, 0.000_34],
+    [0.854_45, 0.381, 0.000_19],
+    [0.642_4, 0.265, 0.000_05],
+    [0.447_9, 0.175, 0.000_02],
+    [0.283_5, 0.107, 0.0],
+    [0.164_9, 0.061, 0.0],
+    [0.087_4, 0.032, 0.0],
+    [0.046_77, 0.017, 0.0],
+    [0.022_7, 0.008_21, 0.0],
+    [0.011_359, 0.004_102, 0.0],
+    [0.005_79, 0.002_091, 0.0],
+    [0.002_899, 0.001_047, 0.0],
+    [0.001_44, 0.000_52, 0.0],
+    [0.000_69, 0.000_249, 0.0],
+    [0.000_332, 0.000_12, 0.0],
+    [0.000_166, 0.000_06, 0.0],
+    [0.000_083, 0.000_03, 0.0],
+    [0.000_042, 0.000_015, 0.0],
+];
+
+impl ColorCie {
+    ///Converts the colour to XYZ. A colour with y of 0 has no luminance and is converted to black
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// let xyz = ColorCie{ x: 0.25, y: 0.5, Y: 40.0 }.to_xyz();
+    /// assert_eq!((xyz.X, xyz.Y, xyz.Z), (20.0, 40.0, 20.0));
+    /// ```
+    pub fn to_xyz(&self) -> ColorXyz {
+        if self.y == 0.0 {
+            return ColorXyz {
+                X: 0.0,
+                Y: 0.0,
+                Z: 0.0,
+            };
+        }
+        ColorXyz {
+            X: self.x * self.Y / self.y,
+            Y: self.Y,
+            Z: (1.0 - self.x - self.y) * self.Y / self.y,
+        }
+    }
+
+    ///Converts XYZ to xyY. Black gets the chromaticity of `COLOR_CIE_WHITE`
+    /// ```rust
+    /// use gdtf_parser::utils::color::ColorXyz;
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// assert_eq!(ColorCie::from_xyz(&ColorXyz{ X: 20.0, Y: 40.0, Z: 20.0 }), ColorCie{ x: 0.25, y: 0.5, Y: 40.0 });
+    /// ```
+    pub fn from_xyz(xyz: &ColorXyz) -> Self {
+        let sum = xyz.X + xyz.Y + xyz.Z;
+        if sum == 0.0 {
+            return ColorCie {
+                Y: 0.0,
+                ..COLOR_CIE_WHITE
+            };
+        }
+        ColorCie {
+            x: xyz.X / sum,
+            y: xyz.Y / sum,
+            Y: xyz.Y,
+        }
+    }
+
+    ///Converts the colour to linear sRGB. Colours outside of the sRGB gamut have components below 0 or above 1
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::COLOR_CIE_WHITE;
+    /// let rgb = COLOR_CIE_WHITE.to_linear_srgb();
+    /// assert!((rgb.r - 1.0).abs() < 0.001 && (rgb.g - 1.0).abs() < 0.001 && (rgb.b - 1.0).abs() < 0.001);
+    /// ```
+    pub fn to_linear_srgb(&self) -> ColorRgb {
+        let xyz = self.to_xyz();
+        let [r, g, b] = XYZ_TO_SRGB.apply([xyz.X / 100.0, xyz.Y / 100.0, xyz.Z / 100.0]);
+        ColorRgb { r, g, b }
+    }
+
+    ///Converts linear sRGB to xyY
+    pub fn from_linear_srgb(rgb: &ColorRgb) -> Self {
+        let [X, Y, Z] = SRGB_TO_XYZ.apply([rgb.r, rgb.g, rgb.b]);
+        Self::from_xyz(&ColorXyz {
+            X: X * 100.0,
+            Y: Y * 100.0,
+            Z: Z * 100.0,
+        })
+    }
+
+    ///Converts the colour to gamma-encoded sRGB to display it, for example as swatch. Components are clipped to 0 to 1, use `clamp_to_gamut` before to keep the hue of saturated colours
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// assert_eq!(ColorCie{ x: 0.64, y: 0.33, Y: 21.26 }.to_srgb().to_u8(), [255, 0, 0]);
+    /// ```
+    pub fn to_srgb(&self) -> ColorRgb {
+        let rgb = self.to_linear_srgb();
+        ColorRgb {
+            r: srgb_encode(rgb.r.clamp(0.0, 1.0)),
+            g: srgb_encode(rgb.g.clamp(0.0, 1.0)),
+            b: srgb_encode(rgb.b.clamp(0.0, 1.0)),
+        }
+    }
+
+    ///Converts gamma-encoded sRGB to xyY
+    /// ```rust
+    /// use gdtf_parser::utils::color::ColorRgb;
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// let white = ColorCie::from_srgb(&ColorRgb{ r: 1.0, g: 1.0, b: 1.0 });
+    /// assert!((white.x - 0.3127).abs() < 0.0001 && (white.y - 0.329).abs() < 0.0001 && (white.Y - 100.0).abs() < 0.001);
+    /// ```
+    pub fn from_srgb(rgb: &ColorRgb) -> Self {
+        Self::from_linear_srgb(&ColorRgb {
+            r: srgb_decode(rgb.r),
+            g: srgb_decode(rgb.g),
+            b: srgb_decode(rgb.b),
+        })
+    }
+
+    ///Converts the colour to CIELAB relative to the given reference white
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::COLOR_CIE_WHITE;
+    /// let lab = COLOR_CIE_WHITE.to_lab(&COLOR_CIE_WHITE);
+    /// assert!((lab.L - 100.0).abs() < 0.001 && lab.a.abs() < 0.001 && lab.b.abs() < 0.001);
+    /// ```
+    pub fn to_lab(&self, white: &ColorCie) -> ColorLab {
+        let xyz = self.to_xyz();
+        let white = white.to_xyz();
+        let fx = lab_f(xyz.X / white.X);
+        let fy = lab_f(xyz.Y / white.Y);
+        let fz = lab_f(xyz.Z / white.Z);
+        ColorLab {
+            L: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+        }
+    }
+
+    ///Converts CIELAB relative to the given reference white to xyY
+    pub fn from_lab(lab: &ColorLab, white: &ColorCie) -> Self {
+        let white = white.to_xyz();
+        let fy = (lab.L + 16.0) / 116.0;
+        Self::from_xyz(&ColorXyz {
+            X: white.X * lab_f_inverse(fy + lab.a / 500.0),
+            Y: white.Y * lab_f_inverse(fy),
+            Z: white.Z * lab_f_inverse(fy - lab.b / 200.0),
+        })
+    }
+
+    ///Returns the coordinates u' and v' of the chromaticity in the CIE 1976 UCS diagram
+    pub fn to_uv_1976(&self) -> (f32, f32) {
+        let (u, v) = self.to_uv_1960();
+        (u, 1.5 * v)
+    }
+
+    ///Returns the coordinates u and v of the chromaticity in the CIE 1960 UCS diagram
+    fn to_uv_1960(&self) -> (f32, f32) {
+        let denominator = -2.0 * self.x + 12.0 * self.y + 3.0;
+        (4.0 * self.x / denominator, 6.0 * self.y / denominator)
+    }
+
+    ///Creates a colour from it's coordinates in the CIE 1960 UCS diagram
+    fn from_uv_1960(u: f32, v: f32, Y: f32) -> Self {
+        let denominator = 2.0 * u - 8.0 * v + 4.0;
+        ColorCie {
+            x: 3.0 * u / denominator,
+            y: 2.0 * v / denominator,
+            Y,
+        }
+    }
+
+    ///Returns the correlated colour temperature and Duv with Robertson's method. Returns None if the colour temperature is below 1667 K
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+    /// let cct = COLOR_CIE_WHITE.cct().unwrap();
+    /// assert!((cct.kelvin - 6504.0).abs() < 5.0);
+    /// assert!((cct.duv - 0.0032).abs() < 0.0002);
+    /// assert!(ColorCie{ x: 0.7, y: 0.3, Y: 100.0 }.cct().is_none());
+    /// ```
+    pub fn cct(&self) -> Option<Cct> {
+        let (u, v) = self.to_uv_1960();
+        let distance = |(_, ui, vi, ti): (f32, f32, f32, f32)| (v - vi) - ti * (u - ui);
+        let mut previous = distance(ROBERTSON[0]);
+        for i in 1..ROBERTSON.len() {
+            let current = distance(ROBERTSON[i]);
+            if (current < 0.0) != (previous < 0.0) {
+                let (mired0, u0, v0, t0) = ROBERTSON[i - 1];
+                let (mired1, u1, v1, t1) = ROBERTSON[i];
+                let previous = previous / (1.0 + t0 * t0).sqrt();
+                let current = current / (1.0 + t1 * t1).sqrt();
+                let p = previous / (previous - current);
+                let mired = mired0 + (mired1 - mired0) * p;
+                let locus_u = u0 + (u1 - u0) * p;
+                let locus_v = v0 + (v1 - v0) * p;
+                let duv = ((u - locus_u).powi(2) + (v - locus_v).powi(2)).sqrt();
+                return Some(Cct {
+                    kelvin: if mired > 0.0 {
+                        1e6 / mired
+                    } else {
+                        f32::INFINITY
+                    },
+                    duv: if v < locus_v { -duv } else { duv },
+                });
+            }
+            previous = current;
+        }
+        None
+    }
+
+    ///Creates a colour from a correlated colour temperature between 1000 K and 15000 K and it's distance Duv to the Planckian locus, with Krystek's approximation of the locus
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// let d65 = ColorCie::from_cct(6504.0, 0.0032, 100.0);
+    /// assert!((d65.x - 0.3127).abs() < 0.0005 && (d65.y - 0.329).abs() < 0.0005);
+    /// ```
+    pub fn from_cct(kelvin: f32, duv: f32, Y: f32) -> Self {
+        let kelvin = kelvin.clamp(1000.0, 15000.0);
+        let (u, v) = planckian_uv(kelvin);
+        let (u_next, v_next) = planckian_uv(kelvin + 1.0);
+        let (du, dv) = (u_next - u, v_next - v);
+        let length = (du * du + dv * dv).sqrt();
+        //The normal points to higher v, the locus runs to smaller u with higher temperatures
+        Self::from_uv_1960(u + duv * dv / length, v - duv * du / length, Y)
+    }
+
+    ///Returns the colour of monochromatic light with the wavelength in nm on the spectral locus. Y is the luminous efficiency relative to 555 nm, scaled to 100. Returns None outside of 380 nm to 780 nm
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// let green = ColorCie::from_wave_length(520.0).unwrap();
+    /// assert!((green.x - 0.0743).abs() < 0.0005 && (green.y - 0.8338).abs() < 0.0005);
+    /// assert!(ColorCie::from_wave_length(300.0).is_none());
+    /// ```
+    pub fn from_wave_length(wave_length: f32) -> Option<Self> {
+        let [X, Y, Z] = color_matching(wave_length)?;
+        Some(Self::from_xyz(&ColorXyz { X, Y, Z }).with_Y(100.0 * Y))
+    }
+
+    ///Returns the colour of a spectrum given as pairs of wavelength in nm and energy, sorted by wavelength. The energy is interpolated linearly between the points and Y is the luminous efficiency of the spectrum relative to 555 nm, scaled to 100. Returns None if the spectrum has no visible energy
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// let equal_energy = ColorCie::from_spectrum(&[(380.0, 1.0), (780.0, 1.0)]).unwrap();
+    /// assert!((equal_energy.x - 0.3333).abs() < 0.001 && (equal_energy.y - 0.3333).abs() < 0.001);
+    /// ```
+    pub fn from_spectrum(spectrum: &[(f32, f32)]) -> Option<Self> {
+        let mut xyz = [0.0_f64; 3];
+        let mut energy = 0.0_f64;
+        for pair in spectrum.windows(2) {
+            let ((from, from_energy), (to, to_energy)) = (pair[0], pair[1]);
+            let steps = (to - from).ceil().max(0.0) as usize;
+            //Integrates in steps of at most 1 nm, so sparse spectra are weighted correctly
+            for step in 0..steps {
+                let width = (to - from) / steps as f32;
+                let wave_length = from + width * (step as f32 + 0.5);
+                let e =
+                    from_energy + (to_energy - from_energy) * (step as f32 + 0.5) / steps as f32;
+                energy += (e * width) as f64;
+                if let Some(cmf) = color_matching(wave_length) {
+                    for (sum, value) in xyz.iter_mut().zip(cmf) {
+                        *sum += (value * e * width) as f64;
+                    }
+                }
+            }
+        }
+        if energy <= 0.0 || xyz[1] <= 0.0 {
+            return None;
+        }
+        let color = Self::from_xyz(&ColorXyz {
+            X: xyz[0] as f32,
+            Y: xyz[1] as f32,
+            Z: xyz[2] as f32,
+        });
+        Some(color.with_Y((100.0 * xyz[1] / energy) as f32))
+    }
+
+    ///Returns the colour with the same chromaticity and an other Y
+    fn with_Y(self, Y: f32) -> Self {
+        Self { Y, ..self }
+    }
+
+    ///Adapts the colour seen under the source white to the destination white with the Bradford transform
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+    /// let d50 = ColorCie{ x: 0.3457, y: 0.3585, Y: 100.0 };
+    /// let adapted = COLOR_CIE_WHITE.adapt(&COLOR_CIE_WHITE, &d50);
+    /// assert!((adapted.x - d50.x).abs() < 0.0001 && (adapted.y - d50.y).abs() < 0.0001);
+    /// ```
+    pub fn adapt(&self, source_white: &ColorCie, destination_white: &ColorCie) -> Self {
+        let cone = |color: &ColorCie| {
+            let xyz = ColorCie {
+                Y: 1.0,
+                ..color.clone()
+            }
+            .to_xyz();
+            BRADFORD.apply([xyz.X, xyz.Y, xyz.Z])
+        };
+        let source = cone(source_white);
+        let destination = cone(destination_white);
+        let scale = ColorMatrix([
+            [destination[0] / source[0], 0.0, 0.0],
+            [0.0, destination[1] / source[1], 0.0],
+            [0.0, 0.0, destination[2] / source[2]],
+        ]);
+        let transform = BRADFORD_INVERSE.mul(&scale).mul(&BRADFORD);
+        let xyz = self.to_xyz();
+        let [X, Y, Z] = transform.apply([xyz.X, xyz.Y, xyz.Z]);
+        Self::from_xyz(&ColorXyz { X, Y, Z })
+    }
+
+    ///Returns the colour difference ΔE2000 to an other colour, both converted to CIELAB relative to the given white
+    /// ```rust
+    /// use gdtf_parser::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+    /// let slightly_darker = ColorCie{ Y: 95.0, ..COLOR_CIE_WHITE };
+    /// assert_eq!(COLOR_CIE_WHITE.delta_e_2000(&COLOR_CIE_WHITE, &COLOR_CIE_WHITE), 0.0);
+    /// assert!((COLOR_CIE_WHITE.delta_e_2000(&slightly_darker, &COLOR_CIE_WHITE) - 1.14).abs() < 0.01);
+    /// ```
+    pub fn delta_e_2000(&self, other: &ColorCie, white: &ColorCie) -> f32 {
+        self.to_lab(white).delta_e_2000(&other.to_lab(white))
+    }
+
+    ///Returns the distance of the chromaticities in the CIE 1976 u'v' diagram, ignoring the luminance
+    pub fn delta_uv(&self, other: &ColorCie) -> f32 {
+        let (u1, v1) = self.to_uv_1976();
+        let (u2, v2) = other.to_uv_1976();
+        ((u1 - u2).powi(2) + (v1 - v2).powi(2)).sqrt()
+    }
+
+    ///Moves the chromaticity towards the white point of the gamut until it is inside of the gamut. The luminance is kept
+    /// ```rust
+    /// use gdtf_parser::utils::color::Gamut;
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// let laser_green = ColorCie{ x: 0.17, y: 0.8, Y: 50.0 };
+    /// let clamped = laser_green.clamp_to_gamut(&Gamut::srgb());
+    /// assert!(Gamut::srgb().contains(&clamped));
+    /// assert_eq!(clamped.Y, 50.0);
+    /// ```
+    pub fn clamp_to_gamut(&self, gamut: &Gamut) -> Self {
+        if gamut.contains(self) {
+            return self.clone();
+        }
+        let white = &gamut.white;
+        let (dx, dy) = (self.x - white.x, self.y - white.y);
+        let mut factor: f32 = 1.0;
+        for (a, b) in gamut.edges() {
+            let (ex, ey) = (b.x - a.x, b.y - a.y);
+            let denominator = dx * ey - dy * ex;
+            if denominator == 0.0 {
+                continue;
+            }
+            //Parameters of the intersection on the line from the white point and on the edge
+            let t = ((a.x - white.x) * ey - (a.y - white.y) * ex) / denominator;
+            let s = ((a.x - white.x) * dy - (a.y - white.y) * dx) / denominator;
+            if (0.0..=1.0).contains(&t) && (0.0..=1.0).contains(&s) {
+                factor = factor.min(t);
+            }
+        }
+        ColorCie {
+            x: white.x + dx * factor,
+            y: white.y + dy * factor,
+            Y: self.Y,
+        }
+    }
+}
+
+impl ColorXyz {
+    ///Returns the sharpened cone responses of the Bradford transform
+    pub(crate) fn to_cone_response(self) -> [f32; 3] {
+        BRADFORD.apply([self.X, self.Y, self.Z])
+    }
+
+    ///Creates XYZ from sharpened cone responses of the Bradford transform
+    pub(crate) fn from_cone_response(cone: [f32; 3]) -> Self {
+        let [X, Y, Z] = BRADFORD_INVERSE.apply(cone);
+        Self { X, Y, Z }
+    }
+}
+
+impl ColorRgb {
+    ///Returns the components as bytes, for example to display gamma-encoded sRGB
+    /// ```rust
+    /// use gdtf_parser::utils::color::ColorRgb;
+    /// assert_eq!(ColorRgb{ r: 1.0, g: 0.5, b: -0.2 }.to_u8(), [255, 128, 0]);
+    /// ```
+    pub fn to_u8(&self) -> [u8; 3] {
+        let byte = |c: f32| (c.clamp(0.0, 1.0) * 255.0).round() as u8;
+        [byte(self.r), byte(self.g), byte(self.b)]
+    }
+}
+
+impl ColorLab {
+    ///Returns the colour difference ΔE2000 (CIEDE2000) to an other colour
+    /// ```rust
+    /// use gdtf_parser::utils::color::ColorLab;
+    /// let a = ColorLab{ L: 50.0, a: 2.6772, b: -79.7751 };
+    /// let b = ColorLab{ L: 50.0, a: 0.0, b: -82.7485 };
+    /// assert!((a.delta_e_2000(&b) - 2.0425).abs() < 0.0001);
+    /// ```
+    pub fn delta_e_2000(&self, other: &ColorLab) -> f32 {
+        let (l1, a1, b1) = (self.L as f64, self.a as f64, self.b as f64);
+        let (l2, a2, b2) = (other.L as f64, other.a as f64, other.b as f64);
+
+        let c_mean = ((a1.hypot(b1)) + (a2.hypot(b2))) / 2.0;
+        let g = 0.5 * (1.0 - (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt());
+        let (a1, a2) = (a1 * (1.0 + g), a2 * (1.0 + g));
+        let (c1, c2) = (a1.hypot(b1), a2.hypot(b2));
+        let hue = |b: f64, a: f64| {
+            if a == 0.0 && b == 0.0 {
+                0.0
+            } else {
+                b.atan2(a).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+
+        let delta_l = l2 - l1;
+        let delta_c = c2 - c1;
+        let delta_h = if c1 * c2 == 0.0 {
+            0.0
+        } else if (h2 - h1).abs() <= 180.0 {
+            h2 - h1
+        } else if h2 - h1 > 180.0 {
+            h2 - h1 - 360.0
+        } else {
+            h2 - h1 + 360.0
+        };
+        let delta_h = 2.0 * (c1 * c2).sqrt() * (delta_h / 2.0).to_radians().sin();
+
+        let l_mean = (l1 + l2) / 2.0;
+        let c_mean = (c1 + c2) / 2.0;
+        let h_mean = if c1 * c2 == 0.0 {
+            h1 + h2
+        } else if (h1 - h2).abs() <= 180.0 {
+            (h1 + h2) / 2.0
+        } else if h1 + h2 < 360.0 {
+            (h1 + h2 + 360.0) / 2.0
+        } else {
+            (h1 + h2 - 360.0) / 2.0
+        };
+
+        let t = 1.0 - 0.17 * (h_mean - 30.0).to_radians().cos()
+            + 0.24 * (2.0 * h_mean).to_radians().cos()
+            + 0.32 * (3.0 * h_mean + 6.0).to_radians().cos()
+            - 0.20 * (4.0 * h_mean - 63.0).to_radians().cos();
+        let delta_theta = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+        let r_c = 2.0 * (c_mean.powi(7) / (c_mean.powi(7) + 25f64.powi(7))).sqrt();
+        let s_l = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+        let s_c = 1.0 + 0.045 * c_mean;
+        let s_h = 1.0 + 0.015 * c_mean * t;
+        let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+        let (l, c, h) = (delta_l / s_l, delta_c / s_c, delta_h / s_h);
+        (l * l + c * c + h * h + r_t * c * h).sqrt() as f32
+    }
+}
+
+impl Gamut {
+    ///Gamut of sRGB with the white point D65
+    pub fn srgb() -> Self {
+        Gamut {
+            red: ColorCie {
+                x: 0.64,
+                y: 0.33,
+                Y: 21.26,
+            },
+            green: ColorCie {
+                x: 0.3,
+                y: 0.6,
+                Y: 71.52,
+            },
+            blue: ColorCie {
+                x: 0.15,
+                y: 0.06,
+                Y: 7.22,
+            },
+            white: COLOR_CIE_WHITE,
+        }
+    }
+
+    ///Returns true if the chromaticity of the colour is inside of the triangle of the primaries
+    /// ```rust
+    /// use gdtf_parser::utils::color::Gamut;
+    /// use gdtf_parser::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+    /// assert!(Gamut::srgb().contains(&COLOR_CIE_WHITE));
+    /// assert!(!Gamut::srgb().contains(&ColorCie{ x: 0.17, y: 0.8, Y: 50.0 }));
+    /// ```
+    pub fn contains(&self, color: &ColorCie) -> bool {
+        let side = |(a, b): (&ColorCie, &ColorCie)| {
+            (b.x - a.x) * (color.y - a.y) - (b.y - a.y) * (color.x - a.x)
+        };
+        let sides: Vec<f32> = self.edges().into_iter().map(side).collect();
+        sides.iter().all(|side| *side >= -1e-6) || sides.iter().all(|side| *side <= 1e-6)
+    }
+
+    ///Edges of the triangle
+    fn edges(&self) -> [(&ColorCie, &ColorCie); 3] {
+        [
+            (&self.red, &self.green),
+            (&self.green, &self.blue),
+            (&self.blue, &self.red),
+        ]
+    }
+}
+
+impl ColorMatrix {
+    ///Derives the matrix that converts linear RGB with the given primaries to XYZ with a white Y of 1. Only the chromaticities are used, RGB of 1, 1, 1 is converted to the white point. Returns None if the primaries are on a line
+    /// ```rust
+    /// use gdtf_parser::utils::color::{ColorMatrix, Gamut};
+    /// let srgb = Gamut::srgb();
+    /// let matrix = ColorMatrix::rgb_to_xyz(&srgb.red, &srgb.green, &srgb.blue, &srgb.white).unwrap();
+    /// assert!((matrix.0[1][0] - 0.2126).abs() < 0.0001);
+    /// ```
+    pub fn rgb_to_xyz(
+        red: &ColorCie,
+        green: &ColorCie,
+        blue: &ColorCie,
+        white: &ColorCie,
+    ) -> Option<ColorMatrix> {
+        let unit = |color: &ColorCie| {
+            ColorCie {
+                Y: 1.0,
+                ..color.clone()
+            }
+            .to_xyz()
+        };
+        let (r, g, b, w) = (unit(red), unit(green), unit(blue), unit(white));
+        let primaries = ColorMatrix([[r.X, g.X, b.X], [r.Y, g.Y, b.Y], [r.Z, g.Z, b.Z]]);
+        let [sr, sg, sb] = primaries.inverse()?.apply([w.X, w.Y, w.Z]);
+        Some(ColorMatrix(
+            primaries.0.map(|[x, y, z]| [x * sr, y * sg, z * sb]),
+        ))
+    }
+
+    ///Multiplies the matrix with a column vector
+    pub fn apply(&self, v: [f32; 3]) -> [f32; 3] {
+        let m = &self.0;
+        [
+            m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+            m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+            m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+        ]
+    }
+
+    ///Multiplies two matrices. The result applies `other` first and then `self`
+    pub fn mul(&self, other: &ColorMatrix) -> ColorMatrix {
+        let mut out = [[0.0; 3]; 3];
+        for (i, row) in out.iter_mut().enumerate() {
+            for (j, value) in row.iter_mut().enumerate() {
+                *value = (0..3).map(|k| self.0[i][k] * other.0[k][j]).sum();
+            }
+        }
+        ColorMatrix(out)
+    }
+
+    ///Returns the inverse of the matrix or None if it is singular
+    /// ```rust
+    /// use gdtf_parser::utils::color::ColorMatrix;
+    /// let m = ColorMatrix([[2.0, 0.0, 0.0], [0.0, 4.0, 0.0], [0.0, 0.0, 0.5]]);
+    /// assert_eq!(m.inverse().unwrap(), ColorMatrix([[0.5, 0.0, 0.0], [0.0, 0.25, 0.0], [0.0, 0.0, 2.0]]));
+    /// assert!(ColorMatrix([[1.0; 3]; 3]).inverse().is_none());
+    /// ```
+    pub fn inverse(&self) -> Option<ColorMatrix> {
+        let m = self.0.map(|row| row.map(f64::from));
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+        let adjugate = [
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ];
+        let determinant =
+            m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+        if determinant.abs() < 1e-12 {
+            return None;
+        }
+        Some(ColorMatrix(
+            adjugate.map(|row| row.map(|value| (value / determinant) as f32)),
+        ))
+    }
+}
+
+///Applies the sRGB transfer function to a linear component
+fn srgb_encode(c: f32) -> f32 {
+    if c <= 0.003_130_8 {
+        12.92 * c
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+///Removes the sRGB transfer function from a gamma-encoded component
+fn srgb_decode(c: f32) -> f32 {
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+///Nonlinear compression of CIELAB
+fn lab_f(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA * DELTA * DELTA {
+        t.cbrt()
+    } else {
+        t / (3.0 * DELTA * DELTA) + 4.0 / 29.0
+    }
+}
+
+///Inverse of `lab_f`
+fn lab_f_inverse(t: f32) -> f32 {
+    const DELTA: f32 = 6.0 / 29.0;
+    if t > DELTA {
+        t * t * t
+    } else {
+        3.0 * DELTA * DELTA * (t - 4.0 / 29.0)
+    }
+}
+
+///Chromaticity of a black body in the CIE 1960 UCS diagram, Krystek's approximation for 1000 K to 15000 K
+fn planckian_uv(kelvin: f32) -> (f32, f32) {
+    let t = kelvin as f64;
+    let u = (0.860_117_757 + 1.541_182_54e-4 * t + 1.286_412_12e-7 * t * t)
+        / (1.0 + 8.424_202_35e-4 * t + 7.081_451_63e-7 * t * t);
+    let v = (0.317_398_726 + 4.228_062_45e-5 * t + 4.204_816_91e-8 * t * t)
+        / (1.0 - 2.897_418_16e-5 * t + 1.614_560_53e-7 * t * t);
+    (u as f32, v as f32)
+}
+
+///Colour matching functions at a wavelength in nm, interpolated linearly. Returns None outside of 380 nm to 780 nm
+fn color_matching(wave_length: f32) -> Option<[f32; 3]> {
+    if !(380.0..=780.0).contains(&wave_length) {
+        return None;
+    }
+    let position = (wave_length - 380.0) / 10.0;
+    let index = (position as usize).min(CIE_1931.len() - 2);
+    let p = position - index as f32;
+    let (from, to) = (CIE_1931[index], CIE_1931[index + 1]);
+    Some([0, 1, 2].map(|i| from[i] + (to[i] - from[i]) * p))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::color::{ColorLab, ColorRgb, ColorXyz, Gamut};
+    use crate::utils::units::color_cie::{ColorCie, COLOR_CIE_WHITE};
+
+    fn assert_close(expected: f32, actual: f32, tolerance: f32) {
+        assert!(
+            (expected - actual).abs() <= tolerance,
+            "Expected {} but got {}",
+            expected,
+            actual
+        );
+    }
+
+    #[test]
+    fn test_xyz() {
+        let color = ColorCie {
+            x: 0.4,
+            y: 0.3,
+            Y: 30.0,
+        };
+        let xyz = color.to_xyz();
+        assert_close(40.0, xyz.X, 0.0001);
+        assert_close(30.0, xyz.Y, 0.0001);
+        assert_close(30.0, xyz.Z, 0.0001);
+        let back = ColorCie::from_xyz(&xyz);
+        assert_close(color.x, back.x, 0.00001);
+        assert_close(color.y, back.y, 0.00001);
+        assert_close(color.Y, back.Y, 0.00001);
+
+        let black = ColorCie::from_xyz(&ColorXyz {
+            X: 0.0,
+            Y: 0.0,
+            Z: 0.0,
+        });
+        assert_eq!(black.Y, 0.0);
+        assert_eq!(black.x, COLOR_CIE_WHITE.x);
+    }
+
+    #[test]
+    fn test_srgb() {
+        for (rgb, x, y, Y) in [
+            ([1.0, 0.0, 0.0], 0.64, 0.33, 21.26),
+            ([0.0, 1.0, 0.0], 0.3, 0.6, 71.52),
+            ([0.0, 0.0, 1.0], 0.15, 0.06, 7.22),
+            ([1.0, 1.0, 1.0], 0.3127, 0.329, 100.0),
+        ] {
+            let linear = ColorRgb {
+                r: rgb[0],
+                g: rgb[1],
+                b: rgb[2],
+            };
+            let color = ColorCie::from_linear_srgb(&linear);
+            assert_close(x, color.x, 0.0001);
+            assert_close(y, color.y, 0.0001);
+            assert_close(Y, color.Y, 0.01);
+            let back = color.to_linear_srgb();
+            assert_close(linear.r, back.r, 0.0001);
+            assert_close(linear.g, back.g, 0.0001);
+            assert_close(linear.b, back.b, 0.0001);
+        }
+
+        let grey = ColorCie {
+            Y: 21.404,
+            ..COLOR_CIE_WHITE
+        };
+        let srgb = grey.to_srgb();
+        assert_close(0.5, srgb.r, 0.002);
+        assert_close(0.5, srgb.g, 0.002);
+        assert_close(0.5, srgb.b, 0.002);
+        let back = ColorCie::from_srgb(&srgb);
+        assert_close(grey.Y, back.Y, 0.001);
+
+        let out_of_gamut = ColorCie {
+            x: 0.17,
+            y: 0.8,
+            Y: 50.0,
+        };
+        assert!(out_of_gamut.to_linear_srgb().r < 0.0);
+        assert_eq!(out_of_gamut.to_srgb().r, 0.0);
+    }
+
+    #[test]
+    fn test_lab() {
+        let red = ColorCie {
+            x: 0.64,
+            y: 0.33,
+            Y: 21.26,
+        };
+        let lab = red.to_lab(&COLOR_CIE_WHITE);
+        assert_close(53.24, lab.L, 0.02);
+        assert_close(80.09, lab.a, 0.05);
+        assert_close(67.20, lab.b, 0.05);
+        let back = ColorCie::from_lab(&lab, &COLOR_CIE_WHITE);
+        assert_close(red.x, back.x, 0.0001);
+        assert_close(red.y, back.y, 0.0001);
+        assert_close(red.Y, back.Y, 0.001);
+
+        let dark = ColorLab {
+            L: 5.0,
+            a: 0.0,
+            b: 0.0,
+        };
+        assert_close(
+            5.0,
+            ColorCie::from_lab(&dark, &COLOR_CIE_WHITE)
+                .to_lab(&COLOR_CIE_WHITE)
+                .L,
+            0.0001,
+        );
+    }
+
+    #[test]
+    fn test_cct() {
+        for (x, y, kelvin, duv) in [
+            (0.3127, 0.329, 6504.0, 0.0032),
+            (0.44757, 0.40745, 2856.0, 0.0),
+            (0.34567, 0.3585, 5003.0, 0.0033),
+        ] {
+            let cct = ColorCie { x, y, Y: 100.0 }.cct().unwrap();
+            assert_close(kelvin, cct.kelvin, 0.002 * kelvin);
+            assert_close(duv, cct.duv, 0.0003);
+
+            let color = ColorCie::from_cct(cct.kelvin, cct.duv, 100.0);
+            assert_close(x, color.x, 0.0005);
+            assert_close(y, color.y, 0.0005);
+        }
+        let below = ColorCie::from_cct(4000.0, -0.01, 100.0).cct().unwrap();
+        assert_close(4000.0, below.kelvin, 20.0);
+        assert_close(-0.01, below.duv, 0.0005);
+        assert!(ColorCie {
+            x: 0.15,
+            y: 0.06,
+            Y: 7.22
+        }
+        .cct()
+        .is_none());
+    }
+
+    #[test]
+    fn test_adapt() {
+        let d50 = ColorCie {
+            x: 0.3457,
+            y: 0.3585,
+            Y: 100.0,
+        };
+        let red = ColorCie {
+            x: 0.64,
+            y: 0.33,
+            Y: 21.26,
+        };
+        let adapted = red.adapt(&COLOR_CIE_WHITE, &d50);
+        //Red of sRGB adapted to D50, as used in ICC profiles
+        assert_close(0.6485, adapted.x, 0.0005);
+        assert_close(0.3309, adapted.y, 0.0005);
+        assert_close(22.25, adapted.Y, 0.05);
+        let back = adapted.adapt(&d50, &COLOR_CIE_WHITE);
+        assert_close(red.x, back.x, 0.0001);
+        assert_close(red.y, back.y, 0.0001);
+
+        let xyz = red.to_xyz();
+        let back = ColorXyz::from_cone_response(xyz.to_cone_response());
+        assert_close(xyz.X, back.X, 0.0001);
+        assert_close(xyz.Y, back.Y, 0.0001);
+        assert_close(xyz.Z, back.Z, 0.0001);
+    }
+
+    #[test]
+    fn test_delta_e_2000() {
+        //Test data of Sharma, Wu and Dalal: The CIEDE2000 Color-Difference Formula
+        for (lab1, lab2, delta_e) in [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615),
+            ((50.0, -1.3802, -84.2814), (50.0, 0.0, -82.7485), 1.0),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            ((50.0, 2.5, 0.0), (50.0, 0.0, -2.5), 4.3065),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+            (
+                (22.7233, 20.0904, -46.694),
+                (23.0331, 14.973, -42.5619),
+                2.0373,
+            ),
+            ((2.0776, 0.0795, -1.135), (0.9033, -0.0636, -0.5514), 0.9082),
+        ] {
+            let lab = |(L, a, b): (f32, f32, f32)| ColorLab { L, a, b };
+            assert_close(delta_e, lab(lab1).delta_e_2000(&lab(lab2)), 0.0002);
+            assert_close(delta_e, lab(lab2).delta_e_2000(&lab(lab1)), 0.0002);
+        }
+    }
+
+    #[test]
+    fn test_gamut() {
+        let gamut = Gamut::srgb();
+        let inside = ColorCie {
+            x: 0.4,
+            y: 0.4,
+            Y: 10.0,
+        };
+        assert!(gamut.contains(&inside));
+        assert_eq!(inside.clamp_to_gamut(&gamut), inside);
+        assert!(gamut.contains(&gamut.red));
+
+        let outside = ColorCie {
+            x: 0.7,
+            y: 0.29,
+            Y: 10.0,
+        };
+        let clamped = outside.clamp_to_gamut(&gamut);
+        assert!(gamut.contains(&clamped));
+        assert!(!gamut.contains(&ColorCie {
+            x: clamped.x + 0.001,
+            ..clamped.clone()
+        }));
+        //The hue is kept, the clamped colour is on the line to the white point
+        let direction = |c: &ColorCie| (c.y - COLOR_CIE_WHITE.y).atan2(c.x - COLOR_CIE_WHITE.x);
+        assert_close(direction(&outside), direction(&clamped), 0.0001);
+        let rgb = clamped.to_linear_srgb();
+        assert!(rgb.r > 0.0 && rgb.g.abs() < 0.001 && rgb.b >= -0.001);
+    }
+
+    #[test]
+    fn test_delta_uv() {
+        let other = ColorCie {
+            x: 0.3457,
+            y: 0.3585,
+            Y: 50.0,
+        };
+        assert_eq!(COLOR_CIE_WHITE.delta_uv(&COLOR_CIE_WHITE), 0.0);
+        assert_close(0.0228, COLOR_CIE_WHITE.delta_uv(&other), 0.0002);
+    }
+
+    #[test]
+    fn test_spectrum() {
+        //A narrow band lies on the spectral locus where the colour matching functions are linear
+        for wave_length in [455.0, 525.0, 595.0, 635.0] {
+            let locus = ColorCie::from_wave_length(wave_length).unwrap();
+            let band = ColorCie::from_spectrum(&[
+                (wave_length - 1.0, 0.0),
+                (wave_length, 1.0),
+                (wave_length + 1.0, 0.0),
+            ])
+            .unwrap();
+            assert_close(locus.x, band.x, 0.002);
+            assert_close(locus.y, band.y, 0.002);
+            assert_close(locus.Y, band.Y, 0.5);
+        }
+        assert_close(99.5, ColorCie::from_wave_length(555.0).unwrap().Y, 0.5);
+
+        //Sampling must not change the colour of a spectrum
+        let sparse = ColorCie::from_spectrum(&[(400.0, 0.0), (500.0, 1.0), (700.0, 0.0)]).unwrap();
+        let dense: Vec<(f32, f32)> = (400..=700)
+            .map(|nm| {
+                let nm = nm as f32;
+                let energy = if nm <= 500.0 {
+                    (nm - 400.0) / 100.0
+                } else {
+                    (700.0 - nm) / 200.0
+                };
+                (nm, energy)
+            })
+            .collect();
+        let dense = ColorCie::from_spectrum(&dense).unwrap();
+        assert_close(sparse.x, dense.x, 0.0001);
+        assert_close(sparse.y, dense.y, 0.0001);
+        assert_close(sparse.Y, dense.Y, 0.01);
+
+        assert!(ColorCie::from_spectrum(&[(800.0, 1.0), (900.0, 1.0)]).is_none());
+        assert!(ColorCie::from_spectrum(&[(500.0, 1.0)]).is_none());
+    }
+}
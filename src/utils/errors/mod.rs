@@ -5,15 +5,19 @@ use std::str::Utf8Error;
 
 use zip::result::ZipError;
 
+#[cfg(feature = "cache")]
+use crate::cache::GdtfCacheError;
 use crate::utils::read::GdtfReadError;
 use crate::utils::units::color_cie::GdtfColorCieError;
 use crate::utils::units::dmx_value::GdtfDmxValueError;
-use crate::utils::units::guid::GdtfGuidError;
+use crate::utils::units::guid::{GdtfGuidError, Guid};
+use crate::utils::units::matrix::GdtfMatrixError;
 use crate::utils::units::name::GdtfNameError;
 use crate::utils::units::node::GdtfNodeError;
 use crate::utils::units::pixel::GdtfPixelError;
 use crate::utils::units::pixel_array::GdtfPixelArrayError;
 use crate::utils::units::rotation::GdtfRotationError;
+use crate::utils::warnings::ParseWarning;
 
 #[derive(Debug)]
 pub enum GdtfError {
@@ -35,6 +39,168 @@ pub enum GdtfError {
     GdtfPixelArrayError(GdtfPixelArrayError),
     GdtfPixelError(GdtfPixelError),
     GdtfRotationError(GdtfRotationError),
+    GdtfMatrixError(GdtfMatrixError),
+    ///Resources that are referenced in the description.xml but are not present in the archive
+    ResourceNotFoundError(Vec<String>),
+    ///The fixture type referenced with RefFT was not found. Contains the FixtureTypeID of the referencing and of the missing fixture type
+    RefFtNotFoundError(Guid, Guid),
+    ///Following RefFT leads back to a fixture type that was already visited. Contains the FixtureTypeIDs of the chain up to the repeated one
+    RefFtCycleError(Vec<Guid>),
+    ///A fixture in an MVR references a GDTFSpec that is not embedded in the archive. Contains the name of the fixture and the GDTFSpec
+    MvrGdtfSpecNotFoundError(String, String),
+    ///A fixture in an MVR references a GDTFMode that does not exist in the fixture type. Contains the name of the fixture and the GDTFMode
+    MvrGdtfModeNotFoundError(String, String),
+    ///A .gdtf file added to an MVR archive has no file name that can be used inside of the archive. Contains the path of the file
+    MvrGdtfFileNameError(String),
+    ///A node created with a builder links to a node that does not exist. Contains the path of the node, the name of the link attribute and the link
+    BuilderLinkNotFoundError(String, String, String),
+    ///A builder contains more than one child with the same name. Contains the path of the parent and the name
+    BuilderDuplicateNameError(String, String),
+    ///An attribute added to a builder without it's definition is not predefined in the GDTF specification
+    BuilderAttributeNotPredefinedError(String),
+    ///A channel set created with a builder has a DMXFrom outside of it's channel function or not above the one of the previous channel set. Contains the path of the channel set and the reason
+    BuilderChannelSetDmxFromError(String, String),
+    ///Deparsing in `ParseMode::Strict` had to substitute values or skip nodes. Contains all substitutions
+    StrictParseError(Vec<ParseWarning>),
+    ///A channel function or wheel slot links to an emitter or filter that is not defined in the physical descriptions. Contains the path of the node, the name of the link attribute and the link
+    PhysicalDescriptionsLinkNotFoundError(String, String, String),
+    ///A thread that read the files of a `FixtureLibrary` panicked. Contains the message of the panic
+    LibraryScanError(String),
+    ///A thread that deparsed DMX modes with the feature `parallel` panicked. Contains the message of the panic
+    ParallelDeparseError(String),
+    ///A cache of a Gdtf could not be decoded or is outdated
+    #[cfg(feature = "cache")]
+    CacheError(GdtfCacheError),
+    ///Error that occurred while deparsing a node of the description.xml. Contains the error and where it occurred
+    LocatedError(Box<GdtfError>, Box<ErrorLocation>),
+}
+
+///Location in the description.xml where an error occurred
+#[derive(Debug, PartialEq, Clone)]
+pub struct ErrorLocation {
+    ///Path of the xml-node, for example `GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/DMXChannels/DMXChannel[3]`. Nodes with a Name contain it in square brackets, other nodes in a list their position starting at 1
+    pub path: String,
+    ///Name and value of the attribute that could not be deparsed, if the error was caused by an attribute
+    pub attribute: Option<(String, String)>,
+    ///Byte position of the start of the xml-node
+    pub position: usize,
+    ///Line of the start of the xml-node, starting at 1. Is 0 if the error did not occur in a whole description.xml
+    pub line: usize,
+    ///Column of the start of the xml-node, starting at 1. Is 0 if the error did not occur in a whole description.xml
+    pub column: usize,
+}
+
+impl fmt::Display for ErrorLocation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.path)?;
+        if let Some((key, value)) = &self.attribute {
+            write!(f, " {}=\"{}\"", key, value)?;
+        }
+        if self.line == 0 {
+            write!(f, " at byte {}", self.position)
+        } else {
+            write!(f, " at line {}, column {}", self.line, self.column)
+        }
+    }
+}
+
+impl GdtfError {
+    ///Returns where in the description.xml the error occurred, if it occurred while deparsing a node
+    /// ```rust
+    /// use gdtf_parser::Gdtf;
+    ///
+    /// let error = Gdtf::from_description_xml(r#"<?xml version="1.0" encoding="UTF-8"?>
+    /// <GDTF DataVersion="1.1">
+    ///   <FixtureType Name="LED PAR" ShortName="PAR" LongName="LED PAR" Manufacturer="Generic" Description="" FixtureTypeID="No GUID" Thumbnail="" RefFT="">
+    ///   </FixtureType>
+    /// </GDTF>"#).unwrap_err();
+    /// let location = error.location().unwrap();
+    /// assert_eq!(location.path, "GDTF/FixtureType[LED PAR]");
+    /// assert_eq!(location.attribute, Some(("FixtureTypeID".to_string(), "No GUID".to_string())));
+    /// assert_eq!((location.line, location.column), (3, 3));
+    /// ```
+    pub fn location(&self) -> Option<&ErrorLocation> {
+        match self {
+            GdtfError::LocatedError(_, location) => Some(location),
+            _ => None,
+        }
+    }
+
+    ///Returns the error without it's location
+    pub fn without_location(&self) -> &GdtfError {
+        match self {
+            GdtfError::LocatedError(error, _) => error,
+            error => error,
+        }
+    }
+
+    ///Adds the node to the front of the path of the error, or locates the error in the node if it has no location yet
+    pub(crate) fn locate(
+        self,
+        node: String,
+        attribute: Option<(String, String)>,
+        position: usize,
+    ) -> Self {
+        match self {
+            GdtfError::LocatedError(error, mut location) => {
+                location.path = format!("{}/{}", node, location.path);
+                GdtfError::LocatedError(error, location)
+            }
+            error => GdtfError::LocatedError(
+                Box::new(error),
+                Box::new(ErrorLocation {
+                    path: node,
+                    attribute,
+                    position,
+                    line: 0,
+                    column: 0,
+                }),
+            ),
+        }
+    }
+
+    ///Adds the position of a node in a list to the first node of the path of the error
+    pub(crate) fn locate_index(self, index: usize) -> Self {
+        match self {
+            GdtfError::LocatedError(error, mut location) => {
+                let end = location.path.find('/').unwrap_or(location.path.len());
+                if !location.path[..end].ends_with(']') {
+                    location.path.insert_str(end, &format!("[{}]", index));
+                }
+                GdtfError::LocatedError(error, location)
+            }
+            error => error,
+        }
+    }
+
+    ///Moves the position of the location by offset, for errors that occurred in a part of the xml that was deparsed on it's own
+    #[cfg(feature = "parallel")]
+    pub(crate) fn offset_location(self, offset: usize) -> Self {
+        match self {
+            GdtfError::LocatedError(error, mut location) => {
+                location.position += offset;
+                GdtfError::LocatedError(error, location)
+            }
+            error => error,
+        }
+    }
+
+    ///Calculates line and column of the location from the xml the error occurred in
+    pub(crate) fn locate_in(self, xml: &str) -> Self {
+        match self {
+            GdtfError::LocatedError(error, mut location) => {
+                let before = xml.get(..location.position).unwrap_or(xml);
+                location.line = before.matches('\n').count() + 1;
+                location.column = before
+                    .rsplit('\n')
+                    .next()
+                    .map_or(0, |line| line.chars().count())
+                    + 1;
+                GdtfError::LocatedError(error, location)
+            }
+            error => error,
+        }
+    }
 }
 
 impl From<GdtfRotationError> for GdtfError {
@@ -118,10 +284,96 @@ impl fmt::Display for GdtfError {
             GdtfError::GdtfPixelArrayError(e) => write!(f, "GdtfError: {}", e),
             GdtfError::GdtfPixelError(e) => write!(f, "GdtfError: {}", e),
             GdtfError::GdtfRotationError(e) => write!(f, "GdtfError: {}", e),
+            GdtfError::GdtfMatrixError(e) => write!(f, "GdtfError: {}", e),
+            GdtfError::ResourceNotFoundError(e) => write!(
+                f,
+                "GdtfError: Referenced resources are missing in archive: {}",
+                e.join(", ")
+            ),
+            GdtfError::RefFtNotFoundError(fixture_type_id, ref_ft) => write!(
+                f,
+                "GdtfError: Fixture type {} references fixture type {} that was not found",
+                fixture_type_id, ref_ft
+            ),
+            GdtfError::RefFtCycleError(chain) => write!(
+                f,
+                "GdtfError: Cycle in referenced fixture types: {}",
+                chain
+                    .iter()
+                    .map(|guid| guid.to_string())
+                    .collect::<Vec<String>>()
+                    .join(" -> ")
+            ),
+            GdtfError::MvrGdtfSpecNotFoundError(fixture, spec) => write!(
+                f,
+                "GdtfError: Fixture {} references GDTFSpec {} that is not in the MVR archive",
+                fixture, spec
+            ),
+            GdtfError::MvrGdtfModeNotFoundError(fixture, mode) => write!(
+                f,
+                "GdtfError: Fixture {} references GDTFMode {} that is not defined in it's fixture type",
+                fixture, mode
+            ),
+            GdtfError::MvrGdtfFileNameError(path) => write!(
+                f,
+                "GdtfError: The file {} has no UTF-8 file name to reference it in an MVR archive",
+                path
+            ),
+            GdtfError::BuilderLinkNotFoundError(path, attribute, link) => write!(
+                f,
+                "GdtfError: {} of {} links to {} that does not exist",
+                attribute, path, link
+            ),
+            GdtfError::BuilderDuplicateNameError(parent, name) => write!(
+                f,
+                "GdtfError: {} contains {} more than once",
+                parent, name
+            ),
+            GdtfError::BuilderAttributeNotPredefinedError(attribute) => write!(
+                f,
+                "GdtfError: Attribute {} is not predefined in the GDTF specification and must be added with it's AttributeDefinitions",
+                attribute
+            ),
+            GdtfError::BuilderChannelSetDmxFromError(path, reason) => {
+                write!(f, "GdtfError: ChannelSet {}: {}", path, reason)
+            }
+            GdtfError::PhysicalDescriptionsLinkNotFoundError(path, attribute, link) => write!(
+                f,
+                "GdtfError: {} of {} links to {} that is not in the physical descriptions",
+                attribute, path, link
+            ),
+            GdtfError::LibraryScanError(message) => write!(
+                f,
+                "GdtfError: Scanning the fixture library panicked: {}",
+                message
+            ),
+            GdtfError::ParallelDeparseError(message) => write!(
+                f,
+                "GdtfError: Deparsing the DMX modes in parallel panicked: {}",
+                message
+            ),
+            #[cfg(feature = "cache")]
+            GdtfError::CacheError(e) => write!(f, "GdtfError: {}", e),
+            GdtfError::LocatedError(error, location) => write!(f, "{} in {}", error, location),
+            GdtfError::StrictParseError(warnings) => write!(
+                f,
+                "GdtfError: Deparsing in strict mode failed: {}",
+                warnings
+                    .iter()
+                    .map(|warning| warning.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
         }
     }
 }
 
+impl From<GdtfMatrixError> for GdtfError {
+    fn from(e: GdtfMatrixError) -> Self {
+        GdtfError::GdtfMatrixError(e)
+    }
+}
+
 impl From<GdtfGuidError> for GdtfError {
     fn from(e: GdtfGuidError) -> Self {
         GdtfError::GuidError(e)
@@ -152,4 +404,104 @@ impl From<std::io::Error> for GdtfError {
     }
 }
 
-impl Error for GdtfError {}
+#[cfg(feature = "cache")]
+impl From<GdtfCacheError> for GdtfError {
+    fn from(e: GdtfCacheError) -> Self {
+        GdtfError::CacheError(e)
+    }
+}
+
+impl Error for GdtfError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            GdtfError::LocatedError(error, _) => Some(error.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error;
+
+    use crate::utils::errors::GdtfError;
+    use crate::utils::units::node::GdtfNodeError;
+    use crate::Gdtf;
+
+    fn description_xml(dmx_channels: &str) -> String {
+        format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<GDTF DataVersion="1.1">
+  <FixtureType Name="LED PAR" ShortName="PAR" LongName="LED PAR" Manufacturer="Generic" Description="" FixtureTypeID="308EA87D-7164-42DE-8106-A6D273F57A51" Thumbnail="" RefFT="">
+    <AttributeDefinitions/>
+    <DMXModes>
+      <DMXMode Name="Mode 1" Geometry="Body">
+        <DMXChannels>
+{}
+        </DMXChannels>
+      </DMXMode>
+    </DMXModes>
+  </FixtureType>
+</GDTF>"#,
+            dmx_channels
+        )
+    }
+
+    #[test]
+    fn test_location() {
+        let error = Gdtf::from_description_xml(&description_xml(
+            r#"          <DMXChannel DMXBreak="1" Offset="1" Geometry="Body"/>
+          <DMXChannel DMXBreak="1" Offset="2" Geometry="Body{"/>"#,
+        ))
+        .unwrap_err();
+        assert!(matches!(error.without_location(), GdtfError::NameError(_)));
+        let location = error.location().unwrap();
+        assert_eq!(
+            location.path,
+            "GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/DMXChannels/DMXChannel[2]"
+        );
+        assert_eq!(
+            location.attribute,
+            Some(("Geometry".to_string(), "Body{".to_string()))
+        );
+        assert_eq!((location.line, location.column), (9, 11));
+        assert!(error.to_string().ends_with(
+            r#" in GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/DMXChannels/DMXChannel[2] Geometry="Body{" at line 9, column 11"#
+        ));
+
+        let error = Gdtf::from_description_xml(&description_xml(
+            r#"          <DMXChannel DMXBreak="1" Offset="1" Geometry="Body">
+            <LogicalChannel Attribute="Dimmer">
+              <ChannelFunction Name="Dimmer" Attribute="Dimmer" DMXFrom="0/1" Wheel="Gobo{"/>
+            </LogicalChannel>
+          </DMXChannel>"#,
+        ))
+        .unwrap_err();
+        let location = error.location().unwrap();
+        assert_eq!(location.path, "GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/DMXChannels/DMXChannel[1]/LogicalChannel/ChannelFunction[Dimmer]");
+        assert_eq!(
+            location.attribute,
+            Some(("Wheel".to_string(), "Gobo{".to_string()))
+        );
+        assert_eq!((location.line, location.column), (10, 15));
+        assert!(matches!(
+            error.source().unwrap().downcast_ref::<GdtfError>(),
+            Some(GdtfError::GdtfNodeError(GdtfNodeError(value))) if value == "Gobo{"
+        ));
+
+        let error = Gdtf::from_description_xml(&description_xml(
+            r#"          <DMXChannel DMXBreak="1" Offset="1" Geometry="Body">
+          </LogicalChannel>"#,
+        ))
+        .unwrap_err();
+        assert!(matches!(
+            error.without_location(),
+            GdtfError::GdtfDeparseError(_)
+        ));
+        assert!(error
+            .location()
+            .unwrap()
+            .path
+            .ends_with("/DMXChannels/DMXChannel[1]"));
+    }
+}
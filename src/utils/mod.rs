@@ -1,6 +1,13 @@
 //! Contains different modules for utils and testing
+pub mod color;
 pub mod errors;
+pub(crate) mod pool;
+pub mod raw_xml;
 pub(crate) mod read;
+#[cfg(feature = "serde")]
+pub(crate) mod serde_str;
 #[cfg(test)]
 pub mod testdata;
 pub mod units;
+pub mod warnings;
+pub(crate) mod write;
@@ -0,0 +1,177 @@
+//! Worker threads shared by all parts of the crate that work in parallel
+//!
+//! The pool has one thread less than there are cores, because the thread that hands work to the pool works on it as well. Concurrent or nested callers share the same threads, so the crate never starts more threads than there are cores, and a caller never waits for work that no thread has started.
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::mpsc::{self, Sender};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard, OnceLock, PoisonError};
+use std::thread;
+use std::vec;
+
+type Job = Box<dyn FnOnce() + Send>;
+
+///Threads waiting for jobs that are sent through the sender
+struct Pool {
+    sender: Mutex<Sender<Job>>,
+    workers: usize,
+}
+
+static POOL: OnceLock<Pool> = OnceLock::new();
+
+///Returns the pool and starts it's threads on the first call
+fn pool() -> &'static Pool {
+    POOL.get_or_init(|| {
+        let cores = thread::available_parallelism().map_or(1, |n| n.get());
+        let (sender, receiver) = mpsc::channel::<Job>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers = (1..cores)
+            .filter(|i| {
+                let receiver = receiver.clone();
+                thread::Builder::new()
+                    .name(format!("gdtf-parser-{}", i))
+                    .spawn(move || loop {
+                        let job = match lock(&receiver).recv() {
+                            Ok(job) => job,
+                            Err(_) => return,
+                        };
+                        job();
+                    })
+                    .is_ok()
+            })
+            .count();
+        Pool {
+            sender: Mutex::new(sender),
+            workers,
+        }
+    })
+}
+
+///Locks the mutex. A panic while it was locked is caught by `map` and reported there, so the data is still used
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+///Items of one `map` call, shared by the calling thread and the threads of the pool that help with it
+struct Batch<T, R, F> {
+    items: Mutex<vec::IntoIter<(usize, T)>>,
+    results: Mutex<Vec<Option<thread::Result<R>>>>,
+    ///Count of items without result
+    pending: Mutex<usize>,
+    done: Condvar,
+    f: F,
+}
+
+impl<T, R, F: Fn(T) -> R> Batch<T, R, F> {
+    ///Takes items until all are taken
+    fn work(&self) {
+        loop {
+            let (index, item) = match lock(&self.items).next() {
+                Some(next) => next,
+                None => return,
+            };
+            let result = panic::catch_unwind(AssertUnwindSafe(|| (self.f)(item)));
+            lock(&self.results)[index] = Some(result);
+            let mut pending = lock(&self.pending);
+            *pending -= 1;
+            if *pending == 0 {
+                self.done.notify_all();
+            }
+        }
+    }
+}
+
+///Calls f with every item on the calling thread and the free threads of the pool and returns the results in the order of the items. Returns the message of the panic if f panicked for an item
+pub(crate) fn map<T, R, F>(items: Vec<T>, f: F) -> Result<Vec<R>, String>
+where
+    T: Send + 'static,
+    R: Send + 'static,
+    F: Fn(T) -> R + Send + Sync + 'static,
+{
+    let count = items.len();
+    let batch = Arc::new(Batch {
+        items: Mutex::new(
+            items
+                .into_iter()
+                .enumerate()
+                .collect::<Vec<_>>()
+                .into_iter(),
+        ),
+        results: Mutex::new((0..count).map(|_| None).collect()),
+        pending: Mutex::new(count),
+        done: Condvar::new(),
+        f,
+    });
+    let pool = pool();
+    for _ in 0..pool.workers.min(count.saturating_sub(1)) {
+        let batch = batch.clone();
+        //The pool lives as long as the process, so sending can't fail
+        let _ = lock(&pool.sender).send(Box::new(move || batch.work()));
+    }
+    batch.work();
+    let mut pending = lock(&batch.pending);
+    while *pending > 0 {
+        pending = batch
+            .done
+            .wait(pending)
+            .unwrap_or_else(PoisonError::into_inner);
+    }
+    drop(pending);
+
+    let results = std::mem::take(&mut *lock(&batch.results));
+    results
+        .into_iter()
+        .map(|result| match result {
+            Some(Ok(result)) => Ok(result),
+            Some(Err(panic)) => Err(panic_message(panic)),
+            None => Err("Item was not processed".to_string()),
+        })
+        .collect()
+}
+
+///Returns the message of a panic
+fn panic_message(panic: Box<dyn Any + Send>) -> String {
+    match panic.downcast::<String>() {
+        Ok(message) => *message,
+        Err(panic) => match panic.downcast::<&str>() {
+            Ok(message) => message.to_string(),
+            Err(_) => "Unknown panic".to_string(),
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use crate::utils::pool;
+
+    #[test]
+    fn test_map() {
+        let results = pool::map((0..100).collect(), |i: usize| i * 2).unwrap();
+        assert_eq!(results, (0..100).map(|i| i * 2).collect::<Vec<usize>>());
+        assert!(pool::map(Vec::new(), |i: usize| i).unwrap().is_empty());
+
+        let called = Arc::new(AtomicUsize::new(0));
+        let counter = called.clone();
+        let panicked = pool::map((0..10).collect(), move |i: usize| {
+            counter.fetch_add(1, Ordering::Relaxed);
+            if i == 5 {
+                panic!("Item {} panicked", i);
+            }
+            i
+        });
+        assert_eq!(panicked, Err("Item 5 panicked".to_string()));
+        assert_eq!(called.load(Ordering::Relaxed), 10);
+
+        //Nested calls don't wait for threads that are busy with the outer call
+        let nested = pool::map((0..8).collect(), |i: usize| {
+            pool::map((0..8).collect(), move |j: usize| i * j)
+                .unwrap()
+                .into_iter()
+                .sum::<usize>()
+        })
+        .unwrap();
+        assert_eq!(nested, (0..8).map(|i| i * 28).collect::<Vec<usize>>());
+    }
+}
@@ -0,0 +1,181 @@
+//! Module for nodes of the description.xml that are not deparsed but kept as xml, so they can be written again
+use std::io::Write;
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::{Reader, Writer};
+
+use crate::utils::errors::GdtfError;
+use crate::utils::read::GdtfReadError;
+use crate::utils::warnings;
+
+///A node and all it's descendants as they were read from the description.xml. Attribute values and texts are kept escaped, whitespace between the nodes is dropped
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RawXml(String);
+
+impl RawXml {
+    ///Reads the first node of the xml together with all it's descendants, e.g. to pass geometries to `FixtureTypeBuilder::geometries`. Returns an error if the xml contains no node or ends before the end of the node
+    pub fn new_from_str(xml: &str) -> Result<Self, GdtfError> {
+        let mut reader = Reader::from_str(xml);
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) => return Self::read_from_event(&mut reader, e.into_owned(), true),
+                Event::Empty(e) => {
+                    return Self::read_from_event(&mut reader, e.into_owned(), false)
+                }
+                Event::Eof => {
+                    return Err(GdtfReadError::new_xml_node_not_found(b"xml", b"any").into())
+                }
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+
+    ///Returns the xml of the node
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    ///Returns the unescaped values of an attribute of all nodes with the given name in the order they appear. Nodes without the attribute are skipped
+    pub(crate) fn attribute_values(
+        &self,
+        node_name: &[u8],
+        attribute: &[u8],
+    ) -> Result<Vec<String>, GdtfError> {
+        let mut reader = Reader::from_str(&self.0);
+        let mut buf: Vec<u8> = Vec::new();
+        let mut values = Vec::new();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) | Event::Empty(e) if e.name() == node_name => {
+                    for attr in e.attributes() {
+                        let attr = attr?;
+                        if attr.key == attribute {
+                            values.push(
+                                String::from_utf8(attr.unescaped_value()?.to_vec())
+                                    .map_err(|e| e.utf8_error())?,
+                            );
+                        }
+                    }
+                }
+                Event::Eof => return Ok(values),
+                _ => {}
+            }
+            buf.clear();
+        }
+    }
+
+    ///Reads the node that triggered the event together with all it's descendants. If has_children is true, the reader is moved to the end of the node. Returns an error if the xml ends before the end of the node
+    pub(crate) fn read_from_event(
+        reader: &mut Reader<&[u8]>,
+        event: BytesStart<'_>,
+        has_children: bool,
+    ) -> Result<Self, GdtfError> {
+        warnings::read_node();
+        let mut writer = Writer::new(Vec::new());
+        if !has_children {
+            writer.write_event(Event::Empty(event))?;
+            return Ok(Self(
+                String::from_utf8(writer.into_inner()).map_err(|e| e.utf8_error())?,
+            ));
+        }
+        let node_name = event.name().to_vec();
+        writer.write_event(Event::Start(event))?;
+        let mut buf: Vec<u8> = Vec::new();
+        let mut depth: usize = 0;
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) => {
+                    depth += 1;
+                    writer.write_event(Event::Start(e))?;
+                }
+                Event::End(e) => {
+                    writer.write_event(Event::End(e))?;
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                }
+                Event::Text(e) if e.iter().all(u8::is_ascii_whitespace) => {}
+                Event::Eof => return Err(GdtfReadError::new_xml_unexpected_eof(&node_name).into()),
+                e => writer.write_event(e)?,
+            }
+            buf.clear();
+        }
+        Ok(Self(
+            String::from_utf8(writer.into_inner()).map_err(|e| e.utf8_error())?,
+        ))
+    }
+
+    ///Writes the node with all it's descendants, indented like the nodes around it
+    pub(crate) fn write<W: Write>(&self, writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        let mut reader = Reader::from_str(&self.0);
+        let mut buf: Vec<u8> = Vec::new();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Text(e) if e.is_empty() => {}
+                Event::Eof => return Ok(()),
+                event => writer.write_event(event)?,
+            }
+            buf.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use quick_xml::Writer;
+
+    use crate::utils::errors::GdtfError;
+    use crate::utils::raw_xml::RawXml;
+    use crate::utils::read::GdtfReadError;
+
+    fn read(xml: &str) -> RawXml {
+        RawXml::new_from_str(xml).unwrap()
+    }
+
+    #[test]
+    fn test_read_write() {
+        let raw = read(
+            r#"<Geometries>
+  <Geometry Name="Base" Model="Base &amp; Yoke">
+    <Geometry Name="Yoke"/>
+  </Geometry>
+  <Geometry Name="Beam">Text</Geometry>
+</Geometries>
+<DMXModes/>"#,
+        );
+        assert_eq!(
+            raw.as_str(),
+            r#"<Geometries><Geometry Name="Base" Model="Base &amp; Yoke"><Geometry Name="Yoke"/></Geometry><Geometry Name="Beam">Text</Geometry></Geometries>"#
+        );
+        assert_eq!(read("<Protocols/>").as_str(), "<Protocols/>");
+        assert_eq!(
+            raw.attribute_values(b"Geometry", b"Model").unwrap(),
+            vec!["Base & Yoke".to_string()]
+        );
+
+        let mut writer = Writer::new(Vec::new());
+        raw.write(&mut writer).unwrap();
+        assert_eq!(
+            read(std::str::from_utf8(&writer.into_inner()).unwrap()),
+            raw
+        );
+    }
+
+    #[test]
+    fn test_read_truncated() {
+        assert!(matches!(
+            RawXml::new_from_str(r#"<Geometries><Geometry Name="Base"><Geometry Name="Yoke"/>"#),
+            Err(GdtfError::GdtfDeparseError(GdtfReadError::QuickXmlUnexpectedEofError(node))) if node == "Geometries"
+        ));
+        assert!(matches!(
+            RawXml::new_from_str("<!-- no node -->"),
+            Err(GdtfError::GdtfDeparseError(
+                GdtfReadError::QuickXmlNodeNotFoundError(_, _)
+            ))
+        ));
+    }
+}
@@ -5,22 +5,31 @@ use std::fmt;
 ///! Module contains traits that can be implemented to simpler deparse structs from quick-xml without serde to have full control of the flow
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::Hash;
+use std::io::{Read, Seek};
 use std::str::FromStr;
 
+use indexmap::IndexMap;
 use quick_xml::events::attributes::Attribute;
 use quick_xml::events::BytesStart;
 use quick_xml::events::Event;
 use quick_xml::Reader;
 
 use crate::utils::errors::GdtfError;
+use crate::utils::units::attribute_name::AttributeName;
+use crate::utils::units::name::Name;
+use crate::utils::warnings;
 
 ///Trait to deparse an xml-node to a struct
 pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
     ///The primary-key of the struct if used in a hash-map or () if no primary key present
     /// A PrimaryKey should be unique across all xml-nodes of the same type in one GDTF file
-    type PrimaryKey: Eq + Hash + Debug + Clone;
+    type PrimaryKey: Eq + Hash + Debug + Clone + RenameDuplicate;
     ///Type of error returned in case of failure on deparse
-    type Error: From<GdtfReadError> + From<quick_xml::Error> + std::error::Error;
+    type Error: From<GdtfReadError>
+        + From<quick_xml::Error>
+        + From<GdtfError>
+        + Into<GdtfError>
+        + std::error::Error;
     ///The struct type to hold data temprary during deparse. Can be the struct that implements `ReadGdtf` itself or a similar struct with more `Option` fields
     type DataHolder: Default;
     ///The name of the node that contains the data for the struct. Declare it as b"GDTF" for example.
@@ -34,7 +43,7 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
 
     /// Is called when an attribute is found in the xml tree. Usually this method contains a match statement that checks attr.key.
     ///
-    /// ⚠️**Be aware that when returning an Error, the whole GDTF-Deparsing will fail!** ⚠️
+    /// ⚠️**Be aware that when returning an Error, the whole GDTF-Deparsing will fail!** ⚠️ If a default can be used instead, report it with `warnings::substituted` or `warnings::dropped` so it shows up in `ParseMode::Lenient`
     ///
     fn read_any_attribute(
         data_holder: &mut Self::DataHolder,
@@ -77,15 +86,14 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
     }
 
     ///Returns NODE_NAME as String
+    #[cfg(test)]
     fn node_name() -> String {
-        std::str::from_utf8(Self::NODE_NAME).unwrap().to_string()
+        String::from_utf8_lossy(Self::NODE_NAME).to_string()
     }
 
     ///Returns PARENT_NODE_NAME as String
     fn parent_node_name() -> String {
-        std::str::from_utf8(Self::PARENT_NODE_NAME)
-            .unwrap()
-            .to_string()
+        String::from_utf8_lossy(Self::PARENT_NODE_NAME).to_string()
     }
 
     /// When a gdtf is deparsed it will go down the tree if a event hits and returns when end of the Node from the event is detected.
@@ -105,43 +113,148 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
         event: BytesStart<'_>,
         has_children: bool,
     ) -> Result<(Option<Self::PrimaryKey>, Self), Self::Error> {
+        let position = warnings::event_start(reader.buffer_position(), &event, has_children);
+        let _node = warnings::enter_node(Self::NODE_NAME, position);
+        let locate = |error: Self::Error, attr: Option<&Attribute<'_>>, position: usize| {
+            Self::locate_error(error, &event, attr, position)
+        };
         let mut data_holder: Self::DataHolder = Default::default();
         let mut primary_key = None;
         for attr in event.attributes().into_iter() {
-            let attr = attr?;
+            let attr = attr.map_err(|e| locate(e.into(), None, position))?;
+            if Self::is_name_attribute(attr.key) {
+                warnings::name(&attr);
+            }
             if attr.key == Self::PRIMARY_KEY_NAME {
-                primary_key = Self::read_primary_key_from_attr(attr)?;
+                primary_key = Self::read_primary_key_from_attr(attr.clone())
+                    .map_err(|e| locate(e, Some(&attr), position))?;
             } else {
-                Self::read_any_attribute(&mut data_holder, attr)?;
+                warnings::attribute(Some(&attr));
+                Self::read_any_attribute(&mut data_holder, attr.clone())
+                    .map_err(|e| locate(e, Some(&attr), position))?;
             }
         }
+        warnings::attribute(None);
         if has_children {
             let mut buf: Vec<u8> = Vec::new();
+            //Depth inside children that were not read by read_any_child. Their descendants are not reported again
+            let mut skipped_depth: usize = 0;
             loop {
-                match reader
-                    .read_event(&mut buf)
-                    .map_err(GdtfReadError::QuickXmlError)?
-                {
+                match reader.read_event(&mut buf).map_err(|e| {
+                    locate(
+                        GdtfReadError::QuickXmlError(e).into(),
+                        None,
+                        reader.buffer_position(),
+                    )
+                })? {
                     Event::Start(e) => {
-                        Self::read_any_child(&mut data_holder, reader, e, true)?;
+                        let child = Self::unread_child(reader, &e, true);
+                        let child_position =
+                            warnings::event_start(reader.buffer_position(), &e, true);
+                        Self::read_any_child(&mut data_holder, reader, e, true)
+                            .map_err(|e| locate(e, None, child_position))?;
+                        Self::report_unread_child(child, &mut skipped_depth, true);
                     }
                     Event::Empty(e) => {
-                        Self::read_any_child(&mut data_holder, reader, e, false)?;
+                        let child = Self::unread_child(reader, &e, false);
+                        let child_position =
+                            warnings::event_start(reader.buffer_position(), &e, false);
+                        Self::read_any_child(&mut data_holder, reader, e, false)
+                            .map_err(|e| locate(e, None, child_position))?;
+                        Self::report_unread_child(child, &mut skipped_depth, false);
                     }
                     Event::End(e) => {
                         if e.name() == Self::NODE_NAME {
                             break;
                         }
+                        skipped_depth = skipped_depth.saturating_sub(1);
                     }
                     Event::Eof => {
-                        break;
+                        return Err(GdtfReadError::new_xml_unexpected_eof(Self::NODE_NAME).into());
                     }
                     _ => {}
                 }
             }
         }
 
-        Ok((primary_key, Self::move_data(data_holder)?))
+        Ok((
+            primary_key,
+            Self::move_data(data_holder).map_err(|e| locate(e, None, position))?,
+        ))
+    }
+
+    /// True if the attribute holds the name of the node shown in paths of errors and warnings. This is the primary key or the attribute Name if the node has no primary key
+    fn is_name_attribute(key: &[u8]) -> bool {
+        key == Self::PRIMARY_KEY_NAME || (Self::PRIMARY_KEY_NAME.is_empty() && key == b"Name")
+    }
+
+    /// Adds the path of the node from the event and the position in the xml to an error that occurred while deparsing the node or one of it's children
+    fn locate_error(
+        error: Self::Error,
+        event: &BytesStart<'_>,
+        attr: Option<&Attribute<'_>>,
+        position: usize,
+    ) -> Self::Error {
+        let mut node = String::from_utf8_lossy(Self::NODE_NAME).to_string();
+        if let Some(Ok(name)) = event
+            .attributes()
+            .find(|a| a.as_ref().is_ok_and(|a| Self::is_name_attribute(a.key)))
+        {
+            node = format!("{}[{}]", node, String::from_utf8_lossy(&name.value));
+        }
+        let attr = attr.map(|attr| {
+            (
+                String::from_utf8_lossy(attr.key).to_string(),
+                String::from_utf8_lossy(&attr.value).to_string(),
+            )
+        });
+        let error: GdtfError = error.into();
+        error.locate(node, attr, position).into()
+    }
+
+    /// Adds `PARENT_NODE_NAME` to the path of an error that occurred while deparsing the children of the parent node at position
+    fn locate_in_parent(error: Self::Error, position: usize) -> Self::Error {
+        let error: GdtfError = error.into();
+        error
+            .locate(Self::parent_node_name(), None, position)
+            .into()
+    }
+
+    /// Adds the position of the node in it's parent, starting at 1, to the path of an error that occurred while deparsing it
+    fn locate_index(error: Self::Error, index: usize) -> Self::Error {
+        let error: GdtfError = error.into();
+        error.locate_index(index).into()
+    }
+
+    /// Returns name, position and the count of nodes read so far for a child that is about to be passed to read_any_child. Returns None if no warnings are collected
+    fn unread_child(
+        reader: &Reader<&[u8]>,
+        event: &BytesStart<'_>,
+        has_children: bool,
+    ) -> Option<(Vec<u8>, usize, usize)> {
+        Some((
+            event.name().to_vec(),
+            warnings::event_start(reader.buffer_position(), event, has_children),
+            warnings::read_nodes()?,
+        ))
+    }
+
+    /// Reports the child returned by `unread_child` as skipped if read_any_child did not read any node. Descendants of skipped children are not reported
+    fn report_unread_child(
+        child: Option<(Vec<u8>, usize, usize)>,
+        skipped_depth: &mut usize,
+        has_children: bool,
+    ) {
+        if let Some((name, position, read_nodes)) = child {
+            if warnings::read_nodes() == Some(read_nodes) {
+                if *skipped_depth == 0 {
+                    warnings::skipped(&name, position);
+                }
+                if has_children {
+                    *skipped_depth += 1;
+                }
+            }
+        }
     }
 
     /// Returns the primary_key of the node if the node hase a primary_key, else it returns none
@@ -163,6 +276,7 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
     fn read_primary_key_from_event(
         event: BytesStart<'_>,
     ) -> Result<Option<Self::PrimaryKey>, Self::Error> {
+        warnings::read_node();
         for attr in event.attributes().into_iter() {
             let attr = attr?;
             if attr.key == Self::PRIMARY_KEY_NAME {
@@ -187,7 +301,7 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
     ///
     /// # Returns
     ///
-    /// * `HashMap` - The hashmap containing the structs with key as it's primary-key
+    /// * `HashMap` - The hashmap containing the structs with key as it's primary-key. Structs with a duplicate primary-key are kept under a renamed key, see `insert_unique`
     fn read_hash_map_from_event(
         reader: &mut Reader<&[u8]>,
         event: BytesStart<'_>,
@@ -196,44 +310,58 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
     where
         Self: Sized,
     {
+        let position = warnings::event_start(reader.buffer_position(), &event, has_children);
+        let _node = warnings::enter_node(Self::PARENT_NODE_NAME, position);
         if event.name() != Self::PARENT_NODE_NAME {
-            panic!("Wrong call of read_hash_map_from_event for node {}. This method can only be used if you have an empty {}. If this is not empty, fill the map manually in your read_one_child() entry by entry.", Self::node_name(), Self::parent_node_name());
+            return Err(Self::locate_in_parent(
+                GdtfReadError::new_xml_unexpected_node(Self::PARENT_NODE_NAME, event.name()).into(),
+                position,
+            ));
         }
         let mut buf: Vec<u8> = Vec::new();
         let mut out: HashMap<Self::PrimaryKey, Self> = HashMap::new();
 
         if has_children {
             loop {
-                match reader
-                    .read_event(&mut buf)
-                    .map_err(GdtfReadError::QuickXmlError)?
-                {
+                match reader.read_event(&mut buf).map_err(|e| {
+                    Self::locate_in_parent(GdtfReadError::QuickXmlError(e).into(), position)
+                })? {
                     Event::Start(e) => {
                         if e.name() == Self::NODE_NAME {
-                            let val = Self::read_single_from_event(reader, e, true)?;
-                            out.insert(
-                                val.0.ok_or_else(|| {
+                            let child_position =
+                                warnings::event_start(reader.buffer_position(), &e, true);
+                            let val = Self::read_single_from_event(reader, e, true)
+                                .map_err(|e| Self::locate_in_parent(e, position))?;
+                            let key = val.0.ok_or_else(|| {
+                                Self::locate_in_parent(
                                     Self::child_primary_key_not_found(
                                         Self::NODE_NAME,
                                         Self::PRIMARY_KEY_NAME,
                                     )
-                                })?,
-                                val.1,
-                            );
+                                    .into(),
+                                    position,
+                                )
+                            })?;
+                            insert_unique(&mut out, Self::NODE_NAME, child_position, key, val.1);
                         }
                     }
                     Event::Empty(e) => {
                         if e.name() == Self::NODE_NAME {
-                            let val = Self::read_single_from_event(reader, e, false)?;
-                            out.insert(
-                                val.0.ok_or_else(|| {
+                            let child_position =
+                                warnings::event_start(reader.buffer_position(), &e, false);
+                            let val = Self::read_single_from_event(reader, e, false)
+                                .map_err(|e| Self::locate_in_parent(e, position))?;
+                            let key = val.0.ok_or_else(|| {
+                                Self::locate_in_parent(
                                     Self::child_primary_key_not_found(
                                         Self::NODE_NAME,
                                         Self::PRIMARY_KEY_NAME,
                                     )
-                                })?,
-                                val.1,
-                            );
+                                    .into(),
+                                    position,
+                                )
+                            })?;
+                            insert_unique(&mut out, Self::NODE_NAME, child_position, key, val.1);
                         }
                     }
                     Event::End(e) => {
@@ -242,7 +370,9 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
                         }
                     }
                     Event::Eof => {
-                        break;
+                        return Err(
+                            GdtfReadError::new_xml_unexpected_eof(Self::PARENT_NODE_NAME).into(),
+                        );
                     }
                     _ => {}
                 }
@@ -273,26 +403,52 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
     where
         Self: Sized,
     {
+        let position = warnings::event_start(reader.buffer_position(), &event, has_children);
+        let _node = warnings::enter_node(Self::PARENT_NODE_NAME, position);
         if event.name() != Self::PARENT_NODE_NAME {
-            panic!("Wrong call of read_vec_from_event for node {}. This method can only be used if you have an empty {}. If this is not empty, fill the vec manually in your read_one_child() entry by entry.", Self::node_name(), Self::parent_node_name());
+            return Err(Self::locate_in_parent(
+                GdtfReadError::new_xml_unexpected_node(Self::PARENT_NODE_NAME, event.name()).into(),
+                position,
+            ));
         }
 
         let mut buf: Vec<u8> = Vec::new();
         let mut out: Vec<Self> = Vec::new();
         if has_children {
             loop {
-                match reader
-                    .read_event(&mut buf)
-                    .map_err(GdtfReadError::QuickXmlError)?
-                {
+                match reader.read_event(&mut buf).map_err(|e| {
+                    Self::locate_in_parent(GdtfReadError::QuickXmlError(e).into(), position)
+                })? {
                     Event::Start(e) => {
                         if e.name() == Self::NODE_NAME {
-                            out.push(Self::read_single_from_event(reader, e, true)?.1);
+                            let index = out.len() + 1;
+                            warnings::index(index);
+                            out.push(
+                                Self::read_single_from_event(reader, e, true)
+                                    .map_err(|e| {
+                                        Self::locate_in_parent(
+                                            Self::locate_index(e, index),
+                                            position,
+                                        )
+                                    })?
+                                    .1,
+                            );
                         }
                     }
                     Event::Empty(e) => {
                         if e.name() == Self::NODE_NAME {
-                            out.push(Self::read_single_from_event(reader, e, false)?.1);
+                            let index = out.len() + 1;
+                            warnings::index(index);
+                            out.push(
+                                Self::read_single_from_event(reader, e, false)
+                                    .map_err(|e| {
+                                        Self::locate_in_parent(
+                                            Self::locate_index(e, index),
+                                            position,
+                                        )
+                                    })?
+                                    .1,
+                            );
                         }
                     }
                     Event::End(e) => {
@@ -301,7 +457,9 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
                         }
                     }
                     Event::Eof => {
-                        break;
+                        return Err(
+                            GdtfReadError::new_xml_unexpected_eof(Self::PARENT_NODE_NAME).into(),
+                        );
                     }
                     _ => {}
                 }
@@ -332,26 +490,37 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
     where
         Self: Sized,
     {
+        let position = warnings::event_start(reader.buffer_position(), &event, has_children);
+        let _node = warnings::enter_node(Self::PARENT_NODE_NAME, position);
         if event.name() != Self::PARENT_NODE_NAME {
-            panic!("Wrong call of read_vec_from_event for node {}. This method can only be used if you have an empty {}. If this is not empty, fill the vec manually in your read_one_child() entry by entry.", Self::node_name(), Self::parent_node_name());
+            return Err(Self::locate_in_parent(
+                GdtfReadError::new_xml_unexpected_node(Self::PARENT_NODE_NAME, event.name()).into(),
+                position,
+            ));
         }
 
         let mut buf: Vec<u8> = Vec::new();
         let mut out: Vec<Self::PrimaryKey> = Vec::new();
         if has_children {
             loop {
-                match reader
-                    .read_event(&mut buf)
-                    .map_err(GdtfReadError::QuickXmlError)?
-                {
+                match reader.read_event(&mut buf).map_err(|e| {
+                    Self::locate_in_parent(GdtfReadError::QuickXmlError(e).into(), position)
+                })? {
                     Event::Start(e) | Event::Empty(e) => {
                         if e.name() == Self::NODE_NAME {
-                            out.push(Self::read_primary_key_from_event(e)?.ok_or_else(|| {
-                                GdtfReadError::new_xml_attribute_not_found(
-                                    Self::NODE_NAME,
-                                    Self::PRIMARY_KEY_NAME,
-                                )
-                            })?);
+                            out.push(
+                                Self::read_primary_key_from_event(e)
+                                    .and_then(|primary_key| {
+                                        primary_key.ok_or_else(|| {
+                                            GdtfReadError::new_xml_attribute_not_found(
+                                                Self::NODE_NAME,
+                                                Self::PRIMARY_KEY_NAME,
+                                            )
+                                            .into()
+                                        })
+                                    })
+                                    .map_err(|e| Self::locate_in_parent(e, position))?,
+                            );
                         }
                     }
                     Event::End(e) => {
@@ -360,7 +529,9 @@ pub(crate) trait ReadGdtf: std::fmt::Debug + Sized + PartialEq {
                         }
                     }
                     Event::Eof => {
-                        break;
+                        return Err(
+                            GdtfReadError::new_xml_unexpected_eof(Self::PARENT_NODE_NAME).into(),
+                        );
                     }
                     _ => {}
                 }
@@ -404,7 +575,16 @@ pub(crate) trait TestReadGdtf: ReadGdtf {
         }
         let mut map: HashMap<Self::PrimaryKey, Self> = Default::default();
         for tup in Self::testdatas().into_iter() {
-            map.insert(tup.0.unwrap(), tup.1.unwrap());
+            insert_unique(&mut map, Self::NODE_NAME, 0, tup.0.unwrap(), tup.1.unwrap());
+        }
+        map
+    }
+
+    /// Like `testdata_hash_map` but keeps the testvalues in the order they are returned by `testdatas()`, for nodes that are stored in an index-map
+    fn testdata_index_map() -> IndexMap<Self::PrimaryKey, Self> {
+        let mut map: IndexMap<Self::PrimaryKey, Self> = Default::default();
+        for tup in Self::testdatas().into_iter() {
+            insert_unique(&mut map, Self::NODE_NAME, 0, tup.0.unwrap(), tup.1.unwrap());
         }
         map
     }
@@ -851,6 +1031,10 @@ pub enum GdtfReadError {
     QuickXmlNodeNotFoundError(String, String),
     ///Error when an expected xml-attribute was not found
     QuickXmlAttributeNotFoundError(String, String),
+    ///Error when a list was read from an xml-node with an unexpected name
+    QuickXmlUnexpectedNodeError(String, String),
+    ///Error when the xml ended before the end of an xml-node
+    QuickXmlUnexpectedEofError(String),
 }
 
 impl GdtfReadError {
@@ -869,6 +1053,19 @@ impl GdtfReadError {
             u8_array_to_string(attribute_name),
         )
     }
+
+    ///Constructor for `QuickXmlUnexpectedEofError`
+    pub fn new_xml_unexpected_eof(node_name: &[u8]) -> Self {
+        Self::QuickXmlUnexpectedEofError(u8_array_to_string(node_name))
+    }
+
+    ///Constructor for `QuickXmlUnexpectedNodeError`
+    pub fn new_xml_unexpected_node(expected_node_name: &[u8], node_name: &[u8]) -> Self {
+        Self::QuickXmlUnexpectedNodeError(
+            u8_array_to_string(expected_node_name),
+            u8_array_to_string(node_name),
+        )
+    }
 }
 
 impl Display for GdtfReadError {
@@ -885,12 +1082,134 @@ impl Display for GdtfReadError {
                 "Could not find xml-node name '{}' in '{}'",
                 node_name, parent_node_name
             ),
+            GdtfReadError::QuickXmlUnexpectedNodeError(expected_node_name, node_name) => write!(
+                f,
+                "Expected xml-node '{}' but found '{}'",
+                expected_node_name, node_name
+            ),
+            GdtfReadError::QuickXmlUnexpectedEofError(node_name) => write!(
+                f,
+                "The xml ended before the end of xml-node '{}'",
+                node_name
+            ),
         }
     }
 }
 
 impl Error for GdtfReadError {}
 
+///Primary key that can be renamed if siblings share the same key
+pub(crate) trait RenameDuplicate: Sized {
+    ///Returns the key with the count of it's occurrence appended, e.g. `Open {2}`. Braces are not allowed in a GDTF Name, so a renamed key can't collide with a name from the description.xml
+    fn renamed(&self, count: usize) -> Self;
+
+    ///Returns the key as it is written in the description.xml
+    fn to_key_string(&self) -> String;
+}
+
+impl RenameDuplicate for () {
+    fn renamed(&self, _: usize) -> Self {}
+
+    fn to_key_string(&self) -> String {
+        String::new()
+    }
+}
+
+impl RenameDuplicate for Name {
+    fn renamed(&self, count: usize) -> Self {
+        if self.0.is_empty() {
+            return Name::from(format!("{{{}}}", count));
+        }
+        Name::from(format!("{} {{{}}}", self.0, count))
+    }
+
+    fn to_key_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl RenameDuplicate for AttributeName {
+    fn renamed(&self, count: usize) -> Self {
+        AttributeName::UserDefined(Name::from(format!("{} {{{}}}", self, count)))
+    }
+
+    fn to_key_string(&self) -> String {
+        self.to_string()
+    }
+}
+
+///Map that holds the deparsed children of a node by their primary-key. Children with a key that is already taken are inserted by `insert_unique` under a renamed key like `Open {2}`, which is outside of the chars allowed for a Name in the description.xml, see `original_key`
+pub(crate) trait ChildMap<K, V> {
+    ///Returns true if a child with the key is already present
+    fn contains_child(&self, key: &K) -> bool;
+
+    ///Inserts a child with a key that is not present yet
+    fn insert_child(&mut self, key: K, value: V);
+}
+
+impl<K: Eq + Hash, V> ChildMap<K, V> for HashMap<K, V> {
+    fn contains_child(&self, key: &K) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert_child(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+///Keeps the children in the order they appear in the description.xml
+impl<K: Eq + Hash, V> ChildMap<K, V> for IndexMap<K, V> {
+    fn contains_child(&self, key: &K) -> bool {
+        self.contains_key(key)
+    }
+
+    fn insert_child(&mut self, key: K, value: V) {
+        self.insert(key, value);
+    }
+}
+
+///Inserts a deparsed child into the map of it's parent. If the key is already taken, the child is kept under a renamed key and reported as duplicate, so no child is lost
+pub(crate) fn insert_unique<K, V, M>(
+    map: &mut M,
+    node_name: &[u8],
+    position: usize,
+    key: K,
+    value: V,
+) where
+    K: RenameDuplicate,
+    M: ChildMap<K, V>,
+{
+    if !map.contains_child(&key) {
+        map.insert_child(key, value);
+        return;
+    }
+    let mut count = 2;
+    let mut renamed = key.renamed(count);
+    while map.contains_child(&renamed) {
+        count += 1;
+        renamed = key.renamed(count);
+    }
+    let key = key.to_key_string();
+    //Nodes without a name are valid, so only named duplicates are reported
+    if !key.is_empty() {
+        warnings::duplicate(node_name, position, &key, &renamed.to_key_string());
+    }
+    map.insert_child(renamed, value);
+}
+
+///Returns the key as it was written in the description.xml if it was renamed by `insert_unique`, e.g. `Open` for `Open {2}`, else None
+pub(crate) fn original_key(key: &str) -> Option<&str> {
+    let count = key.strip_suffix('}')?;
+    let start = count.rfind('{')?;
+    if start + 1 == count.len() || !count[start + 1..].bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    match start {
+        0 => Some(""),
+        _ => count[..start].strip_suffix(' '),
+    }
+}
+
 ///Parses an xml-attribute to str but returns "" if any error occurs
 pub(crate) fn attr_to_str<'a>(attr: &'a Attribute) -> &'a str {
     std::str::from_utf8(attr.value.borrow()).unwrap_or("")
@@ -903,14 +1222,20 @@ pub(crate) fn attr_try_to_str<'a>(attr: &'a Attribute) -> Result<&'a str, GdtfEr
 
 ///Parses an xml-attribute to f32 but returns 0 if any error occurs
 pub(crate) fn attr_to_f32(attr: Attribute) -> f32 {
-    f32::from_str(attr_try_to_str(&attr).unwrap_or("")).unwrap_or(0_f32)
+    f32::from_str(attr_try_to_str(&attr).unwrap_or("")).unwrap_or_else(|_| {
+        warnings::substituted(0);
+        0_f32
+    })
 }
 
 ///Parses an xml-attribute to f32 but returns None if any error occurs
 pub(crate) fn attr_to_f32_option(attr: Attribute) -> Option<f32> {
     match f32::from_str(attr_try_to_str(&attr).unwrap_or("")) {
         Ok(f) => Some(f),
-        Err(_) => None,
+        Err(_) => {
+            warnings::dropped();
+            None
+        }
     }
 }
 
@@ -931,11 +1256,44 @@ pub(crate) fn attr_to_string(attr: Attribute) -> String {
 pub(crate) fn attr_to_u8_option(attr: Attribute) -> Option<u8> {
     match u8::from_str(attr_try_to_str(&attr).unwrap_or("")) {
         Ok(f) => Some(f),
-        Err(_) => None,
+        Err(_) => {
+            warnings::dropped();
+            None
+        }
     }
 }
 
+///Extracts the content of the description.xml from a .gdtf archive
+pub(crate) fn description_xml_from_archive<R: Read + Seek>(reader: R) -> Result<String, GdtfError> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let mut description_xml = String::new();
+    archive
+        .by_name("description.xml")?
+        .read_to_string(&mut description_xml)?;
+    Ok(description_xml)
+}
+
 ///Helper function to create useful error messages
 fn u8_array_to_string(val: &[u8]) -> String {
     std::str::from_utf8(val).map_or("?".to_string(), |e| e.to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::read::{original_key, RenameDuplicate};
+    use crate::utils::units::name::Name;
+
+    #[test]
+    fn test_original_key() {
+        let renamed = Name::from("Open").renamed(2);
+        assert_eq!(original_key(renamed.as_str()), Some("Open"));
+        let renamed = Name::from("").renamed(12);
+        assert_eq!(original_key(renamed.as_str()), Some(""));
+        assert_eq!(original_key("Open  {3}"), Some("Open "));
+        assert_eq!(original_key("Open (2)"), None);
+        assert_eq!(original_key("Open{2}"), None);
+        assert_eq!(original_key("Open {}"), None);
+        assert_eq!(original_key("Open {x}"), None);
+        assert_eq!(original_key("Open"), None);
+    }
+}
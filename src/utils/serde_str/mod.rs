@@ -0,0 +1,38 @@
+//! Module contains helpers to serialise units with serde in the same string-format they have in the gdtf-xml-description, for example `"255/1s"` for a DmxValue
+use std::fmt;
+use std::fmt::Display;
+use std::marker::PhantomData;
+
+use serde::de::{Error, Visitor};
+use serde::Deserializer;
+
+///Deserialises a str and converts it with the given function that is also used to parse the value from the gdtf-xml-description. Errors of the function are returned as custom errors of the deserializer
+///
+///The str is borrowed from the input if the deserializer supports it, so no String is allocated for every value
+pub(crate) fn deserialize<'de, D, T, E, F>(deserializer: D, from_str: F) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    E: Display,
+    F: FnOnce(&str) -> Result<T, E>,
+{
+    deserializer.deserialize_str(StrVisitor(from_str, PhantomData))
+}
+
+///Visitor that converts any str with the function it contains
+struct StrVisitor<F, T>(F, PhantomData<T>);
+
+impl<'de, T, E, F> Visitor<'de> for StrVisitor<F, T>
+where
+    E: Display,
+    F: FnOnce(&str) -> Result<T, E>,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "a string in the format of the gdtf-xml-description")
+    }
+
+    fn visit_str<R: Error>(self, value: &str) -> Result<T, R> {
+        (self.0)(value).map_err(R::custom)
+    }
+}
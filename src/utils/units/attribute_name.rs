@@ -1,6 +1,8 @@
 //!AttributeName is a preferred Name for Attributes in GDTF Format.
 #![allow(non_camel_case_types)]
 
+use std::fmt;
+use std::fmt::{Display, Formatter};
 use std::str::FromStr;
 
 use lazy_static::lazy_static;
@@ -8,7 +10,10 @@ use quick_xml::events::attributes::Attribute;
 use regex::{Regex, RegexSet, SetMatches};
 
 use crate::utils::read;
+use crate::utils::units::color_cie::ColorCie;
 use crate::utils::units::name::{GdtfNameError, Name};
+use crate::utils::units::node::Node;
+use crate::utils::units::physical_unit::PhysicalUnit;
 
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
 ///AttributeName is an enum for preferred Names used in GDTF for Attributes. It contains an option UserDefined(Name) which can contain all other Names for Atttribute
@@ -548,7 +553,7 @@ pub enum AttributeName {
 ///```rust
 /// use gdtf_parser::utils::units::attribute_name::AttributeName;
 /// use gdtf_parser::utils::units::name::Name;
-/// assert_eq!(AttributeName::UserDefined(Name("".to_string())), Default::default())
+/// assert_eq!(AttributeName::UserDefined(Name::from("")), Default::default())
 ///```
 impl Default for AttributeName {
     fn default() -> Self {
@@ -564,7 +569,7 @@ impl AttributeName {
     /// assert_eq!(AttributeName::new_from_str("Tilt").unwrap(), AttributeName::Tilt);
     /// assert_eq!(AttributeName::new_from_str("PanTiltMode").unwrap(), AttributeName::PanTiltMode);
     /// assert_eq!(AttributeName::new_from_str("Effects1Adjust2").unwrap(), AttributeName::Effects_n_Adjust_m_(1,2));
-    /// assert_eq!(AttributeName::new_from_str("Something else").unwrap(), AttributeName::UserDefined(Name("Something else".to_string())));
+    /// assert_eq!(AttributeName::new_from_str("Something else").unwrap(), AttributeName::UserDefined(Name::from("Something else")));
     /// assert!(AttributeName::new_from_str("Name with invalid char {").is_err());
     /// assert!(AttributeName::new_from_str("Name with invalid char ȸ").is_err());
     ///```
@@ -850,9 +855,17 @@ impl AttributeName {
                         static ref RE2: Regex = Regex::new(r"\d{1,}").unwrap();
                     }
 
-                    let mut caps = RE2.captures_iter(&value);
-                    let n = caps.next().map_or(0_u8, |m| u8::from_str(&m[0]).unwrap());
-                    let m = caps.next().map_or(0_u8, |m| u8::from_str(&m[0]).unwrap());
+                    let numbers: Result<Vec<u8>, _> = RE2
+                        .find_iter(value)
+                        .map(|m| u8::from_str(m.as_str()))
+                        .collect();
+                    //Numbers that don't fit into the wildcard are kept as a user defined name
+                    let numbers = match numbers {
+                        Ok(numbers) => numbers,
+                        Err(_) => return Ok(UserDefined(Name::new(value)?)),
+                    };
+                    let n = numbers.first().copied().unwrap_or(0);
+                    let m = numbers.get(1).copied().unwrap_or(0);
 
                     if matches.matched(0) {
                         return Ok(Gobo_n_(n));
@@ -1158,12 +1171,932 @@ impl AttributeName {
     /// assert_eq!(AttributeName::new_from_attr(Attribute{ key: &[], value: Cow::Borrowed(b"Tilt")}).unwrap(), AttributeName::Tilt);
     /// assert_eq!(AttributeName::new_from_attr(Attribute{ key: &[], value: Cow::Borrowed(b"PanTiltMode")}).unwrap(), AttributeName::PanTiltMode);
     /// assert_eq!(AttributeName::new_from_attr(Attribute{ key: &[], value: Cow::Borrowed(b"Effects1Adjust2")}).unwrap(), AttributeName::Effects_n_Adjust_m_(1,2));
-    /// assert_eq!(AttributeName::new_from_attr(Attribute{ key: &[], value: Cow::Borrowed(b"Something else")}).unwrap(), AttributeName::UserDefined(Name("Something else".to_string())));
+    /// assert_eq!(AttributeName::new_from_attr(Attribute{ key: &[], value: Cow::Borrowed(b"Something else")}).unwrap(), AttributeName::UserDefined(Name::from("Something else")));
     /// assert!(AttributeName::new_from_attr(Attribute{ key: &[], value: Cow::Borrowed(b"Name with invalid char {")}).is_err());
     ///```
     pub fn new_from_attr(attr: Attribute<'_>) -> Result<Self, GdtfNameError> {
         Self::new_from_str(read::attr_to_str(&attr))
     }
+
+    ///Returns the feature group the GDTF specification assigns to a predefined attribute. For UserDefined it returns None
+    ///```rust
+    /// use gdtf_parser::utils::units::attribute_name::AttributeName;
+    /// use gdtf_parser::utils::units::name::Name;
+    /// assert_eq!(AttributeName::Pan.default_feature_group(), Some(Name::from("Position")));
+    /// assert_eq!(AttributeName::Gobo_n_WheelSpin(1).default_feature_group(), Some(Name::from("Gobo")));
+    /// assert_eq!(AttributeName::UserDefined(Name::from("Something else")).default_feature_group(), None);
+    ///```
+    pub fn default_feature_group(&self) -> Option<Name> {
+        self.default_feature_strs()
+            .map(|(group, _)| Name::from(group))
+    }
+
+    ///Returns the link to the feature the GDTF specification assigns to a predefined attribute in the format `FeatureGroup.Feature`, as it is used in `Attribute.feature`. For UserDefined it returns None
+    ///```rust
+    /// use gdtf_parser::utils::units::attribute_name::AttributeName;
+    /// use gdtf_parser::utils::units::name::Name;
+    /// use gdtf_parser::utils::units::node::Node;
+    /// assert_eq!(AttributeName::Pan.default_feature(), Node::new_from_str("Position.PanTilt").unwrap());
+    /// assert_eq!(AttributeName::ColorAdd_R.default_feature(), Node::new_from_str("Color.RGB").unwrap());
+    /// assert_eq!(AttributeName::UserDefined(Name::from("Something else")).default_feature(), None);
+    ///```
+    pub fn default_feature(&self) -> Option<Node> {
+        self.default_feature_strs()
+            .map(|(group, feature)| Node(vec![Name::from(group), Name::from(feature)]))
+    }
+
+    ///Feature group and feature of the predefined attributes as listed in the annex of the GDTF specification
+    fn default_feature_strs(&self) -> Option<(&'static str, &'static str)> {
+        use AttributeName::*;
+        Some(match self {
+            UserDefined(_) => return None,
+            Dimmer => ("Dimmer", "Dimmer"),
+            Pan | Tilt | PanRotate | TiltRotate | PositionEffect | PositionEffectRate
+            | PositionEffectFade => ("Position", "PanTilt"),
+            Xyz_X | Xyz_Y | Xyz_Z => ("Position", "XYZ"),
+            Rot_X | Rot_Y | Rot_Z => ("Position", "Rotation"),
+            Scale_X | Scale_Y | Scale_Z | Scale_Xyz => ("Position", "Scale"),
+            Gobo_n_(_)
+            | Gobo_n_SelectSpin(_)
+            | Gobo_n_SelectShake(_)
+            | Gobo_n_SelectEffects(_)
+            | Gobo_n_WheelIndex(_)
+            | Gobo_n_WheelSpin(_)
+            | Gobo_n_WheelShake(_)
+            | Gobo_n_WheelRandom(_)
+            | Gobo_n_WheelAudio(_)
+            | Gobo_n_Pos(_)
+            | Gobo_n_PosRotate(_)
+            | Gobo_n_PosShake(_)
+            | AnimationWheel_n_(_)
+            | AnimationWheel_n_Audio(_)
+            | AnimationWheel_n_Macro(_)
+            | AnimationWheel_n_Random(_)
+            | AnimationWheel_n_SelectEffects(_)
+            | AnimationWheel_n_SelectShake(_)
+            | AnimationWheel_n_SelectSpin(_)
+            | AnimationWheel_n_Pos(_)
+            | AnimationWheel_n_PosRotate(_)
+            | AnimationWheel_n_PosShake(_)
+            | AnimationSystem_n_(_)
+            | AnimationSystem_n_Ramp(_)
+            | AnimationSystem_n_Shake(_)
+            | AnimationSystem_n_Audio(_)
+            | AnimationSystem_n_Random(_)
+            | AnimationSystem_n_Pos(_)
+            | AnimationSystem_n_PosRotate(_)
+            | AnimationSystem_n_PosShake(_)
+            | AnimationSystem_n_PosRandom(_)
+            | AnimationSystem_n_PosAudio(_)
+            | AnimationSystem_n_Macro(_) => ("Gobo", "Gobo"),
+            MediaFolder_n_(_) | MediaContent_n_(_) | ModelFolder_n_(_) | ModelContent_n_(_)
+            | PlayMode | PlayBegin | PlayEnd | PlaySpeed => ("Gobo", "Media"),
+            ColorEffects_n_(_)
+            | Color_n_(_)
+            | Color_n_WheelIndex(_)
+            | Color_n_WheelSpin(_)
+            | Color_n_WheelRandom(_)
+            | Color_n_WheelAudio(_)
+            | ColorMacro_n_(_)
+            | ColorMacro_n_Rate(_)
+            | Cto
+            | Ctc
+            | Ctb
+            | Tint => ("Color", "Color"),
+            ColorAdd_R | ColorAdd_G | ColorAdd_B | ColorAdd_C | ColorAdd_M | ColorAdd_Y
+            | ColorAdd_Ry | ColorAdd_Gy | ColorAdd_Gc | ColorAdd_Bc | ColorAdd_Bm | ColorAdd_Rm
+            | ColorAdd_W | ColorAdd_Ww | ColorAdd_Cw | ColorAdd_Uv | ColorSub_R | ColorSub_G
+            | ColorSub_B | ColorSub_C | ColorSub_M | ColorSub_Y => ("Color", "RGB"),
+            Hsb_Hue | Hsb_Saturation | Hsb_Brightness | Hsb_Quality => ("Color", "HSB"),
+            Cie_X | Cie_Y | Cie_Brightness => ("Color", "CIE"),
+            ColorRgb_Red | ColorRgb_Green | ColorRgb_Blue | ColorRgb_Cyan | ColorRgb_Magenta
+            | ColorRgb_Yellow | ColorRgb_Quality => ("Color", "Indirect"),
+            VideoBoost_R | VideoBoost_G | VideoBoost_B => ("Color", "ColorCorrection"),
+            VideoHueShift | VideoSaturation | VideoBrightness | VideoContrast => {
+                ("Color", "HSBC_Shift")
+            }
+            VideoKeyColor_R | VideoKeyColor_G | VideoKeyColor_B | VideoKeyIntensity
+            | VideoKeyTolerance => ("Color", "ColorKey"),
+            StrobeDuration
+            | StrobeRate
+            | Shutter_n_(_)
+            | Shutter_n_Strobe(_)
+            | Shutter_n_StrobePulse(_)
+            | Shutter_n_StrobePulseClose(_)
+            | Shutter_n_StrobePulseOpen(_)
+            | Shutter_n_StrobeRandom(_)
+            | Shutter_n_StrobeRandomPulse(_)
+            | Shutter_n_StrobeRandomPulseClose(_)
+            | Shutter_n_StrobeRandomPulseOpen(_)
+            | Shutter_n_StrobeEffect(_)
+            | Iris
+            | IrisStrobe
+            | IrisStrobeRandom
+            | IrisPulseClose
+            | IrisPulseOpen
+            | IrisRandomPulseClose
+            | IrisRandomPulseOpen
+            | Frost_n_(_)
+            | Frost_n_PulseOpen(_)
+            | Frost_n_PulseClose(_)
+            | Frost_n_Ramp(_)
+            | Prism_n_(_)
+            | Prism_n_SelectSpin(_)
+            | Prism_n_Macro(_)
+            | Prism_n_Pos(_)
+            | Prism_n_PosRotate(_)
+            | Effects_n_(_)
+            | Effects_n_Rate(_)
+            | Effects_n_Fade(_)
+            | Effects_n_Adjust_m_(_, _)
+            | Effects_n_Pos(_)
+            | Effects_n_PosRotate(_)
+            | EffectsSync
+            | BeamShaper
+            | BeamShaperMacro
+            | BeamShaperPos
+            | BeamShaperPosRotate
+            | Zoom
+            | ZoomModeSpot
+            | ZoomModeBeam => ("Beam", "Beam"),
+            Focus_n_(_) | Focus_n_Adjust(_) | Focus_n_Distance(_) => ("Focus", "Focus"),
+            Blade_n_A(_) | Blade_n_B(_) | Blade_n_Rot(_) | ShaperRot | ShaperMacros
+            | ShaperMacrosSpeed | BladeSoft_n_A(_) | BladeSoft_n_B(_) | KeyStone_n_A(_)
+            | KeyStone_n_B(_) => ("Shapers", "Shapers"),
+            Video
+            | VideoEffect_n_Type(_)
+            | VideoEffect_n_Parameter_m_(_, _)
+            | VideoCamera_n_(_)
+            | VideoSoundVolume_n_(_)
+            | VideoBlendMode
+            | InputSource
+            | FieldOfView => ("Video", "Video"),
+            _ => ("Control", "Control"),
+        })
+    }
+
+    ///Returns the physical unit the GDTF specification assigns to a predefined attribute. For UserDefined and attributes without a unit it returns PhysicalUnit::None
+    ///```rust
+    /// use gdtf_parser::utils::units::attribute_name::AttributeName;
+    /// use gdtf_parser::utils::units::physical_unit::PhysicalUnit;
+    /// use gdtf_parser::utils::units::name::Name;
+    /// assert_eq!(AttributeName::Tilt.default_physical_unit(), PhysicalUnit::Angle);
+    /// assert_eq!(AttributeName::Shutter_n_Strobe(1).default_physical_unit(), PhysicalUnit::Frequency);
+    /// assert_eq!(AttributeName::UserDefined(Name::from("Something else")).default_physical_unit(), PhysicalUnit::None);
+    ///```
+    pub fn default_physical_unit(&self) -> PhysicalUnit {
+        use AttributeName::*;
+        match self {
+            Dimmer => PhysicalUnit::LuminousIntensity,
+            Pan
+            | Tilt
+            | Rot_X
+            | Rot_Y
+            | Rot_Z
+            | Gobo_n_WheelIndex(_)
+            | Gobo_n_Pos(_)
+            | AnimationWheel_n_Pos(_)
+            | AnimationSystem_n_Pos(_)
+            | Color_n_WheelIndex(_)
+            | Prism_n_Pos(_)
+            | Effects_n_Pos(_)
+            | BeamShaperPos
+            | Zoom
+            | Hsb_Hue
+            | VideoHueShift
+            | Blade_n_Rot(_)
+            | ShaperRot
+            | FieldOfView => PhysicalUnit::Angle,
+            PanRotate
+            | TiltRotate
+            | Gobo_n_SelectSpin(_)
+            | Gobo_n_WheelSpin(_)
+            | Gobo_n_PosRotate(_)
+            | AnimationWheel_n_SelectSpin(_)
+            | AnimationWheel_n_PosRotate(_)
+            | AnimationSystem_n_PosRotate(_)
+            | Color_n_WheelSpin(_)
+            | Prism_n_SelectSpin(_)
+            | Prism_n_PosRotate(_)
+            | Effects_n_PosRotate(_)
+            | BeamShaperPosRotate => PhysicalUnit::AngularSpeed,
+            PositionEffectRate
+            | Gobo_n_SelectShake(_)
+            | Gobo_n_WheelShake(_)
+            | Gobo_n_WheelRandom(_)
+            | Gobo_n_PosShake(_)
+            | AnimationWheel_n_SelectShake(_)
+            | AnimationWheel_n_Random(_)
+            | AnimationWheel_n_PosShake(_)
+            | AnimationSystem_n_Ramp(_)
+            | AnimationSystem_n_Shake(_)
+            | AnimationSystem_n_Random(_)
+            | AnimationSystem_n_PosShake(_)
+            | AnimationSystem_n_PosRandom(_)
+            | Color_n_WheelRandom(_)
+            | ColorMacro_n_Rate(_)
+            | StrobeRate
+            | Shutter_n_Strobe(_)
+            | Shutter_n_StrobePulse(_)
+            | Shutter_n_StrobePulseClose(_)
+            | Shutter_n_StrobePulseOpen(_)
+            | Shutter_n_StrobeRandom(_)
+            | Shutter_n_StrobeRandomPulse(_)
+            | Shutter_n_StrobeRandomPulseClose(_)
+            | Shutter_n_StrobeRandomPulseOpen(_)
+            | Shutter_n_StrobeEffect(_)
+            | IrisStrobe
+            | IrisStrobeRandom
+            | IrisPulseClose
+            | IrisPulseOpen
+            | IrisRandomPulseClose
+            | IrisRandomPulseOpen
+            | Frost_n_PulseOpen(_)
+            | Frost_n_PulseClose(_)
+            | Frost_n_Ramp(_)
+            | Effects_n_Rate(_)
+            | LedFrequency => PhysicalUnit::Frequency,
+            Xyz_X | Xyz_Y | Xyz_Z | Focus_n_Distance(_) | Blade_n_A(_) | Blade_n_B(_)
+            | KeyStone_n_A(_) | KeyStone_n_B(_) => PhysicalUnit::Length,
+            Scale_X
+            | Scale_Y
+            | Scale_Z
+            | Scale_Xyz
+            | AnimationSystem_n_(_)
+            | Tint
+            | Hsb_Saturation
+            | Hsb_Brightness
+            | Hsb_Quality
+            | Cie_Brightness
+            | VideoSaturation
+            | VideoBrightness
+            | VideoContrast
+            | VideoKeyIntensity
+            | VideoKeyTolerance
+            | Iris
+            | Frost_n_(_)
+            | Effects_n_Fade(_)
+            | Effects_n_Adjust_m_(_, _)
+            | Focus_n_(_)
+            | Focus_n_Adjust(_)
+            | BladeSoft_n_A(_)
+            | BladeSoft_n_B(_)
+            | Blower_n_(_)
+            | Fan_n_(_)
+            | Fog_n_(_)
+            | Haze_n_(_)
+            | VideoSoundVolume_n_(_) => PhysicalUnit::Percent,
+            ColorAdd_R | ColorAdd_G | ColorAdd_B | ColorAdd_C | ColorAdd_M | ColorAdd_Y
+            | ColorAdd_Ry | ColorAdd_Gy | ColorAdd_Gc | ColorAdd_Bc | ColorAdd_Bm | ColorAdd_Rm
+            | ColorAdd_W | ColorAdd_Ww | ColorAdd_Cw | ColorAdd_Uv | ColorSub_R | ColorSub_G
+            | ColorSub_B | ColorSub_C | ColorSub_M | ColorSub_Y | ColorRgb_Red | ColorRgb_Green
+            | ColorRgb_Blue | ColorRgb_Cyan | ColorRgb_Magenta | ColorRgb_Yellow
+            | ColorRgb_Quality | VideoBoost_R | VideoBoost_G | VideoBoost_B | VideoKeyColor_R
+            | VideoKeyColor_G | VideoKeyColor_B => PhysicalUnit::ColorComponent,
+            Cto | Ctc | Ctb => PhysicalUnit::Temperature,
+            StrobeDuration
+            | PositionEffectFade
+            | PlayBegin
+            | PlayEnd
+            | IntensityMSpeed
+            | PositionMSpeed
+            | ColorMixMSpeed
+            | ColorWheelSelectMSpeed
+            | GoboWheel_n_MSpeed(_)
+            | IrisMSpeed
+            | Prism_n_MSpeed(_)
+            | FocusMSpeed
+            | Frost_n_MSpeed(_)
+            | ZoomMSpeed
+            | FrameMSpeed
+            | GlobalMSpeed => PhysicalUnit::Time,
+            _ => PhysicalUnit::None,
+        }
+    }
+
+    ///Returns the main attribute the GDTF specification assigns to a predefined attribute, e.g. `Gobo1` for `Gobo1WheelSpin`. Returns None if the attribute is a main attribute itself or UserDefined
+    ///```rust
+    /// use gdtf_parser::utils::units::attribute_name::AttributeName;
+    /// assert_eq!(AttributeName::Gobo_n_WheelSpin(2).default_main_attribute(), Some(AttributeName::Gobo_n_(2)));
+    /// assert_eq!(AttributeName::Gobo_n_PosRotate(2).default_main_attribute(), Some(AttributeName::Gobo_n_Pos(2)));
+    /// assert_eq!(AttributeName::Effects_n_Adjust_m_(3, 1).default_main_attribute(), Some(AttributeName::Effects_n_(3)));
+    /// assert_eq!(AttributeName::Dimmer.default_main_attribute(), None);
+    ///```
+    pub fn default_main_attribute(&self) -> Option<AttributeName> {
+        use AttributeName::*;
+        Some(match self {
+            PositionEffectRate | PositionEffectFade => PositionEffect,
+            Gobo_n_SelectSpin(n)
+            | Gobo_n_SelectShake(n)
+            | Gobo_n_SelectEffects(n)
+            | Gobo_n_WheelIndex(n)
+            | Gobo_n_WheelSpin(n)
+            | Gobo_n_WheelShake(n)
+            | Gobo_n_WheelRandom(n)
+            | Gobo_n_WheelAudio(n) => Gobo_n_(*n),
+            Gobo_n_PosRotate(n) | Gobo_n_PosShake(n) => Gobo_n_Pos(*n),
+            AnimationWheel_n_Audio(n)
+            | AnimationWheel_n_Macro(n)
+            | AnimationWheel_n_Random(n)
+            | AnimationWheel_n_SelectEffects(n)
+            | AnimationWheel_n_SelectShake(n)
+            | AnimationWheel_n_SelectSpin(n) => AnimationWheel_n_(*n),
+            AnimationWheel_n_PosRotate(n) | AnimationWheel_n_PosShake(n) => {
+                AnimationWheel_n_Pos(*n)
+            }
+            AnimationSystem_n_Ramp(n)
+            | AnimationSystem_n_Shake(n)
+            | AnimationSystem_n_Audio(n)
+            | AnimationSystem_n_Random(n)
+            | AnimationSystem_n_Macro(n) => AnimationSystem_n_(*n),
+            AnimationSystem_n_PosRotate(n)
+            | AnimationSystem_n_PosShake(n)
+            | AnimationSystem_n_PosRandom(n)
+            | AnimationSystem_n_PosAudio(n) => AnimationSystem_n_Pos(*n),
+            Color_n_WheelIndex(n)
+            | Color_n_WheelSpin(n)
+            | Color_n_WheelRandom(n)
+            | Color_n_WheelAudio(n) => Color_n_(*n),
+            ColorMacro_n_Rate(n) => ColorMacro_n_(*n),
+            Shutter_n_Strobe(n)
+            | Shutter_n_StrobePulse(n)
+            | Shutter_n_StrobePulseClose(n)
+            | Shutter_n_StrobePulseOpen(n)
+            | Shutter_n_StrobeRandom(n)
+            | Shutter_n_StrobeRandomPulse(n)
+            | Shutter_n_StrobeRandomPulseClose(n)
+            | Shutter_n_StrobeRandomPulseOpen(n)
+            | Shutter_n_StrobeEffect(n) => Shutter_n_(*n),
+            IrisStrobe | IrisStrobeRandom | IrisPulseClose | IrisPulseOpen
+            | IrisRandomPulseClose | IrisRandomPulseOpen => Iris,
+            Frost_n_PulseOpen(n) | Frost_n_PulseClose(n) | Frost_n_Ramp(n) => Frost_n_(*n),
+            Prism_n_SelectSpin(n) | Prism_n_Macro(n) => Prism_n_(*n),
+            Prism_n_PosRotate(n) => Prism_n_Pos(*n),
+            Effects_n_Rate(n) | Effects_n_Fade(n) | Effects_n_Adjust_m_(n, _) => Effects_n_(*n),
+            Effects_n_PosRotate(n) => Effects_n_Pos(*n),
+            BeamShaperMacro => BeamShaper,
+            BeamShaperPosRotate => BeamShaperPos,
+            ZoomModeSpot | ZoomModeBeam => Zoom,
+            Focus_n_Adjust(n) | Focus_n_Distance(n) => Focus_n_(*n),
+            _ => return None,
+        })
+    }
+
+    ///Returns the pretty name the GDTF specification suggests for a predefined attribute. For UserDefined it returns None
+    ///```rust
+    /// use gdtf_parser::utils::units::attribute_name::AttributeName;
+    /// use gdtf_parser::utils::units::name::Name;
+    /// assert_eq!(AttributeName::Dimmer.default_pretty(), Some("Dim".to_string()));
+    /// assert_eq!(AttributeName::Gobo_n_(2).default_pretty(), Some("G2".to_string()));
+    /// assert_eq!(AttributeName::Effects_n_Adjust_m_(1, 3).default_pretty(), Some("Adjust3".to_string()));
+    /// assert_eq!(AttributeName::UserDefined(Name::from("Something else")).default_pretty(), None);
+    ///```
+    pub fn default_pretty(&self) -> Option<String> {
+        use AttributeName::*;
+        Some(match self {
+            UserDefined(_) => return None,
+            Dimmer => "Dim".to_string(),
+            Pan => "P".to_string(),
+            Tilt => "T".to_string(),
+            PanRotate => "P Rotate".to_string(),
+            TiltRotate => "T Rotate".to_string(),
+            PositionEffect => "Pos FX".to_string(),
+            PositionEffectRate => "Pos FX Rate".to_string(),
+            PositionEffectFade => "Pos FX Fade".to_string(),
+            Xyz_X => "X".to_string(),
+            Xyz_Y => "Y".to_string(),
+            Xyz_Z => "Z".to_string(),
+            Rot_X => "Rot X".to_string(),
+            Rot_Y => "Rot Y".to_string(),
+            Rot_Z => "Rot Z".to_string(),
+            Scale_X => "Scale X".to_string(),
+            Scale_Y => "Scale Y".to_string(),
+            Scale_Z => "Scale Z".to_string(),
+            Scale_Xyz => "Scale XYZ".to_string(),
+            Gobo_n_(n) => format!("G{}", n),
+            Gobo_n_Pos(n) => format!("G{} <>", n),
+            AnimationWheel_n_(n) => format!("Anim{}", n),
+            AnimationWheel_n_Pos(n) => format!("Anim{} <>", n),
+            AnimationSystem_n_(n) => format!("Anim System{}", n),
+            AnimationSystem_n_Pos(n) => format!("Anim System{} <>", n),
+            Gobo_n_SelectSpin(_) | AnimationWheel_n_SelectSpin(_) | Prism_n_SelectSpin(_) => {
+                "Select Spin".to_string()
+            }
+            Gobo_n_SelectShake(_) | AnimationWheel_n_SelectShake(_) => "Select Shake".to_string(),
+            Gobo_n_SelectEffects(_) | AnimationWheel_n_SelectEffects(_) => {
+                "Select Effects".to_string()
+            }
+            Gobo_n_WheelIndex(_) | Color_n_WheelIndex(_) => "Wheel Index".to_string(),
+            Gobo_n_WheelSpin(_) | Color_n_WheelSpin(_) => "Wheel Spin".to_string(),
+            Gobo_n_WheelShake(_) => "Wheel Shake".to_string(),
+            Gobo_n_WheelRandom(_) | Color_n_WheelRandom(_) => "Wheel Random".to_string(),
+            Gobo_n_WheelAudio(_) | Color_n_WheelAudio(_) => "Wheel Audio".to_string(),
+            Gobo_n_PosRotate(_)
+            | AnimationWheel_n_PosRotate(_)
+            | AnimationSystem_n_PosRotate(_)
+            | Prism_n_PosRotate(_)
+            | Effects_n_PosRotate(_)
+            | BeamShaperPosRotate => "Rotate".to_string(),
+            Gobo_n_PosShake(_) | AnimationWheel_n_PosShake(_) | AnimationSystem_n_PosShake(_) => {
+                "Shake".to_string()
+            }
+            AnimationWheel_n_Audio(_) | AnimationSystem_n_Audio(_) => "Audio".to_string(),
+            AnimationWheel_n_Macro(_) | AnimationSystem_n_Macro(_) | Prism_n_Macro(_) => {
+                "Macro".to_string()
+            }
+            AnimationWheel_n_Random(_) | AnimationSystem_n_Random(_) => "Random".to_string(),
+            AnimationSystem_n_Ramp(_) | Frost_n_Ramp(_) => "Ramp".to_string(),
+            AnimationSystem_n_Shake(_) => "Shake".to_string(),
+            AnimationSystem_n_PosRandom(_) => "Random".to_string(),
+            AnimationSystem_n_PosAudio(_) => "Audio".to_string(),
+            MediaFolder_n_(n) => format!("Media Folder{}", n),
+            MediaContent_n_(n) => format!("Media Content{}", n),
+            ModelFolder_n_(n) => format!("Model Folder{}", n),
+            ModelContent_n_(n) => format!("Model Content{}", n),
+            PlayMode => "Play Mode".to_string(),
+            PlayBegin => "Play Begin".to_string(),
+            PlayEnd => "Play End".to_string(),
+            PlaySpeed => "Play Speed".to_string(),
+            ColorEffects_n_(n) => format!("Color Effects{}", n),
+            Color_n_(n) => format!("C{}", n),
+            ColorAdd_R => "R".to_string(),
+            ColorAdd_G => "G".to_string(),
+            ColorAdd_B => "B".to_string(),
+            ColorAdd_C => "C".to_string(),
+            ColorAdd_M => "M".to_string(),
+            ColorAdd_Y => "Y".to_string(),
+            ColorAdd_Ry => "Amber".to_string(),
+            ColorAdd_Gy => "Lime".to_string(),
+            ColorAdd_Gc => "Blue-Green".to_string(),
+            ColorAdd_Bc => "Light-Blue".to_string(),
+            ColorAdd_Bm => "Purple".to_string(),
+            ColorAdd_Rm => "Pink".to_string(),
+            ColorAdd_W => "White".to_string(),
+            ColorAdd_Ww => "Warm White".to_string(),
+            ColorAdd_Cw => "Cool White".to_string(),
+            ColorAdd_Uv => "UV".to_string(),
+            ColorSub_R => "R".to_string(),
+            ColorSub_G => "G".to_string(),
+            ColorSub_B => "B".to_string(),
+            ColorSub_C => "C".to_string(),
+            ColorSub_M => "M".to_string(),
+            ColorSub_Y => "Y".to_string(),
+            ColorMacro_n_(n) => format!("Color Macro{}", n),
+            ColorMacro_n_Rate(_) => "Rate".to_string(),
+            Cto => "CTO".to_string(),
+            Ctc => "CTC".to_string(),
+            Ctb => "CTB".to_string(),
+            Tint => "Tint".to_string(),
+            Hsb_Hue => "H".to_string(),
+            Hsb_Saturation => "S".to_string(),
+            Hsb_Brightness => "B".to_string(),
+            Hsb_Quality => "Q".to_string(),
+            Cie_X => "X".to_string(),
+            Cie_Y => "Y".to_string(),
+            Cie_Brightness => "Brightness".to_string(),
+            ColorRgb_Red => "R".to_string(),
+            ColorRgb_Green => "G".to_string(),
+            ColorRgb_Blue => "B".to_string(),
+            ColorRgb_Cyan => "C".to_string(),
+            ColorRgb_Magenta => "M".to_string(),
+            ColorRgb_Yellow => "Y".to_string(),
+            ColorRgb_Quality => "Quality".to_string(),
+            VideoBoost_R => "Boost R".to_string(),
+            VideoBoost_G => "Boost G".to_string(),
+            VideoBoost_B => "Boost B".to_string(),
+            VideoHueShift => "Hue".to_string(),
+            VideoSaturation => "Saturation".to_string(),
+            VideoBrightness => "Brightness".to_string(),
+            VideoContrast => "Contrast".to_string(),
+            VideoKeyColor_R => "Key R".to_string(),
+            VideoKeyColor_G => "Key G".to_string(),
+            VideoKeyColor_B => "Key B".to_string(),
+            VideoKeyIntensity => "Key Intensity".to_string(),
+            VideoKeyTolerance => "Key Tolerance".to_string(),
+            StrobeDuration => "Strobe Duration".to_string(),
+            StrobeRate => "Strobe Rate".to_string(),
+            Shutter_n_(n) => format!("Sh{}", n),
+            Shutter_n_Strobe(_) => "Strobe".to_string(),
+            Shutter_n_StrobePulse(_) => "Pulse".to_string(),
+            Shutter_n_StrobePulseClose(_) => "Pulse Close".to_string(),
+            Shutter_n_StrobePulseOpen(_) => "Pulse Open".to_string(),
+            Shutter_n_StrobeRandom(_) => "Random".to_string(),
+            Shutter_n_StrobeRandomPulse(_) => "Random Pulse".to_string(),
+            Shutter_n_StrobeRandomPulseClose(_) => "Random Pulse Close".to_string(),
+            Shutter_n_StrobeRandomPulseOpen(_) => "Random Pulse Open".to_string(),
+            Shutter_n_StrobeEffect(_) => "Effect".to_string(),
+            Iris => "Iris".to_string(),
+            IrisStrobe => "Strobe".to_string(),
+            IrisStrobeRandom => "Random Strobe".to_string(),
+            IrisPulseClose => "Pulse Close".to_string(),
+            IrisPulseOpen => "Pulse Open".to_string(),
+            IrisRandomPulseClose => "Random Pulse Close".to_string(),
+            IrisRandomPulseOpen => "Random Pulse Open".to_string(),
+            Frost_n_(n) => format!("Frost{}", n),
+            Frost_n_PulseOpen(_) => "Pulse Open".to_string(),
+            Frost_n_PulseClose(_) => "Pulse Close".to_string(),
+            Prism_n_(n) => format!("Prism{}", n),
+            Prism_n_Pos(n) => format!("Prism{} Pos", n),
+            Effects_n_(n) => format!("FX{}", n),
+            Effects_n_Rate(_) => "Rate".to_string(),
+            Effects_n_Fade(_) => "Fade".to_string(),
+            Effects_n_Adjust_m_(_, m) => format!("Adjust{}", m),
+            Effects_n_Pos(n) => format!("FX{} Pos", n),
+            EffectsSync => "FX Sync".to_string(),
+            BeamShaper => "Beam Shaper".to_string(),
+            BeamShaperMacro => "Beam Shaper Macro".to_string(),
+            BeamShaperPos => "Beam Shaper <>".to_string(),
+            Zoom => "Zoom".to_string(),
+            ZoomModeSpot => "Zoom Spot".to_string(),
+            ZoomModeBeam => "Zoom Beam".to_string(),
+            Focus_n_(n) => format!("Focus{}", n),
+            Focus_n_Adjust(_) => "Adjust".to_string(),
+            Focus_n_Distance(_) => "Distance".to_string(),
+            Control_n_(n) => format!("Ctrl{}", n),
+            Gobo_n_WheelMode(n) => format!("G{} Mode", n),
+            AnimationWheel_n_Mode(n) => format!("Anim{} Mode", n),
+            Color_n_Mode(n) => format!("C{} Mode", n),
+            Fan_n_Mode(n) => format!("Fan{} Mode", n),
+            GoboWheel_n_MSpeed(n) => format!("G{} MSpeed", n),
+            Prism_n_MSpeed(n) => format!("Prism{} MSpeed", n),
+            Frost_n_MSpeed(n) => format!("Frost{} MSpeed", n),
+            Blower_n_(n) => format!("Blower{}", n),
+            Fan_n_(n) => format!("Fan{}", n),
+            Fog_n_(n) => format!("Fog{}", n),
+            Haze_n_(n) => format!("Haze{}", n),
+            Blade_n_A(n) => format!("Blade{}A", n),
+            Blade_n_B(n) => format!("Blade{}B", n),
+            Blade_n_Rot(n) => format!("Blade{} Rot", n),
+            BladeSoft_n_A(n) => format!("Blade Soft{}A", n),
+            BladeSoft_n_B(n) => format!("Blade Soft{}B", n),
+            KeyStone_n_A(n) => format!("KeyStone{}A", n),
+            KeyStone_n_B(n) => format!("KeyStone{}B", n),
+            ShaperRot => "Shaper Rot".to_string(),
+            ShaperMacros => "Shaper Macros".to_string(),
+            ShaperMacrosSpeed => "Shaper Macros Speed".to_string(),
+            VideoEffect_n_Type(n) => format!("Video Effect{} Type", n),
+            VideoEffect_n_Parameter_m_(n, m) => format!("Video Effect{} Parameter{}", n, m),
+            VideoCamera_n_(n) => format!("Video Camera{}", n),
+            VideoSoundVolume_n_(n) => format!("Video Sound Volume{}", n),
+            VideoBlendMode => "Video Blend Mode".to_string(),
+            InputSource => "Input Source".to_string(),
+            FieldOfView => "Field of View".to_string(),
+            CriMode => "CRI Mode".to_string(),
+            UvStability => "UV Stability".to_string(),
+            LedFrequency => "LED Frequency".to_string(),
+            LedZoneMode => "LED Zone Mode".to_string(),
+            DmxInput => "DMX Input".to_string(),
+            CtbReset => "CTB Reset".to_string(),
+            CtoReset => "CTO Reset".to_string(),
+            CtcReset => "CTC Reset".to_string(),
+            _ => split_camel_case(&self.to_string()),
+        })
+    }
+
+    ///Returns the color the GDTF specification assigns to a predefined color attribute, e.g. the emitter or filter color of `ColorAdd_R` or `ColorSub_C`. Returns None for all other attributes
+    ///```rust
+    /// use gdtf_parser::utils::units::attribute_name::AttributeName;
+    /// use gdtf_parser::utils::units::color_cie::ColorCie;
+    /// assert_eq!(AttributeName::ColorAdd_R.default_color(), Some(ColorCie{x: 0.7347, y: 0.2653, Y: 100.0}));
+    /// assert_eq!(AttributeName::ColorSub_C.default_color(), Some(ColorCie{x: 0.1559, y: 0.2528, Y: 100.0}));
+    /// assert_eq!(AttributeName::Dimmer.default_color(), None);
+    ///```
+    pub fn default_color(&self) -> Option<ColorCie> {
+        use AttributeName::*;
+        let (x, y) = match self {
+            ColorAdd_R | ColorSub_R | ColorRgb_Red => (0.7347, 0.2653),
+            ColorAdd_G | ColorSub_G | ColorRgb_Green => (0.1596, 0.8404),
+            ColorAdd_B | ColorSub_B | ColorRgb_Blue => (0.0366, 0.001),
+            ColorAdd_C | ColorSub_C | ColorRgb_Cyan => (0.1559, 0.2528),
+            ColorAdd_M | ColorSub_M | ColorRgb_Magenta => (0.3209, 0.1541),
+            ColorAdd_Y | ColorSub_Y | ColorRgb_Yellow => (0.4637, 0.5245),
+            ColorAdd_Ry => (0.5991, 0.3997),
+            ColorAdd_Gy => (0.4073, 0.5778),
+            ColorAdd_Gc => (0.0845, 0.5007),
+            ColorAdd_Bc => (0.1398, 0.1063),
+            ColorAdd_Bm => (0.2357, 0.0686),
+            ColorAdd_Rm => (0.6156, 0.2308),
+            ColorAdd_W => (0.3127, 0.329),
+            ColorAdd_Ww => (0.4578, 0.4101),
+            ColorAdd_Cw => (0.2898, 0.2987),
+            ColorAdd_Uv => (0.1666, 0.0089),
+            _ => return None,
+        };
+        Some(ColorCie { x, y, Y: 100.0 })
+    }
+}
+
+///Inserts a space before each inner uppercase char, e.g. `ColorMixReset` becomes `Color Mix Reset`
+fn split_camel_case(value: &str) -> String {
+    let mut pretty = String::with_capacity(value.len() + 4);
+    for (i, c) in value.chars().enumerate() {
+        if i > 0 && c.is_ascii_uppercase() {
+            pretty.push(' ');
+        }
+        pretty.push(c);
+    }
+    pretty
+}
+
+///Displays an AttributeName in the format used in gdtf-xml-description, with the indices of `_n_` and `_m_` inserted
+///```rust
+/// use gdtf_parser::utils::units::attribute_name::AttributeName;
+/// use gdtf_parser::utils::units::name::Name;
+/// assert_eq!(AttributeName::Xyz_X.to_string(), "XYZ_X");
+/// assert_eq!(AttributeName::Gobo_n_WheelSpin(2).to_string(), "Gobo2WheelSpin");
+/// assert_eq!(AttributeName::Effects_n_Adjust_m_(1, 3).to_string(), "Effects1Adjust3");
+/// assert_eq!(AttributeName::UserDefined(Name::from("Something else")).to_string(), "Something else");
+///```
+impl Display for AttributeName {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use AttributeName::*;
+        match self {
+            UserDefined(name) => write!(f, "{}", name.0),
+            Dimmer => write!(f, "Dimmer"),
+            Pan => write!(f, "Pan"),
+            Tilt => write!(f, "Tilt"),
+            PanRotate => write!(f, "PanRotate"),
+            TiltRotate => write!(f, "TiltRotate"),
+            PositionEffect => write!(f, "PositionEffect"),
+            PositionEffectRate => write!(f, "PositionEffectRate"),
+            PositionEffectFade => write!(f, "PositionEffectFade"),
+            Xyz_X => write!(f, "XYZ_X"),
+            Xyz_Y => write!(f, "XYZ_Y"),
+            Xyz_Z => write!(f, "XYZ_Z"),
+            Rot_X => write!(f, "Rot_X"),
+            Rot_Y => write!(f, "Rot_Y"),
+            Rot_Z => write!(f, "Rot_Z"),
+            Scale_X => write!(f, "Scale_X"),
+            Scale_Y => write!(f, "Scale_Y"),
+            Scale_Z => write!(f, "Scale_Z"),
+            Scale_Xyz => write!(f, "Scale_XYZ"),
+            PlayMode => write!(f, "PlayMode"),
+            PlayBegin => write!(f, "PlayBegin"),
+            PlayEnd => write!(f, "PlayEnd"),
+            PlaySpeed => write!(f, "PlaySpeed"),
+            ColorAdd_R => write!(f, "ColorAdd_R"),
+            ColorAdd_G => write!(f, "ColorAdd_G"),
+            ColorAdd_B => write!(f, "ColorAdd_B"),
+            ColorAdd_C => write!(f, "ColorAdd_C"),
+            ColorAdd_M => write!(f, "ColorAdd_M"),
+            ColorAdd_Y => write!(f, "ColorAdd_Y"),
+            ColorAdd_Ry => write!(f, "ColorAdd_RY"),
+            ColorAdd_Gy => write!(f, "ColorAdd_GY"),
+            ColorAdd_Gc => write!(f, "ColorAdd_GC"),
+            ColorAdd_Bc => write!(f, "ColorAdd_BC"),
+            ColorAdd_Bm => write!(f, "ColorAdd_BM"),
+            ColorAdd_Rm => write!(f, "ColorAdd_RM"),
+            ColorAdd_W => write!(f, "ColorAdd_W"),
+            ColorAdd_Ww => write!(f, "ColorAdd_WW"),
+            ColorAdd_Cw => write!(f, "ColorAdd_CW"),
+            ColorAdd_Uv => write!(f, "ColorAdd_UV"),
+            ColorSub_R => write!(f, "ColorSub_R"),
+            ColorSub_G => write!(f, "ColorSub_G"),
+            ColorSub_B => write!(f, "ColorSub_B"),
+            ColorSub_C => write!(f, "ColorSub_C"),
+            ColorSub_M => write!(f, "ColorSub_M"),
+            ColorSub_Y => write!(f, "ColorSub_Y"),
+            Cto => write!(f, "CTO"),
+            Ctc => write!(f, "CTC"),
+            Ctb => write!(f, "CTB"),
+            Tint => write!(f, "Tint"),
+            Hsb_Hue => write!(f, "HSB_Hue"),
+            Hsb_Saturation => write!(f, "HSB_Saturation"),
+            Hsb_Brightness => write!(f, "HSB_Brightness"),
+            Hsb_Quality => write!(f, "HSB_Quality"),
+            Cie_X => write!(f, "CIE_X"),
+            Cie_Y => write!(f, "CIE_Y"),
+            Cie_Brightness => write!(f, "CIE_Brightness"),
+            ColorRgb_Red => write!(f, "ColorRGB_Red"),
+            ColorRgb_Green => write!(f, "ColorRGB_Green"),
+            ColorRgb_Blue => write!(f, "ColorRGB_Blue"),
+            ColorRgb_Cyan => write!(f, "ColorRGB_Cyan"),
+            ColorRgb_Magenta => write!(f, "ColorRGB_Magenta"),
+            ColorRgb_Yellow => write!(f, "ColorRGB_Yellow"),
+            ColorRgb_Quality => write!(f, "ColorRGB_Quality"),
+            VideoBoost_R => write!(f, "VideoBoost_R"),
+            VideoBoost_G => write!(f, "VideoBoost_G"),
+            VideoBoost_B => write!(f, "VideoBoost_B"),
+            VideoHueShift => write!(f, "VideoHueShift"),
+            VideoSaturation => write!(f, "VideoSaturation"),
+            VideoBrightness => write!(f, "VideoBrightness"),
+            VideoContrast => write!(f, "VideoContrast"),
+            VideoKeyColor_R => write!(f, "VideoKeyColor_R"),
+            VideoKeyColor_G => write!(f, "VideoKeyColor_G"),
+            VideoKeyColor_B => write!(f, "VideoKeyColor_B"),
+            VideoKeyIntensity => write!(f, "VideoKeyIntensity"),
+            VideoKeyTolerance => write!(f, "VideoKeyTolerance"),
+            StrobeDuration => write!(f, "StrobeDuration"),
+            StrobeRate => write!(f, "StrobeRate"),
+            Iris => write!(f, "Iris"),
+            IrisStrobe => write!(f, "IrisStrobe"),
+            IrisStrobeRandom => write!(f, "IrisStrobeRandom"),
+            IrisPulseClose => write!(f, "IrisPulseClose"),
+            IrisPulseOpen => write!(f, "IrisPulseOpen"),
+            IrisRandomPulseClose => write!(f, "IrisRandomPulseClose"),
+            IrisRandomPulseOpen => write!(f, "IrisRandomPulseOpen"),
+            EffectsSync => write!(f, "EffectsSync"),
+            BeamShaper => write!(f, "BeamShaper"),
+            BeamShaperMacro => write!(f, "BeamShaperMacro"),
+            BeamShaperPos => write!(f, "BeamShaperPos"),
+            BeamShaperPosRotate => write!(f, "BeamShaperPosRotate"),
+            Zoom => write!(f, "Zoom"),
+            ZoomModeSpot => write!(f, "ZoomModeSpot"),
+            ZoomModeBeam => write!(f, "ZoomModeBeam"),
+            DimmerMode => write!(f, "DimmerMode"),
+            DimmerCurve => write!(f, "DimmerCurve"),
+            BlackoutMode => write!(f, "BlackoutMode"),
+            LedFrequency => write!(f, "LEDFrequency"),
+            LedZoneMode => write!(f, "LEDZoneMode"),
+            PixelMode => write!(f, "PixelMode"),
+            PanMode => write!(f, "PanMode"),
+            TiltMode => write!(f, "TiltMode"),
+            PanTiltMode => write!(f, "PanTiltMode"),
+            PositionModes => write!(f, "PositionModes"),
+            AnimationWheelShortcutMode => write!(f, "AnimationWheelShortcutMode"),
+            ColorWheelShortcutMode => write!(f, "ColorWheelShortcutMode"),
+            CyanMode => write!(f, "CyanMode"),
+            MagentaMode => write!(f, "MagentaMode"),
+            YellowMode => write!(f, "YellowMode"),
+            ColorMixMode => write!(f, "ColorMixMode"),
+            ChromaticMode => write!(f, "ChromaticMode"),
+            ColorCalibrationMode => write!(f, "ColorCalibrationMode"),
+            ColorConsistency => write!(f, "ColorConsistency"),
+            ColorControl => write!(f, "ColorControl"),
+            ColorModelMode => write!(f, "ColorModelMode"),
+            ColorSettingsReset => write!(f, "ColorSettingsReset"),
+            ColorUniformity => write!(f, "ColorUniformity"),
+            CriMode => write!(f, "CRIMode"),
+            CustomColor => write!(f, "CustomColor"),
+            UvStability => write!(f, "UVStability"),
+            WavelengthCorrection => write!(f, "WavelengthCorrection"),
+            WhiteCount => write!(f, "WhiteCount"),
+            StrobeMode => write!(f, "StrobeMode"),
+            ZoomMode => write!(f, "ZoomMode"),
+            FocusMode => write!(f, "FocusMode"),
+            IrisMode => write!(f, "IrisMode"),
+            FollowSpotMode => write!(f, "FollowSpotMode"),
+            BeamEffectIndexRotateMode => write!(f, "BeamEffectIndexRotateMode"),
+            IntensityMSpeed => write!(f, "IntensityMSpeed"),
+            PositionMSpeed => write!(f, "PositionMSpeed"),
+            ColorMixMSpeed => write!(f, "ColorMixMSpeed"),
+            ColorWheelSelectMSpeed => write!(f, "ColorWheelSelectMSpeed"),
+            IrisMSpeed => write!(f, "IrisMSpeed"),
+            FocusMSpeed => write!(f, "FocusMSpeed"),
+            ZoomMSpeed => write!(f, "ZoomMSpeed"),
+            FrameMSpeed => write!(f, "FrameMSpeed"),
+            GlobalMSpeed => write!(f, "GlobalMSpeed"),
+            ReflectorAdjust => write!(f, "ReflectorAdjust"),
+            FixtureGlobalReset => write!(f, "FixtureGlobalReset"),
+            ShutterReset => write!(f, "ShutterReset"),
+            BeamReset => write!(f, "BeamReset"),
+            ColorMixReset => write!(f, "ColorMixReset"),
+            ColorWheelReset => write!(f, "ColorWheelReset"),
+            FocusReset => write!(f, "FocusReset"),
+            FrameReset => write!(f, "FrameReset"),
+            GoboWheelReset => write!(f, "GoboWheelReset"),
+            IntensityReset => write!(f, "IntensityReset"),
+            IrisReset => write!(f, "IrisReset"),
+            PositionReset => write!(f, "PositionReset"),
+            PanReset => write!(f, "PanReset"),
+            TiltReset => write!(f, "TiltReset"),
+            ZoomReset => write!(f, "ZoomReset"),
+            CtbReset => write!(f, "CTBReset"),
+            CtoReset => write!(f, "CTOReset"),
+            CtcReset => write!(f, "CTCReset"),
+            AnimationSystemReset => write!(f, "AnimationSystemReset"),
+            FixtureCalibrationReset => write!(f, "FixtureCalibrationReset"),
+            Function => write!(f, "Function"),
+            LampControl => write!(f, "LampControl"),
+            DisplayIntensity => write!(f, "DisplayIntensity"),
+            DmxInput => write!(f, "DMXInput"),
+            NoFeature => write!(f, "NoFeature"),
+            LampPowerMode => write!(f, "LampPowerMode"),
+            Fans => write!(f, "Fans"),
+            ShaperRot => write!(f, "ShaperRot"),
+            ShaperMacros => write!(f, "ShaperMacros"),
+            ShaperMacrosSpeed => write!(f, "ShaperMacrosSpeed"),
+            Video => write!(f, "Video"),
+            VideoBlendMode => write!(f, "VideoBlendMode"),
+            InputSource => write!(f, "InputSource"),
+            FieldOfView => write!(f, "FieldOfView"),
+            Gobo_n_(n) => write!(f, "Gobo{}", n),
+            Gobo_n_SelectSpin(n) => write!(f, "Gobo{}SelectSpin", n),
+            Gobo_n_SelectShake(n) => write!(f, "Gobo{}SelectShake", n),
+            Gobo_n_SelectEffects(n) => write!(f, "Gobo{}SelectEffects", n),
+            Gobo_n_WheelIndex(n) => write!(f, "Gobo{}WheelIndex", n),
+            Gobo_n_WheelSpin(n) => write!(f, "Gobo{}WheelSpin", n),
+            Gobo_n_WheelShake(n) => write!(f, "Gobo{}WheelShake", n),
+            Gobo_n_WheelRandom(n) => write!(f, "Gobo{}WheelRandom", n),
+            Gobo_n_WheelAudio(n) => write!(f, "Gobo{}WheelAudio", n),
+            Gobo_n_Pos(n) => write!(f, "Gobo{}Pos", n),
+            Gobo_n_PosRotate(n) => write!(f, "Gobo{}PosRotate", n),
+            Gobo_n_PosShake(n) => write!(f, "Gobo{}PosShake", n),
+            AnimationWheel_n_(n) => write!(f, "AnimationWheel{}", n),
+            AnimationWheel_n_Audio(n) => write!(f, "AnimationWheel{}Audio", n),
+            AnimationWheel_n_Macro(n) => write!(f, "AnimationWheel{}Macro", n),
+            AnimationWheel_n_Random(n) => write!(f, "AnimationWheel{}Random", n),
+            AnimationWheel_n_SelectEffects(n) => write!(f, "AnimationWheel{}SelectEffects", n),
+            AnimationWheel_n_SelectShake(n) => write!(f, "AnimationWheel{}SelectShake", n),
+            AnimationWheel_n_SelectSpin(n) => write!(f, "AnimationWheel{}SelectSpin", n),
+            AnimationWheel_n_Pos(n) => write!(f, "AnimationWheel{}Pos", n),
+            AnimationWheel_n_PosRotate(n) => write!(f, "AnimationWheel{}PosRotate", n),
+            AnimationWheel_n_PosShake(n) => write!(f, "AnimationWheel{}PosShake", n),
+            AnimationSystem_n_(n) => write!(f, "AnimationSystem{}", n),
+            AnimationSystem_n_Ramp(n) => write!(f, "AnimationSystem{}Ramp", n),
+            AnimationSystem_n_Shake(n) => write!(f, "AnimationSystem{}Shake", n),
+            AnimationSystem_n_Audio(n) => write!(f, "AnimationSystem{}Audio", n),
+            AnimationSystem_n_Random(n) => write!(f, "AnimationSystem{}Random", n),
+            AnimationSystem_n_Pos(n) => write!(f, "AnimationSystem{}Pos", n),
+            AnimationSystem_n_PosRotate(n) => write!(f, "AnimationSystem{}PosRotate", n),
+            AnimationSystem_n_PosShake(n) => write!(f, "AnimationSystem{}PosShake", n),
+            AnimationSystem_n_PosRandom(n) => write!(f, "AnimationSystem{}PosRandom", n),
+            AnimationSystem_n_PosAudio(n) => write!(f, "AnimationSystem{}PosAudio", n),
+            AnimationSystem_n_Macro(n) => write!(f, "AnimationSystem{}Macro", n),
+            MediaFolder_n_(n) => write!(f, "MediaFolder{}", n),
+            MediaContent_n_(n) => write!(f, "MediaContent{}", n),
+            ModelFolder_n_(n) => write!(f, "ModelFolder{}", n),
+            ModelContent_n_(n) => write!(f, "ModelContent{}", n),
+            ColorEffects_n_(n) => write!(f, "ColorEffects{}", n),
+            Color_n_(n) => write!(f, "Color{}", n),
+            Color_n_WheelIndex(n) => write!(f, "Color{}WheelIndex", n),
+            Color_n_WheelSpin(n) => write!(f, "Color{}WheelSpin", n),
+            Color_n_WheelRandom(n) => write!(f, "Color{}WheelRandom", n),
+            Color_n_WheelAudio(n) => write!(f, "Color{}WheelAudio", n),
+            ColorMacro_n_(n) => write!(f, "ColorMacro{}", n),
+            ColorMacro_n_Rate(n) => write!(f, "ColorMacro{}Rate", n),
+            Shutter_n_(n) => write!(f, "Shutter{}", n),
+            Shutter_n_Strobe(n) => write!(f, "Shutter{}Strobe", n),
+            Shutter_n_StrobePulse(n) => write!(f, "Shutter{}StrobePulse", n),
+            Shutter_n_StrobePulseClose(n) => write!(f, "Shutter{}StrobePulseClose", n),
+            Shutter_n_StrobePulseOpen(n) => write!(f, "Shutter{}StrobePulseOpen", n),
+            Shutter_n_StrobeRandom(n) => write!(f, "Shutter{}StrobeRandom", n),
+            Shutter_n_StrobeRandomPulse(n) => write!(f, "Shutter{}StrobeRandomPulse", n),
+            Shutter_n_StrobeRandomPulseClose(n) => write!(f, "Shutter{}StrobeRandomPulseClose", n),
+            Shutter_n_StrobeRandomPulseOpen(n) => write!(f, "Shutter{}StrobeRandomPulseOpen", n),
+            Shutter_n_StrobeEffect(n) => write!(f, "Shutter{}StrobeEffect", n),
+            Frost_n_(n) => write!(f, "Frost{}", n),
+            Frost_n_PulseOpen(n) => write!(f, "Frost{}PulseOpen", n),
+            Frost_n_PulseClose(n) => write!(f, "Frost{}PulseClose", n),
+            Frost_n_Ramp(n) => write!(f, "Frost{}Ramp", n),
+            Prism_n_(n) => write!(f, "Prism{}", n),
+            Prism_n_SelectSpin(n) => write!(f, "Prism{}SelectSpin", n),
+            Prism_n_Macro(n) => write!(f, "Prism{}Macro", n),
+            Prism_n_Pos(n) => write!(f, "Prism{}Pos", n),
+            Prism_n_PosRotate(n) => write!(f, "Prism{}PosRotate", n),
+            Effects_n_(n) => write!(f, "Effects{}", n),
+            Effects_n_Rate(n) => write!(f, "Effects{}Rate", n),
+            Effects_n_Fade(n) => write!(f, "Effects{}Fade", n),
+            Effects_n_Adjust_m_(n, m) => write!(f, "Effects{}Adjust{}", n, m),
+            Effects_n_Pos(n) => write!(f, "Effects{}Pos", n),
+            Effects_n_PosRotate(n) => write!(f, "Effects{}PosRotate", n),
+            Focus_n_(n) => write!(f, "Focus{}", n),
+            Focus_n_Adjust(n) => write!(f, "Focus{}Adjust", n),
+            Focus_n_Distance(n) => write!(f, "Focus{}Distance", n),
+            Control_n_(n) => write!(f, "Control{}", n),
+            Gobo_n_WheelMode(n) => write!(f, "Gobo{}WheelMode", n),
+            AnimationWheel_n_Mode(n) => write!(f, "AnimationWheel{}Mode", n),
+            Color_n_Mode(n) => write!(f, "Color{}Mode", n),
+            Fan_n_Mode(n) => write!(f, "Fan{}Mode", n),
+            GoboWheel_n_MSpeed(n) => write!(f, "GoboWheel{}MSpeed", n),
+            Prism_n_MSpeed(n) => write!(f, "Prism{}MSpeed", n),
+            Frost_n_MSpeed(n) => write!(f, "Frost{}MSpeed", n),
+            Blower_n_(n) => write!(f, "Blower{}", n),
+            Fan_n_(n) => write!(f, "Fan{}", n),
+            Fog_n_(n) => write!(f, "Fog{}", n),
+            Haze_n_(n) => write!(f, "Haze{}", n),
+            Blade_n_A(n) => write!(f, "Blade{}A", n),
+            Blade_n_B(n) => write!(f, "Blade{}B", n),
+            Blade_n_Rot(n) => write!(f, "Blade{}Rot", n),
+            BladeSoft_n_A(n) => write!(f, "BladeSoft{}A", n),
+            BladeSoft_n_B(n) => write!(f, "BladeSoft{}B", n),
+            KeyStone_n_A(n) => write!(f, "KeyStone{}A", n),
+            KeyStone_n_B(n) => write!(f, "KeyStone{}B", n),
+            VideoEffect_n_Type(n) => write!(f, "VideoEffect{}Type", n),
+            VideoEffect_n_Parameter_m_(n, m) => write!(f, "VideoEffect{}Parameter{}", n, m),
+            VideoCamera_n_(n) => write!(f, "VideoCamera{}", n),
+            VideoSoundVolume_n_(n) => write!(f, "VideoSoundVolume{}", n),
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for AttributeName {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for AttributeName {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, |value: &str| {
+            match read::original_key(value) {
+                Some(_) => Ok(AttributeName::UserDefined(Name::new_or_renamed(value)?)),
+                None => Self::new_from_str(value),
+            }
+        })
+    }
 }
 
 #[cfg(test)]
@@ -1172,6 +2105,7 @@ mod tests {
     use crate::utils::testdata;
     use crate::utils::units::attribute_name::AttributeName as T;
     use crate::utils::units::name::Name;
+    use crate::utils::units::physical_unit::PhysicalUnit;
 
     #[test]
     fn test_default() -> Result<(), GdtfError> {
@@ -1206,6 +2140,10 @@ mod tests {
         assert_eq!(Gobo_n_(1), T::new_from_str("Gobo1")?);
         assert_eq!(Gobo_n_(2), T::new_from_str("Gobo2")?);
         assert_eq!(Gobo_n_(120), T::new_from_str("Gobo120")?);
+        assert_eq!(
+            UserDefined(Name::new("Gobo300")?),
+            T::new_from_str("Gobo300")?
+        );
         assert_eq!(Gobo_n_SelectSpin(1), T::new_from_str("Gobo1SelectSpin")?);
         assert_eq!(Gobo_n_SelectSpin(2), T::new_from_str("Gobo2SelectSpin")?);
         assert_eq!(
@@ -2201,4 +3139,429 @@ mod tests {
 
         Ok(())
     }
+
+    ///All predefined AttributeNames with some indices for testing the tables
+    fn all_predefined() -> Vec<T> {
+        use T::*;
+        vec![
+            Dimmer,
+            Pan,
+            Tilt,
+            PanRotate,
+            TiltRotate,
+            PositionEffect,
+            PositionEffectRate,
+            PositionEffectFade,
+            Xyz_X,
+            Xyz_Y,
+            Xyz_Z,
+            Rot_X,
+            Rot_Y,
+            Rot_Z,
+            Scale_X,
+            Scale_Y,
+            Scale_Z,
+            Scale_Xyz,
+            Gobo_n_(1),
+            Gobo_n_(120),
+            Gobo_n_SelectSpin(1),
+            Gobo_n_SelectSpin(120),
+            Gobo_n_SelectShake(1),
+            Gobo_n_SelectShake(120),
+            Gobo_n_SelectEffects(1),
+            Gobo_n_SelectEffects(120),
+            Gobo_n_WheelIndex(1),
+            Gobo_n_WheelIndex(120),
+            Gobo_n_WheelSpin(1),
+            Gobo_n_WheelSpin(120),
+            Gobo_n_WheelShake(1),
+            Gobo_n_WheelShake(120),
+            Gobo_n_WheelRandom(1),
+            Gobo_n_WheelRandom(120),
+            Gobo_n_WheelAudio(1),
+            Gobo_n_WheelAudio(120),
+            Gobo_n_Pos(1),
+            Gobo_n_Pos(120),
+            Gobo_n_PosRotate(1),
+            Gobo_n_PosRotate(120),
+            Gobo_n_PosShake(1),
+            Gobo_n_PosShake(120),
+            AnimationWheel_n_(1),
+            AnimationWheel_n_(120),
+            AnimationWheel_n_Audio(1),
+            AnimationWheel_n_Audio(120),
+            AnimationWheel_n_Macro(1),
+            AnimationWheel_n_Macro(120),
+            AnimationWheel_n_Random(1),
+            AnimationWheel_n_Random(120),
+            AnimationWheel_n_SelectEffects(1),
+            AnimationWheel_n_SelectEffects(120),
+            AnimationWheel_n_SelectShake(1),
+            AnimationWheel_n_SelectShake(120),
+            AnimationWheel_n_SelectSpin(1),
+            AnimationWheel_n_SelectSpin(120),
+            AnimationWheel_n_Pos(1),
+            AnimationWheel_n_Pos(120),
+            AnimationWheel_n_PosRotate(1),
+            AnimationWheel_n_PosRotate(120),
+            AnimationWheel_n_PosShake(1),
+            AnimationWheel_n_PosShake(120),
+            AnimationSystem_n_(1),
+            AnimationSystem_n_(120),
+            AnimationSystem_n_Ramp(1),
+            AnimationSystem_n_Ramp(120),
+            AnimationSystem_n_Shake(1),
+            AnimationSystem_n_Shake(120),
+            AnimationSystem_n_Audio(1),
+            AnimationSystem_n_Audio(120),
+            AnimationSystem_n_Random(1),
+            AnimationSystem_n_Random(120),
+            AnimationSystem_n_Pos(1),
+            AnimationSystem_n_Pos(120),
+            AnimationSystem_n_PosRotate(1),
+            AnimationSystem_n_PosRotate(120),
+            AnimationSystem_n_PosShake(1),
+            AnimationSystem_n_PosShake(120),
+            AnimationSystem_n_PosRandom(1),
+            AnimationSystem_n_PosRandom(120),
+            AnimationSystem_n_PosAudio(1),
+            AnimationSystem_n_PosAudio(120),
+            AnimationSystem_n_Macro(1),
+            AnimationSystem_n_Macro(120),
+            MediaFolder_n_(1),
+            MediaFolder_n_(120),
+            MediaContent_n_(1),
+            MediaContent_n_(120),
+            ModelFolder_n_(1),
+            ModelFolder_n_(120),
+            ModelContent_n_(1),
+            ModelContent_n_(120),
+            PlayMode,
+            PlayBegin,
+            PlayEnd,
+            PlaySpeed,
+            ColorEffects_n_(1),
+            ColorEffects_n_(120),
+            Color_n_(1),
+            Color_n_(120),
+            Color_n_WheelIndex(1),
+            Color_n_WheelIndex(120),
+            Color_n_WheelSpin(1),
+            Color_n_WheelSpin(120),
+            Color_n_WheelRandom(1),
+            Color_n_WheelRandom(120),
+            Color_n_WheelAudio(1),
+            Color_n_WheelAudio(120),
+            ColorAdd_R,
+            ColorAdd_G,
+            ColorAdd_B,
+            ColorAdd_C,
+            ColorAdd_M,
+            ColorAdd_Y,
+            ColorAdd_Ry,
+            ColorAdd_Gy,
+            ColorAdd_Gc,
+            ColorAdd_Bc,
+            ColorAdd_Bm,
+            ColorAdd_Rm,
+            ColorAdd_W,
+            ColorAdd_Ww,
+            ColorAdd_Cw,
+            ColorAdd_Uv,
+            ColorSub_R,
+            ColorSub_G,
+            ColorSub_B,
+            ColorSub_C,
+            ColorSub_M,
+            ColorSub_Y,
+            ColorMacro_n_(1),
+            ColorMacro_n_(120),
+            ColorMacro_n_Rate(1),
+            ColorMacro_n_Rate(120),
+            Cto,
+            Ctc,
+            Ctb,
+            Tint,
+            Hsb_Hue,
+            Hsb_Saturation,
+            Hsb_Brightness,
+            Hsb_Quality,
+            Cie_X,
+            Cie_Y,
+            Cie_Brightness,
+            ColorRgb_Red,
+            ColorRgb_Green,
+            ColorRgb_Blue,
+            ColorRgb_Cyan,
+            ColorRgb_Magenta,
+            ColorRgb_Yellow,
+            ColorRgb_Quality,
+            VideoBoost_R,
+            VideoBoost_G,
+            VideoBoost_B,
+            VideoHueShift,
+            VideoSaturation,
+            VideoBrightness,
+            VideoContrast,
+            VideoKeyColor_R,
+            VideoKeyColor_G,
+            VideoKeyColor_B,
+            VideoKeyIntensity,
+            VideoKeyTolerance,
+            StrobeDuration,
+            StrobeRate,
+            Shutter_n_(1),
+            Shutter_n_(120),
+            Shutter_n_Strobe(1),
+            Shutter_n_Strobe(120),
+            Shutter_n_StrobePulse(1),
+            Shutter_n_StrobePulse(120),
+            Shutter_n_StrobePulseClose(1),
+            Shutter_n_StrobePulseClose(120),
+            Shutter_n_StrobePulseOpen(1),
+            Shutter_n_StrobePulseOpen(120),
+            Shutter_n_StrobeRandom(1),
+            Shutter_n_StrobeRandom(120),
+            Shutter_n_StrobeRandomPulse(1),
+            Shutter_n_StrobeRandomPulse(120),
+            Shutter_n_StrobeRandomPulseClose(1),
+            Shutter_n_StrobeRandomPulseClose(120),
+            Shutter_n_StrobeRandomPulseOpen(1),
+            Shutter_n_StrobeRandomPulseOpen(120),
+            Shutter_n_StrobeEffect(1),
+            Shutter_n_StrobeEffect(120),
+            Iris,
+            IrisStrobe,
+            IrisStrobeRandom,
+            IrisPulseClose,
+            IrisPulseOpen,
+            IrisRandomPulseClose,
+            IrisRandomPulseOpen,
+            Frost_n_(1),
+            Frost_n_(120),
+            Frost_n_PulseOpen(1),
+            Frost_n_PulseOpen(120),
+            Frost_n_PulseClose(1),
+            Frost_n_PulseClose(120),
+            Frost_n_Ramp(1),
+            Frost_n_Ramp(120),
+            Prism_n_(1),
+            Prism_n_(120),
+            Prism_n_SelectSpin(1),
+            Prism_n_SelectSpin(120),
+            Prism_n_Macro(1),
+            Prism_n_Macro(120),
+            Prism_n_Pos(1),
+            Prism_n_Pos(120),
+            Prism_n_PosRotate(1),
+            Prism_n_PosRotate(120),
+            Effects_n_(1),
+            Effects_n_(120),
+            Effects_n_Rate(1),
+            Effects_n_Rate(120),
+            Effects_n_Fade(1),
+            Effects_n_Fade(120),
+            Effects_n_Adjust_m_(1, 2),
+            Effects_n_Adjust_m_(120, 3),
+            Effects_n_Pos(1),
+            Effects_n_Pos(120),
+            Effects_n_PosRotate(1),
+            Effects_n_PosRotate(120),
+            EffectsSync,
+            BeamShaper,
+            BeamShaperMacro,
+            BeamShaperPos,
+            BeamShaperPosRotate,
+            Zoom,
+            ZoomModeSpot,
+            ZoomModeBeam,
+            Focus_n_(1),
+            Focus_n_(120),
+            Focus_n_Adjust(1),
+            Focus_n_Adjust(120),
+            Focus_n_Distance(1),
+            Focus_n_Distance(120),
+            Control_n_(1),
+            Control_n_(120),
+            DimmerMode,
+            DimmerCurve,
+            BlackoutMode,
+            LedFrequency,
+            LedZoneMode,
+            PixelMode,
+            PanMode,
+            TiltMode,
+            PanTiltMode,
+            PositionModes,
+            Gobo_n_WheelMode(1),
+            Gobo_n_WheelMode(120),
+            AnimationWheel_n_Mode(1),
+            AnimationWheel_n_Mode(120),
+            AnimationWheelShortcutMode,
+            Color_n_Mode(1),
+            Color_n_Mode(120),
+            ColorWheelShortcutMode,
+            CyanMode,
+            MagentaMode,
+            YellowMode,
+            ColorMixMode,
+            ChromaticMode,
+            ColorCalibrationMode,
+            ColorConsistency,
+            ColorControl,
+            ColorModelMode,
+            ColorSettingsReset,
+            ColorUniformity,
+            CriMode,
+            CustomColor,
+            UvStability,
+            WavelengthCorrection,
+            WhiteCount,
+            StrobeMode,
+            ZoomMode,
+            FocusMode,
+            IrisMode,
+            Fan_n_Mode(1),
+            Fan_n_Mode(120),
+            FollowSpotMode,
+            BeamEffectIndexRotateMode,
+            IntensityMSpeed,
+            PositionMSpeed,
+            ColorMixMSpeed,
+            ColorWheelSelectMSpeed,
+            GoboWheel_n_MSpeed(1),
+            GoboWheel_n_MSpeed(120),
+            IrisMSpeed,
+            Prism_n_MSpeed(1),
+            Prism_n_MSpeed(120),
+            FocusMSpeed,
+            Frost_n_MSpeed(1),
+            Frost_n_MSpeed(120),
+            ZoomMSpeed,
+            FrameMSpeed,
+            GlobalMSpeed,
+            ReflectorAdjust,
+            FixtureGlobalReset,
+            ShutterReset,
+            BeamReset,
+            ColorMixReset,
+            ColorWheelReset,
+            FocusReset,
+            FrameReset,
+            GoboWheelReset,
+            IntensityReset,
+            IrisReset,
+            PositionReset,
+            PanReset,
+            TiltReset,
+            ZoomReset,
+            CtbReset,
+            CtoReset,
+            CtcReset,
+            AnimationSystemReset,
+            FixtureCalibrationReset,
+            Function,
+            LampControl,
+            DisplayIntensity,
+            DmxInput,
+            NoFeature,
+            Blower_n_(1),
+            Blower_n_(120),
+            Fan_n_(1),
+            Fan_n_(120),
+            Fog_n_(1),
+            Fog_n_(120),
+            Haze_n_(1),
+            Haze_n_(120),
+            LampPowerMode,
+            Fans,
+            Blade_n_A(1),
+            Blade_n_A(120),
+            Blade_n_B(1),
+            Blade_n_B(120),
+            Blade_n_Rot(1),
+            Blade_n_Rot(120),
+            ShaperRot,
+            ShaperMacros,
+            ShaperMacrosSpeed,
+            BladeSoft_n_A(1),
+            BladeSoft_n_A(120),
+            BladeSoft_n_B(1),
+            BladeSoft_n_B(120),
+            KeyStone_n_A(1),
+            KeyStone_n_A(120),
+            KeyStone_n_B(1),
+            KeyStone_n_B(120),
+            Video,
+            VideoEffect_n_Type(1),
+            VideoEffect_n_Type(120),
+            VideoEffect_n_Parameter_m_(1, 2),
+            VideoEffect_n_Parameter_m_(120, 3),
+            VideoCamera_n_(1),
+            VideoCamera_n_(120),
+            VideoSoundVolume_n_(1),
+            VideoSoundVolume_n_(120),
+            VideoBlendMode,
+            InputSource,
+            FieldOfView,
+        ]
+    }
+
+    #[test]
+    fn test_display() -> Result<(), GdtfError> {
+        use T::*;
+        assert_eq!(
+            "Something else",
+            UserDefined(Name::new("Something else")?).to_string()
+        );
+        assert_eq!("ColorAdd_RY", ColorAdd_Ry.to_string());
+        assert_eq!("CTBReset", CtbReset.to_string());
+        assert_eq!(
+            "VideoEffect3Parameter12",
+            VideoEffect_n_Parameter_m_(3, 12).to_string()
+        );
+        for attribute_name in all_predefined() {
+            assert_eq!(
+                attribute_name,
+                T::new_from_str(&attribute_name.to_string())?
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_defaults() -> Result<(), GdtfError> {
+        use T::*;
+        for attribute_name in all_predefined() {
+            assert!(attribute_name.default_feature().is_some());
+            assert!(attribute_name.default_pretty().is_some());
+            if let Some(main_attribute) = attribute_name.default_main_attribute() {
+                assert_eq!(
+                    main_attribute.default_feature(),
+                    attribute_name.default_feature()
+                );
+                assert_eq!(main_attribute.default_main_attribute(), None);
+            }
+        }
+        assert_eq!(
+            Some("Color Mix Reset".to_string()),
+            ColorMixReset.default_pretty()
+        );
+        assert_eq!(
+            PhysicalUnit::ColorComponent,
+            ColorSub_M.default_physical_unit()
+        );
+        assert_eq!(PhysicalUnit::Temperature, Cto.default_physical_unit());
+        assert_eq!(
+            Some(Effects_n_Pos(4)),
+            Effects_n_PosRotate(4).default_main_attribute()
+        );
+        assert!(ColorAdd_Ww.default_color().is_some());
+        assert!(ColorRgb_Quality.default_color().is_none());
+        assert_eq!(
+            None,
+            UserDefined(Name::new("test")?).default_main_attribute()
+        );
+        Ok(())
+    }
 }
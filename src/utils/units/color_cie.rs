@@ -9,6 +9,8 @@ use quick_xml::events::attributes::Attribute;
 use crate::utils::read;
 
 ///CIE color representation xyY 1931 used in GDTF
+///
+///Conversions to XYZ, sRGB, CIELAB and colour temperature are implemented in `utils::color`
 #[derive(Debug, PartialEq, Clone)]
 #[allow(non_snake_case)]
 pub struct ColorCie {
@@ -60,6 +62,31 @@ pub const COLOR_CIE_WHITE: ColorCie = ColorCie {
     Y: 100.0,
 };
 
+///Displays a ColorCie in the format x,y,Y used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::color_cie::ColorCie;
+/// assert_eq!(ColorCie{ x: 0.3127, y: 0.329, Y: 100.0}.to_string(), "0.3127,0.329,100");
+/// ```
+impl fmt::Display for ColorCie {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.Y)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ColorCie {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ColorCie {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[derive(Debug)]
 ///Error when ColorCIE could not be parsed
 pub enum GdtfColorCieError {
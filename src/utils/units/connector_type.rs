@@ -1,4 +1,7 @@
 //!A list of predefined connectors or Other with Name
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
 use quick_xml::events::attributes::Attribute;
 
 use crate::utils::errors::GdtfError;
@@ -286,6 +289,120 @@ impl ConnectorType {
     }
 }
 
+///Displays a ConnectorType as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::connector_type::ConnectorType;
+/// use gdtf_parser::utils::units::name::Name;
+/// assert_eq!(ConnectorType::Xlr5.to_string(), "XLR5");
+/// assert_eq!(ConnectorType::Other(Name::from("Something else")).to_string(), "Something else");
+/// ```
+impl Display for ConnectorType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            ConnectorType::Other(name) => return write!(f, "{}", name),
+            ConnectorType::Bnc => "BNC",
+            ConnectorType::Tblk => "TBLK",
+            ConnectorType::Tag => "TAG",
+            ConnectorType::Krn => "KRN",
+            ConnectorType::Stj => "STJ",
+            ConnectorType::Mstj => "MSTJ",
+            ConnectorType::Rca => "RCA",
+            ConnectorType::Scart => "SCART",
+            ConnectorType::Svideo => "SVIDEO",
+            ConnectorType::Mdin4 => "MDIN4",
+            ConnectorType::Mdin5 => "MDIN5",
+            ConnectorType::Mdin6 => "MDIN6",
+            ConnectorType::Xlr3 => "XLR3",
+            ConnectorType::Xlr4 => "XLR4",
+            ConnectorType::Xlr5 => "XLR5",
+            ConnectorType::Rj45 => "RJ45",
+            ConnectorType::Rj11 => "RJ11",
+            ConnectorType::Db9 => "DB9",
+            ConnectorType::Db15 => "DB15",
+            ConnectorType::Db25 => "DB25",
+            ConnectorType::Db37 => "DB37",
+            ConnectorType::Db50 => "DB50",
+            ConnectorType::Hd15 => "HD15",
+            ConnectorType::Hd25 => "HD25",
+            ConnectorType::Din3 => "DIN3",
+            ConnectorType::Din5 => "DIN5",
+            ConnectorType::Edac20 => "EDAC20",
+            ConnectorType::Edac38 => "EDAC38",
+            ConnectorType::Edac56 => "EDAC56",
+            ConnectorType::Edac90 => "EDAC90",
+            ConnectorType::Edac120 => "EDAC120",
+            ConnectorType::Dl96 => "DL96",
+            ConnectorType::Scsi68 => "SCSI68",
+            ConnectorType::Iee488 => "IEE488",
+            ConnectorType::Cent50 => "CENT50",
+            ConnectorType::Cent36 => "CENT36",
+            ConnectorType::Cent24 => "CENT24",
+            ConnectorType::DisplayPort => "DisplayPort",
+            ConnectorType::Dvi => "DVI",
+            ConnectorType::Hdmi => "HDMI",
+            ConnectorType::Ps2 => "PS2",
+            ConnectorType::Tl_St => "TL-ST",
+            ConnectorType::Lcdup => "LCDUP",
+            ConnectorType::Scdup => "SCDUP",
+            ConnectorType::Sc => "SC",
+            ConnectorType::St => "ST",
+            ConnectorType::Nl4 => "NL4",
+            ConnectorType::Cacom => "CACOM",
+            ConnectorType::Usb => "USB",
+            ConnectorType::N_Con => "N_CON",
+            ConnectorType::F_Con => "F_CON",
+            ConnectorType::Iec_60320_C7_C8 => "IEC 60320-C7/C8",
+            ConnectorType::Cee_7_7 => "CEE 7/7",
+            ConnectorType::Iec_60320_C13_14 => "IEC 60320-C13/14",
+            ConnectorType::Edison => "Edison",
+            ConnectorType::Wieland => "Wieland",
+            ConnectorType::_16A_Cee_2P => "16A-CEE-2P",
+            ConnectorType::_16A_Cee_2P_110 => "16A-CEE-2P-110",
+            ConnectorType::_16A_Cee => "16A-CEE",
+            ConnectorType::_32A_Cee => "32A-CEE",
+            ConnectorType::_32A_Cee_2P => "32A-CEE-2P",
+            ConnectorType::_32A_Cee_2P_110 => "32A-CEE-2P-110",
+            ConnectorType::_63A_Cee => "63A-CEE",
+            ConnectorType::_125A_Cee => "125A-CEE",
+            ConnectorType::Powerlock => "Powerlock",
+            ConnectorType::Powerlock_120A => "Powerlock 120A",
+            ConnectorType::Powerlock_400A => "Powerlock 400A",
+            ConnectorType::Powerlock_660A => "Powerlock 660A",
+            ConnectorType::Powerlock_800A => "Powerlock 800A",
+            ConnectorType::Camlock => "Camlock",
+            ConnectorType::Nac3Fca => "NAC3FCA",
+            ConnectorType::Nac3Fcb => "NAC3FCB",
+            ConnectorType::PowerconTrue1 => "PowerconTRUE1",
+            ConnectorType::PowerconTrue1Top => "powerCONTRUE1TOP",
+            ConnectorType::Socapex_16 => "Socapex-16",
+            ConnectorType::Socapex_7 => "Socapex-7",
+            ConnectorType::Socapex_9 => "Socapex-9",
+            ConnectorType::Han_16 => "HAN-16",
+            ConnectorType::Han_4 => "HAN-4",
+            ConnectorType::L6_20 => "L6-20",
+            ConnectorType::L15_30 => "L15-30",
+            ConnectorType::Stagepin => "Stagepin",
+            ConnectorType::Hubbell_6_4 => "HUBBELL-6-4",
+            ConnectorType::Din_56905 => "DIN 56905",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for ConnectorType {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for ConnectorType {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::borrow::Cow;
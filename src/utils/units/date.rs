@@ -87,6 +87,35 @@ impl Date {
     }
 }
 
+///Displays a Date in the format yyyy-mm-ddThh:mm:ss used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::date::Date;
+/// assert_eq!(Date{ year: 2021, month: 4, day: 1, hour: 15, minute: 3, second: 16}.to_string(), "2021-04-01T15:03:16");
+/// ```
+impl Display for Date {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}",
+            self.year, self.month, self.day, self.hour, self.minute, self.second
+        )
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Date {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Date {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[derive(Debug)]
 /// Error that occures if the format of Date is wrong e.q. not yyyy-mm-ddThh:mm:ss
 pub struct GdtfDateError {}
@@ -31,6 +31,7 @@ impl DmxValue {
     /// assert!(DmxValue::new_from_str("Something invalid").is_err());
     /// ```
     pub fn new_from_str(value: &str) -> Result<Self, GdtfDmxValueError> {
+        let original = value;
         let (value, is_byte_shifting) = if value.strip_suffix('s').is_some() {
             (&value[..value.len() - 1], true)
         } else {
@@ -38,12 +39,13 @@ impl DmxValue {
         };
         let value: Vec<&str> = value.split('/').collect();
         if value.len() != 2 {
-            return Err(GdtfDmxValueError {});
+            return Err(GdtfDmxValueError(original.to_string()));
         }
 
         Ok(DmxValue {
-            initial_value: u32::from_str(value[0]).map_err(|_| GdtfDmxValueError {})?,
-            n: u8::from_str(value[1]).map_err(|_| GdtfDmxValueError {})?,
+            initial_value: u32::from_str(value[0])
+                .map_err(|_| GdtfDmxValueError(original.to_string()))?,
+            n: u8::from_str(value[1]).map_err(|_| GdtfDmxValueError(original.to_string()))?,
             is_byte_shifting,
         })
     }
@@ -60,17 +62,79 @@ impl DmxValue {
     pub fn new_from_attr(attr: Attribute<'_>) -> Result<Self, GdtfDmxValueError> {
         Self::new_from_str(read::attr_to_str(&attr))
     }
+
+    ///Returns the value in a DMX channel with the given byte count, using byte mirroring or byte shifting as defined in the DmxValue
+    ///```rust
+    /// use gdtf_parser::utils::units::dmx_value::DmxValue;
+    /// assert_eq!(DmxValue::new_from_str("255/1").unwrap().to_resolution(2), 65535);
+    /// assert_eq!(DmxValue::new_from_str("255/1s").unwrap().to_resolution(2), 65280);
+    /// assert_eq!(DmxValue::new_from_str("128/1").unwrap().to_resolution(3), 8421504);
+    /// assert_eq!(DmxValue::new_from_str("32768/2").unwrap().to_resolution(1), 128);
+    /// ```
+    pub fn to_resolution(&self, bytes: u8) -> u64 {
+        let n = u32::from(self.n.max(1));
+        let bytes = u32::from(bytes.max(1));
+        let value = u64::from(self.initial_value);
+        if bytes <= n {
+            return value.checked_shr(8 * (n - bytes)).unwrap_or(0);
+        }
+        if self.is_byte_shifting {
+            return value.checked_shl(8 * (bytes - n)).unwrap_or(0);
+        }
+        let mut result: u64 = 0;
+        let mut remaining = bytes;
+        while remaining >= n {
+            result = result.checked_shl(8 * n).unwrap_or(0) | value;
+            remaining -= n;
+        }
+        if remaining > 0 {
+            result = result.checked_shl(8 * remaining).unwrap_or(0)
+                | value.checked_shr(8 * (n - remaining)).unwrap_or(0);
+        }
+        result
+    }
+}
+
+///Displays a DmxValue in the format Uint/n or Uint/ns used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::dmx_value::DmxValue;
+/// assert_eq!(DmxValue{ initial_value: 255, n: 1, is_byte_shifting: false}.to_string(), "255/1");
+/// assert_eq!(DmxValue{ initial_value: 255, n: 1, is_byte_shifting: true}.to_string(), "255/1s");
+/// ```
+impl Display for DmxValue {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.initial_value, self.n)?;
+        if self.is_byte_shifting {
+            write!(f, "s")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for DmxValue {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for DmxValue {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
 }
 
 #[derive(Debug)]
-/// Error that occures if the format of DmxValue is wrong e.q. not Uint/n or Uint/ns
-pub struct GdtfDmxValueError {}
+/// Error that occures if the format of DmxValue is wrong e.q. not Uint/n or Uint/ns. Contains the wrong value
+pub struct GdtfDmxValueError(pub String);
 
 impl Display for GdtfDmxValueError {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "The DMXValue must be formatted Uint/n or Uint/ns. Current form"
+            "The DMXValue '{}' must be formatted Uint/n or Uint/ns",
+            self.0
         )
     }
 }
@@ -78,8 +142,8 @@ impl Display for GdtfDmxValueError {
 impl Error for GdtfDmxValueError {}
 
 impl From<Utf8Error> for GdtfDmxValueError {
-    fn from(_: Utf8Error) -> Self {
-        GdtfDmxValueError {}
+    fn from(e: Utf8Error) -> Self {
+        GdtfDmxValueError(e.to_string())
     }
 }
 
@@ -132,6 +196,7 @@ mod tests {
         assert!(DmxValue::new_from_str("-1/-3").is_err());
         assert!(DmxValue::new_from_str("1/-3s").is_err());
         assert!(DmxValue::new_from_str("1/-3").is_err());
+        assert_eq!(DmxValue::new_from_str("1/-3s").unwrap_err().0, "1/-3s");
     }
 
     #[test]
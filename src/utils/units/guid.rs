@@ -44,7 +44,7 @@ const CHAR_E_AS_U8: u8 = 0x45;
 const CHAR_F_AS_U8: u8 = 0x46;
 
 ///GUID representation used in FixtureType in GDTF
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
 pub struct Guid(pub [u8; 16]);
 
 impl Guid {
@@ -67,6 +67,13 @@ impl Guid {
     /// assert!(Guid::new_from_str("Somthing invalid").is_err());
     /// ```
     pub fn new_from_str(s: &str) -> Result<Self, GdtfGuidError> {
+        Self::bytes_from_str(s)
+            .map(Guid)
+            .map_err(|_| GdtfGuidError(s.to_string()))
+    }
+
+    ///Helper method for `new_from_str` that returns the bytes of the GUID
+    fn bytes_from_str(s: &str) -> Result<[u8; 16], ()> {
         let s: Vec<char> = s.chars().collect();
         let mut s = s.iter().map(|c| *c as u8).collect::<Vec<_>>();
 
@@ -86,7 +93,7 @@ impl Guid {
         //Is safe because only 0..F and - chars will succeed anyway
         match s.pop() {
             Some(CHAR_MINUS_AS_U8) => {}
-            _ => return Err(GdtfGuidError {}),
+            _ => return Err(()),
         }
         //Is safe because only 0..F and - chars will succeed anyway
         bytes[9] = Self::pop_last_byte(&mut s)?;
@@ -95,7 +102,7 @@ impl Guid {
         //Is safe because only 0..F and - chars will succeed anyway
         match s.pop() {
             Some(CHAR_MINUS_AS_U8) => {}
-            _ => return Err(GdtfGuidError {}),
+            _ => return Err(()),
         }
         //Is safe because only 0..F and - chars will succeed anyway
         bytes[7] = Self::pop_last_byte(&mut s)?;
@@ -104,7 +111,7 @@ impl Guid {
         //Is safe because only 0..F and - chars will succeed anyway
         match s.pop() {
             Some(CHAR_MINUS_AS_U8) => {}
-            _ => return Err(GdtfGuidError {}),
+            _ => return Err(()),
         }
         //Is safe because only 0..F and - chars will succeed anyway
         bytes[5] = Self::pop_last_byte(&mut s)?;
@@ -113,7 +120,7 @@ impl Guid {
         //Is safe because only 0..F and - chars will succeed anyway
         match s.pop() {
             Some(CHAR_MINUS_AS_U8) => {}
-            _ => return Err(GdtfGuidError {}),
+            _ => return Err(()),
         }
         //Is safe because only 0..F and - chars will succeed anyway
         bytes[3] = Self::pop_last_byte(&mut s)?;
@@ -124,7 +131,7 @@ impl Guid {
         //Is safe because only 0..F and - chars will succeed anyway
         bytes[0] = Self::pop_last_byte(&mut s)?;
 
-        Ok(Guid(bytes))
+        Ok(bytes)
     }
 
     /// Converts a quick-xml-attribute from gdtf-xml-description to a FixtureType_Guid. Returns an error if the format is not correct.
@@ -148,6 +155,14 @@ impl Guid {
     /// assert_eq!( Guid([48, 142, 168, 125, 113, 100, 66, 222, 129, 6, 166, 210, 115, 245, 122, 81]).to_str().unwrap(), "308EA87D-7164-42DE-8106-A6D273F57A51".to_string());
     ///```
     pub fn to_str(&self) -> Result<String, GdtfGuidError> {
+        let chars = self
+            .hex_chars()
+            .map_err(|_| GdtfGuidError(format!("{:?}", self.0)))?;
+        Ok(std::str::from_utf8(&chars)?.to_string())
+    }
+
+    ///Helper method for `to_str` that returns the UTF8 chars of the GUID
+    fn hex_chars(&self) -> Result<[u8; 36], ()> {
         let val = self.0;
         let mut v = [0_u8; 36];
 
@@ -213,15 +228,15 @@ impl Guid {
         v[34] = Self::halfbyte_to_hexcharbyte(v1)?;
         v[35] = Self::halfbyte_to_hexcharbyte(v2)?;
 
-        Ok(std::str::from_utf8(&v)?.to_string())
+        Ok(v)
     }
 
     ///Helper method to convert a str to GUID. It pops the last byte from a str in format XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX where XX is a byte in hex in UTF8 format and returns it's value as u8. This method will always pop the last two chars who represent a byte.
-    fn pop_last_byte(vec: &mut Vec<u8>) -> Result<u8, GdtfGuidError> {
+    fn pop_last_byte(vec: &mut Vec<u8>) -> Result<u8, ()> {
         let (first, second) = match vec.pop() {
-            None => Err(GdtfGuidError {}),
+            None => Err(()),
             Some(val2) => match vec.pop() {
-                None => Err(GdtfGuidError {}),
+                None => Err(()),
                 Some(val1) => Ok((val1, val2)),
             },
         }?;
@@ -234,7 +249,7 @@ impl Guid {
     /// assert_eq!(Guid::hexcharbyte_to_halfbyte(0x39).unwrap(), 9);
     /// assert_eq!(Guid::hexcharbyte_to_halfbyte(0x41).unwrap(), 10);
     /// ```
-    fn hexcharbyte_to_halfbyte(c: u8) -> Result<u8, GdtfGuidError> {
+    fn hexcharbyte_to_halfbyte(c: u8) -> Result<u8, ()> {
         match c {
             CHAR_0_AS_U8 => Ok(0),
             CHAR_1_AS_U8 => Ok(1),
@@ -252,7 +267,7 @@ impl Guid {
             CHAR_D_AS_U8 => Ok(13),
             CHAR_E_AS_U8 => Ok(14),
             CHAR_F_AS_U8 => Ok(15),
-            _ => Err(GdtfGuidError {}),
+            _ => Err(()),
         }
     }
 
@@ -262,7 +277,7 @@ impl Guid {
     /// assert_eq!(Guid::halfbyte_to_hexcharbyte(13).unwrap(), 0x44);
     /// assert_eq!(Guid::halfbyte_to_hexcharbyte(14).unwrap(), 0x45);
     ///  ```
-    fn halfbyte_to_hexcharbyte(c: u8) -> Result<u8, GdtfGuidError> {
+    fn halfbyte_to_hexcharbyte(c: u8) -> Result<u8, ()> {
         match c {
             0 => Ok(CHAR_0_AS_U8),
             1 => Ok(CHAR_1_AS_U8),
@@ -280,7 +295,7 @@ impl Guid {
             13 => Ok(CHAR_D_AS_U8),
             14 => Ok(CHAR_E_AS_U8),
             15 => Ok(CHAR_F_AS_U8),
-            _ => Err(GdtfGuidError {}),
+            _ => Err(()),
         }
     }
 
@@ -292,7 +307,7 @@ impl Guid {
     /// assert_eq!(Guid::is_byte_one_at_index(0b1001_0101,3).unwrap(),true);
     /// assert_eq!(Guid::is_byte_one_at_index(0b1000_0101,3).unwrap(),false);
     ///  ```
-    fn is_byte_one_at_index(byte: u8, index: u8) -> Result<bool, GdtfGuidError> {
+    fn is_byte_one_at_index(byte: u8, index: u8) -> Result<bool, ()> {
         match index {
             0 => Ok(0b1000_0000_u8 & byte == 0b1000_0000_u8),
             1 => Ok(0b0100_0000_u8 & byte == 0b0100_0000_u8),
@@ -302,7 +317,7 @@ impl Guid {
             5 => Ok(0b0000_0100_u8 & byte == 0b0000_0100_u8),
             6 => Ok(0b0000_0010_u8 & byte == 0b0000_0010_u8),
             7 => Ok(0b0000_0001_u8 & byte == 0b0000_0001_u8),
-            _ => Err(GdtfGuidError {}),
+            _ => Err(()),
         }
     }
 
@@ -311,7 +326,7 @@ impl Guid {
     /// use gdtf_parser::utils::units::guid::Guid;
     /// assert_eq!(Guid::shift_byte_lower_to_upper(0b0100_0101).unwrap(), 0b0101_0000);
     /// ```
-    fn shift_byte_lower_to_upper(byte: u8) -> Result<u8, GdtfGuidError> {
+    fn shift_byte_lower_to_upper(byte: u8) -> Result<u8, ()> {
         let s1 = if Self::is_byte_one_at_index(byte, 4)? {
             0b1000_0000_u8
         } else {
@@ -341,7 +356,7 @@ impl Guid {
     /// assert_eq!(Guid::join_two_halfbytes(0b0000_0101,0b0000_0001).unwrap(),0b0101_0001);
     /// assert_eq!(Guid::join_two_halfbytes(0b0000_1101,0b0000_1001).unwrap(),0b1101_1001);
     /// ```
-    fn join_two_halfbytes(first_half: u8, second_half: u8) -> Result<u8, GdtfGuidError> {
+    fn join_two_halfbytes(first_half: u8, second_half: u8) -> Result<u8, ()> {
         Ok(Self::shift_byte_lower_to_upper(first_half)? + second_half)
     }
 
@@ -351,7 +366,7 @@ impl Guid {
     /// assert_eq!(Guid::get_upper_halfbyte(0b0000_0100).unwrap(),0b0000_0000);
     /// assert_eq!(Guid::get_upper_halfbyte(0b0010_1100).unwrap(),0b0000_0010);
     ///  ```
-    fn get_upper_halfbyte(byte: u8) -> Result<u8, GdtfGuidError> {
+    fn get_upper_halfbyte(byte: u8) -> Result<u8, ()> {
         let s1 = if Self::is_byte_one_at_index(byte, 0)? {
             0b0000_1000_u8
         } else {
@@ -382,7 +397,7 @@ impl Guid {
     /// assert_eq!(Guid::get_lower_halfbyte(0b0010_1100).unwrap(),0b0000_1100);
     ///  ```
     ///
-    fn get_lower_halfbyte(byte: u8) -> Result<u8, GdtfGuidError> {
+    fn get_lower_halfbyte(byte: u8) -> Result<u8, ()> {
         let s1 = if Self::is_byte_one_at_index(byte, 4)? {
             0b0000_1000_u8
         } else {
@@ -412,7 +427,7 @@ impl Guid {
     /// assert_eq!(Guid::split_into_two_halfbytes(0b0100_0101).unwrap(), (0b0000_0100,0b0000_0101));
     /// assert_eq!(Guid::split_into_two_halfbytes(0b1001_1101).unwrap(), (0b0000_1001,0b0000_1101));
     ///  ```
-    fn split_into_two_halfbytes(b: u8) -> Result<(u8, u8), GdtfGuidError> {
+    fn split_into_two_halfbytes(b: u8) -> Result<(u8, u8), ()> {
         Ok((Self::get_upper_halfbyte(b)?, Self::get_lower_halfbyte(b)?))
     }
 
@@ -421,7 +436,7 @@ impl Guid {
     /// use gdtf_parser::utils::units::guid::Guid;
     /// assert_eq!(Guid::hexcharbytes_to_byte(0x41,0x33).unwrap(),163_u8);
     ///  ```
-    fn hexcharbytes_to_byte(c1: u8, c2: u8) -> Result<u8, GdtfGuidError> {
+    fn hexcharbytes_to_byte(c1: u8, c2: u8) -> Result<u8, ()> {
         Self::join_two_halfbytes(
             Self::hexcharbyte_to_halfbyte(c1)?,
             Self::hexcharbyte_to_halfbyte(c2)?,
@@ -443,19 +458,33 @@ impl Display for Guid {
     }
 }
 
+#[cfg(feature = "serde")]
+impl serde::Serialize for Guid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_str().map_err(serde::ser::Error::custom)?)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Guid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[derive(Debug)]
-/// Error that occures if the format of GUID is wrong e.q. not XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX
-pub struct GdtfGuidError {}
+/// Error that occures if the format of GUID is wrong e.q. not XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX. Contains the wrong value
+pub struct GdtfGuidError(pub String);
 
 impl Display for GdtfGuidError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        write!(f, "Wrong argument for GUID in GDTF. Format must be RFC 4122. The format is XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX where XX is a byte in hex in UTF8 format!")
+        write!(f, "Wrong argument '{}' for GUID in GDTF. Format must be RFC 4122. The format is XXXXXXXX-XXXX-XXXX-XXXX-XXXXXXXXXXXX where XX is a byte in hex in UTF8 format!", self.0)
     }
 }
 
 impl From<Utf8Error> for GdtfGuidError {
-    fn from(_: Utf8Error) -> Self {
-        GdtfGuidError {}
+    fn from(e: Utf8Error) -> Self {
+        GdtfGuidError(e.to_string())
     }
 }
 
@@ -673,6 +702,12 @@ mod tests {
         assert!(T::new_from_str("308EA87D-7164_42DE-8106-A6D273F57A51").is_err());
         assert!(T::new_from_str("308EA87D-7164-42DE/8106-A6D273F57A51").is_err());
         assert!(T::new_from_str("308EA87D-7164-42DE-8106_A6D273F57A51").is_err());
+        assert_eq!(
+            T::new_from_str("308EA87D-7164_42DE-8106-A6D273F57A51")
+                .unwrap_err()
+                .0,
+            "308EA87D-7164_42DE-8106-A6D273F57A51"
+        );
     }
 
     #[test]
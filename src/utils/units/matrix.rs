@@ -14,7 +14,7 @@ use crate::utils::read;
 
 ///The transformation matrix consists 4 x 4 floats. Stored in a row-major order
 ///The matrix rotation is stored in the first three columns, and the translation is stored in the 4th column. The metric system consists of the Right-handed Cartesian Coordinates XYZ
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Clone)]
 pub struct Matrix(pub [[f32; 4]; 4]);
 
 impl Matrix {
@@ -37,7 +37,8 @@ impl Matrix {
     pub fn new_from_str(s: &str) -> Result<Self, GdtfMatrixError> {
         use GdtfMatrixError as E;
         lazy_static! {
-            static ref REGEX: Regex = Regex::new(r"[-+]?([0-9]*\.[0-9]+|[0-9]+)").unwrap();
+            static ref REGEX: Regex =
+                Regex::new(r"[-+]?([0-9]*\.[0-9]+|[0-9]+)([eE][-+]?[0-9]+)?").unwrap();
         }
         let mut caps = REGEX.captures_iter(s);
 
@@ -73,6 +74,62 @@ impl Matrix {
         ]))
     }
 
+    ///Parses a matrix in the format used in MVR to Matrix. MVR stores the three axis vectors u, v, w and the offset o in the format {u1,u2,u3}{v1,v2,v3}{w1,w2,w3}{o1,o2,o3}. The axis vectors are the columns of the rotation and the offset is the translation
+    /// ```rust
+    /// use gdtf_parser::utils::units::matrix::Matrix;
+    ///
+    /// assert_eq!(
+    ///             Matrix::new_from_mvr_str("{1,0,0}{0,1,0}{0,0,1}{1000,-2000.5,3000}").unwrap(),
+    ///             Matrix([
+    ///                 [1.0, 0.0, 0.0, 1000.0],
+    ///                 [0.0, 1.0, 0.0, -2000.5],
+    ///                 [0.0, 0.0, 1.0, 3000.0],
+    ///                 [0.0, 0.0, 0.0, 1.0],
+    ///             ])
+    ///         );
+    /// assert!(Matrix::new_from_mvr_str("{1,0,0}{0,1,0}{0,0,1}").is_err());
+    /// ```
+    pub fn new_from_mvr_str(s: &str) -> Result<Self, GdtfMatrixError> {
+        lazy_static! {
+            static ref REGEX: Regex =
+                Regex::new(r"[-+]?([0-9]*\.[0-9]+|[0-9]+)([eE][-+]?[0-9]+)?").unwrap();
+        }
+        let values = REGEX
+            .captures_iter(s)
+            .map(|c| f32::from_str(&c[0]))
+            .collect::<Result<Vec<f32>, ParseFloatError>>()?;
+        if values.len() != 12 {
+            return Err(GdtfMatrixError::WrongFormatError);
+        }
+        let [u, v, w, o] = [&values[0..3], &values[3..6], &values[6..9], &values[9..12]];
+        Ok(Self([
+            [u[0], v[0], w[0], o[0]],
+            [u[1], v[1], w[1], o[1]],
+            [u[2], v[2], w[2], o[2]],
+            [0.0, 0.0, 0.0, 1.0],
+        ]))
+    }
+
+    ///Formats the Matrix in the format {u1,u2,u3}{v1,v2,v3}{w1,w2,w3}{o1,o2,o3} used in MVR. The last row is not part of the format and is ignored. Counterpart of `new_from_mvr_str`
+    /// ```rust
+    /// use gdtf_parser::utils::units::matrix::Matrix;
+    ///
+    /// let matrix = Matrix([
+    ///     [0.0, -1.0, 0.0, 1000.0],
+    ///     [1.0, 0.0, 0.0, -2000.5],
+    ///     [0.0, 0.0, 1.0, 3000.0],
+    ///     [0.0, 0.0, 0.0, 1.0],
+    /// ]);
+    /// assert_eq!(matrix.to_mvr_string(), "{0,1,0}{-1,0,0}{0,0,1}{1000,-2000.5,3000}");
+    /// assert_eq!(Matrix::new_from_mvr_str(&matrix.to_mvr_string()).unwrap(), matrix);
+    /// ```
+    pub fn to_mvr_string(&self) -> String {
+        let m = &self.0;
+        (0..4)
+            .map(|c| format!("{{{},{},{}}}", m[0][c], m[1][c], m[2][c]))
+            .collect()
+    }
+
     ///Parses a quick-xml-attribute defined in gdtf-xml-description to Matrix
     /// ```rust
     /// use gdtf_parser::utils::units::matrix::Matrix;
@@ -96,6 +153,37 @@ impl Matrix {
     }
 }
 
+///Displays a Matrix in the format {float,float,float,float}{float,float,float,float}{float,float,float,float}{float,float,float,float} used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::matrix::Matrix;
+/// assert_eq!(
+///     Matrix([[1.1, 1.2, 1.3, 1.4], [2.1, 2.2, 2.3, 2.4], [3.1, 3.2, 3.3, 3.4], [4.1, 4.2, 4.3, 4.4]]).to_string(),
+///     "{1.1,1.2,1.3,1.4}{2.1,2.2,2.3,2.4}{3.1,3.2,3.3,3.4}{4.1,4.2,4.3,4.4}"
+/// );
+/// ```
+impl Display for Matrix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.0.iter() {
+            write!(f, "{{{},{},{},{}}}", row[0], row[1], row[2], row[3])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Matrix {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Matrix {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[derive(Debug)]
 pub enum GdtfMatrixError {
     ParseFloatError(ParseFloatError),
@@ -150,6 +238,24 @@ mod tests {
                 [4.1, 4.2, 4.3, 4.4],
             ])
         );
+        assert_eq!(
+            Matrix::new_from_str("{1,0,0,1e-05}{0,1,0,-2.5E+03}{0,0,1,3.0e2}{0,0,0,1}").unwrap(),
+            Matrix([
+                [1.0, 0.0, 0.0, 0.00001],
+                [0.0, 1.0, 0.0, -2500.0],
+                [0.0, 0.0, 1.0, 300.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ])
+        );
+        assert_eq!(
+            Matrix::new_from_mvr_str("{1,0,0}{0,1,-1e-05}{0,0,1}{1.5e3,0,0}").unwrap(),
+            Matrix([
+                [1.0, 0.0, 0.0, 1500.0],
+                [0.0, 1.0, 0.0, 0.0],
+                [0.0, -0.00001, 1.0, 0.0],
+                [0.0, 0.0, 0.0, 1.0],
+            ])
+        );
         assert!(Matrix::new_from_str(
             "{1.1,1.2,1.3,1.4},{2.1,2.2,2.3,2.4},{3.1,3.2,3.3,3.4},{4.1,4.2,4.3}"
         )
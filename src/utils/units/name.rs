@@ -1,6 +1,11 @@
 //! Module for the unit Name used in GDTF
+use std::borrow::Borrow;
+use std::cell::RefCell;
+use std::collections::HashSet;
 use std::error::Error;
 use std::fmt;
+use std::ops::Deref;
+use std::sync::{Arc, Mutex, PoisonError};
 
 use quick_xml::events::attributes::Attribute;
 use unicode_segmentation::UnicodeSegmentation;
@@ -8,9 +13,48 @@ use unicode_segmentation::UnicodeSegmentation;
 use crate::utils::read;
 
 ///Name representation used in GDTF spec
-///Name contains a String that only can hold letters with restricted literals `[32..=122] = (SPACE..='z')` due to GDTF specifications.
+///Name contains a str that only can hold letters with restricted literals `[32..=122] = (SPACE..='z')` due to GDTF specifications.
+///
+///The str is shared, so cloning a Name does not allocate. All Names created during one deparse with equal content share the same allocation. The str is accessed with `as_str`, `Deref` or `AsRef<str>`, so the way it is stored can change without breaking the API
+///
+///If siblings in the description.xml share the same Name, the Name is used as key for the first one and the others are kept under a renamed key with the count of the occurrence appended, e.g. `Open {2}`. A renamed key is the only Name outside of the allowed chars, so it can't be created with `new` and paths built from it can't be deparsed as `Node`. `original_name` returns the Name as it was written in the description.xml
 #[derive(Debug, PartialEq, Eq, Clone, Hash)]
-pub struct Name(pub String);
+pub struct Name(pub(crate) Arc<str>);
+
+thread_local! {
+    ///Names created during the deparse that is currently running on this thread. Only present while `interning` is running
+    static INTERNED: RefCell<Option<Interner>> = const { RefCell::new(None) };
+}
+
+///Names created during one deparse. Cloning it shares the Names, so threads that work on the same deparse can use the same Interner
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Interner(Arc<Mutex<HashSet<Arc<str>>>>);
+
+///Runs the deparse in f, all equal Names created during it share their allocation. If called during another deparse, the Names are shared with that deparse
+pub(crate) fn interning<T, F: FnOnce() -> T>(f: F) -> T {
+    match interner() {
+        Some(_) => f(),
+        None => interning_with(Some(Interner::default()), f),
+    }
+}
+
+///Returns the Interner of the deparse that is currently running on this thread
+pub(crate) fn interner() -> Option<Interner> {
+    INTERNED.with(|interned| interned.borrow().clone())
+}
+
+///Runs f with the Interner of a deparse that was started on another thread and restores the previous one afterwards, also if f panics
+pub(crate) fn interning_with<T, F: FnOnce() -> T>(interner: Option<Interner>, f: F) -> T {
+    struct Restore(Option<Interner>);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            INTERNED.with(|interned| *interned.borrow_mut() = self.0.take());
+        }
+    }
+
+    let _restore = Restore(INTERNED.with(|interned| interned.replace(interner)));
+    f()
+}
 
 ///Default is an empty Name
 /// ```rust
@@ -19,7 +63,7 @@ pub struct Name(pub String);
 /// ```
 impl Default for Name {
     fn default() -> Self {
-        Name("".to_string())
+        Name::from("")
     }
 }
 
@@ -28,15 +72,60 @@ impl Name {
     /// ## Examples
     /// ```rust
     /// use gdtf_parser::utils::units::name::Name;
-    /// assert_eq!(Name("".to_string()), Name::new("").unwrap());
-    /// assert_eq!(Name("Some Name".to_string()), Name::new("Some Name").unwrap());
+    /// assert_eq!(Name::from(""), Name::new("").unwrap());
+    /// assert_eq!(Name::from("Some Name"), Name::new("Some Name").unwrap());
     /// assert!(Name::new("Some Name with invalid char {").is_err());
     /// assert!(Name::new("Some Name with invalid char ȸ").is_err());
     ///
     /// ```
     pub fn new(name: &str) -> Result<Self, GdtfNameError> {
         Self::validate_chars(name)?;
-        Ok(Self(name.to_string()))
+        Ok(Self::interned(name))
+    }
+
+    ///Creates a Name like `new` but also accepts the keys of duplicates that were renamed while deparsing, like `Open {2}`, so they can be deserialised again
+    #[cfg(feature = "serde")]
+    pub(crate) fn new_or_renamed(name: &str) -> Result<Self, GdtfNameError> {
+        Self::validate_chars(read::original_key(name).unwrap_or(name))?;
+        Ok(Self::interned(name))
+    }
+
+    ///Returns the Name that was already created with the same content during the running deparse or creates a new one
+    fn interned(name: &str) -> Self {
+        INTERNED.with(|interned| match interned.borrow().as_ref() {
+            Some(Interner(interned)) => {
+                let mut interned = interned.lock().unwrap_or_else(PoisonError::into_inner);
+                match interned.get(name) {
+                    Some(name) => Name(name.clone()),
+                    None => {
+                        let name: Arc<str> = Arc::from(name);
+                        interned.insert(name.clone());
+                        Name(name)
+                    }
+                }
+            }
+            None => Name(Arc::from(name)),
+        })
+    }
+
+    ///Returns the Name as str
+    /// ```rust
+    /// use gdtf_parser::utils::units::name::Name;
+    /// assert_eq!(Name::new("Some Name").unwrap().as_str(), "Some Name");
+    /// ```
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    ///Returns the Name as it was written in the description.xml. For a renamed key of a duplicate this is the Name without the appended count, else the Name itself
+    /// ```rust
+    /// use gdtf_parser::utils::units::name::Name;
+    /// assert_eq!(Name::new("Open").unwrap().original_name(), "Open");
+    /// assert_eq!(Name::from("Open {2}").original_name(), "Open");
+    /// assert_eq!(Name::from("{2}").original_name(), "");
+    /// ```
+    pub fn original_name(&self) -> &str {
+        read::original_key(&self.0).unwrap_or(&self.0)
     }
 
     ///Creates a new instance of Name from a fast-xml Attribute. Only chars `[32..=122] = (SPACE..='z')` are allowed. If one of other chars is passed to the function, it will return an Error
@@ -45,8 +134,8 @@ impl Name {
     /// use gdtf_parser::utils::units::name::Name;
     /// use quick_xml::events::attributes::Attribute;
     /// use std::borrow::Cow;
-    /// assert_eq!(Name("".to_string()), Name::new_from_attr(Attribute{key: &[], value: Cow::Borrowed(b"")}).unwrap());
-    /// assert_eq!(Name("Some Name".to_string()), Name::new_from_attr(Attribute{key: &[], value: Cow::Borrowed(b"Some Name")}).unwrap());
+    /// assert_eq!(Name::from(""), Name::new_from_attr(Attribute{key: &[], value: Cow::Borrowed(b"")}).unwrap());
+    /// assert_eq!(Name::from("Some Name"), Name::new_from_attr(Attribute{key: &[], value: Cow::Borrowed(b"Some Name")}).unwrap());
     /// assert!(Name::new_from_attr(Attribute{key: &[], value: Cow::Borrowed(b"Some Name with invalid char {")}).is_err());
     /// ```
     pub fn new_from_attr(attr: Attribute) -> Result<Self, GdtfNameError> {
@@ -74,6 +163,9 @@ impl Name {
     /// }
     /// ```
     pub fn validate_chars(s: &str) -> Result<(), GdtfNameError> {
+        if s.bytes().all(|byte| (32..=122).contains(&byte)) {
+            return Ok(());
+        }
         for grapheme in s.graphemes(true) {
             if grapheme.len() != 1 {
                 return Err(GdtfNameError::NotAllowedCharError(grapheme.to_string()));
@@ -101,6 +193,76 @@ impl Name {
     }
 }
 
+///Displays a Name as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::name::Name;
+/// assert_eq!(Name::from("Some name").to_string(), "Some name");
+/// ```
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for Name {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+///Allows to look up maps with Name as key by str
+/// ```rust
+/// use std::collections::HashMap;
+/// use gdtf_parser::utils::units::name::Name;
+/// let mut map = HashMap::new();
+/// map.insert(Name::new("Dimmer").unwrap(), 1);
+/// assert_eq!(map["Dimmer"], 1);
+/// ```
+impl Borrow<str> for Name {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+///Creates a Name without checking the chars. Use `Name::new` for input that was not yet validated
+/// ```rust
+/// use gdtf_parser::utils::units::name::Name;
+/// assert_eq!(Name::from("Some Name"), Name::new("Some Name").unwrap());
+/// ```
+impl From<&str> for Name {
+    fn from(name: &str) -> Self {
+        Name(Arc::from(name))
+    }
+}
+
+impl From<String> for Name {
+    fn from(name: String) -> Self {
+        Name(Arc::from(name))
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Name {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Name {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_or_renamed)
+    }
+}
+
 #[derive(Debug)]
 ///Error used to indicate an Error during creating of Name
 pub enum GdtfNameError {
@@ -126,30 +288,54 @@ impl fmt::Display for GdtfNameError {
 
 #[cfg(test)]
 mod tests {
+    use std::convert::TryFrom;
     use std::num::ParseIntError;
+    use std::path::Path;
+    use std::sync::Arc;
 
     use crate::utils::errors::GdtfError;
     use crate::utils::testdata;
-    use crate::utils::units::name::{GdtfNameError, Name};
+    use crate::utils::units::name::{self, GdtfNameError, Name};
+    use crate::Gdtf;
 
     #[test]
     fn test_new() -> Result<(), GdtfError> {
-        assert_eq!(Name("".to_string()), Name::new("")?);
-        assert_eq!(Name("Some Name".to_string()), Name::new("Some Name")?);
+        assert_eq!(Name::from(""), Name::new("")?);
+        assert_eq!(Name::from("Some Name"), Name::new("Some Name")?);
         assert!(Name::new("Some Name with invalid char {").is_err());
         assert!(Name::new("Some Name with invalid char ȸ").is_err());
         assert!(Name::new(std::str::from_utf8(&[20, 19, 21])?).is_err());
         Ok(())
     }
 
+    #[test]
+    fn test_interning() -> Result<(), GdtfError> {
+        let (first, second) = name::interning(|| (Name::new("Beam"), Name::new("Beam")));
+        assert!(Arc::ptr_eq(&first?.0, &second?.0));
+        assert!(!Arc::ptr_eq(&Name::new("Beam")?.0, &Name::new("Beam")?.0));
+
+        let gdtf = Gdtf::try_from(Path::new("test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf"))?;
+        let geometries: Vec<&Name> = gdtf.fixture_type.dmx_modes["Mode 1 (35 ch)"]
+            .dmx_channels
+            .iter()
+            .map(|dmx_channel| &dmx_channel.geometry)
+            .filter(|geometry| geometry.as_str() == "Head")
+            .collect();
+        assert!(geometries.len() > 1);
+        assert!(geometries
+            .iter()
+            .all(|geometry| Arc::ptr_eq(&geometry.0, &geometries[0].0)));
+        Ok(())
+    }
+
     #[test]
     fn test_new_from_attr_owned() -> Result<(), GdtfNameError> {
         assert_eq!(
-            Name("".to_string()),
+            Name::from(""),
             Name::new_from_attr(testdata::to_attr_owned(b""))?
         );
         assert_eq!(
-            Name("Some Name".to_string()),
+            Name::from("Some Name"),
             Name::new_from_attr(testdata::to_attr_owned(b"Some Name"))?
         );
         assert!(
@@ -162,11 +348,11 @@ mod tests {
     #[test]
     fn test_new_from_attr_borrowed() -> Result<(), GdtfNameError> {
         assert_eq!(
-            Name("".to_string()),
+            Name::from(""),
             Name::new_from_attr(testdata::to_attr_borrowed(b""))?
         );
         assert_eq!(
-            Name("Some Name".to_string()),
+            Name::from("Some Name"),
             Name::new_from_attr(testdata::to_attr_borrowed(b"Some Name"))?
         );
         assert!(
@@ -19,13 +19,13 @@ impl Node {
     /// use gdtf_parser::utils::units::name::Name;
     /// use gdtf_parser::utils::units::node::Node;
     ///
-    /// assert_eq!(Node::strs_to_names_vec(vec!["Name1", "Name2"]).unwrap(), vec![Name("Name1".to_string()), Name("Name2".to_string())] );
+    /// assert_eq!(Node::strs_to_names_vec(vec!["Name1", "Name2"]).unwrap(), vec![Name::from("Name1"), Name::from("Name2")] );
     /// assert!(Node::strs_to_names_vec(vec!["Name1", "Name with invalid char {"]).is_err());
     /// ```
     pub fn strs_to_names_vec(names: Vec<&str>) -> Result<Vec<Name>, GdtfNodeError> {
         let mut ns = vec![];
         for name in names.iter() {
-            ns.push(Name::new(name)?)
+            ns.push(Name::new(name).map_err(|_| GdtfNodeError(names.join(".")))?)
         }
         Ok(ns)
     }
@@ -35,17 +35,16 @@ impl Node {
     /// use gdtf_parser::utils::units::name::Name;
     /// use gdtf_parser::utils::units::node::Node;
     ///
-    /// assert_eq!(Node::str_to_names_vec("Name1.Name2").unwrap().unwrap(), vec![Name("Name1".to_string()), Name("Name2".to_string())] );
+    /// assert_eq!(Node::str_to_names_vec("Name1.Name2").unwrap().unwrap(), vec![Name::from("Name1"), Name::from("Name2")] );
     /// assert!(Node::str_to_names_vec("Name1.Name with invalid char {").is_err());
     /// ```
     pub fn str_to_names_vec(value: &str) -> Result<Option<Vec<Name>>, GdtfNodeError> {
         if value.is_empty() {
             return Ok(None);
         }
-        let value = value.split('.');
         let mut tree: Vec<Name> = vec![];
-        for value in value.into_iter() {
-            tree.push(Name::new(value)?);
+        for name in value.split('.') {
+            tree.push(Name::new(name).map_err(|_| GdtfNodeError(value.to_string()))?);
         }
         Ok(Some(tree))
     }
@@ -85,22 +84,58 @@ impl Node {
     }
 }
 
+///Displays a Node in the format used in gdtf-xml-description, where the names are joined with a dot
+/// ```rust
+/// use gdtf_parser::utils::units::node::Node;
+/// assert_eq!(Node::new_from_str("Name1.Name2").unwrap().unwrap().to_string(), "Name1.Name2");
+/// ```
+impl Display for Node {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (i, name) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, ".")?;
+            }
+            write!(f, "{}", name.0)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Node {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Node {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, |value| {
+            Self::new_from_str(value)?.ok_or_else(|| GdtfNodeError(value.to_string()))
+        })
+    }
+}
+
 #[derive(Debug)]
-/// Error that occures if the format of Node is wrong
-pub struct GdtfNodeError {}
+/// Error that occures if the format of Node is wrong. Contains the wrong value
+pub struct GdtfNodeError(pub String);
 
 impl Display for GdtfNodeError {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "Wrong argument for Node in GDTF. Format must be Name.Name.Name...!"
+            "Wrong argument '{}' for Node in GDTF. Format must be Name.Name.Name...!",
+            self.0
         )
     }
 }
 
 impl From<GdtfNameError> for GdtfNodeError {
-    fn from(_: GdtfNameError) -> Self {
-        GdtfNodeError {}
+    fn from(e: GdtfNameError) -> Self {
+        match e {
+            GdtfNameError::NotAllowedCharError(name) => GdtfNodeError(name),
+        }
     }
 }
 
@@ -163,6 +198,10 @@ mod tests {
         );
         assert!(T::new_from_str("Invalid char {").is_err());
         assert!(T::new_from_str("Invalid char ȸ").is_err());
+        assert_eq!(
+            T::new_from_str("Name.Invalid {").unwrap_err().0,
+            "Name.Invalid {"
+        );
         Ok(())
     }
 
@@ -1,8 +1,12 @@
 //! Module for the unit PhysicalUnit used in GDTF
 
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
 use quick_xml::events::attributes::Attribute;
 
 use crate::utils::read;
+use crate::utils::warnings;
 
 ///Physical Unit representation used in GDTF
 #[derive(Debug, PartialEq, Clone)]
@@ -85,7 +89,11 @@ impl PhysicalUnit {
             "AngularAccc" => AngularAccc,
             "WaveLength" => WaveLength,
             "ColorComponent" => ColorComponent,
-            _ => None,
+            "None" => None,
+            _ => {
+                warnings::substituted("None");
+                None
+            }
         }
     }
 
@@ -106,6 +114,57 @@ impl PhysicalUnit {
     }
 }
 
+///Displays a PhysicalUnit as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::physical_unit::PhysicalUnit;
+/// assert_eq!(PhysicalUnit::AngularSpeed.to_string(), "AngularSpeed");
+/// assert_eq!(PhysicalUnit::None.to_string(), "None");
+/// ```
+impl Display for PhysicalUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        use PhysicalUnit::*;
+        let s = match self {
+            None => "None",
+            Percent => "Percent",
+            Length => "Length",
+            Mass => "Mass",
+            Time => "Time",
+            Temperature => "Temperature",
+            LuminousIntensity => "LuminousIntensity",
+            Angle => "Angle",
+            Force => "Force",
+            Frequency => "Frequency",
+            Current => "Current",
+            Voltage => "Voltage",
+            Power => "Power",
+            Energy => "Energy",
+            Area => "Area",
+            Volume => "Volume",
+            Speed => "Speed",
+            Acceleration => "Acceleration",
+            AngularSpeed => "AngularSpeed",
+            AngularAccc => "AngularAccc",
+            WaveLength => "WaveLength",
+            ColorComponent => "ColorComponent",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PhysicalUnit {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PhysicalUnit {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::new_from_str(&String::deserialize(deserializer)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::utils::testdata;
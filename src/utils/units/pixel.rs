@@ -46,6 +46,31 @@ impl Pixel {
     }
 }
 
+///Displays a Pixel as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::pixel::Pixel;
+/// assert_eq!(Pixel(12.0).to_string(), "12");
+/// ```
+impl Display for Pixel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Pixel {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Pixel {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[derive(Debug)]
 pub struct GdtfPixelError {}
 
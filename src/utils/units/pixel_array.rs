@@ -51,6 +51,32 @@ impl PixelArray {
     }
 }
 
+///Displays a PixelArray in the format Pixel,Pixel used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::pixel::Pixel;
+/// use gdtf_parser::utils::units::pixel_array::PixelArray;
+/// assert_eq!(PixelArray(Pixel(0.0), Pixel(12.0)).to_string(), "0,12");
+/// ```
+impl Display for PixelArray {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for PixelArray {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for PixelArray {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[derive(Debug)]
 pub struct GdtfPixelArrayError {}
 
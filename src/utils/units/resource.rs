@@ -1,5 +1,8 @@
 //!Module for the unit Resource used in GDTF
 
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
 use quick_xml::events::attributes::Attribute;
 
 use crate::utils::read;
@@ -31,6 +34,31 @@ impl Resource {
     }
 }
 
+///Displays a Resource as it is used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::resource::Resource;
+/// assert_eq!(Resource("head.png".to_string()).to_string(), "head.png");
+/// ```
+impl Display for Resource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Resource {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Resource {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(Self::new_from_str(&String::deserialize(deserializer)?))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::utils::testdata;
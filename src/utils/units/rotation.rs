@@ -35,7 +35,8 @@ impl Rotation {
     pub fn new_from_str(s: &str) -> Result<Self, GdtfRotationError> {
         use GdtfRotationError as E;
         lazy_static! {
-            static ref REGEX: Regex = Regex::new(r"[-+]?([0-9]*\.[0-9]+|[0-9]+)").unwrap();
+            static ref REGEX: Regex =
+                Regex::new(r"[-+]?([0-9]*\.[0-9]+|[0-9]+)([eE][-+]?[0-9]+)?").unwrap();
         }
         let mut caps = REGEX.captures_iter(s);
 
@@ -80,6 +81,37 @@ impl Rotation {
     }
 }
 
+///Displays a Rotation in the format {float,float,float}{float,float,float}{float,float,float} used in gdtf-xml-description
+/// ```rust
+/// use gdtf_parser::utils::units::rotation::Rotation;
+/// assert_eq!(
+///     Rotation([[1.1, 1.2, 1.3], [2.1, 2.2, 2.3], [3.1, 3.2, 3.3]]).to_string(),
+///     "{1.1,1.2,1.3}{2.1,2.2,2.3}{3.1,3.2,3.3}"
+/// );
+/// ```
+impl Display for Rotation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for row in self.0.iter() {
+            write!(f, "{{{},{},{}}}", row[0], row[1], row[2])?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Rotation {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Rotation {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        crate::utils::serde_str::deserialize(deserializer, Self::new_from_str)
+    }
+}
+
 #[derive(Debug)]
 pub enum GdtfRotationError {
     ParseFloatError(ParseFloatError),
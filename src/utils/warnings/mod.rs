@@ -0,0 +1,603 @@
+//! Warnings collected while deparsing a GDTF in lenient mode
+//!
+//! Deparsing never fails on a value it can't read, but replaces it by a default or drops it. With `ParseMode::Lenient` every such substitution is collected as `ParseWarning` together with it's location in the description.xml, with `ParseMode::Strict` deparsing fails instead.
+use std::cell::RefCell;
+use std::fmt;
+use std::fmt::{Display, Formatter};
+
+use quick_xml::events::attributes::Attribute;
+use quick_xml::events::BytesStart;
+
+use crate::utils::errors::GdtfError;
+
+///Defines how values that can't be deparsed are handled
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ParseMode {
+    ///Deparsing fails with `GdtfError::StrictParseError` if any value had to be substituted, any node was skipped or renamed
+    Strict,
+    ///Values that can't be deparsed are substituted and reported as `ParseWarning`
+    Lenient,
+}
+
+///What was wrong with the deparsed node
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ParseWarningKind {
+    ///The value of an attribute could not be deparsed. If substitute is None the value was dropped
+    InvalidValue {
+        attribute: String,
+        value: String,
+        substitute: Option<String>,
+    },
+    ///A required attribute was missing and the substitute was used instead
+    MissingValue {
+        attribute: String,
+        substitute: String,
+    },
+    ///A child node is not known by it's parent and was not deparsed
+    SkippedNode { node: String },
+    ///A node had the same primary key as one of it's siblings and was kept under substitute
+    DuplicateKey {
+        node: String,
+        key: String,
+        substitute: String,
+    },
+}
+
+///Substitution or skipped node with it's location in the description.xml
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseWarning {
+    ///What was wrong
+    pub kind: ParseWarningKind,
+    ///Path of the xml-node, for example `GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/DMXChannels/DMXChannel[3]`. Nodes with a Name contain it in square brackets, other nodes in a list their position starting at 1
+    pub path: String,
+    ///Line of the start of the xml-node, starting at 1
+    pub line: usize,
+    ///Column of the start of the xml-node, starting at 1
+    pub column: usize,
+}
+
+///Displays the warning in the format `line:column path: message`
+/// ```rust
+/// use gdtf_parser::utils::warnings::{ParseWarning, ParseWarningKind};
+///
+/// let warning = ParseWarning {
+///     kind: ParseWarningKind::InvalidValue {
+///         attribute: "Mode".to_string(),
+///         value: "Adobe".to_string(),
+///         substitute: Some("sRGB".to_string()),
+///     },
+///     path: "GDTF/FixtureType/PhysicalDescriptions/ColorSpace".to_string(),
+///     line: 12,
+///     column: 7,
+/// };
+/// assert_eq!(warning.to_string(), "12:7 GDTF/FixtureType/PhysicalDescriptions/ColorSpace: Invalid value 'Adobe' in Mode was replaced by 'sRGB'");
+/// ```
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{} {}: ", self.line, self.column, self.path)?;
+        match &self.kind {
+            ParseWarningKind::InvalidValue {
+                attribute,
+                value,
+                substitute: Some(substitute),
+            } => write!(
+                f,
+                "Invalid value '{}' in {} was replaced by '{}'",
+                value, attribute, substitute
+            ),
+            ParseWarningKind::InvalidValue {
+                attribute,
+                value,
+                substitute: None,
+            } => write!(f, "Invalid value '{}' in {} was ignored", value, attribute),
+            ParseWarningKind::MissingValue {
+                attribute,
+                substitute,
+            } => write!(f, "Missing {} was replaced by '{}'", attribute, substitute),
+            ParseWarningKind::SkippedNode { node } => {
+                write!(f, "Unknown node {} was skipped", node)
+            }
+            ParseWarningKind::DuplicateKey {
+                node,
+                key,
+                substitute,
+            } => write!(
+                f,
+                "Duplicate {} '{}' was renamed to '{}'",
+                node, key, substitute
+            ),
+        }
+    }
+}
+
+thread_local! {
+    static CONTEXT: RefCell<Option<ParseContext>> = const { RefCell::new(None) };
+}
+
+///State of the deparse that is currently running on this thread. Only present while `collect` is running
+#[derive(Default)]
+struct ParseContext {
+    ///Path of the nodes that are currently deparsed with the position of their start in the xml
+    nodes: Vec<(String, usize)>,
+    ///Key and value of the attribute that is currently deparsed
+    attribute: Option<(String, String)>,
+    ///Count of nodes that were read so far, used to detect children that were not read by their parent
+    read_nodes: usize,
+    ///Position in it's parent of the next node that is entered, if it is part of a list
+    index: Option<usize>,
+    warnings: Vec<(ParseWarningKind, String, usize)>,
+}
+
+impl ParseContext {
+    fn push(&mut self, kind: ParseWarningKind, node: Option<&[u8]>, position: Option<usize>) {
+        let mut path = self
+            .nodes
+            .iter()
+            .map(|(node, _)| node.as_str())
+            .collect::<Vec<&str>>()
+            .join("/");
+        if let Some(node) = node {
+            path = format!("{}/{}", path, String::from_utf8_lossy(node));
+        }
+        let position = position
+            .or_else(|| self.nodes.last().map(|(_, position)| *position))
+            .unwrap_or(0);
+        self.warnings.push((kind, path, position));
+    }
+}
+
+fn with_context<F: FnOnce(&mut ParseContext)>(f: F) {
+    CONTEXT.with(|context| {
+        if let Some(context) = context.borrow_mut().as_mut() {
+            f(context)
+        }
+    })
+}
+
+///Restores the context of an outer deparse, also if the inner one panics
+struct Restore(Option<ParseContext>);
+
+impl Drop for Restore {
+    fn drop(&mut self) {
+        CONTEXT.with(|context| *context.borrow_mut() = self.0.take());
+    }
+}
+
+///Runs the deparse in f and collects all warnings that occur during it
+pub(crate) fn collect<T, F: FnOnce() -> Result<T, GdtfError>>(
+    mode: ParseMode,
+    xml: &str,
+    f: F,
+) -> Result<(T, Vec<ParseWarning>), GdtfError> {
+    let restore = Restore(CONTEXT.with(|context| context.replace(Some(Default::default()))));
+    let out = f();
+    let context = CONTEXT
+        .with(|context| context.borrow_mut().take())
+        .unwrap_or_default();
+    drop(restore);
+    let out = out?;
+
+    let line_starts: Vec<usize> = std::iter::once(0)
+        .chain(xml.match_indices('\n').map(|(i, _)| i + 1))
+        .collect();
+    let warnings: Vec<ParseWarning> = context
+        .warnings
+        .into_iter()
+        .map(|(kind, path, position)| {
+            let line = line_starts.partition_point(|start| *start <= position);
+            let line_start = line_starts[line - 1];
+            let column = xml
+                .get(line_start..position)
+                .map_or(position - line_start, |s| s.chars().count());
+            ParseWarning {
+                kind,
+                path,
+                line,
+                column: column + 1,
+            }
+        })
+        .collect();
+
+    if mode == ParseMode::Strict && !warnings.is_empty() {
+        return Err(GdtfError::StrictParseError(warnings));
+    }
+    Ok((out, warnings))
+}
+
+///Warnings and count of read nodes of a part of the deparse that ran on another thread
+#[cfg(feature = "parallel")]
+pub(crate) struct Forked {
+    read_nodes: usize,
+    warnings: Vec<(ParseWarningKind, String, usize)>,
+}
+
+///Runs a part of the deparse in f with it's own context, so it can run on another thread than the deparse it belongs to. If collecting is true, the warnings of f are returned to be added to the deparse with `join`
+#[cfg(feature = "parallel")]
+pub(crate) fn fork<T, F: FnOnce() -> T>(collecting: bool, f: F) -> (T, Option<Forked>) {
+    let context = if collecting {
+        Some(ParseContext::default())
+    } else {
+        None
+    };
+    let restore = Restore(CONTEXT.with(|c| c.replace(context)));
+    let out = f();
+    let forked = CONTEXT
+        .with(|context| context.borrow_mut().take())
+        .map(|context| Forked {
+            read_nodes: context.read_nodes,
+            warnings: context.warnings,
+        });
+    drop(restore);
+    (out, forked)
+}
+
+///Adds the warnings of a part of the deparse that ran with `fork` to the current node. Offset is the position of the xml the part was deparsed from
+#[cfg(feature = "parallel")]
+pub(crate) fn join(forked: Forked, offset: usize) {
+    with_context(|context| {
+        let parent = context
+            .nodes
+            .iter()
+            .map(|(node, _)| node.as_str())
+            .collect::<Vec<&str>>()
+            .join("/");
+        context.read_nodes += forked.read_nodes;
+        context
+            .warnings
+            .extend(forked.warnings.into_iter().map(|(kind, path, position)| {
+                let path = match (parent.is_empty(), path.is_empty()) {
+                    (true, _) => path,
+                    (false, true) => parent.clone(),
+                    (false, false) => format!("{}/{}", parent, path),
+                };
+                (kind, path, position + offset)
+            }));
+    });
+}
+
+///Marks a node as read. Must be called by every function that reads a node
+pub(crate) fn read_node() {
+    with_context(|context| context.read_nodes += 1);
+}
+
+///Returns the count of nodes read so far or None if no warnings are collected
+pub(crate) fn read_nodes() -> Option<usize> {
+    CONTEXT.with(|context| context.borrow().as_ref().map(|context| context.read_nodes))
+}
+
+///Position of the start of the event that was just read by the reader
+pub(crate) fn event_start(
+    buffer_position: usize,
+    event: &BytesStart<'_>,
+    has_children: bool,
+) -> usize {
+    buffer_position.saturating_sub(event.len() + if has_children { 2 } else { 3 })
+}
+
+///Sets the position in it's parent of the next node that is entered, starting at 1
+pub(crate) fn index(index: usize) {
+    with_context(|context| context.index = Some(index));
+}
+
+///Enters a node. The node is left when the returned guard is dropped
+pub(crate) fn enter_node(node_name: &[u8], position: usize) -> NodeGuard {
+    with_context(|context| {
+        context.read_nodes += 1;
+        context.attribute = None;
+        let mut node = String::from_utf8_lossy(node_name).to_string();
+        if let Some(index) = context.index.take() {
+            node = format!("{}[{}]", node, index);
+        }
+        context.nodes.push((node, position));
+    });
+    NodeGuard {}
+}
+
+///Leaves the node entered with `enter_node` on drop
+pub(crate) struct NodeGuard {}
+
+impl Drop for NodeGuard {
+    fn drop(&mut self) {
+        with_context(|context| {
+            context.nodes.pop();
+            context.attribute = None;
+        });
+    }
+}
+
+///Adds the name of the current node to it's path
+pub(crate) fn name(attr: &Attribute<'_>) {
+    with_context(|context| {
+        if let Some((node, _)) = context.nodes.last_mut() {
+            if let Some(index) = node.find('[') {
+                node.truncate(index);
+            }
+            *node = format!("{}[{}]", node, String::from_utf8_lossy(&attr.value));
+        }
+    });
+}
+
+///Sets the attribute that is currently deparsed or resets it if None
+pub(crate) fn attribute(attr: Option<&Attribute<'_>>) {
+    with_context(|context| {
+        context.attribute = attr.map(|attr| {
+            (
+                String::from_utf8_lossy(attr.key).to_string(),
+                String::from_utf8_lossy(&attr.value).to_string(),
+            )
+        })
+    });
+}
+
+///Reports that the value of the current attribute was replaced by substitute
+pub(crate) fn substituted<T: Display>(substitute: T) {
+    with_context(|context| {
+        if let Some((attribute, value)) = context.attribute.clone() {
+            context.push(
+                ParseWarningKind::InvalidValue {
+                    attribute,
+                    value,
+                    substitute: Some(substitute.to_string()),
+                },
+                None,
+                None,
+            );
+        }
+    });
+}
+
+///Reports that the value of the current attribute was dropped
+pub(crate) fn dropped() {
+    with_context(|context| {
+        if let Some((attribute, value)) = context.attribute.clone() {
+            context.push(
+                ParseWarningKind::InvalidValue {
+                    attribute,
+                    value,
+                    substitute: None,
+                },
+                None,
+                None,
+            );
+        }
+    });
+}
+
+///Reports that a required attribute of the current node was missing and substitute was used
+pub(crate) fn missing<T: Display>(attribute: &str, substitute: T) {
+    with_context(|context| {
+        context.push(
+            ParseWarningKind::MissingValue {
+                attribute: attribute.to_string(),
+                substitute: substitute.to_string(),
+            },
+            None,
+            None,
+        )
+    });
+}
+
+///Reports that a required attribute of a child node at position was missing and substitute was used
+pub(crate) fn missing_in_child<T: Display>(
+    node: &[u8],
+    position: usize,
+    attribute: &str,
+    substitute: T,
+) {
+    with_context(|context| {
+        context.push(
+            ParseWarningKind::MissingValue {
+                attribute: attribute.to_string(),
+                substitute: substitute.to_string(),
+            },
+            Some(node),
+            Some(position),
+        )
+    });
+}
+
+///Reports that a child node of the current node was skipped
+pub(crate) fn skipped(node: &[u8], position: usize) {
+    with_context(|context| {
+        context.push(
+            ParseWarningKind::SkippedNode {
+                node: String::from_utf8_lossy(node).to_string(),
+            },
+            Some(node),
+            Some(position),
+        )
+    });
+}
+
+///Reports that a child node at position had the same key as a sibling and was renamed to substitute
+pub(crate) fn duplicate(node: &[u8], position: usize, key: &str, substitute: &str) {
+    with_context(|context| {
+        let node = String::from_utf8_lossy(node).to_string();
+        context.push(
+            ParseWarningKind::DuplicateKey {
+                node: node.clone(),
+                key: key.to_string(),
+                substitute: substitute.to_string(),
+            },
+            Some(format!("{}[{}]", node, key).as_bytes()),
+            Some(position),
+        )
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs::File;
+
+    use crate::utils::errors::GdtfError;
+    use crate::utils::units::dmx_value::DmxValue;
+    use crate::utils::units::name::Name;
+    use crate::utils::warnings::{ParseMode, ParseWarning, ParseWarningKind};
+    use crate::Gdtf;
+
+    const DESCRIPTION_XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<GDTF DataVersion="1.1">
+  <FixtureType Name="LED PAR" ShortName="PAR" LongName="LED PAR" Manufacturer="Generic" Description="" FixtureTypeID="308EA87D-7164-42DE-8106-A6D273F57A51" Thumbnail="" RefFT="">
+    <AttributeDefinitions/>
+    <Wheels>
+      <Wheel Name="Gobo1">
+        <Slot Color="0.3127,0.3290,100.000000"/>
+      </Wheel>
+    </Wheels>
+    <PhysicalDescriptions>
+      <ColorSpace Mode="Adobe"/>
+    </PhysicalDescriptions>
+    <Geometries>
+      <Geometry Name="Base"/>
+    </Geometries>
+    <DMXModes>
+      <DMXMode Name="Mode 1" Geometry="Base">
+        <DMXChannels/>
+        <Relations>
+          <Relation Name="Rel" Follower="Dimmer" Type="Override"/>
+        </Relations>
+        <Unknown>
+          <Child/>
+        </Unknown>
+      </DMXMode>
+    </DMXModes>
+  </FixtureType>
+</GDTF>"#;
+
+    #[test]
+    fn test_lenient() {
+        let (gdtf, warnings) =
+            Gdtf::from_description_xml_with_mode(DESCRIPTION_XML, ParseMode::Lenient).unwrap();
+        assert_eq!(gdtf, Gdtf::from_description_xml(DESCRIPTION_XML).unwrap());
+        assert_eq!(
+            warnings,
+            vec![
+                ParseWarning {
+                    kind: ParseWarningKind::MissingValue {
+                        attribute: "Name".to_string(),
+                        substitute: "?".to_string(),
+                    },
+                    path: "GDTF/FixtureType[LED PAR]/Wheels/Wheel[Gobo1]/Slot".to_string(),
+                    line: 7,
+                    column: 9,
+                },
+                ParseWarning {
+                    kind: ParseWarningKind::InvalidValue {
+                        attribute: "Mode".to_string(),
+                        value: "Adobe".to_string(),
+                        substitute: Some("sRGB".to_string()),
+                    },
+                    path: "GDTF/FixtureType[LED PAR]/PhysicalDescriptions/ColorSpace".to_string(),
+                    line: 11,
+                    column: 7,
+                },
+                ParseWarning {
+                    kind: ParseWarningKind::MissingValue {
+                        attribute: "Master".to_string(),
+                        substitute: "?".to_string(),
+                    },
+                    path:
+                        "GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/Relations/Relation[Rel]"
+                            .to_string(),
+                    line: 20,
+                    column: 11,
+                },
+                ParseWarning {
+                    kind: ParseWarningKind::SkippedNode {
+                        node: "Unknown".to_string(),
+                    },
+                    path: "GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/Unknown".to_string(),
+                    line: 22,
+                    column: 9,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strict() {
+        match Gdtf::from_description_xml_with_mode(DESCRIPTION_XML, ParseMode::Strict) {
+            Err(GdtfError::StrictParseError(warnings)) => assert_eq!(warnings.len(), 4),
+            _ => panic!("Strict deparsing should fail"),
+        }
+        let file = File::open("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").unwrap();
+        assert!(Gdtf::from_reader_with_mode(file, ParseMode::Strict).is_ok());
+        let file = File::open("test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf").unwrap();
+        match Gdtf::from_reader_with_mode(file, ParseMode::Strict) {
+            Err(GdtfError::StrictParseError(warnings)) => {
+                assert_eq!(warnings.len(), 14);
+                assert_eq!(warnings.iter().filter(|warning| matches!(&warning.kind, ParseWarningKind::InvalidValue { attribute, .. } if attribute == "WheelSlotIndex")).count(), 4);
+                assert_eq!(warnings.iter().filter(|warning| matches!(&warning.kind, ParseWarningKind::DuplicateKey { node, .. } if node == "ChannelSet")).count(), 10);
+            }
+            _ => panic!("Strict deparsing should fail"),
+        }
+    }
+
+    #[test]
+    fn test_duplicate_keys() {
+        let description_xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<GDTF DataVersion="1.1">
+  <FixtureType Name="LED PAR" ShortName="PAR" LongName="LED PAR" Manufacturer="Generic" Description="" FixtureTypeID="308EA87D-7164-42DE-8106-A6D273F57A51" Thumbnail="" RefFT="">
+    <AttributeDefinitions/>
+    <Wheels>
+      <Wheel Name="Gobo1"/>
+      <Wheel Name="Gobo1"/>
+    </Wheels>
+    <DMXModes>
+      <DMXMode Name="Mode 1" Geometry="Base">
+        <DMXChannels>
+          <DMXChannel DMXBreak="1" Offset="1" Geometry="Base">
+            <LogicalChannel Attribute="Dimmer">
+              <ChannelFunction Name="Dimmer" Attribute="Dimmer" DMXFrom="0/1">
+                <ChannelSet Name="Closed" DMXFrom="0/1"/>
+                <ChannelSet Name="" DMXFrom="1/1"/>
+                <ChannelSet Name="Closed" DMXFrom="2/1"/>
+                <ChannelSet Name="" DMXFrom="3/1"/>
+              </ChannelFunction>
+            </LogicalChannel>
+          </DMXChannel>
+        </DMXChannels>
+      </DMXMode>
+    </DMXModes>
+  </FixtureType>
+</GDTF>"#;
+        let (gdtf, warnings) =
+            Gdtf::from_description_xml_with_mode(description_xml, ParseMode::Lenient).unwrap();
+        let fixture_type = &gdtf.fixture_type;
+        let wheels = fixture_type.wheels.as_ref().unwrap();
+        assert_eq!(wheels.len(), 2);
+        assert!(wheels.contains_key(&Name::from("Gobo1 {2}")));
+        let channel_sets = &fixture_type.dmx_modes[&Name::new("Mode 1").unwrap()].dmx_channels[0]
+            .logical_channels[0]
+            .channel_functions[&Name::new("Dimmer").unwrap()]
+            .channel_sets;
+        assert_eq!(channel_sets.len(), 4);
+        assert_eq!(
+            channel_sets[&Name::from("Closed {2}")].dmx_from,
+            DmxValue::new_from_str("2/1").unwrap()
+        );
+        assert_eq!(
+            channel_sets[&Name::from("{2}")].dmx_from,
+            DmxValue::new_from_str("3/1").unwrap()
+        );
+        assert_eq!(
+            warnings.iter().map(|warning| warning.to_string()).collect::<Vec<String>>(),
+            vec![
+                "7:7 GDTF/FixtureType[LED PAR]/Wheels/Wheel[Gobo1]: Duplicate Wheel 'Gobo1' was renamed to 'Gobo1 {2}'",
+                "17:17 GDTF/FixtureType[LED PAR]/DMXModes/DMXMode[Mode 1]/DMXChannels/DMXChannel[1]/LogicalChannel/ChannelFunction[Dimmer]/ChannelSet[Closed]: Duplicate ChannelSet 'Closed' was renamed to 'Closed {2}'",
+            ]
+        );
+
+        let written = gdtf.to_description_xml().unwrap();
+        assert!(!written.contains('{'));
+        assert_eq!(written.matches(r#"<ChannelSet Name="" "#).count(), 2);
+        assert_eq!(written.matches(r#"<ChannelSet Name="Closed" "#).count(), 2);
+        assert_eq!(Gdtf::from_description_xml(&written).unwrap(), gdtf);
+        match Gdtf::from_description_xml_with_mode(description_xml, ParseMode::Strict) {
+            Err(GdtfError::StrictParseError(warnings)) => assert_eq!(warnings.len(), 2),
+            _ => panic!("Strict deparsing should fail"),
+        }
+    }
+}
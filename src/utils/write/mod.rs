@@ -0,0 +1,244 @@
+//! Module contains traits that can be implemented to serialise structs back to a gdtf-xml-description with quick-xml. It is the counterpart of the module read
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::io::Write;
+
+use indexmap::IndexMap;
+use quick_xml::events::{BytesEnd, BytesStart, Event};
+use quick_xml::Writer;
+
+use crate::utils::errors::GdtfError;
+use crate::utils::read;
+use crate::utils::read::ReadGdtf;
+
+///Trait to serialise a struct to an xml-node. Uses the constants declared in `ReadGdtf` so both directions always agree on node names
+///
+///Children stored in a hash-map lost their order while deparsing and are written sorted by their primary-key, children stored in a vec or an index-map are written in the order they were read. Deparsing the output results in an equal struct, but it is not always byte-equal to the original xml
+pub(crate) trait WriteGdtf: ReadGdtf {
+    /// Is called when the node is written. Pushes all xml-attributes except the primary-key to the event. Optional values that are `None` must be omitted so they are deparsed to `None` again
+    fn write_any_attributes(&self, event: &mut BytesStart<'_>);
+
+    /// Returns true if the node is written like <Node></Node> and `write_any_children` must be executed. If false the node is written like <Node/>
+    fn has_children(&self) -> bool {
+        false
+    }
+
+    /// Is called between the start and the end of the node if `has_children` returns true. This method should call `write_single`, `write_hash_map` or `write_vec` on the right children
+    fn write_any_children<W: Write>(&self, _: &mut Writer<W>) -> Result<(), GdtfError> {
+        Ok(())
+    }
+
+    /// Writes the struct as node `NODE_NAME`.
+    ///
+    /// # Arguments
+    ///
+    /// * `writer` - The quick-xml-Writer that is passed trough the whole tree
+    /// * `primary_key` - The primary key of the struct if it is stored in a hash-map. It will be written as attribute `PRIMARY_KEY_NAME`
+    fn write_single<W: Write>(
+        &self,
+        writer: &mut Writer<W>,
+        primary_key: Option<&dyn Display>,
+    ) -> Result<(), GdtfError> {
+        let mut event = BytesStart::borrowed_name(Self::NODE_NAME);
+        if let Some(primary_key) = primary_key {
+            push_attribute(&mut event, Self::PRIMARY_KEY_NAME, primary_key);
+        }
+        self.write_any_attributes(&mut event);
+        if self.has_children() {
+            writer.write_event(Event::Start(event))?;
+            self.write_any_children(writer)?;
+            writer.write_event(Event::End(BytesEnd::borrowed(Self::NODE_NAME)))?;
+        } else {
+            writer.write_event(Event::Empty(event))?;
+        }
+        Ok(())
+    }
+
+    /// Writes all structs of a hash-map with their key as primary-key inside of a node `PARENT_NODE_NAME`.
+    ///
+    /// The order of the nodes is lost in a hash-map, so they are written sorted by their primary-key to produce a stable output.
+    fn write_hash_map<W: Write>(
+        map: &HashMap<Self::PrimaryKey, Self>,
+        writer: &mut Writer<W>,
+    ) -> Result<(), GdtfError>
+    where
+        Self::PrimaryKey: Display,
+    {
+        write_parent(writer, Self::PARENT_NODE_NAME, !map.is_empty(), |writer| {
+            Self::write_entries(&Self::sorted_entries(map), writer)
+        })
+    }
+
+    /// Returns the entries of a hash-map with their primary-key formatted as String, sorted by the primary-key
+    fn sorted_entries(map: &HashMap<Self::PrimaryKey, Self>) -> Vec<(String, &Self)>
+    where
+        Self::PrimaryKey: Display,
+    {
+        let mut entries: Vec<(String, &Self)> =
+            map.iter().map(|(k, v)| (k.to_string(), v)).collect();
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+    }
+
+    /// Returns the entries of an index-map with their primary-key formatted as String, in the order they were read
+    fn ordered_entries(map: &IndexMap<Self::PrimaryKey, Self>) -> Vec<(String, &Self)>
+    where
+        Self::PrimaryKey: Display,
+    {
+        map.iter().map(|(k, v)| (k.to_string(), v)).collect()
+    }
+
+    /// Writes structs with their primary-key in the given order without a parent node. Can be used if the nodes are direct children of the calling struct or must be written in a certain order.
+    ///
+    /// Keys of duplicates renamed while deparsing are written with the name they had in the description.xml
+    fn write_entries<W: Write>(
+        entries: &[(String, &Self)],
+        writer: &mut Writer<W>,
+    ) -> Result<(), GdtfError> {
+        for (primary_key, entry) in entries.iter() {
+            let primary_key = read::original_key(primary_key).unwrap_or(primary_key);
+            entry.write_single(writer, Some(&primary_key))?;
+        }
+        Ok(())
+    }
+
+    /// Writes all structs of a vec inside of a node `PARENT_NODE_NAME`
+    fn write_vec<W: Write>(vec: &[Self], writer: &mut Writer<W>) -> Result<(), GdtfError> {
+        write_parent(writer, Self::PARENT_NODE_NAME, !vec.is_empty(), |writer| {
+            for entry in vec.iter() {
+                entry.write_single(writer, None)?;
+            }
+            Ok(())
+        })
+    }
+
+    /// Writes nodes that only contain a primary-key inside of a node `PARENT_NODE_NAME`. Counterpart of `read_primary_key_vec_from_event`
+    fn write_primary_key_vec<W: Write>(
+        vec: &[Self::PrimaryKey],
+        writer: &mut Writer<W>,
+    ) -> Result<(), GdtfError>
+    where
+        Self::PrimaryKey: Display,
+    {
+        write_parent(writer, Self::PARENT_NODE_NAME, !vec.is_empty(), |writer| {
+            Self::write_primary_keys(vec, writer)
+        })
+    }
+
+    /// Writes nodes that only contain a primary-key without a parent node. Can be used if the nodes are direct children of the calling struct
+    fn write_primary_keys<W: Write>(
+        vec: &[Self::PrimaryKey],
+        writer: &mut Writer<W>,
+    ) -> Result<(), GdtfError>
+    where
+        Self::PrimaryKey: Display,
+    {
+        for primary_key in vec.iter() {
+            let mut event = BytesStart::borrowed_name(Self::NODE_NAME);
+            push_attribute(&mut event, Self::PRIMARY_KEY_NAME, primary_key);
+            writer.write_event(Event::Empty(event))?;
+        }
+        Ok(())
+    }
+}
+
+///Writes a node without attributes. If has_children is false, it is written like <Node/>, else the children are written by the closure between <Node> and </Node>
+pub(crate) fn write_parent<W: Write, F>(
+    writer: &mut Writer<W>,
+    name: &[u8],
+    has_children: bool,
+    children: F,
+) -> Result<(), GdtfError>
+where
+    F: FnOnce(&mut Writer<W>) -> Result<(), GdtfError>,
+{
+    if !has_children {
+        writer.write_event(Event::Empty(BytesStart::borrowed_name(name)))?;
+        return Ok(());
+    }
+    writer.write_event(Event::Start(BytesStart::borrowed_name(name)))?;
+    children(writer)?;
+    writer.write_event(Event::End(BytesEnd::borrowed(name)))?;
+    Ok(())
+}
+
+///Pushes an xml-attribute with the value formatted in it's gdtf-xml-description syntax
+pub(crate) fn push_attribute<T: Display + ?Sized>(
+    event: &mut BytesStart<'_>,
+    key: &[u8],
+    value: &T,
+) {
+    let value = escape(&value.to_string());
+    event.push_attribute((key, value.as_bytes()));
+}
+
+///Pushes an xml-attribute with a value that was unescaped when it was read. Unlike `push_attribute` every `&` is escaped, so references in the value are kept as text
+pub(crate) fn push_plain_attribute<T: Display + ?Sized>(
+    event: &mut BytesStart<'_>,
+    key: &[u8],
+    value: &T,
+) {
+    let value = value.to_string();
+    event.push_attribute((key, quick_xml::escape::escape(value.as_bytes()).as_ref()));
+}
+
+///Pushes an xml-attribute if the value is Some, else the attribute is omitted
+pub(crate) fn push_attribute_option<T: Display>(
+    event: &mut BytesStart<'_>,
+    key: &[u8],
+    value: &Option<T>,
+) {
+    if let Some(value) = value {
+        push_attribute(event, key, value);
+    }
+}
+
+///Escapes characters that are not allowed in an xml-attribute.
+///
+/// Values are read from the xml without unescaping, so references that are already present are kept as they are. This way a value is written exactly the way it was read.
+fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for (i, c) in value.char_indices() {
+        match c {
+            '<' => out.push_str("&lt;"),
+            '"' => out.push_str("&quot;"),
+            '&' if !starts_with_reference(&value[i..]) => out.push_str("&amp;"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+///Returns true if the str starts with a valid xml-reference like `&amp;` or `&#123;`
+fn starts_with_reference(value: &str) -> bool {
+    let end = match value.find(';') {
+        None => return false,
+        Some(end) => end,
+    };
+    match &value[1..end] {
+        "amp" | "lt" | "gt" | "quot" | "apos" => true,
+        s => match s.strip_prefix('#') {
+            Some(s) => match s.strip_prefix('x') {
+                Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+                None => !s.is_empty() && s.chars().all(|c| c.is_ascii_digit()),
+            },
+            None => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::utils::write::escape;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("Plain text"), "Plain text");
+        assert_eq!(escape("A & B"), "A &amp; B");
+        assert_eq!(escape("A &amp; B"), "A &amp; B");
+        assert_eq!(escape("&#10;&#x0A;&#;&#xZ;"), "&#10;&#x0A;&amp;#;&amp;#xZ;");
+        assert_eq!(escape("<\"quoted\">"), "&lt;&quot;quoted&quot;>");
+        assert_eq!(escape("&unknown;"), "&amp;unknown;");
+        assert_eq!(escape("&"), "&amp;");
+    }
+}
@@ -0,0 +1,886 @@
+//! Checks a deparsed GDTF for content that is syntactically valid but inconsistent
+//!
+//! The parser only rejects descriptions that can't be deparsed. `validate` checks the deparsed `Gdtf` against rules of the GDTF specification and returns a `Diagnostic` for every violation.
+//! Resource files are not part of the deparsed `Gdtf`, so the rule `missing-resource` is only checked by `validate_archive`.
+//!
+//! ## Example
+//!
+//! ```rust
+//! use std::fs::File;
+//! use gdtf_parser::validate::{validate_archive, Severity};
+//!
+//! let file = File::open("test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf").unwrap();
+//! for diagnostic in validate_archive(file).unwrap() {
+//!     if diagnostic.severity == Severity::Error {
+//!         println!("{}", diagnostic);
+//!     }
+//! }
+//! ```
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fmt::{Display, Formatter};
+use std::io::{Read, Seek};
+
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+
+use crate::archive;
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::channel_function::ChannelFunction;
+use crate::fixture_type::dmx_mode::dmx_channel::logical_channel::LogicalChannel;
+use crate::fixture_type::dmx_mode::dmx_channel::{DmxBreak, DmxChannel};
+use crate::fixture_type::dmx_mode::DmxMode;
+use crate::fixture_type::wheel::Wheel;
+use crate::fixture_type::FixtureType;
+use crate::utils::errors::GdtfError;
+use crate::utils::raw_xml::RawXml;
+use crate::utils::read;
+use crate::utils::units::attribute_name::AttributeName;
+use crate::utils::units::dmx_value::DmxValue;
+use crate::utils::units::name::Name;
+use crate::Gdtf;
+
+///A rule of the GDTF specification that is checked by `validate`
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Rule {
+    ///`channel-set-dmx-from`: DMXFrom of channel sets is unique, ascending in the order of the description.xml and inside the range of their channel function
+    ChannelSetDmxFrom,
+    ///`wheel-slot-index`: WheelSlotIndex of channel sets is within the slot count of the wheel of their channel function
+    WheelSlotIndex,
+    ///`offset-collision`: DMX channels of a mode don't use the same address
+    OffsetCollision,
+    ///`channel-geometry`: The geometry of a DMX channel is part of the geometry of it's DMX mode
+    ChannelGeometry,
+    ///`duplicate-name`: Names are unique among siblings
+    DuplicateName,
+    ///`physical-unit`: The physical unit of a predefined attribute is the one of the GDTF specification
+    PhysicalUnit,
+    ///`missing-resource`: Resource files referenced in the description exist in the archive
+    MissingResource,
+}
+
+impl Rule {
+    ///Returns the ID of the rule that is used in reports
+    ///```rust
+    /// use gdtf_parser::validate::Rule;
+    /// assert_eq!(Rule::OffsetCollision.id(), "offset-collision");
+    /// ```
+    pub fn id(&self) -> &'static str {
+        match self {
+            Rule::ChannelSetDmxFrom => "channel-set-dmx-from",
+            Rule::WheelSlotIndex => "wheel-slot-index",
+            Rule::OffsetCollision => "offset-collision",
+            Rule::ChannelGeometry => "channel-geometry",
+            Rule::DuplicateName => "duplicate-name",
+            Rule::PhysicalUnit => "physical-unit",
+            Rule::MissingResource => "missing-resource",
+        }
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.id())
+    }
+}
+
+///Severity of a diagnostic
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Severity {
+    ///The fixture type is usable, but some information is probably wrong
+    Warning,
+    ///The fixture type can't be used as described
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+///A violation of a rule
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Diagnostic {
+    ///The violated rule
+    pub rule: Rule,
+    ///How severe the violation is
+    pub severity: Severity,
+    ///The path of the node that violates the rule in the style of a `Node`, for example `DMXModes.Mode 1.Beam_Dimmer`, or the path of a missing resource file. Duplicates are named by their renamed key, e.g. `Open {2}`, so such a path can't be deparsed as `Node`
+    pub path: String,
+    ///Description of the violation
+    pub message: String,
+}
+
+///Displays the diagnostic in the format `severity[rule-id] path: message`
+/// ```rust
+/// use gdtf_parser::validate::{Diagnostic, Rule, Severity};
+///
+/// let diagnostic = Diagnostic {
+///     rule: Rule::OffsetCollision,
+///     severity: Severity::Error,
+///     path: "DMXModes.Mode 1.Beam_Dimmer".to_string(),
+///     message: "Offset 1 is also used by Yoke_Pan".to_string(),
+/// };
+/// assert_eq!(diagnostic.to_string(), "error[offset-collision] DMXModes.Mode 1.Beam_Dimmer: Offset 1 is also used by Yoke_Pan");
+/// ```
+impl Display for Diagnostic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}[{}] {}: {}",
+            self.severity, self.rule, self.path, self.message
+        )
+    }
+}
+
+///Checks all rules that can be checked on the deparsed Gdtf. Use `validate_archive` to check resource files as well
+pub fn validate(gdtf: &Gdtf) -> Vec<Diagnostic> {
+    let fixture_type = &gdtf.fixture_type;
+    let mut diagnostics = Vec::new();
+    validate_attribute_definitions(fixture_type, &mut diagnostics);
+    let empty = HashMap::new();
+    let wheels = fixture_type.wheels.as_ref().unwrap_or(&empty);
+    validate_unique(
+        "DMXModes",
+        original_names(sorted(&fixture_type.dmx_modes).into_iter().map(|(k, _)| k)),
+        "DMXMode",
+        &mut diagnostics,
+    );
+    validate_wheels(wheels, &mut diagnostics);
+    let geometries = match fixture_type
+        .geometries
+        .as_ref()
+        .map(Geometries::from_raw_xml)
+    {
+        Some(Ok(geometries)) => geometries,
+        Some(Err(e)) => {
+            push(
+                &mut diagnostics,
+                Rule::ChannelGeometry,
+                Severity::Error,
+                "Geometries".to_string(),
+                format!("Geometries can't be read: {}", e),
+            );
+            Geometries::default()
+        }
+        None => Geometries::default(),
+    };
+    for (mode_name, dmx_mode) in sorted(&fixture_type.dmx_modes) {
+        let mode_path = format!("DMXModes.{}", mode_name);
+        geometries.validate(&mode_path, dmx_mode, &mut diagnostics);
+        validate_dmx_channel_names(&mode_path, dmx_mode, &mut diagnostics);
+        validate_offsets(&mode_path, dmx_mode, &mut diagnostics);
+        for dmx_channel in dmx_mode.dmx_channels.iter() {
+            validate_dmx_channel(&mode_path, dmx_channel, wheels, &mut diagnostics);
+        }
+    }
+    diagnostics
+}
+
+///Deparses a .gdtf archive and checks all rules including the resource files. Returns an error if the archive can't be deparsed
+pub fn validate_archive<R: Read + Seek>(reader: R) -> Result<Vec<Diagnostic>, GdtfError> {
+    let mut archive = zip::ZipArchive::new(reader)?;
+    let files: HashSet<String> = archive.file_names().map(|name| name.to_string()).collect();
+    let mut description_xml = String::new();
+    archive
+        .by_name("description.xml")?
+        .read_to_string(&mut description_xml)?;
+    let gdtf = Gdtf::from_description_xml(&description_xml)?;
+
+    let mut diagnostics = validate(&gdtf);
+    for path in archive::missing_resources(&gdtf, |path| files.contains(path)) {
+        let severity = if path.starts_with("wheels/") {
+            Severity::Error
+        } else {
+            Severity::Warning
+        };
+        diagnostics.push(Diagnostic {
+            rule: Rule::MissingResource,
+            severity,
+            message: "Referenced resource is missing in archive".to_string(),
+            path,
+        });
+    }
+    Ok(diagnostics)
+}
+
+fn push(
+    diagnostics: &mut Vec<Diagnostic>,
+    rule: Rule,
+    severity: Severity,
+    path: String,
+    message: String,
+) {
+    diagnostics.push(Diagnostic {
+        rule,
+        severity,
+        path,
+        message,
+    });
+}
+
+///Returns the entries of a hash-map sorted by their Name, so diagnostics are reported in a stable order
+fn sorted<T>(map: &HashMap<Name, T>) -> Vec<(&Name, &T)> {
+    let mut entries: Vec<(&Name, &T)> = map.iter().collect();
+    entries.sort_by(|(a, _), (b, _)| a.0.cmp(&b.0));
+    entries
+}
+
+///Returns the names of children stored in a map as they were written in the description.xml. Duplicates are renamed while deparsing, so they are found by `validate_unique` with their original names. Nameless children are valid more than once and are skipped
+fn original_names<'a, K: Display + 'a>(
+    keys: impl Iterator<Item = &'a K> + 'a,
+) -> impl Iterator<Item = String> + 'a {
+    keys.filter_map(|key| {
+        let key = key.to_string();
+        let name = read::original_key(&key).unwrap_or(&key).to_string();
+        if name.is_empty() {
+            None
+        } else {
+            Some(name)
+        }
+    })
+}
+
+///Reports every name that occurs more than once
+fn validate_unique(
+    path: &str,
+    names: impl Iterator<Item = String>,
+    kind: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut seen: HashSet<String> = HashSet::new();
+    let mut reported: HashSet<String> = HashSet::new();
+    for name in names {
+        if !seen.insert(name.clone()) && reported.insert(name.clone()) {
+            push(
+                diagnostics,
+                Rule::DuplicateName,
+                Severity::Error,
+                path.to_string(),
+                format!("{} {} is defined more than once", kind, name),
+            );
+        }
+    }
+}
+
+fn validate_attribute_definitions(fixture_type: &FixtureType, diagnostics: &mut Vec<Diagnostic>) {
+    let attribute_definitions = &fixture_type.attribute_definitions;
+    validate_unique(
+        "AttributeDefinitions.ActivationGroups",
+        attribute_definitions
+            .activation_groups
+            .iter()
+            .map(|name| name.to_string()),
+        "ActivationGroup",
+        diagnostics,
+    );
+    validate_unique(
+        "AttributeDefinitions.FeatureGroups",
+        original_names(
+            sorted(&attribute_definitions.feature_groups)
+                .into_iter()
+                .map(|(k, _)| k),
+        ),
+        "FeatureGroup",
+        diagnostics,
+    );
+    for (group_name, feature_group) in sorted(&attribute_definitions.feature_groups) {
+        validate_unique(
+            &format!("AttributeDefinitions.FeatureGroups.{}", group_name),
+            feature_group.features.iter().map(|name| name.to_string()),
+            "Feature",
+            diagnostics,
+        );
+    }
+    let mut attributes: Vec<_> = attribute_definitions.attributes.iter().collect();
+    attributes.sort_by_key(|(name, _)| name.to_string());
+    validate_unique(
+        "AttributeDefinitions.Attributes",
+        original_names(attributes.iter().map(|(k, _)| *k)),
+        "Attribute",
+        diagnostics,
+    );
+    for (attribute_name, attribute) in attributes {
+        if let AttributeName::UserDefined(_) = attribute_name {
+            continue;
+        }
+        let expected = attribute_name.default_physical_unit();
+        if attribute.physical_unit != expected {
+            push(
+                diagnostics,
+                Rule::PhysicalUnit,
+                Severity::Warning,
+                format!("AttributeDefinitions.Attributes.{}", attribute_name),
+                format!(
+                    "PhysicalUnit is {:?} but the GDTF specification defines {:?}",
+                    attribute.physical_unit, expected
+                ),
+            );
+        }
+    }
+}
+
+fn validate_wheels(wheels: &HashMap<Name, Wheel>, diagnostics: &mut Vec<Diagnostic>) {
+    validate_unique(
+        "Wheels",
+        original_names(sorted(wheels).into_iter().map(|(k, _)| k)),
+        "Wheel",
+        diagnostics,
+    );
+    for (wheel_name, wheel) in sorted(wheels) {
+        validate_unique(
+            &format!("Wheels.{}", wheel_name),
+            original_names(wheel.slots.keys()),
+            "Slot",
+            diagnostics,
+        );
+    }
+}
+
+fn validate_dmx_channel_names(
+    mode_path: &str,
+    dmx_mode: &DmxMode,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    validate_unique(
+        mode_path,
+        dmx_mode
+            .dmx_channels
+            .iter()
+            .map(|dmx_channel| dmx_channel.name().to_string()),
+        "DMXChannel",
+        diagnostics,
+    );
+    for dmx_channel in dmx_mode.dmx_channels.iter() {
+        validate_unique(
+            &format!("{}.{}", mode_path, dmx_channel.name()),
+            dmx_channel
+                .logical_channels
+                .iter()
+                .map(|logical_channel| logical_channel.attribute.to_string()),
+            "LogicalChannel",
+            diagnostics,
+        );
+    }
+}
+
+///Reports DMX channels that use an address already used by another DMX channel on the same DMXBreak. Channels with DMXBreak Overwrite are patched with their geometry reference and are not compared
+fn validate_offsets(mode_path: &str, dmx_mode: &DmxMode, diagnostics: &mut Vec<Diagnostic>) {
+    let mut used: HashMap<(u32, i32), Name> = HashMap::new();
+    for dmx_channel in dmx_mode.dmx_channels.iter() {
+        let dmx_break = match dmx_channel.dmx_break {
+            DmxBreak::Value(dmx_break) => dmx_break,
+            DmxBreak::Overwrite => continue,
+        };
+        let offset = match &dmx_channel.offset {
+            Some(offset) => offset,
+            None => continue,
+        };
+        let name = dmx_channel.name();
+        for address in offset.0.iter() {
+            match used.get(&(dmx_break, *address)) {
+                Some(other) if other != &name => push(
+                    diagnostics,
+                    Rule::OffsetCollision,
+                    Severity::Error,
+                    format!("{}.{}", mode_path, name),
+                    format!(
+                        "Offset {} on DMXBreak {} is also used by {}",
+                        address, dmx_break, other
+                    ),
+                ),
+                Some(_) => {}
+                None => {
+                    used.insert((dmx_break, *address), name.clone());
+                }
+            }
+        }
+    }
+}
+
+///Returns the byte count of the DMX channel. Virtual channels without offset use the highest byte count of their DMX values
+fn resolution(dmx_channel: &DmxChannel) -> u8 {
+    match &dmx_channel.offset {
+        Some(offset) if !offset.0.is_empty() => offset.0.len().min(4) as u8,
+        _ => dmx_channel
+            .logical_channels
+            .iter()
+            .flat_map(|logical_channel| logical_channel.channel_functions.values())
+            .map(|channel_function| channel_function.dmx_from.n)
+            .max()
+            .unwrap_or(1)
+            .clamp(1, 4),
+    }
+}
+
+fn validate_dmx_channel(
+    mode_path: &str,
+    dmx_channel: &DmxChannel,
+    wheels: &HashMap<Name, Wheel>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    for logical_channel in dmx_channel.logical_channels.iter() {
+        let logical_path = format!(
+            "{}.{}.{}",
+            mode_path,
+            dmx_channel.name(),
+            logical_channel.attribute
+        );
+        validate_unique(
+            &logical_path,
+            original_names(
+                sorted(&logical_channel.channel_functions)
+                    .into_iter()
+                    .map(|(k, _)| k),
+            ),
+            "ChannelFunction",
+            diagnostics,
+        );
+        for (function_name, channel_function) in sorted(&logical_channel.channel_functions) {
+            let function_path = format!("{}.{}", logical_path, function_name);
+            validate_unique(
+                &function_path,
+                original_names(channel_function.channel_sets.keys()),
+                "ChannelSet",
+                diagnostics,
+            );
+            validate_wheel_slot_indices(&function_path, channel_function, wheels, diagnostics);
+        }
+    }
+    validate_channel_set_dmx_from(mode_path, dmx_channel, diagnostics);
+}
+
+///Reports channel sets of the DMX channel with a DMXFrom outside of their channel function or not above DMXFrom of the previous channel set. Also used by `FixtureTypeBuilder::build`
+pub(crate) fn validate_channel_set_dmx_from(
+    mode_path: &str,
+    dmx_channel: &DmxChannel,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let bytes = resolution(dmx_channel);
+    let max = (1_u64 << (8 * u32::from(bytes))) - 1;
+    for logical_channel in dmx_channel.logical_channels.iter() {
+        for (function_name, channel_function) in sorted(&logical_channel.channel_functions) {
+            let function_path = format!(
+                "{}.{}.{}.{}",
+                mode_path,
+                dmx_channel.name(),
+                logical_channel.attribute,
+                function_name
+            );
+            let from = channel_function.dmx_from.to_resolution(bytes);
+            let to = function_end(logical_channel, channel_function, bytes).unwrap_or(max);
+            validate_channel_sets(
+                &function_path,
+                channel_function,
+                bytes,
+                (from, to),
+                diagnostics,
+            );
+        }
+    }
+}
+
+///Returns the last DMX value of a channel function, that is DMXFrom of the next channel function with the same ModeMaster - 1
+fn function_end(
+    logical_channel: &LogicalChannel,
+    channel_function: &ChannelFunction,
+    bytes: u8,
+) -> Option<u64> {
+    let from = channel_function.dmx_from.to_resolution(bytes);
+    let mode_master = channel_function
+        .mode_master
+        .as_ref()
+        .map(|mode_master| &mode_master.mode_master);
+    logical_channel
+        .channel_functions
+        .values()
+        .filter(|other| {
+            other
+                .mode_master
+                .as_ref()
+                .map(|mode_master| &mode_master.mode_master)
+                == mode_master
+        })
+        .map(|other| other.dmx_from.to_resolution(bytes))
+        .filter(|other_from| *other_from > from)
+        .min()
+        .map(|next_from| next_from - 1)
+}
+
+///The end of a channel set is DMXFrom of the next one - 1, so DMXFrom must be ascending in the order of the description.xml
+fn validate_channel_sets(
+    function_path: &str,
+    channel_function: &ChannelFunction,
+    bytes: u8,
+    (from, to): (u64, u64),
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut starts: HashMap<u64, &Name> = HashMap::new();
+    let mut previous: Option<(u64, &DmxValue)> = None;
+    for (set_name, channel_set) in channel_function.channel_sets.iter() {
+        let set_path = format!("{}.{}", function_path, set_name);
+        let set_from = channel_set.dmx_from.to_resolution(bytes);
+        if set_from < from || set_from > to {
+            push(
+                diagnostics,
+                Rule::ChannelSetDmxFrom,
+                Severity::Error,
+                set_path.clone(),
+                format!(
+                    "DMXFrom {} is outside of the channel function range {}..{}",
+                    channel_set.dmx_from, from, to
+                ),
+            );
+        }
+        match previous {
+            Some((previous_from, previous_dmx_from)) if set_from < previous_from => push(
+                diagnostics,
+                Rule::ChannelSetDmxFrom,
+                Severity::Error,
+                set_path.clone(),
+                format!(
+                    "DMXFrom {} is lower than DMXFrom {} of a previous channel set",
+                    channel_set.dmx_from, previous_dmx_from
+                ),
+            ),
+            _ => previous = Some((set_from, &channel_set.dmx_from)),
+        }
+        match starts.get(&set_from) {
+            Some(other) => push(
+                diagnostics,
+                Rule::ChannelSetDmxFrom,
+                Severity::Error,
+                set_path,
+                format!(
+                    "DMXFrom {} is also used by channel set {}",
+                    channel_set.dmx_from, other
+                ),
+            ),
+            None => {
+                starts.insert(set_from, set_name);
+            }
+        }
+    }
+}
+
+///WheelSlotIndex 0 is used by channel sets that don't select a slot and is not reported
+fn validate_wheel_slot_indices(
+    function_path: &str,
+    channel_function: &ChannelFunction,
+    wheels: &HashMap<Name, Wheel>,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let wheel = channel_function
+        .wheel
+        .as_ref()
+        .and_then(|wheel| wheel.0.last())
+        .and_then(|wheel| wheels.get(wheel).map(|slots| (wheel, slots)));
+    for (set_name, channel_set) in channel_function.channel_sets.iter() {
+        let index = match channel_set.wheel_slot_index {
+            Some(index) if index > 0 => index,
+            _ => continue,
+        };
+        let message = match wheel {
+            Some((_, w)) if usize::from(index) <= w.slots.len() => continue,
+            Some((wheel_name, w)) => format!(
+                "WheelSlotIndex {} is out of range, wheel {} has {} slots",
+                index,
+                wheel_name,
+                w.slots.len()
+            ),
+            None => format!(
+                "WheelSlotIndex {} is set but the channel function has no existing wheel",
+                index
+            ),
+        };
+        push(
+            diagnostics,
+            Rule::WheelSlotIndex,
+            Severity::Error,
+            format!("{}.{}", function_path, set_name),
+            message,
+        );
+    }
+}
+
+///Names of all geometries inside of the top level geometries, read from `FixtureType::geometries` because geometries are not deparsed
+#[derive(Debug, Default)]
+pub(crate) struct Geometries {
+    ///All geometry names inside of a top level geometry including it's own name, with the top level geometry as key
+    names: HashMap<String, HashSet<String>>,
+    ///Top level geometries referenced with GeometryReference inside of a top level geometry
+    references: HashMap<String, Vec<String>>,
+}
+
+impl Geometries {
+    ///Reads the names of the geometries from the raw xml of the node Geometries
+    pub(crate) fn from_raw_xml(geometries_xml: &RawXml) -> Result<Self, GdtfError> {
+        let mut geometries = Self::default();
+        let mut reader = Reader::from_str(geometries_xml.as_str());
+        let mut buf: Vec<u8> = Vec::new();
+        let mut in_geometries = false;
+        let mut depth = 0;
+        let mut top_level = String::new();
+        loop {
+            match reader.read_event(&mut buf)? {
+                Event::Start(e) if e.name() == b"Geometries" => in_geometries = true,
+                Event::End(e) if e.name() == b"Geometries" => break,
+                Event::Start(e) if in_geometries => {
+                    geometries.add(&e, depth, &mut top_level)?;
+                    depth += 1;
+                }
+                Event::Empty(e) if in_geometries => geometries.add(&e, depth, &mut top_level)?,
+                Event::End(_) if in_geometries => depth -= 1,
+                Event::Eof => break,
+                _ => {}
+            }
+            buf.clear();
+        }
+        Ok(geometries)
+    }
+
+    fn add(
+        &mut self,
+        event: &BytesStart<'_>,
+        depth: usize,
+        top_level: &mut String,
+    ) -> Result<(), GdtfError> {
+        let mut name = None;
+        let mut reference = None;
+        for attr in event.attributes() {
+            let attr = attr?;
+            match attr.key {
+                b"Name" => name = Some(read::attr_to_string(attr)),
+                b"Geometry" if event.name() == b"GeometryReference" => {
+                    reference = Some(read::attr_to_string(attr))
+                }
+                _ => {}
+            }
+        }
+        let name = match name {
+            Some(name) => name,
+            None => return Ok(()),
+        };
+        if depth == 0 {
+            *top_level = name.clone();
+        }
+        self.names
+            .entry(top_level.clone())
+            .or_default()
+            .insert(name);
+        if let Some(reference) = reference {
+            self.references
+                .entry(top_level.clone())
+                .or_default()
+                .push(reference);
+        }
+        Ok(())
+    }
+
+    ///Returns true if a top level geometry with the name exists
+    pub(crate) fn is_top_level(&self, geometry: &str) -> bool {
+        self.names.contains_key(geometry)
+    }
+
+    ///Returns all geometry names that can be used by DMX channels of a mode with the given top level geometry, including those of referenced geometries
+    pub(crate) fn names_of(&self, geometry: &str) -> HashSet<&str> {
+        let mut names = HashSet::new();
+        let mut visited = HashSet::new();
+        let mut stack = vec![geometry];
+        while let Some(top_level) = stack.pop() {
+            if !visited.insert(top_level) {
+                continue;
+            }
+            names.extend(
+                self.names
+                    .get(top_level)
+                    .into_iter()
+                    .flatten()
+                    .map(|name| name.as_str()),
+            );
+            stack.extend(
+                self.references
+                    .get(top_level)
+                    .into_iter()
+                    .flatten()
+                    .map(|name| name.as_str()),
+            );
+        }
+        names
+    }
+
+    ///Reports a top level geometry of the mode that does not exist and DMX channels with a geometry that is not part of it
+    fn validate(&self, mode_path: &str, dmx_mode: &DmxMode, diagnostics: &mut Vec<Diagnostic>) {
+        if !self.is_top_level(dmx_mode.geometry.as_str()) {
+            push(
+                diagnostics,
+                Rule::ChannelGeometry,
+                Severity::Error,
+                mode_path.to_string(),
+                format!("Geometry {} is not a top level geometry", dmx_mode.geometry),
+            );
+            return;
+        }
+        let names = self.names_of(&dmx_mode.geometry.0);
+        for dmx_channel in dmx_mode.dmx_channels.iter() {
+            if !names.contains(dmx_channel.geometry.as_str()) {
+                push(
+                    diagnostics,
+                    Rule::ChannelGeometry,
+                    Severity::Error,
+                    format!("{}.{}", mode_path, dmx_channel.name()),
+                    format!(
+                        "Geometry {} is not part of the geometry {} of the DMX mode",
+                        dmx_channel.geometry, dmx_mode.geometry
+                    ),
+                );
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::convert::TryFrom;
+    use std::fs::File;
+    use std::io::Cursor;
+    use std::path::Path;
+
+    use crate::archive::GdtfArchiveWriter;
+    use crate::fixture_type::dmx_mode::dmx_channel::Offset;
+    use crate::utils::units::attribute_name::AttributeName;
+    use crate::utils::units::dmx_value::DmxValue;
+    use crate::utils::units::name::Name;
+    use crate::utils::units::physical_unit::PhysicalUnit;
+    use crate::validate::{validate, validate_archive, Rule, Severity};
+    use crate::Gdtf;
+
+    const ACME: &str = "test/ACME@ACME_AE-610_BEAM@ACME_AE-610_BEAM.gdtf";
+
+    #[test]
+    fn test_validate_archive() {
+        assert_eq!(validate_archive(File::open(ACME).unwrap()).unwrap(), vec![]);
+
+        let diagnostics =
+            validate_archive(File::open("test/JB-Lighting@P12_Spot_HP@V_1.15.gdtf").unwrap())
+                .unwrap();
+        let errors: Vec<String> = diagnostics
+            .iter()
+            .filter(|d| d.severity == Severity::Error)
+            .map(|d| d.to_string())
+            .collect();
+        assert_eq!(
+            errors,
+            vec![
+                "error[duplicate-name] DMXModes.Mode 1 (35 ch): DMXChannel Head is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 1 (35 ch).Head_Shutter1.Shutter1.Shutter Rnd Pulse Close: ChannelSet rnd pulse close fast is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 1 (35 ch).Head_Shutter1.Shutter1.Shutter Rnd Pulse open: ChannelSet rnd pulse open fast is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 1 (35 ch).Head_SparkleSpeed.SparkleSpeed.Sparkle Faded: ChannelSet sparkle faded slow is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 1 (35 ch).Head_SparkleSpeed.SparkleSpeed.Sparkle Faded (2): ChannelSet sparkle faded slow (2) is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 1 (35 ch).Head_SparkleSpeed.SparkleSpeed.Sparkle Faded (3): ChannelSet sparkle faded slow (3) is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 2 (47 ch): DMXChannel Head is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 2 (47 ch).Head_Shutter1.Shutter1.Shutter Rnd Pulse Close: ChannelSet rnd pulse close fast is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 2 (47 ch).Head_Shutter1.Shutter1.Shutter Rnd Pulse open: ChannelSet rnd pulse open fast is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 2 (47 ch).Head_SparkleSpeed.SparkleSpeed.Sparkle Faded: ChannelSet sparkle faded slow is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 2 (47 ch).Head_SparkleSpeed.SparkleSpeed.Sparkle Faded (2): ChannelSet sparkle faded slow (2) is defined more than once",
+                "error[duplicate-name] DMXModes.Mode 2 (47 ch).Head_SparkleSpeed.SparkleSpeed.Sparkle Faded (3): ChannelSet sparkle faded slow (3) is defined more than once",
+            ]
+        );
+        assert!(diagnostics
+            .iter()
+            .filter(|d| d.severity == Severity::Warning)
+            .all(|d| d.rule == Rule::PhysicalUnit));
+
+        //Written archives contain the geometries but no resources
+        let gdtf = Gdtf::try_from(Path::new(ACME)).unwrap();
+        let mut archive = Cursor::new(Vec::new());
+        let mut archive_writer = GdtfArchiveWriter::new(&gdtf);
+        archive_writer
+            .keep_resources_from_file(Path::new(ACME))
+            .unwrap();
+        archive_writer.remove_resource("wheels/201906022.png");
+        archive_writer
+            .write(&mut Cursor::new(Vec::new()))
+            .unwrap_err();
+        let missing = archive_writer.missing_resources();
+        for path in missing.iter() {
+            archive_writer.set_resource(path, vec![]);
+        }
+        archive_writer.write(&mut archive).unwrap();
+        archive.set_position(0);
+        let diagnostics = validate_archive(archive).unwrap();
+        assert!(diagnostics
+            .iter()
+            .all(|d| d.severity == Severity::Warning && d.rule == Rule::PhysicalUnit));
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut gdtf = Gdtf::try_from(Path::new(ACME)).unwrap();
+        assert_eq!(validate(&gdtf), vec![]);
+
+        let fixture_type = &mut gdtf.fixture_type;
+        let dmx_mode = fixture_type.dmx_modes.values_mut().next().unwrap();
+        dmx_mode.dmx_channels[2].offset = Some(Offset(vec![4]));
+        dmx_mode.dmx_channels[3].geometry = Name::new("Unknown").unwrap();
+        let pan = dmx_mode.dmx_channels[0].logical_channels[0].clone();
+        dmx_mode.dmx_channels[0].logical_channels.push(pan);
+        let pan_function = dmx_mode.dmx_channels[0].logical_channels[0]
+            .channel_functions
+            .values_mut()
+            .next()
+            .unwrap();
+        let center = pan_function
+            .channel_sets
+            .get_mut(&Name::new("Center").unwrap())
+            .unwrap();
+        center.dmx_from = DmxValue::new_from_str("0/2").unwrap();
+        let gobo_function = dmx_mode.dmx_channels[6].logical_channels[0]
+            .channel_functions
+            .values_mut()
+            .find(|channel_function| channel_function.wheel.is_some())
+            .unwrap();
+        gobo_function
+            .channel_sets
+            .values_mut()
+            .next()
+            .unwrap()
+            .wheel_slot_index = Some(200);
+        fixture_type
+            .attribute_definitions
+            .attributes
+            .get_mut(&AttributeName::Pan)
+            .unwrap()
+            .physical_unit = PhysicalUnit::Length;
+
+        let diagnostics = validate(&gdtf);
+        let rules = |rule: Rule| diagnostics.iter().filter(|d| d.rule == rule).count();
+        assert_eq!(rules(Rule::OffsetCollision), 1);
+        assert_eq!(rules(Rule::DuplicateName), 1);
+        assert_eq!(rules(Rule::ChannelSetDmxFrom), 2);
+        assert_eq!(rules(Rule::WheelSlotIndex), 1);
+        assert_eq!(rules(Rule::PhysicalUnit), 1);
+        assert_eq!(rules(Rule::ChannelGeometry), 1);
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.to_string()
+                == "error[offset-collision] DMXModes.Mode 1 12 DMX.Head_PT Speed: Offset 4 on DMXBreak 1 is also used by Head_Tilt"));
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.to_string()
+                == "error[channel-set-dmx-from] DMXModes.Mode 1 12 DMX.Yoke_Pan.Pan.Pan 1.Center: DMXFrom 0/2 is lower than DMXFrom 1/2 of a previous channel set"));
+
+        gdtf.fixture_type.geometries = None;
+        assert!(validate(&gdtf)
+            .iter()
+            .any(|d| d.rule == Rule::ChannelGeometry
+                && d.path == "DMXModes.Mode 1 12 DMX"
+                && d.message == "Geometry Base is not a top level geometry"));
+    }
+}